@@ -9,6 +9,12 @@ pub struct AddressResolutionPacketProcessing<EINPDO: EthernetIncomingNetworkPack
 	dropped_packet_reporting: Rc<EINPDO>,
 	
 	our_valid_internet_protocol_version_4_host_addresses: Rc<OurValidInternetProtocolVersion4HostAddresses>,
+	
+	inspection: Option<AddressResolutionProtocolInspection>,
+	
+	proxy: Option<AddressResolutionProtocolProxy>,
+	
+	link_local_address_autoconfiguration: Option<Rc<LinkLocalAddressAutoconfiguration>>,
 }
 
 impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=AddressResolutionProtocolIncomingNetworkPacketDropReason>> Layer3PacketProcessing for AddressResolutionPacketProcessing<EINPDO>
@@ -41,6 +47,7 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=AddressResolutio
 		{
 			now,
 			ethernet_addresses,
+			internal_priority: packet.internal_priority(),
 			reason,
 		};
 		
@@ -51,6 +58,56 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=AddressResolutio
 
 impl<EINPDO: EthernetIncomingNetworkPacketDropObserver> AddressResolutionPacketProcessing<EINPDO>
 {
+	/// Creates a new instance.
+	///
+	/// `inspection` enables dynamic ARP inspection; `proxy` enables proxy ARP; `link_local_address_autoconfiguration` enables conflict detection for a link-local address.
+	#[inline(always)]
+	pub fn new(dropped_packet_reporting: Rc<EINPDO>, our_valid_internet_protocol_version_4_host_addresses: Rc<OurValidInternetProtocolVersion4HostAddresses>, inspection: Option<AddressResolutionProtocolInspection>, proxy: Option<AddressResolutionProtocolProxy>, link_local_address_autoconfiguration: Option<Rc<LinkLocalAddressAutoconfiguration>>) -> Self
+	{
+		Self
+		{
+			dropped_packet_reporting,
+			our_valid_internet_protocol_version_4_host_addresses,
+			inspection,
+			proxy,
+			link_local_address_autoconfiguration,
+		}
+	}
+	
+	/// Dynamic ARP inspection, if any, eg to add bindings learned from Dynamic Host Configuration Protocol (DHCP) leases.
+	#[inline(always)]
+	pub fn inspection(&self) -> Option<&AddressResolutionProtocolInspection>
+	{
+		self.inspection.as_ref()
+	}
+	
+	/// Proxy ARP, if any, eg to set a route look up or transmitter.
+	#[inline(always)]
+	pub fn proxy(&self) -> Option<&AddressResolutionProtocolProxy>
+	{
+		self.proxy.as_ref()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn inspect(&self, now: MonotonicMillisecondTimestamp, header: NonNull<AddressResolutionProtocolPacketHeader>, source_ethernet_address: &MediaAccessControlAddress, payload: &AddressResolutionProtocolPacketInternetProtocolVersion4Payload) -> Result<(), AddressResolutionProtocolIncomingNetworkPacketDropReason>
+	{
+		match self.inspection
+		{
+			None => Ok(()),
+			
+			Some(ref inspection) => inspection.inspect(now, header, source_ethernet_address, payload),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn detect_link_local_address_conflict(&self, now: MonotonicMillisecondTimestamp, payload: &AddressResolutionProtocolPacketInternetProtocolVersion4Payload)
+	{
+		if let Some(ref link_local_address_autoconfiguration) = self.link_local_address_autoconfiguration
+		{
+			link_local_address_autoconfiguration.detect_conflict(now, payload)
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn is_internet_protocol_version_4_host_address_one_of_ours(&self, internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress) -> bool
 	{
@@ -71,6 +128,27 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver> AddressResolutionPacketP
 		arp_unsupported!("replies to broadcasts are not supported");
 	}
 	
+	#[inline(always)]
+	pub(crate) fn is_proxied(&self, target_protocol_address: InternetProtocolVersion4HostAddress) -> bool
+	{
+		match self.proxy
+		{
+			None => false,
+			
+			Some(ref proxy) => proxy.should_answer_for(target_protocol_address),
+		}
+	}
+	
+	/// Only called if `is_proxied()` is true.
+	#[inline(always)]
+	pub(crate) fn reply_on_behalf_of(&self, payload: &AddressResolutionProtocolPacketInternetProtocolVersion4Payload)
+	{
+		if let Some(ref proxy) = self.proxy
+		{
+			proxy.reply(payload.target_protocol_address, payload.sender_hardware_address, payload.sender_protocol_address)
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn add_to_address_resolution_cache(&self, _sender_hardware_address: &MediaAccessControlAddress, _sender_protocol_address: InternetProtocolVersion4HostAddress, packet: impl EthernetIncomingNetworkPacket)
 	{
@@ -0,0 +1,35 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Address Resolution Protocol (ARP) packet processing configuration.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct AddressResolutionPacketProcessingConfiguration
+{
+	/// Dynamic ARP inspection.
+	///
+	/// Defaults to none.
+	#[serde(default)] pub inspection: Option<AddressResolutionProtocolInspectionConfiguration>,
+	
+	/// Proxy ARP.
+	///
+	/// Defaults to none.
+	#[serde(default)] pub proxy: Option<AddressResolutionProtocolProxyConfiguration>,
+}
+
+impl AddressResolutionPacketProcessingConfiguration
+{
+	/// Configure.
+	///
+	/// `our_valid_unicast_ethernet_address` is used to answer for proxied addresses; `ingress_network_interface_index` identifies the network interface requests are received on, for the proxy's route look up.
+	///
+	/// `link_local_address_autoconfiguration`, if any, is told of every valid ARP packet so that it can detect address conflicts.
+	#[inline(always)]
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver>(self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_internet_protocol_version_4_host_addresses: &Rc<OurValidInternetProtocolVersion4HostAddresses>, our_valid_unicast_ethernet_address: MediaAccessControlAddress, ingress_network_interface_index: u32, link_local_address_autoconfiguration: Option<&Rc<LinkLocalAddressAutoconfiguration>>) -> AddressResolutionPacketProcessing<EINPDO>
+	{
+		let inspection = self.inspection.map(|inspection| inspection.configure());
+		let proxy = self.proxy.map(|proxy| proxy.configure(our_valid_unicast_ethernet_address, ingress_network_interface_index));
+		AddressResolutionPacketProcessing::new(dropped_packet_reporting.clone(), our_valid_internet_protocol_version_4_host_addresses.clone(), inspection, proxy, link_local_address_autoconfiguration.cloned())
+	}
+}
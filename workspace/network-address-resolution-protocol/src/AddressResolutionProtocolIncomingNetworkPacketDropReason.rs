@@ -143,6 +143,46 @@ pub enum AddressResolutionProtocolIncomingNetworkPacketDropReason
 		#[serde(serialize_with = "AddressResolutionProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
 		header: NonNull<AddressResolutionProtocolPacketHeader>,
 	},
+	
+	/// Occurs during Address Resolution Protocol (ARP) inspection.
+	///
+	/// Too many ARP packets were received from the source ethernet address in the current interval, or too many source ethernet addresses have already been seen in the current interval.
+	RateLimitExceeded
+	{
+		/// Address Reolution Protocol (ARP) packet header.
+		#[serde(serialize_with = "AddressResolutionProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<AddressResolutionProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Address Resolution Protocol (ARP) inspection.
+	///
+	/// The sender protocol address does not have a binding (or its binding has expired).
+	SenderIsNotBound
+	{
+		/// Address Reolution Protocol (ARP) packet header.
+		#[serde(serialize_with = "AddressResolutionProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<AddressResolutionProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Address Resolution Protocol (ARP) inspection.
+	///
+	/// The sender hardware address is not the ethernet address bound to the sender protocol address; this is likely to be ARP spoofing.
+	SenderHardwareAddressDoesNotMatchBinding
+	{
+		/// Address Reolution Protocol (ARP) packet header.
+		#[serde(serialize_with = "AddressResolutionProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<AddressResolutionProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Address Resolution Protocol (ARP) inspection.
+	///
+	/// Gratuitous ARP for the sender protocol address has changed ethernet address too many times in the current interval.
+	GratuitousIsFlapping
+	{
+		/// Address Reolution Protocol (ARP) packet header.
+		#[serde(serialize_with = "AddressResolutionProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<AddressResolutionProtocolPacketHeader>,
+	},
 }
 
 impl IncomingNetworkPacketProcessingDropReason for AddressResolutionProtocolIncomingNetworkPacketDropReason
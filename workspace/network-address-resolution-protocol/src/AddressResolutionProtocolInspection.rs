@@ -0,0 +1,227 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Dynamic Address Resolution Protocol (ARP) inspection.
+///
+/// The sender of ARP requests and replies is validated against a binding table of internet protocol (IP) version 4 host addresses to ethernet addresses; bindings are either static or learned from Dynamic Host Configuration Protocol (DHCP) leases.
+///
+/// Gratuitous ARP for the same internet protocol (IP) version 4 host address from different ethernet addresses (flapping) is detected, and ARP is rate limited per source ethernet address.
+///
+/// Flapping and rate limits are counted over fixed intervals; at the end of each interval, all counts are discarded, which bounds memory use when flooded.
+///
+/// Rate limits are also only counted for a maximum number of source ethernet addresses in each interval; once full, ARP from other source ethernet addresses is dropped until the next interval, so a flood from many spoofed source ethernet addresses can not exhaust memory.
+#[derive(Debug)]
+pub struct AddressResolutionProtocolInspection
+{
+	drop_unbound_senders: bool,
+	
+	bindings: RefCell<HashMap<InternetProtocolVersion4HostAddress, AddressResolutionProtocolInspectionBinding>>,
+	
+	flapping_interval: MillisecondDuration,
+	
+	maximum_flaps_per_interval: u8,
+	
+	flapping_interval_started_at: Cell<MonotonicMillisecondTimestamp>,
+	
+	flapping: RefCell<HashMap<InternetProtocolVersion4HostAddress, (MediaAccessControlAddress, u8)>>,
+	
+	rate_limit_interval: MillisecondDuration,
+	
+	maximum_packets_per_rate_limit_interval: u16,
+	
+	rate_limit_interval_started_at: Cell<MonotonicMillisecondTimestamp>,
+	
+	rate_limits: RefCell<BoundedHashMap<MediaAccessControlAddress, u16>>,
+}
+
+impl AddressResolutionProtocolInspection
+{
+	/// Creates a new instance without any bindings.
+	///
+	/// If `drop_unbound_senders` is false, senders without a binding are permitted, and only senders whose ethernet address does not match their binding are dropped.
+	///
+	/// `maximum_rate_limited_source_ethernet_addresses` bounds the number of source ethernet addresses rate limited in an interval.
+	#[inline(always)]
+	pub fn new(drop_unbound_senders: bool, flapping_interval: MillisecondDuration, maximum_flaps_per_interval: u8, rate_limit_interval: MillisecondDuration, maximum_packets_per_rate_limit_interval: u16, maximum_rate_limited_source_ethernet_addresses: usize) -> Self
+	{
+		Self
+		{
+			drop_unbound_senders,
+			bindings: RefCell::new(HashMap::default()),
+			flapping_interval,
+			maximum_flaps_per_interval,
+			flapping_interval_started_at: Cell::new(MonotonicMillisecondTimestamp::Zero),
+			flapping: RefCell::new(HashMap::default()),
+			rate_limit_interval,
+			maximum_packets_per_rate_limit_interval,
+			rate_limit_interval_started_at: Cell::new(MonotonicMillisecondTimestamp::Zero),
+			rate_limits: RefCell::new(BoundedHashMap::new(maximum_rate_limited_source_ethernet_addresses)),
+		}
+	}
+	
+	/// Binds (or rebinds) an internet protocol (IP) version 4 host address to an ethernet address indefinitely.
+	#[inline(always)]
+	pub fn bind_statically(&self, sender_protocol_address: InternetProtocolVersion4HostAddress, sender_hardware_address: MediaAccessControlAddress)
+	{
+		self.bind(sender_protocol_address, AddressResolutionProtocolInspectionBinding { hardware_address: sender_hardware_address, expires_at: None })
+	}
+	
+	/// Binds (or rebinds) an internet protocol (IP) version 4 host address to an ethernet address for the duration of a Dynamic Host Configuration Protocol (DHCP) lease, eg when snooping a DHCPACK.
+	///
+	/// Does not replace a static binding.
+	#[inline(always)]
+	pub fn bind_from_dynamic_host_configuration_protocol_lease(&self, now: MonotonicMillisecondTimestamp, sender_protocol_address: InternetProtocolVersion4HostAddress, sender_hardware_address: MediaAccessControlAddress, lease_duration: MillisecondDuration)
+	{
+		if let Some(binding) = self.binding(sender_protocol_address)
+		{
+			if binding.expires_at.is_none()
+			{
+				return
+			}
+		}
+		
+		self.bind(sender_protocol_address, AddressResolutionProtocolInspectionBinding { hardware_address: sender_hardware_address, expires_at: Some(now + lease_duration) })
+	}
+	
+	/// Removes a binding, eg when a Dynamic Host Configuration Protocol (DHCP) lease is released; returns it if it existed.
+	#[inline(always)]
+	pub fn unbind(&self, sender_protocol_address: InternetProtocolVersion4HostAddress) -> Option<AddressResolutionProtocolInspectionBinding>
+	{
+		self.bindings.borrow_mut().remove(&sender_protocol_address)
+	}
+	
+	/// Binding, if any; it may have expired.
+	#[inline(always)]
+	pub fn binding(&self, sender_protocol_address: InternetProtocolVersion4HostAddress) -> Option<AddressResolutionProtocolInspectionBinding>
+	{
+		self.bindings.borrow().get(&sender_protocol_address).cloned()
+	}
+	
+	/// Inspects the sender of an internet protocol (IP) version 4 ARP request or reply.
+	#[inline(always)]
+	pub(crate) fn inspect(&self, now: MonotonicMillisecondTimestamp, header: NonNull<AddressResolutionProtocolPacketHeader>, source_ethernet_address: &MediaAccessControlAddress, payload: &AddressResolutionProtocolPacketInternetProtocolVersion4Payload) -> Result<(), AddressResolutionProtocolIncomingNetworkPacketDropReason>
+	{
+		if unlikely!(self.is_rate_limit_exceeded(now, source_ethernet_address))
+		{
+			return Err(RateLimitExceeded { header })
+		}
+		
+		let sender_protocol_address = payload.sender_protocol_address;
+		
+		// An ARP probe does not have a sender protocol address to validate.
+		if sender_protocol_address.is_unspecified()
+		{
+			return Ok(())
+		}
+		
+		let sender_hardware_address = payload.sender_hardware_address;
+		
+		match self.bound_hardware_address(now, sender_protocol_address)
+		{
+			None => if unlikely!(self.drop_unbound_senders)
+			{
+				return Err(SenderIsNotBound { header })
+			},
+			
+			Some(bound_hardware_address) => if unlikely!(bound_hardware_address != sender_hardware_address)
+			{
+				return Err(SenderHardwareAddressDoesNotMatchBinding { header })
+			},
+		}
+		
+		let is_gratuitous = sender_protocol_address == payload.target_protocol_address;
+		if is_gratuitous
+		{
+			if unlikely!(self.is_flapping(now, sender_protocol_address, sender_hardware_address))
+			{
+				return Err(GratuitousIsFlapping { header })
+			}
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn bind(&self, sender_protocol_address: InternetProtocolVersion4HostAddress, binding: AddressResolutionProtocolInspectionBinding)
+	{
+		debug_assert!(sender_protocol_address.is_valid_unicast(), "sender_protocol_address '{}' is not valid unicast", sender_protocol_address);
+		debug_assert!(binding.hardware_address.is_valid_unicast(), "hardware_address '{}' is not valid unicast", binding.hardware_address);
+		
+		self.bindings.borrow_mut().insert(sender_protocol_address, binding);
+	}
+	
+	#[inline(always)]
+	fn bound_hardware_address(&self, now: MonotonicMillisecondTimestamp, sender_protocol_address: InternetProtocolVersion4HostAddress) -> Option<MediaAccessControlAddress>
+	{
+		let mut bindings = self.bindings.borrow_mut();
+		
+		match bindings.get(&sender_protocol_address).cloned()
+		{
+			None => None,
+			
+			Some(binding) => if binding.has_expired(now)
+			{
+				bindings.remove(&sender_protocol_address);
+				None
+			}
+			else
+			{
+				Some(binding.hardware_address)
+			},
+		}
+	}
+	
+	#[inline(always)]
+	fn is_flapping(&self, now: MonotonicMillisecondTimestamp, sender_protocol_address: InternetProtocolVersion4HostAddress, sender_hardware_address: MediaAccessControlAddress) -> bool
+	{
+		let mut flapping = self.flapping.borrow_mut();
+		if Self::start_new_interval_if_elapsed(now, &self.flapping_interval_started_at, self.flapping_interval)
+		{
+			flapping.clear()
+		}
+		
+		let &mut (ref mut last_sender_hardware_address, ref mut flaps) = flapping.entry(sender_protocol_address).or_insert((sender_hardware_address, 0));
+		if *last_sender_hardware_address != sender_hardware_address
+		{
+			*last_sender_hardware_address = sender_hardware_address;
+			*flaps = flaps.saturating_add(1);
+		}
+		*flaps > self.maximum_flaps_per_interval
+	}
+	
+	#[inline(always)]
+	fn is_rate_limit_exceeded(&self, now: MonotonicMillisecondTimestamp, source_ethernet_address: &MediaAccessControlAddress) -> bool
+	{
+		let mut rate_limits = self.rate_limits.borrow_mut();
+		if Self::start_new_interval_if_elapsed(now, &self.rate_limit_interval_started_at, self.rate_limit_interval)
+		{
+			rate_limits.clear()
+		}
+		
+		if let Some(packets) = rate_limits.get_mut(source_ethernet_address)
+		{
+			*packets = packets.saturating_add(1);
+			return *packets > self.maximum_packets_per_rate_limit_interval
+		}
+		
+		if unlikely!(rate_limits.is_full())
+		{
+			return true
+		}
+		
+		let packets = rate_limits.insert_uniquely_and_return_mutable_reference(*source_ethernet_address, 1);
+		*packets > self.maximum_packets_per_rate_limit_interval
+	}
+	
+	#[inline(always)]
+	fn start_new_interval_if_elapsed(now: MonotonicMillisecondTimestamp, interval_started_at: &Cell<MonotonicMillisecondTimestamp>, interval: MillisecondDuration) -> bool
+	{
+		let elapsed = now - interval_started_at.get() >= interval;
+		if elapsed
+		{
+			interval_started_at.set(now);
+		}
+		elapsed
+	}
+}
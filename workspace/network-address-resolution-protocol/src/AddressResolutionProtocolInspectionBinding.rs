@@ -0,0 +1,32 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A binding of an internet protocol (IP) version 4 host address to an ethernet address.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct AddressResolutionProtocolInspectionBinding
+{
+	/// Bound ethernet address.
+	pub hardware_address: MediaAccessControlAddress,
+	
+	/// When this binding expires, eg at the end of a Dynamic Host Configuration Protocol (DHCP) lease.
+	///
+	/// Static bindings never expire.
+	pub expires_at: Option<MonotonicMillisecondTimestamp>,
+}
+
+impl AddressResolutionProtocolInspectionBinding
+{
+	/// Has this binding expired?
+	#[inline(always)]
+	pub fn has_expired(&self, now: MonotonicMillisecondTimestamp) -> bool
+	{
+		match self.expires_at
+		{
+			None => false,
+			
+			Some(expires_at) => now >= expires_at,
+		}
+	}
+}
@@ -0,0 +1,111 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Dynamic Address Resolution Protocol (ARP) inspection configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct AddressResolutionProtocolInspectionConfiguration
+{
+	/// Static bindings of internet protocol (IP) version 4 host addresses to ethernet addresses.
+	#[serde(default)] pub static_bindings: HashMap<InternetProtocolVersion4HostAddress, MediaAccessControlAddress>,
+	
+	/// Drop ARP from senders without a binding.
+	///
+	/// Defaults to true.
+	#[serde(default = "AddressResolutionProtocolInspectionConfiguration::drop_unbound_senders_default")] pub drop_unbound_senders: bool,
+	
+	/// Interval over which gratuitous ARP flapping is counted.
+	///
+	/// Defaults to 10 seconds.
+	#[serde(default = "AddressResolutionProtocolInspectionConfiguration::flapping_interval_default")] pub flapping_interval: MillisecondDuration,
+	
+	/// Maximum number of times gratuitous ARP for the same internet protocol (IP) version 4 host address can change ethernet address in an interval.
+	///
+	/// Defaults to 2.
+	#[serde(default = "AddressResolutionProtocolInspectionConfiguration::maximum_flaps_per_interval_default")] pub maximum_flaps_per_interval: u8,
+	
+	/// Interval over which ARP is rate limited.
+	///
+	/// Defaults to 1 second.
+	#[serde(default = "AddressResolutionProtocolInspectionConfiguration::rate_limit_interval_default")] pub rate_limit_interval: MillisecondDuration,
+	
+	/// Maximum number of ARP packets accepted from a source ethernet address in an interval.
+	///
+	/// Defaults to 15.
+	#[serde(default = "AddressResolutionProtocolInspectionConfiguration::maximum_packets_per_rate_limit_interval_default")] pub maximum_packets_per_rate_limit_interval: u16,
+	
+	/// Maximum number of source ethernet addresses rate limited in an interval; ARP from further source ethernet addresses is dropped until the next interval.
+	///
+	/// Defaults to 4,096.
+	#[serde(default = "AddressResolutionProtocolInspectionConfiguration::maximum_rate_limited_source_ethernet_addresses_default")] pub maximum_rate_limited_source_ethernet_addresses: usize,
+}
+
+impl Default for AddressResolutionProtocolInspectionConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			static_bindings: HashMap::default(),
+			drop_unbound_senders: Self::drop_unbound_senders_default(),
+			flapping_interval: Self::flapping_interval_default(),
+			maximum_flaps_per_interval: Self::maximum_flaps_per_interval_default(),
+			rate_limit_interval: Self::rate_limit_interval_default(),
+			maximum_packets_per_rate_limit_interval: Self::maximum_packets_per_rate_limit_interval_default(),
+			maximum_rate_limited_source_ethernet_addresses: Self::maximum_rate_limited_source_ethernet_addresses_default(),
+		}
+	}
+}
+
+impl AddressResolutionProtocolInspectionConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self) -> AddressResolutionProtocolInspection
+	{
+		let inspection = AddressResolutionProtocolInspection::new(self.drop_unbound_senders, self.flapping_interval, self.maximum_flaps_per_interval, self.rate_limit_interval, self.maximum_packets_per_rate_limit_interval, self.maximum_rate_limited_source_ethernet_addresses);
+		for (sender_protocol_address, sender_hardware_address) in self.static_bindings
+		{
+			inspection.bind_statically(sender_protocol_address, sender_hardware_address)
+		}
+		inspection
+	}
+	
+	#[inline(always)]
+	fn drop_unbound_senders_default() -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn flapping_interval_default() -> MillisecondDuration
+	{
+		MillisecondDuration::TenSeconds
+	}
+	
+	#[inline(always)]
+	fn maximum_flaps_per_interval_default() -> u8
+	{
+		2
+	}
+	
+	#[inline(always)]
+	fn rate_limit_interval_default() -> MillisecondDuration
+	{
+		MillisecondDuration::OneSecond
+	}
+	
+	#[inline(always)]
+	fn maximum_packets_per_rate_limit_interval_default() -> u16
+	{
+		15
+	}
+	
+	#[inline(always)]
+	fn maximum_rate_limited_source_ethernet_addresses_default() -> usize
+	{
+		4096
+	}
+}
@@ -170,6 +170,14 @@ impl AddressResolutionProtocolPacket
 		}
 
 		debug_assert!(destination_ethernet_address.is_valid_unicast() || destination_ethernet_address.is_broadcast(), "destination_ethernet_address '{}' is not valid unicast or broadcast()", destination_ethernet_address);
+		
+		// RFC 3927 Section 2.5: conflicts must be detected from every ARP packet, including those dynamic ARP inspection would drop, eg as the sender is not bound.
+		packet_processing.detect_link_local_address_conflict(now, self.internet_protocol_version_4_payload());
+		
+		if let Err(reason) = packet_processing.inspect(now, header, source_ethernet_address, self.internet_protocol_version_4_payload())
+		{
+			drop!(now, reason, ethernet_addresses, packet_processing, packet)
+		}
 
 		match self.header.operation
 		{
@@ -272,6 +280,13 @@ impl AddressResolutionProtocolPacket
 				drop!(now, ReuseInReply, ethernet_addresses, packet_processing, packet);
 			}
 			
+			let we_proxy_the_target_protocol_address_so_reply = packet_processing.is_proxied(target_protocol_address);
+			if we_proxy_the_target_protocol_address_so_reply
+			{
+				packet_processing.reply_on_behalf_of(payload);
+				drop!(now, ReuseInReply, ethernet_addresses, packet_processing, packet);
+			}
+			
 			drop!(now, BroadcastIsNotForUs { header }, ethernet_addresses, packet_processing, packet)
 		}
 	}
@@ -0,0 +1,144 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Proxy Address Resolution Protocol (ARP) (RFC 1027), used to answer ARP requests on behalf of internet protocol (IP) version 4 host addresses that are not ours, eg when fronting a routed subnet on a flat layer 2 segment.
+///
+/// Requests are answered using our ethernet address if their target protocol address is within one of the proxied prefixes and is not within one of the exclusions.
+///
+/// ARP probes are never answered, as this would make every proxied address appear to be in use.
+///
+/// Nothing is answered until a transmitter has been set with `set_transmitter()`.
+#[derive(Debug)]
+pub struct AddressResolutionProtocolProxy
+{
+	our_valid_unicast_ethernet_address: MediaAccessControlAddress,
+	
+	ingress_network_interface_index: u32,
+	
+	prefixes: Vec<InternetProtocolVersion4NetworkAddress>,
+	
+	exclusions: Vec<InternetProtocolVersion4NetworkAddress>,
+	
+	answer_only_when_routed_out_of_a_different_network_interface: bool,
+	
+	route_look_up: RefCell<Option<Rc<dyn AddressResolutionProtocolProxyRouteLookUp>>>,
+	
+	transmitter: RefCell<Option<Rc<dyn AddressResolutionProtocolProxyTransmitter>>>,
+}
+
+impl AddressResolutionProtocolProxy
+{
+	/// Size of a reply frame, excluding the frame check sequence.
+	pub const ReplyFrameSize: usize = 42;
+	
+	/// Creates a new instance.
+	///
+	/// `ingress_network_interface_index` identifies the network interface requests are received on, eg a Linux `ifindex`; it is passed to the route look up.
+	///
+	/// If `answer_only_when_routed_out_of_a_different_network_interface` is true, nothing is answered until a route look up has been set with `set_route_look_up()`.
+	#[inline(always)]
+	pub fn new(our_valid_unicast_ethernet_address: MediaAccessControlAddress, ingress_network_interface_index: u32, prefixes: Vec<InternetProtocolVersion4NetworkAddress>, exclusions: Vec<InternetProtocolVersion4NetworkAddress>, answer_only_when_routed_out_of_a_different_network_interface: bool) -> Self
+	{
+		debug_assert!(our_valid_unicast_ethernet_address.is_valid_unicast(), "our_valid_unicast_ethernet_address '{}' is not valid unicast", our_valid_unicast_ethernet_address);
+		
+		Self
+		{
+			our_valid_unicast_ethernet_address,
+			ingress_network_interface_index,
+			prefixes,
+			exclusions,
+			answer_only_when_routed_out_of_a_different_network_interface,
+			route_look_up: RefCell::new(None),
+			transmitter: RefCell::new(None),
+		}
+	}
+	
+	/// Sets (or replaces) the route look up used if answering only when the route to the target points out of a different network interface.
+	#[inline(always)]
+	pub fn set_route_look_up(&self, route_look_up: Rc<dyn AddressResolutionProtocolProxyRouteLookUp>)
+	{
+		*self.route_look_up.borrow_mut() = Some(route_look_up)
+	}
+	
+	/// Sets (or replaces) the transmitter used to send replies.
+	#[inline(always)]
+	pub fn set_transmitter(&self, transmitter: Rc<dyn AddressResolutionProtocolProxyTransmitter>)
+	{
+		*self.transmitter.borrow_mut() = Some(transmitter)
+	}
+	
+	/// Our ethernet address, used as the sender hardware address when answering.
+	#[inline(always)]
+	pub fn our_valid_unicast_ethernet_address(&self) -> &MediaAccessControlAddress
+	{
+		&self.our_valid_unicast_ethernet_address
+	}
+	
+	/// Should a request for `target_protocol_address` be answered on its behalf?
+	#[inline(always)]
+	pub fn should_answer_for(&self, target_protocol_address: InternetProtocolVersion4HostAddress) -> bool
+	{
+		if unlikely!(self.transmitter.borrow().is_none())
+		{
+			return false
+		}
+		
+		let is_proxied = self.prefixes.iter().any(|prefix| prefix.contains(target_protocol_address)) && !self.exclusions.iter().any(|exclusion| exclusion.contains(target_protocol_address));
+		if likely!(!is_proxied)
+		{
+			return false
+		}
+		
+		if self.answer_only_when_routed_out_of_a_different_network_interface
+		{
+			match *self.route_look_up.borrow()
+			{
+				None => false,
+				
+				Some(ref route_look_up) => route_look_up.is_routed_out_of_a_different_network_interface(target_protocol_address, self.ingress_network_interface_index),
+			}
+		}
+		else
+		{
+			true
+		}
+	}
+	
+	/// An ARP reply, unicast to the requester, giving our ethernet address as the hardware address of `target_protocol_address`.
+	#[inline(always)]
+	pub fn reply_frame(our_valid_unicast_ethernet_address: MediaAccessControlAddress, target_protocol_address: InternetProtocolVersion4HostAddress, requester_hardware_address: MediaAccessControlAddress, requester_protocol_address: InternetProtocolVersion4HostAddress) -> [u8; AddressResolutionProtocolProxy::ReplyFrameSize]
+	{
+		let our_valid_unicast_ethernet_address = our_valid_unicast_ethernet_address.to_octets();
+		let requester_hardware_address = requester_hardware_address.to_octets();
+		let target_protocol_address: [u8; InternetProtocolVersion4HostAddress::Size] = target_protocol_address.into();
+		let requester_protocol_address: [u8; InternetProtocolVersion4HostAddress::Size] = requester_protocol_address.into();
+		
+		let mut frame = [0u8; Self::ReplyFrameSize];
+		
+		// Ethernet header.
+		frame[0 .. 6].copy_from_slice(&requester_hardware_address);
+		frame[6 .. 12].copy_from_slice(&our_valid_unicast_ethernet_address);
+		frame[12 .. 14].copy_from_slice(&[0x08, 0x06]);
+		
+		// Hardware type (ethernet), protocol type (internet protocol version 4), hardware address length, protocol address length and operation (reply).
+		frame[14 .. 22].copy_from_slice(&[0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x02]);
+		frame[22 .. 28].copy_from_slice(&our_valid_unicast_ethernet_address);
+		frame[28 .. 32].copy_from_slice(&target_protocol_address);
+		frame[32 .. 38].copy_from_slice(&requester_hardware_address);
+		frame[38 .. 42].copy_from_slice(&requester_protocol_address);
+		
+		frame
+	}
+	
+	/// Answers a request from `requester_hardware_address` and `requester_protocol_address` for `target_protocol_address`, which should have been checked with `should_answer_for()`.
+	#[inline(always)]
+	pub(crate) fn reply(&self, target_protocol_address: InternetProtocolVersion4HostAddress, requester_hardware_address: MediaAccessControlAddress, requester_protocol_address: InternetProtocolVersion4HostAddress)
+	{
+		if let Some(ref transmitter) = *self.transmitter.borrow()
+		{
+			let frame = Self::reply_frame(self.our_valid_unicast_ethernet_address, target_protocol_address, requester_hardware_address, requester_protocol_address);
+			transmitter.transmit(&frame[..])
+		}
+	}
+}
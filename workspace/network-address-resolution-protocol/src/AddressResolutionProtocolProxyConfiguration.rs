@@ -0,0 +1,32 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Proxy Address Resolution Protocol (ARP) configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct AddressResolutionProtocolProxyConfiguration
+{
+	/// Prefixes to answer for.
+	pub prefixes: Vec<InternetProtocolVersion4NetworkAddress>,
+	
+	/// Prefixes (or, with a `/32` mask, host addresses) within `prefixes` not to answer for.
+	#[serde(default)] pub exclusions: Vec<InternetProtocolVersion4NetworkAddress>,
+	
+	/// Answer only when the route to the target protocol address points out of a different network interface to the one the request was received on.
+	///
+	/// Requires a route look up to be set with `AddressResolutionProtocolProxy::set_route_look_up()`.
+	///
+	/// Defaults to false.
+	#[serde(default)] pub answer_only_when_routed_out_of_a_different_network_interface: bool,
+}
+
+impl AddressResolutionProtocolProxyConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self, our_valid_unicast_ethernet_address: MediaAccessControlAddress, ingress_network_interface_index: u32) -> AddressResolutionProtocolProxy
+	{
+		AddressResolutionProtocolProxy::new(our_valid_unicast_ethernet_address, ingress_network_interface_index, self.prefixes, self.exclusions, self.answer_only_when_routed_out_of_a_different_network_interface)
+	}
+}
@@ -0,0 +1,12 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Looks up routes for proxy Address Resolution Protocol (ARP), eg using a routing table.
+pub trait AddressResolutionProtocolProxyRouteLookUp: Debug
+{
+	/// Does the route to `target_protocol_address` point out of a different network interface to `ingress_network_interface_index`, the one the ARP request was received on?
+	///
+	/// Should return false if there is no route.
+	fn is_routed_out_of_a_different_network_interface(&self, target_protocol_address: InternetProtocolVersion4HostAddress, ingress_network_interface_index: u32) -> bool;
+}
@@ -0,0 +1,305 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Internet protocol (IP) version 4 link-local address autoconfiguration (RFC 3927).
+///
+/// A candidate address in `169.254.1.0` to `169.254.254.255` inclusive is selected pseudo-randomly, using a generator seeded from our media access control address so that the same address tends to be selected each time.
+/// The candidate is probed for with ARP Probes; if no conflict is detected it is added to our valid internet protocol (IP) version 4 host addresses and announced with ARP Announcements.
+/// Thereafter it is defended; if a second conflict occurs within `DEFEND_INTERVAL` it is given up and a new candidate is selected.
+///
+/// `progress()` should be called periodically to send probes and announcements.
+#[derive(Debug)]
+pub struct LinkLocalAddressAutoconfiguration
+{
+	our_valid_unicast_ethernet_address: MediaAccessControlAddress,
+	
+	our_valid_internet_protocol_version_4_host_addresses: Rc<OurValidInternetProtocolVersion4HostAddresses>,
+	
+	transmitter: Rc<dyn AddressAnnouncementTransmitter>,
+	
+	state: Cell<LinkLocalAddressAutoconfigurationState>,
+	
+	conflicts: Cell<u8>,
+	
+	random: Cell<u64>,
+}
+
+impl LinkLocalAddressAutoconfiguration
+{
+	/// RFC 3927 Section 9 `PROBE_WAIT`.
+	pub const ProbeWait: MillisecondDuration = MillisecondDuration::from_seconds(1);
+	
+	/// RFC 3927 Section 9 `PROBE_NUM`.
+	pub const ProbeNumber: u8 = 3;
+	
+	/// RFC 3927 Section 9 `PROBE_MIN`.
+	pub const ProbeMinimum: MillisecondDuration = MillisecondDuration::from_seconds(1);
+	
+	/// RFC 3927 Section 9 `PROBE_MAX`.
+	pub const ProbeMaximum: MillisecondDuration = MillisecondDuration::from_seconds(2);
+	
+	/// RFC 3927 Section 9 `ANNOUNCE_WAIT`.
+	pub const AnnounceWait: MillisecondDuration = MillisecondDuration::from_seconds(2);
+	
+	/// RFC 3927 Section 9 `ANNOUNCE_NUM`.
+	pub const AnnounceNumber: u8 = 2;
+	
+	/// RFC 3927 Section 9 `ANNOUNCE_INTERVAL`.
+	pub const AnnounceInterval: MillisecondDuration = MillisecondDuration::from_seconds(2);
+	
+	/// RFC 3927 Section 9 `MAX_CONFLICTS`.
+	pub const MaximumConflicts: u8 = 10;
+	
+	/// RFC 3927 Section 9 `RATE_LIMIT_INTERVAL`.
+	pub const RateLimitInterval: MillisecondDuration = MillisecondDuration::from_seconds(60);
+	
+	/// RFC 3927 Section 9 `DEFEND_INTERVAL`.
+	pub const DefendInterval: MillisecondDuration = MillisecondDuration::from_seconds(10);
+	
+	const FirstCandidateOctet: u8 = 1;
+	
+	const NumberOfCandidates: u64 = 254 * 256;
+	
+	/// Creates a new instance and selects the first candidate address.
+	///
+	/// If `our_valid_internet_protocol_version_4_host_addresses` was created with address announcements then these are sent in addition to the ARP Announcements sent by this instance.
+	#[inline(always)]
+	pub fn new(now: MonotonicMillisecondTimestamp, our_valid_unicast_ethernet_address: MediaAccessControlAddress, our_valid_internet_protocol_version_4_host_addresses: Rc<OurValidInternetProtocolVersion4HostAddresses>, transmitter: Rc<dyn AddressAnnouncementTransmitter>) -> Self
+	{
+		let this = Self
+		{
+			our_valid_unicast_ethernet_address,
+			our_valid_internet_protocol_version_4_host_addresses,
+			transmitter,
+			state: Cell::new(LinkLocalAddressAutoconfigurationState::WaitingToProbe { candidate: InternetProtocolVersion4HostAddress::default(), probe_at: now }),
+			conflicts: Cell::new(0),
+			random: Cell::new(Self::seed(&our_valid_unicast_ethernet_address)),
+		};
+		
+		this.select_candidate(now, MillisecondDuration::Zero, Self::ProbeWait);
+		this
+	}
+	
+	/// Current state.
+	#[inline(always)]
+	pub fn state(&self) -> LinkLocalAddressAutoconfigurationState
+	{
+		self.state.get()
+	}
+	
+	/// Sends ARP Probes and ARP Announcements that are due.
+	#[inline]
+	pub fn progress(&self, now: MonotonicMillisecondTimestamp)
+	{
+		use self::LinkLocalAddressAutoconfigurationState::*;
+		
+		match self.state.get()
+		{
+			WaitingToProbe { candidate, probe_at } => if now >= probe_at
+			{
+				self.transmit_probe(candidate);
+				self.state.set(Probing { candidate, probes_sent: 1, next_at: now + self.probe_interval() });
+			},
+			
+			Probing { candidate, probes_sent, next_at } => if now >= next_at
+			{
+				if probes_sent < Self::ProbeNumber
+				{
+					self.transmit_probe(candidate);
+					let probes_sent = probes_sent + 1;
+					let wait = if probes_sent == Self::ProbeNumber
+					{
+						Self::AnnounceWait
+					}
+					else
+					{
+						self.probe_interval()
+					};
+					self.state.set(Probing { candidate, probes_sent, next_at: now + wait });
+				}
+				else
+				{
+					self.our_valid_internet_protocol_version_4_host_addresses.insert(now, candidate, self.our_valid_unicast_ethernet_address);
+					self.transmit_announcement(candidate);
+					self.state.set(Announcing { address: candidate, announcements_sent: 1, next_at: now + Self::AnnounceInterval, last_defended_at: None });
+				}
+			},
+			
+			Announcing { address, announcements_sent, next_at, last_defended_at } => if now >= next_at
+			{
+				self.transmit_announcement(address);
+				let announcements_sent = announcements_sent + 1;
+				if announcements_sent == Self::AnnounceNumber
+				{
+					self.state.set(Configured { address, last_defended_at });
+				}
+				else
+				{
+					self.state.set(Announcing { address, announcements_sent, next_at: now + Self::AnnounceInterval, last_defended_at });
+				}
+			},
+			
+			Configured { .. } => (),
+		}
+	}
+	
+	/// An ARP Probe, broadcast, with a zero sender protocol address and the target protocol address set to `candidate`.
+	#[inline(always)]
+	pub fn probe_frame(candidate: InternetProtocolVersion4HostAddress, media_access_control_address: MediaAccessControlAddress) -> [u8; AddressAnnouncements::GratuitousAddressResolutionProtocolFrameSize]
+	{
+		let mut frame = AddressAnnouncements::gratuitous_address_resolution_protocol_frame(candidate, media_access_control_address);
+		frame[28 .. 32].copy_from_slice(&[0; 4]);
+		frame
+	}
+	
+	/// RFC 3927 Sections 2.2.1 and 2.5: checks every received ARP packet for a conflict with our candidate or selected address.
+	#[inline]
+	pub(crate) fn detect_conflict(&self, now: MonotonicMillisecondTimestamp, payload: &AddressResolutionProtocolPacketInternetProtocolVersion4Payload)
+	{
+		use self::LinkLocalAddressAutoconfigurationState::*;
+		
+		if unlikely!(payload.sender_hardware_address == self.our_valid_unicast_ethernet_address)
+		{
+			return
+		}
+		
+		let sender_protocol_address = payload.sender_protocol_address;
+		
+		match self.state.get()
+		{
+			WaitingToProbe { candidate, .. } | Probing { candidate, .. } =>
+			{
+				let is_probe_for_candidate = sender_protocol_address.is_unspecified() && payload.target_protocol_address == candidate;
+				if unlikely!(sender_protocol_address == candidate || is_probe_for_candidate)
+				{
+					self.reselect(now)
+				}
+			}
+			
+			Announcing { address, announcements_sent, next_at, last_defended_at } => if unlikely!(sender_protocol_address == address)
+			{
+				if self.defend(now, address, last_defended_at)
+				{
+					self.state.set(Announcing { address, announcements_sent, next_at, last_defended_at: Some(now) });
+				}
+			},
+			
+			Configured { address, last_defended_at } => if unlikely!(sender_protocol_address == address)
+			{
+				if self.defend(now, address, last_defended_at)
+				{
+					self.state.set(Configured { address, last_defended_at: Some(now) });
+				}
+			},
+		}
+	}
+	
+	/// Returns `true` if the address was defended; otherwise it has been given up and a new candidate selected.
+	#[inline(always)]
+	fn defend(&self, now: MonotonicMillisecondTimestamp, address: InternetProtocolVersion4HostAddress, last_defended_at: Option<MonotonicMillisecondTimestamp>) -> bool
+	{
+		match last_defended_at
+		{
+			Some(last_defended_at) if now - last_defended_at < Self::DefendInterval =>
+			{
+				self.our_valid_internet_protocol_version_4_host_addresses.remove(address);
+				self.reselect(now);
+				false
+			}
+			
+			_ =>
+			{
+				self.transmit_announcement(address);
+				true
+			}
+		}
+	}
+	
+	/// RFC 3927 Section 2.2.1: after `MAX_CONFLICTS` conflicts new candidates are probed for at most once every `RATE_LIMIT_INTERVAL`.
+	#[inline(always)]
+	fn reselect(&self, now: MonotonicMillisecondTimestamp)
+	{
+		let conflicts = self.conflicts.get().saturating_add(1);
+		self.conflicts.set(conflicts);
+		
+		if unlikely!(conflicts >= Self::MaximumConflicts)
+		{
+			self.select_candidate(now, Self::RateLimitInterval, Self::RateLimitInterval)
+		}
+		else
+		{
+			self.select_candidate(now, MillisecondDuration::Zero, Self::ProbeWait)
+		}
+	}
+	
+	#[inline(always)]
+	fn select_candidate(&self, now: MonotonicMillisecondTimestamp, minimum_wait: MillisecondDuration, maximum_wait: MillisecondDuration)
+	{
+		let offset = self.next_random() % Self::NumberOfCandidates;
+		let candidate = InternetProtocolVersion4HostAddress::from([169, 254, Self::FirstCandidateOctet + (offset / 256) as u8, (offset % 256) as u8]);
+		
+		let probe_at = now + self.random_duration(minimum_wait, maximum_wait);
+		self.state.set(LinkLocalAddressAutoconfigurationState::WaitingToProbe { candidate, probe_at });
+	}
+	
+	#[inline(always)]
+	fn transmit_probe(&self, candidate: InternetProtocolVersion4HostAddress)
+	{
+		let frame = Self::probe_frame(candidate, self.our_valid_unicast_ethernet_address);
+		self.transmitter.transmit(&frame[..])
+	}
+	
+	#[inline(always)]
+	fn transmit_announcement(&self, address: InternetProtocolVersion4HostAddress)
+	{
+		let frame = AddressAnnouncements::gratuitous_address_resolution_protocol_frame(address, self.our_valid_unicast_ethernet_address);
+		self.transmitter.transmit(&frame[..])
+	}
+	
+	#[inline(always)]
+	fn probe_interval(&self) -> MillisecondDuration
+	{
+		self.random_duration(Self::ProbeMinimum, Self::ProbeMaximum)
+	}
+	
+	#[inline(always)]
+	fn random_duration(&self, minimum: MillisecondDuration, maximum: MillisecondDuration) -> MillisecondDuration
+	{
+		let minimum: u64 = minimum.into();
+		let maximum: u64 = maximum.into();
+		MillisecondDuration::from_milliseconds(minimum + self.next_random() % (maximum - minimum + 1))
+	}
+	
+	/// xorshift64*.
+	#[inline(always)]
+	fn next_random(&self) -> u64
+	{
+		let mut x = self.random.get();
+		x ^= x >> 12;
+		x ^= x << 25;
+		x ^= x >> 27;
+		self.random.set(x);
+		x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+	}
+	
+	/// RFC 3927 Section 2.1: the generator should be seeded from the media access control address so that the same candidate tends to be selected each time.
+	#[inline(always)]
+	fn seed(media_access_control_address: &MediaAccessControlAddress) -> u64
+	{
+		let mut seed = 0xCBF2_9CE4_8422_2325u64;
+		for octet in media_access_control_address.to_octets_reference().iter()
+		{
+			seed = (seed ^ (*octet as u64)).wrapping_mul(0x0000_0100_0000_01B3);
+		}
+		
+		if unlikely!(seed == 0)
+		{
+			1
+		}
+		else
+		{
+			seed
+		}
+	}
+}
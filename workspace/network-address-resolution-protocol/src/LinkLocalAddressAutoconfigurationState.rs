@@ -0,0 +1,76 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// State of internet protocol (IP) version 4 link-local address autoconfiguration (RFC 3927).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LinkLocalAddressAutoconfigurationState
+{
+	/// A candidate address has been selected; the first ARP Probe is sent at `probe_at`.
+	WaitingToProbe
+	{
+		/// Candidate address.
+		candidate: InternetProtocolVersion4HostAddress,
+		
+		/// When to send the first ARP Probe.
+		probe_at: MonotonicMillisecondTimestamp,
+	},
+	
+	/// ARP Probes are being sent for a candidate address.
+	///
+	/// Once `PROBE_NUM` probes have been sent, `next_at` is `ANNOUNCE_WAIT` after the last one.
+	Probing
+	{
+		/// Candidate address.
+		candidate: InternetProtocolVersion4HostAddress,
+		
+		/// Number of ARP Probes sent so far.
+		probes_sent: u8,
+		
+		/// When to send the next ARP Probe, or, if all have been sent, when to start announcing.
+		next_at: MonotonicMillisecondTimestamp,
+	},
+	
+	/// The address is in use and ARP Announcements are being sent for it.
+	Announcing
+	{
+		/// Selected address.
+		address: InternetProtocolVersion4HostAddress,
+		
+		/// Number of ARP Announcements sent so far.
+		announcements_sent: u8,
+		
+		/// When to send the next ARP Announcement.
+		next_at: MonotonicMillisecondTimestamp,
+		
+		/// When a conflicting ARP packet was last defended against, if any.
+		last_defended_at: Option<MonotonicMillisecondTimestamp>,
+	},
+	
+	/// The address is in use and is defended.
+	Configured
+	{
+		/// Selected address.
+		address: InternetProtocolVersion4HostAddress,
+		
+		/// When a conflicting ARP packet was last defended against, if any.
+		last_defended_at: Option<MonotonicMillisecondTimestamp>,
+	},
+}
+
+impl LinkLocalAddressAutoconfigurationState
+{
+	/// The address in use, if any.
+	#[inline(always)]
+	pub fn address_in_use(&self) -> Option<InternetProtocolVersion4HostAddress>
+	{
+		use self::LinkLocalAddressAutoconfigurationState::*;
+		
+		match *self
+		{
+			WaitingToProbe { .. } | Probing { .. } => None,
+			
+			Announcing { address, .. } | Configured { address, .. } => Some(address),
+		}
+	}
+}
@@ -18,7 +18,9 @@
 
 
 #[cfg(feature = "dpdk-sys")] extern crate dpdk_sys;
+extern crate hashbrown;
 #[macro_use] extern crate likely;
+extern crate network_collections;
 extern crate network_endian;
 extern crate network_ethernet;
 extern crate network_internet_protocol;
@@ -31,6 +33,8 @@ extern crate serde;
 use self::AddressResolutionProtocolIncomingNetworkPacketDropReason::*;
 #[cfg(feature = "dpdk-sys")] use dpdk_sys::*;
 #[cfg(feature = "libc")] use libc::*;
+use ::hashbrown::HashMap;
+use ::network_collections::BoundedHashMap;
 use ::network_endian::*;
 use ::network_ethernet::EthernetAddresses;
 use ::network_ethernet::EtherType;
@@ -41,12 +45,17 @@ use ::network_ethernet::packet_processing::EthernetIncomingNetworkPacketDropReas
 use ::network_ethernet::packet_processing::Layer3PacketProcessing;
 use ::network_ethernet::MediaAccessControlAddress;
 use ::network_internet_protocol::*;
+use ::network_internet_protocol::neighbour_resolution::AddressAnnouncements;
+use ::network_internet_protocol::neighbour_resolution::AddressAnnouncementTransmitter;
 use ::network_internet_protocol::packet_processing::*;
 use ::network_internet_protocol::version_4::*;
 use ::network_packet::IncomingNetworkPacketProcessingDropReason;
+use ::network_time::MillisecondDuration;
 use ::network_time::MonotonicMillisecondTimestamp;
 use ::serde::Serialize;
 use ::serde::Serializer;
+use ::std::cell::Cell;
+use ::std::cell::RefCell;
 use ::std::fmt;
 use ::std::fmt::Debug;
 use ::std::fmt::Display;
@@ -63,11 +72,21 @@ include!("drop.rs");
 
 
 include!("AddressResolutionPacketProcessing.rs");
+include!("AddressResolutionPacketProcessingConfiguration.rs");
 //include!("AddressResolutionProtocolAddressConflictState.rs");
 include!("AddressResolutionProtocolIncomingNetworkPacketDropReason.rs");
+include!("AddressResolutionProtocolInspection.rs");
+include!("AddressResolutionProtocolInspectionBinding.rs");
+include!("AddressResolutionProtocolInspectionConfiguration.rs");
 include!("AddressResolutionProtocolPacket.rs");
 include!("AddressResolutionProtocolPacketHeader.rs");
 include!("AddressResolutionProtocolPacketInternetProtocolVersion4Payload.rs");
 include!("AddressResolutionProtocolPacketPayload.rs");
+include!("AddressResolutionProtocolProxy.rs");
+include!("AddressResolutionProtocolProxyConfiguration.rs");
+include!("AddressResolutionProtocolProxyRouteLookUp.rs");
+include!("AddressResolutionProtocolProxyTransmitter.rs");
 include!("HardwareType.rs");
+include!("LinkLocalAddressAutoconfiguration.rs");
+include!("LinkLocalAddressAutoconfigurationState.rs");
 include!("Operation.rs");
@@ -130,7 +130,7 @@ impl Rfc1141CompliantCheckSum
 		{
 			let (sum, uneven_data_pointer) = accumulate_u16_chunks(data_pointer, data_length - 1, initial_value);
 			
-			let uneven_final_byte = (unsafe { *((uneven_data_pointer + 1) as *mut u8) }) as u16;
+			let uneven_final_byte = (unsafe { *(uneven_data_pointer as *mut u8) }) as u16;
 			let word = (uneven_final_byte << 8) as u32;
 			
 			sum + word
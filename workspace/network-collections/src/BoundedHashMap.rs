@@ -26,6 +26,13 @@ impl<K: Hash + Eq, V> BoundedHashMap<K, V>
 		}
 	}
 	
+	/// Identical to HashMap.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.map.clear()
+	}
+	
 	/// Identical to HashMap.
 	#[inline(always)]
 	pub fn contains_key(&self, key: &K) -> bool
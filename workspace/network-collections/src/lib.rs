@@ -13,7 +13,7 @@
 
 
 extern crate arrayvec;
-#[cfg(any(os = "android", os = "linux"))] extern crate dpdk_unix;
+#[cfg(any(target_os = "android", target_os = "linux"))] extern crate dpdk_unix;
 extern crate hashbrown;
 extern crate libc;
 #[macro_use] extern crate likely;
@@ -34,7 +34,7 @@ pub mod least_recently_used_cache;
 
 
 /// Magic ring buffers.
-#[cfg(any(os = "android", os = "linux"))] pub mod magic_ring_buffer;
+#[cfg(any(target_os = "android", target_os = "linux"))] pub mod magic_ring_buffer;
 
 
 pub use ::arrayvec::ArrayVec;
@@ -90,6 +90,21 @@ impl EtherType
 	/// IEEE Std 802.3-2015, Annex 57A.
 	pub const Slow: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x09]));
 	
+	/// Media access control (MAC) control, used by IEEE 802.3x PAUSE and IEEE 802.1Qbb Priority-based Flow Control (PFC).
+	///
+	/// IEEE Std 802.3-2015, Clause 31.
+	pub const MediaAccessControlControl: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x08]));
+	
+	/// IEEE 802.1X Extensible Authentication Protocol over LAN (EAPOL).
+	///
+	/// IEEE Std 802.1X-2010, Section 11.1.4.
+	pub const ExtensibleAuthenticationProtocolOverLan: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x8E]));
+	
+	/// MACsec.
+	///
+	/// IEEE Std 802.1AE-2006, Section 10.5.
+	pub const MediaAccessControlSecurity: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0xE5]));
+	
 	/// Internet protocol (IP) version 4 ether type.
 	pub const InternetProtocolVersion4: Self = EtherType(NetworkEndianU16::from_network_endian([0x08, 0x00]));
 	
@@ -108,6 +123,18 @@ impl EtherType
 	/// IEEE 802.1ad QinQ tagging.
 	pub const QinQVlanTagging: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0xA8]));
 	
+	/// Multiprotocol Label Switching (MPLS) unicast.
+	pub const MultiprotocolLabelSwitchingUnicast: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x47]));
+	
+	/// Multiprotocol Label Switching (MPLS) multicast (upstream assigned labels).
+	pub const MultiprotocolLabelSwitchingMulticast: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x48]));
+	
+	/// Point-to-Point Protocol over Ethernet (PPPoE) discovery stage.
+	pub const PointToPointProtocolOverEthernetDiscovery: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x63]));
+	
+	/// Point-to-Point Protocol over Ethernet (PPPoE) session stage.
+	pub const PointToPointProtocolOverEthernetSession: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0x64]));
+	
 	/// IEEE1588 / 802.1AS Precise time protocol (PTP).
 	pub const PreciseTimeProtocol: Self = EtherType(NetworkEndianU16::from_network_endian([0x88, 0xF7]));
 	
@@ -40,6 +40,19 @@ macro_rules! parse_802_1q_virtual_lan_tag_control_information_drop
 	}
 }
 
+macro_rules! police_virtual_lan_tag
+{
+	($now: ident, $self: ident, $tag_control_information: ident, $colour: ident, $action: ident, $is_outer_virtual_lan_tag: expr, $packet_processing: ident, $packet: ident) =>
+	{
+		match $action
+		{
+			PolicingAction::Drop => drop!(PolicedClassOfService { now: $now, ethernet_addresses: $self.ethernet_addresses(), tag_control_information: $tag_control_information, colour: $colour }, $packet_processing, $packet),
+			
+			PolicingAction::SetDropEligibleIndicator => $self.set_drop_eligible_indicator($packet, $is_outer_virtual_lan_tag),
+		}
+	}
+}
+
 macro_rules! parse_802_1q_virtual_lan_tag_control_information
 {
 	($now: ident, $self: ident, $tag_control_information: ident, $packet: ident, $packet_processing_by_virtual_lan: ident) =>
@@ -66,6 +79,11 @@ macro_rules! parse_802_1q_virtual_lan_tag_control_information
 							parse_802_1q_virtual_lan_tag_control_information_drop!($now, DropThisClassOfServiceFor8011QVirtualLan, $self, $tag_control_information, packet_processing, $packet)
 						}
 						
+						if let Some((colour, action)) = packet_processing.police_class_of_service($now, class_of_service, $packet.packet_length_if_contiguous())
+						{
+							police_virtual_lan_tag!($now, $self, $tag_control_information, colour, action, false, packet_processing, $packet)
+						}
+						
 						packet_processing
 					}
 				}
@@ -133,6 +151,11 @@ macro_rules! parse_802_1ad_virtual_lan_tag_control_information
 						parse_802_1ad_virtual_lan_tag_control_information_drop!($now, DropThisClassOfServiceForOuterVirtualLan, $self, $outer_tag_control_information, $inner_tag_control_information, $packet_processing_by_virtual_lan, $packet)
 					}
 					
+					if let Some((colour, action)) = packet_processing_for_q_in_q_virtual_lan.police_outer_class_of_service($now, outer_class_of_service, $packet.packet_length_if_contiguous())
+					{
+						police_virtual_lan_tag!($now, $self, $outer_tag_control_information, colour, action, true, $packet_processing_by_virtual_lan, $packet)
+					}
+					
 					if unlikely!(packet_processing_for_q_in_q_virtual_lan.honour_inner_drop_eligible_indicator(inner_drop_eligible_indicator))
 					{
 						parse_802_1ad_virtual_lan_tag_control_information_drop!($now, DropEligibleForInnerVirtualLan, $self, $outer_tag_control_information, $inner_tag_control_information, $packet_processing_by_virtual_lan, $packet)
@@ -140,7 +163,12 @@ macro_rules! parse_802_1ad_virtual_lan_tag_control_information
 					
 					if unlikely!(packet_processing_for_q_in_q_virtual_lan.drop_packets_of_inner_class_of_service(inner_class_of_service))
 					{
-						parse_802_1ad_virtual_lan_tag_control_information_drop!($now, DropThisClassOfServiceForInnerVirtualLan, $self, $outer_tag_control_information, $outer_tag_control_information, $packet_processing_by_virtual_lan, $packet)
+						parse_802_1ad_virtual_lan_tag_control_information_drop!($now, DropThisClassOfServiceForInnerVirtualLan, $self, $outer_tag_control_information, $inner_tag_control_information, $packet_processing_by_virtual_lan, $packet)
+					}
+					
+					if let Some((colour, action)) = packet_processing_for_q_in_q_virtual_lan.police_inner_class_of_service($now, inner_class_of_service, $packet.packet_length_if_contiguous())
+					{
+						police_virtual_lan_tag!($now, $self, $inner_tag_control_information, colour, action, false, $packet_processing_by_virtual_lan, $packet)
 					}
 					
 					&packet_processing_for_q_in_q_virtual_lan.inner_packet_processing
@@ -202,6 +230,38 @@ macro_rules! guard_is_valid_ethernet_packet
 	}
 }
 
+macro_rules! guard_link_aggregation_member_is_collecting
+{
+	($now: ident, $self: ident, $packet_processing_by_virtual_lan: ident, $packet: ident) =>
+	{
+		{
+			if unlikely!($packet_processing_by_virtual_lan.is_link_aggregation_member_not_collecting())
+			{
+				if $self.potentially_invalid_ether_type() != EtherType::Slow
+				{
+					drop!(LinkAggregationMemberPortIsNotCollecting { now: $now, ethernet_addresses: $self.ethernet_addresses() }, $packet_processing_by_virtual_lan, $packet)
+				}
+			}
+		}
+	}
+}
+
+macro_rules! guard_media_access_control_security_is_not_required
+{
+	($now: ident, $self: ident, $packet_processing_by_virtual_lan: ident, $packet: ident) =>
+	{
+		{
+			if let Some(ref media_access_control_security) = $packet_processing_by_virtual_lan.media_access_control_security
+			{
+				if unlikely!(!media_access_control_security.permits_unprotected_frame($self.potentially_invalid_ether_type()))
+				{
+					drop!(MediaAccessControlSecurityIsRequired { now: $now, ethernet_addresses: $self.ethernet_addresses() }, $packet_processing_by_virtual_lan, $packet)
+				}
+			}
+		}
+	}
+}
+
 macro_rules! guard_ethernet_addresses_drop
 {
 	($now: ident, $reason: tt, $ethernet_addresses: ident, $packet_processing: ident, $packet: ident) =>
@@ -233,6 +293,11 @@ macro_rules! guard_ethernet_addresses
 				guard_ethernet_addresses_drop!($now, DeniedSourceEthernetAddress, $ethernet_addresses, $packet_processing, $packet)
 			}
 			
+			if unlikely!($packet_processing.is_unauthorised_source_ethernet_address(source_ethernet_address))
+			{
+				guard_ethernet_addresses_drop!($now, SourceEthernetAddressIsNotAuthorised, $ethernet_addresses, $packet_processing, $packet)
+			}
+			
 			if unlikely!(destination_ethernet_address.is_zero())
 			{
 				guard_ethernet_addresses_drop!($now, DestinationEthernetAddressIsZero, $ethernet_addresses, $packet_processing, $packet)
@@ -380,6 +445,8 @@ impl EthernetPacket
 	pub fn process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
 	{
 		guard_is_valid_ethernet_packet!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_link_aggregation_member_is_collecting!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_media_access_control_security_is_not_required!(now, self, packet_processing_by_virtual_lan, packet);
 
 		let packet_processing = if unlikely!(packet.was_vlan_tag_control_information_stripped())
 		{
@@ -393,7 +460,14 @@ impl EthernetPacket
 		}
 		else
 		{
-			&packet_processing_by_virtual_lan.none
+			match self.potentially_invalid_ether_type()
+			{
+				EtherType::Slow => return self.process_slow_protocols(now, packet, packet_processing_by_virtual_lan),
+				
+				EtherType::MediaAccessControlSecurity => return self.process_media_access_control_security(now, packet, packet_processing_by_virtual_lan),
+				
+				_ => &packet_processing_by_virtual_lan.none,
+			}
 		};
 	
 		let layer_3_length = packet.packet_length_if_contiguous_less_ethernet_packet_header();
@@ -405,6 +479,8 @@ impl EthernetPacket
 	pub fn process_poll_mode_driver_offloads_only_vlan_tagging_stripping<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
 	{
 		guard_is_valid_ethernet_packet!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_link_aggregation_member_is_collecting!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_media_access_control_security_is_not_required!(now, self, packet_processing_by_virtual_lan, packet);
 
 		if unlikely!(packet.was_vlan_tag_control_information_stripped())
 		{
@@ -438,6 +514,41 @@ impl EthernetPacket
 					process_802_1ad_virtual_lan_tagging!(now, self, packet, packet_processing_by_virtual_lan)
 				}
 				
+				EtherType::Slow =>
+				{
+					self.process_slow_protocols(now, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::MediaAccessControlControl =>
+				{
+					self.process_media_access_control_control(now, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::ExtensibleAuthenticationProtocolOverLan =>
+				{
+					self.process_extensible_authentication_protocol_over_lan(now, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::MediaAccessControlSecurity =>
+				{
+					self.process_media_access_control_security(now, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::MultiprotocolLabelSwitchingUnicast | EtherType::MultiprotocolLabelSwitchingMulticast =>
+				{
+					self.process_multiprotocol_label_switching(now, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::PointToPointProtocolOverEthernetDiscovery | EtherType::PointToPointProtocolOverEthernetSession =>
+				{
+					self.process_point_to_point_protocol_over_ethernet(now, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::PreciseTimeProtocol =>
+				{
+					self.process_precision_time_protocol(now, packet, packet_processing_by_virtual_lan)
+				}
+				
 				potentially_invalid_ether_type @ _ => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, self.ethernet_addresses(), potentially_invalid_ether_type), packet_processing_by_virtual_lan, packet),
 			}
 		}
@@ -448,7 +559,15 @@ impl EthernetPacket
 	pub fn poll_mode_driver_does_not_offload_any_vlan_stripping<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
 	{
 		guard_is_valid_ethernet_packet!(now, self, packet_processing_by_virtual_lan, packet);
-
+		guard_link_aggregation_member_is_collecting!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_media_access_control_security_is_not_required!(now, self, packet_processing_by_virtual_lan, packet);
+		
+		self.process_without_offloaded_vlan_stripping(now, packet, packet_processing_by_virtual_lan)
+	}
+	
+	#[inline(always)]
+	fn process_without_offloaded_vlan_stripping<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
 		match self.potentially_invalid_ether_type()
 		{
 			EtherType::InternetProtocolVersion4 =>
@@ -471,6 +590,41 @@ impl EthernetPacket
 				process_802_1ad_virtual_lan_tagging!(now, self, packet, packet_processing_by_virtual_lan)
 			}
 
+			EtherType::Slow =>
+			{
+				self.process_slow_protocols(now, packet, packet_processing_by_virtual_lan)
+			}
+
+			EtherType::MediaAccessControlControl =>
+			{
+				self.process_media_access_control_control(now, packet, packet_processing_by_virtual_lan)
+			}
+
+			EtherType::ExtensibleAuthenticationProtocolOverLan =>
+			{
+				self.process_extensible_authentication_protocol_over_lan(now, packet, packet_processing_by_virtual_lan)
+			}
+			
+			EtherType::MediaAccessControlSecurity =>
+			{
+				self.process_media_access_control_security(now, packet, packet_processing_by_virtual_lan)
+			}
+
+			EtherType::MultiprotocolLabelSwitchingUnicast | EtherType::MultiprotocolLabelSwitchingMulticast =>
+			{
+				self.process_multiprotocol_label_switching(now, packet, packet_processing_by_virtual_lan)
+			}
+
+			EtherType::PointToPointProtocolOverEthernetDiscovery | EtherType::PointToPointProtocolOverEthernetSession =>
+			{
+				self.process_point_to_point_protocol_over_ethernet(now, packet, packet_processing_by_virtual_lan)
+			}
+
+			EtherType::PreciseTimeProtocol =>
+			{
+				self.process_precision_time_protocol(now, packet, packet_processing_by_virtual_lan)
+			}
+
 			EtherType::VlanTagging =>
 			{
 				if unlikely!(packet.is_too_short_to_be_a_vlan_ethernet_packet())
@@ -497,6 +651,363 @@ impl EthernetPacket
 		}
 	}
 
+	/// Process as a port of a learning bridge.
+	///
+	/// Frames are forwarded or flooded to other bridge ports as necessary; frames which are multicast, broadcast or destined for one of our unicast ethernet addresses are then processed locally as if by `process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping()` (if hardware stripped a Virtual LAN tag) or `poll_mode_driver_does_not_offload_any_vlan_stripping()`.
+	///
+	/// The Virtual LAN used for bridging is the IEEE 802.1Q Virtual LAN or, for IEEE 802.1ad QinQ, the outer Virtual LAN; source ethernet addresses are learned and checked, and destination ethernet addresses checked, against the packet processing of the frame's Virtual LAN(s).
+	///
+	/// Bridge Protocol Data Units (BPDUs) are passed to the learning bridge's spanning tree; frames received on a port the spanning tree has not put into the forwarding state are neither forwarded nor processed locally.
+	#[inline(always)]
+	pub fn process_as_learning_bridge_port<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>, BFT: LearningBridgeFrameTransmitter>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, ingress_port_number: u16, learning_bridge: &LearningBridge<BFT>, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		guard_is_valid_ethernet_packet!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_link_aggregation_member_is_collecting!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_media_access_control_security_is_not_required!(now, self, packet_processing_by_virtual_lan, packet);
+		
+		if unlikely!(self.is_bridge_protocol_data_unit(packet))
+		{
+			return self.process_bridge_protocol_data_unit(now, packet, ingress_port_number, learning_bridge, packet_processing_by_virtual_lan)
+		}
+		
+		let hardware_stripped_virtual_lan_tag = packet.was_vlan_tag_control_information_stripped() || packet.was_vlan_qinq_tag_control_information_stripped();
+		
+		// Frames with a Virtual LAN tag that can not be parsed are not bridged; local processing will drop them.
+		if let Ok((virtual_lan_identifier, packet_processing)) = self.bridging_virtual_lan(packet, packet_processing_by_virtual_lan)
+		{
+			use self::LearningBridgeOutcome::*;
+			
+			let ethernet_addresses = self.ethernet_addresses();
+			let (source_ethernet_address, destination_ethernet_address) = ethernet_addresses.addresses();
+			
+			// A frame in a Virtual LAN without packet processing has no ethernet addresses of ours nor denied source ethernet addresses.
+			let (source_may_be_learned, destination_is_ours) = match packet_processing
+			{
+				None => (source_ethernet_address.is_valid_unicast(), false),
+				
+				Some(packet_processing) =>
+				(
+					source_ethernet_address.is_valid_unicast() && !packet_processing.is_ethernet_address_our_valid_unicast_ethernet_address(source_ethernet_address) && !packet_processing.is_denied_source_ethernet_address(source_ethernet_address),
+					destination_ethernet_address.is_valid_unicast() && packet_processing.is_ethernet_address_our_valid_unicast_ethernet_address(destination_ethernet_address),
+				),
+			};
+			
+			match learning_bridge.bridge(now, ingress_port_number, virtual_lan_identifier, source_may_be_learned, destination_is_ours, packet)
+			{
+				DeliverLocally => (),
+				
+				Consumed => return,
+				
+				IngressPortIsNotAMemberOfVirtualLan => drop!(BridgeIngressPortIsNotAMemberOfVirtualLan { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet),
+				
+				IngressPortIsNotForwarding => drop!(BridgeIngressPortIsNotForwarding { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet),
+				
+				SourceEthernetAddressIsStaticallyBoundToAnotherPort => drop!(BridgeSourceEthernetAddressIsStaticallyBoundToAnotherPort { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet),
+				
+				DestinationEthernetAddressIsOnIngressPort => drop!(BridgeDestinationEthernetAddressIsOnIngressPort { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet),
+			}
+		}
+		
+		if hardware_stripped_virtual_lan_tag
+		{
+			self.process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping(now, packet, packet_processing_by_virtual_lan)
+		}
+		else
+		{
+			self.poll_mode_driver_does_not_offload_any_vlan_stripping(now, packet, packet_processing_by_virtual_lan)
+		}
+	}
+	
+	#[inline(always)]
+	fn is_bridge_protocol_data_unit(&self, packet: impl EthernetIncomingNetworkPacket) -> bool
+	{
+		let (_source_ethernet_address, destination_ethernet_address) = self.ethernet_addresses().addresses();
+		
+		destination_ethernet_address == &MediaAccessControlAddress::BridgeGroupAddress && !packet.was_vlan_tag_control_information_stripped() && !packet.was_vlan_qinq_tag_control_information_stripped() && !self.potentially_invalid_ether_type().is_valid_ether_type()
+	}
+	
+	#[inline(always)]
+	fn process_bridge_protocol_data_unit<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>, BFT: LearningBridgeFrameTransmitter>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, ingress_port_number: u16, learning_bridge: &LearningBridge<BFT>, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		
+		let layer_3_length = packet.packet_length_if_contiguous_less_ethernet_packet_header();
+		if unlikely!(layer_3_length < BridgeProtocolDataUnit::TopologyChangeNotificationSizeU16)
+		{
+			drop!(BridgeProtocolDataUnitIsInvalid { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		let bridge_protocol_data_unit: &BridgeProtocolDataUnit = self.layer_3_packet().as_type();
+		let kind = match bridge_protocol_data_unit.validate(layer_3_length)
+		{
+			Err(()) => drop!(BridgeProtocolDataUnitIsInvalid { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet),
+			
+			Ok(kind) => kind,
+		};
+		
+		if unlikely!(!learning_bridge.process_bridge_protocol_data_unit(now, ingress_port_number, bridge_protocol_data_unit, kind))
+		{
+			drop!(SpanningTreeIsNotConfigured { now, ethernet_addresses, ingress_port_number }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		packet.free_direct_contiguous_packet()
+	}
+	
+	/// The Virtual LAN used for bridging and the packet processing, if configured, of the Virtual LAN(s) the frame is in.
+	#[inline(always)]
+	fn bridging_virtual_lan<'packet_processing, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&self, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &'packet_processing VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>) -> Result<(Option<VirtualLanIdentifier>, Option<&'packet_processing EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>>), ()>
+	{
+		let (outer_virtual_lan_identifier, inner_virtual_lan_identifier) = self.rewriting_virtual_lan_identifiers(packet)?;
+		
+		match outer_virtual_lan_identifier
+		{
+			None => Ok((inner_virtual_lan_identifier, packet_processing_by_virtual_lan.get_packet_processing_for_inner_virtual_lan(inner_virtual_lan_identifier))),
+			
+			Some(outer_virtual_lan_identifier) =>
+			{
+				let packet_processing = packet_processing_by_virtual_lan.get_packet_processing_for_outer_virtual_lan(outer_virtual_lan_identifier, inner_virtual_lan_identifier).map(|packet_processing_for_q_in_q_virtual_lan| &packet_processing_for_q_in_q_virtual_lan.inner_packet_processing);
+				Ok((outer_virtual_lan_identifier, packet_processing))
+			}
+		}
+	}
+	
+	/// Use this to process packets when Virtual LAN tags should be rewritten (pushed, popped, swapped or remapped) and the packet transmitted out of another port.
+	///
+	/// Packets are classified by their IEEE 802.1ad QinQ (outer and inner), IEEE 802.1Q or absent Virtual LAN tags, whether stripped by hardware or not, and looked up in `packet_processing_by_virtual_lan`'s rewrites.
+	///
+	/// Packets for which there is no rewrite are processed locally as if by `process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping()` (if hardware stripped a Virtual LAN tag) or `poll_mode_driver_does_not_offload_any_vlan_stripping()`.
+	#[inline(always)]
+	pub fn process_rewriting_virtual_lan_tags<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>, VLTRT: VirtualLanTagRewriteTransmitter>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, transmitter: &VLTRT, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		guard_is_valid_ethernet_packet!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_link_aggregation_member_is_collecting!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_media_access_control_security_is_not_required!(now, self, packet_processing_by_virtual_lan, packet);
+		
+		let hardware_stripped_virtual_lan_tag = packet.was_vlan_tag_control_information_stripped() || packet.was_vlan_qinq_tag_control_information_stripped();
+		
+		// Packets with a Virtual LAN tag that can not be parsed are not rewritten; local processing will drop them.
+		let virtual_lan_tag_rewrite = match self.rewriting_virtual_lan_identifiers(packet)
+		{
+			Ok((Some(outer_virtual_lan_identifier), inner_virtual_lan_identifier)) => packet_processing_by_virtual_lan.get_virtual_lan_tag_rewrite_for_outer_virtual_lan(outer_virtual_lan_identifier, inner_virtual_lan_identifier),
+			
+			Ok((None, inner_virtual_lan_identifier)) => packet_processing_by_virtual_lan.get_virtual_lan_tag_rewrite_for_inner_virtual_lan(inner_virtual_lan_identifier),
+			
+			Err(()) => None,
+		};
+		
+		if let Some(virtual_lan_tag_rewrite) = virtual_lan_tag_rewrite
+		{
+			if unlikely!(virtual_lan_tag_rewrite.rewrite(packet).is_err())
+			{
+				// The ethernet addresses may have moved if a tag was pushed or popped before the rewrite failed.
+				let ethernet_addresses = packet.ethernet_packet().ethernet_addresses();
+				drop!(CouldNotRewriteVirtualLanTags { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet)
+			}
+			
+			return transmitter.transmit(virtual_lan_tag_rewrite.egress_port_number, packet)
+		}
+		
+		if hardware_stripped_virtual_lan_tag
+		{
+			self.process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping(now, packet, packet_processing_by_virtual_lan)
+		}
+		else
+		{
+			self.poll_mode_driver_does_not_offload_any_vlan_stripping(now, packet, packet_processing_by_virtual_lan)
+		}
+	}
+	
+	/// Returns `(outer, inner)`; `outer` is `Some` only for IEEE 802.1ad QinQ packets.
+	#[inline(always)]
+	fn rewriting_virtual_lan_identifiers(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<(Option<Option<VirtualLanIdentifier>>, Option<VirtualLanIdentifier>), ()>
+	{
+		#[inline(always)]
+		fn virtual_lan_identifier(tag_control_information: TagControlInformation) -> Result<Option<VirtualLanIdentifier>, ()>
+		{
+			let (_class_of_service, _drop_eligible_indicator, virtual_lan_identifier) = tag_control_information.parse()?;
+			Ok(virtual_lan_identifier)
+		}
+		
+		if packet.was_vlan_qinq_tag_control_information_stripped()
+		{
+			let (outer_tag_control_information, inner_tag_control_information) = packet.stripped_vlan_qinq_tag_control_information();
+			return Ok((Some(virtual_lan_identifier(outer_tag_control_information)?), virtual_lan_identifier(inner_tag_control_information)?))
+		}
+		
+		if packet.was_vlan_tag_control_information_stripped()
+		{
+			return Ok((None, virtual_lan_identifier(packet.stripped_vlan_tag_control_information())?))
+		}
+		
+		match self.potentially_invalid_ether_type()
+		{
+			EtherType::VlanTagging =>
+			{
+				if unlikely!(packet.is_too_short_to_be_a_vlan_ethernet_packet())
+				{
+					return Err(())
+				}
+				Ok((None, virtual_lan_identifier(self.virtual_lan_packet().tag_control_information())?))
+			}
+			
+			EtherType::QinQVlanTagging =>
+			{
+				if unlikely!(packet.is_too_short_to_be_a_qinq_vlan_ethernet_packet())
+				{
+					return Err(())
+				}
+				
+				let qinq_virtual_lan_packet = self.qinq_virtual_lan_packet();
+				if unlikely!(qinq_virtual_lan_packet.potentially_invalid_ether_type() != EtherType::VlanTagging)
+				{
+					return Err(())
+				}
+				
+				Ok((Some(virtual_lan_identifier(qinq_virtual_lan_packet.tag_control_information())?), virtual_lan_identifier(qinq_virtual_lan_packet.virtual_lan_packet().tag_control_information())?))
+			}
+			
+			_ => Ok((None, None)),
+		}
+	}
+	
+	/// Use this to process packets when tunnels, such as Virtual eXtensible Local Area Network (VXLAN), Generic Routing Encapsulation (GRE), Network Virtualization using Generic Routing Encapsulation (NVGRE) or Generic Network Virtualization Encapsulation (Geneve), should be terminated.
+	///
+	/// Tunnel packets are recognised by the poll mode driver's hardware offload categorisation (`hardware_offload_tunnel_packet_type()`); the outer headers are validated, removed and the inner ethernet frame processed using the tunnel's own Virtual LAN packet processing, as if by `poll_mode_driver_does_not_offload_any_vlan_stripping()` with the inner hardware offload categorisations.
+	///
+	/// Inner internet protocol (IP) packets (for GRE and Geneve) are given a synthesized ethernet header addressed to the tunnel's own unicast ethernet address.
+	///
+	/// Other packets, including those for tunnels which are not configured, are processed as if by `process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping()` (if hardware stripped a Virtual LAN tag) or `poll_mode_driver_does_not_offload_any_vlan_stripping()`.
+	///
+	/// Tunnels are not nested; a tunnel packet inside a tunnel is processed as an ordinary packet.
+	#[inline(always)]
+	pub fn process_terminating_tunnels<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		if let Some(configured_tunnel) = packet_processing_by_virtual_lan.configured_tunnel(packet)
+		{
+			self.process_tunnel(now, packet, packet_processing_by_virtual_lan, configured_tunnel)
+		}
+		else if packet.was_vlan_tag_control_information_stripped() || packet.was_vlan_qinq_tag_control_information_stripped()
+		{
+			self.process_poll_mode_driver_offloads_qinq_vlan_tagging_stripping(now, packet, packet_processing_by_virtual_lan)
+		}
+		else
+		{
+			self.poll_mode_driver_does_not_offload_any_vlan_stripping(now, packet, packet_processing_by_virtual_lan)
+		}
+	}
+	
+	#[inline(always)]
+	fn process_tunnel<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>, configured_tunnel: ConfiguredTunnel<EINPDO, ARP, IPV4, IPV6>)
+	{
+		if unlikely!(packet.is_too_short_to_be_an_ethernet_packet())
+		{
+			drop!(IsTooShortToBeAnEthernetPacket { now }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		if unlikely!(packet.hardware_offload_categorisation_indicates_an_unwanted_packet())
+		{
+			drop!(HardwareOffloadingCategorisationIsUnwanted { now, ethernet_addresses: self.ethernet_addresses() }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		guard_link_aggregation_member_is_collecting!(now, self, packet_processing_by_virtual_lan, packet);
+		guard_media_access_control_security_is_not_required!(now, self, packet_processing_by_virtual_lan, packet);
+		
+		// The outer frame is subject to the packet processing of its own Virtual LAN(s).
+		let packet_processing = if unlikely!(packet.was_vlan_tag_control_information_stripped())
+		{
+			let tag_control_information = packet.stripped_vlan_tag_control_information();
+			parse_802_1q_virtual_lan_tag_control_information!(now, self, tag_control_information, packet, packet_processing_by_virtual_lan)
+		}
+		else if unlikely!(packet.was_vlan_qinq_tag_control_information_stripped())
+		{
+			let (outer_tag_control_information, inner_tag_control_information) = packet.stripped_vlan_qinq_tag_control_information();
+			parse_802_1ad_virtual_lan_tag_control_information!(now, self, outer_tag_control_information, inner_tag_control_information, packet, packet_processing_by_virtual_lan)
+		}
+		else
+		{
+			match self.potentially_invalid_ether_type()
+			{
+				EtherType::VlanTagging =>
+				{
+					if unlikely!(packet.is_too_short_to_be_a_vlan_ethernet_packet())
+					{
+						drop!(IsTooShortToBeA8021QVirtualLanEthernetPacket { now }, packet_processing_by_virtual_lan, packet)
+					}
+					
+					let tag_control_information = self.virtual_lan_packet().tag_control_information();
+					parse_802_1q_virtual_lan_tag_control_information!(now, self, tag_control_information, packet, packet_processing_by_virtual_lan)
+				}
+				
+				EtherType::QinQVlanTagging =>
+				{
+					if unlikely!(packet.is_too_short_to_be_a_qinq_vlan_ethernet_packet())
+					{
+						drop!(IsTooShortToBeAQinQVirtualLanEthernetPacket { now }, packet_processing_by_virtual_lan, packet)
+					}
+					
+					let qinq_virtual_lan_packet = self.qinq_virtual_lan_packet();
+					let outer_tag_control_information = qinq_virtual_lan_packet.tag_control_information();
+					let inner_tag_control_information = qinq_virtual_lan_packet.virtual_lan_packet().tag_control_information();
+					parse_802_1ad_virtual_lan_tag_control_information!(now, self, outer_tag_control_information, inner_tag_control_information, packet, packet_processing_by_virtual_lan)
+				}
+				
+				_ => &packet_processing_by_virtual_lan.none,
+			}
+		};
+		
+		let ethernet_addresses = self.ethernet_addresses();
+		guard_ethernet_addresses!(now, ethernet_addresses, packet, packet_processing);
+		
+		let terminated_tunnel = match configured_tunnel.terminate(packet)
+		{
+			Err(reason) => drop!(InvalidTunnelPacket { now, ethernet_addresses, reason }, packet_processing_by_virtual_lan, packet),
+			
+			Ok(terminated_tunnel) => terminated_tunnel,
+		};
+		
+		let inner_packet = terminated_tunnel.decapsulate(packet);
+		inner_packet.ethernet_packet().poll_mode_driver_does_not_offload_any_vlan_stripping(now, inner_packet, terminated_tunnel.packet_processing_by_virtual_lan)
+	}
+	
+	/// Sets the drop eligible indicator of either the outer (IEEE 802.1ad QinQ) or inner (IEEE 802.1Q) Virtual LAN tag, whether stripped by hardware or not.
+	#[inline(always)]
+	fn set_drop_eligible_indicator(&self, packet: impl EthernetIncomingNetworkPacket, is_outer_virtual_lan_tag: bool)
+	{
+		const OuterTagControlInformationOffset: usize = EthernetPacketHeader::SizeU32 as usize;
+		const InnerTagControlInformationOffset: usize = OuterTagControlInformationOffset + VirtualLanPacketHeader::IEEE_802_1ad_Size;
+		
+		if packet.was_vlan_qinq_tag_control_information_stripped()
+		{
+			let (outer_tag_control_information, inner_tag_control_information) = packet.stripped_vlan_qinq_tag_control_information();
+			if is_outer_virtual_lan_tag
+			{
+				packet.set_stripped_vlan_qinq_tag_control_information(outer_tag_control_information.with_drop_eligible_indicator(true), inner_tag_control_information)
+			}
+			else
+			{
+				packet.set_stripped_vlan_qinq_tag_control_information(outer_tag_control_information, inner_tag_control_information.with_drop_eligible_indicator(true))
+			}
+		}
+		else if packet.was_vlan_tag_control_information_stripped()
+		{
+			packet.set_stripped_vlan_tag_control_information(packet.stripped_vlan_tag_control_information().with_drop_eligible_indicator(true))
+		}
+		else
+		{
+			let offset = if is_outer_virtual_lan_tag || self.potentially_invalid_ether_type() != EtherType::QinQVlanTagging
+			{
+				OuterTagControlInformationOffset
+			}
+			else
+			{
+				InnerTagControlInformationOffset
+			};
+			
+			let tag_control_information = packet.offset_into_data::<TagControlInformation>(offset).as_ptr();
+			unsafe { *tag_control_information = (*tag_control_information).with_drop_eligible_indicator(true) }
+		}
+	}
+	
 	#[inline(always)]
 	fn process<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, layer_3_length: u16, potentially_invalid_ether_type: EtherType)
 	{
@@ -509,6 +1020,9 @@ impl EthernetPacket
 		let ethernet_packet = packet.ethernet_packet();
 		let ethernet_addresses = ethernet_packet.ethernet_addresses();
 		
+		let internal_priority = packet_processing.quality_of_service().ingress_internal_priority(Self::innermost_class_of_service(packet), || Self::differentiated_service_code_point(layer_3_packet, layer_3_length, potentially_invalid_ether_type));
+		packet.set_internal_priority(internal_priority);
+		
 		match potentially_invalid_ether_type
 		{
 			EtherType::InternetProtocolVersion4 =>
@@ -531,10 +1045,224 @@ impl EthernetPacket
 				packet_processing.process_address_resolution_protocol(now, packet, layer_3_packet, layer_3_length, ethernet_addresses)
 			}
 
+			EtherType::MultiprotocolLabelSwitchingUnicast | EtherType::MultiprotocolLabelSwitchingMulticast =>
+			{
+				guard_ethernet_addresses!(now, ethernet_addresses, packet, packet_processing);
+				Self::process_multiprotocol_label_switching_label_stack(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, potentially_invalid_ether_type)
+			}
+
+			EtherType::PointToPointProtocolOverEthernetDiscovery | EtherType::PointToPointProtocolOverEthernetSession =>
+			{
+				guard_ethernet_addresses!(now, ethernet_addresses, packet, packet_processing);
+				Self::process_point_to_point_protocol_over_ethernet_payload(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, potentially_invalid_ether_type)
+			}
+
+			EtherType::PreciseTimeProtocol =>
+			{
+				guard_ethernet_addresses!(now, ethernet_addresses, packet, packet_processing);
+				Self::process_precision_time_protocol_message(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, potentially_invalid_ether_type)
+			}
+
+			EtherType::MediaAccessControlControl =>
+			{
+				guard_ethernet_addresses!(now, ethernet_addresses, packet, packet_processing);
+				Self::process_media_access_control_control_frame(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses)
+			}
+
+			// Not guarded, as Extensible Authentication Protocol over LAN (EAPOL) packets must be accepted from unauthorised source ethernet addresses.
+			EtherType::ExtensibleAuthenticationProtocolOverLan =>
+			{
+				Self::process_extensible_authentication_protocol_over_lan_frame(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, potentially_invalid_ether_type)
+			}
+
 			_ => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, potentially_invalid_ether_type), packet_processing, packet),
 		}
 	}
 
+	/// Pops the label stack and processes the payload as an internet protocol (IP) version 4 or version 6 packet.
+	#[inline(always)]
+	fn process_multiprotocol_label_switching_label_stack<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(layer_3_packet: &Layer3Packet, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, layer_3_length: u16, ethernet_addresses: &'ethernet_addresses EthernetAddresses, potentially_invalid_ether_type: EtherType)
+	{
+		let multiprotocol_label_switching_packet_processing = match packet_processing.multiprotocol_label_switching_packet_processing()
+		{
+			None => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, potentially_invalid_ether_type), packet_processing, packet),
+			
+			Some(multiprotocol_label_switching_packet_processing) => multiprotocol_label_switching_packet_processing,
+		};
+		
+		let (label_stack_length, payload_ether_type) = match multiprotocol_label_switching_packet_processing.pop_label_stack(layer_3_packet, layer_3_length)
+		{
+			Err(reason) => drop!(ProblematicMultiprotocolLabelSwitchingPacket { now, ethernet_addresses, reason }, packet_processing, packet),
+			
+			Ok(popped) => popped,
+		};
+		
+		Self::process_internet_protocol_payload(layer_3_packet, label_stack_length, now, packet, packet_processing, layer_3_length - label_stack_length, ethernet_addresses, payload_ether_type)
+	}
+	
+	/// Processes a Point-to-Point Protocol over Ethernet (PPPoE) discovery packet or decapsulates a session packet and processes its payload as an internet protocol (IP) version 4 or version 6 packet.
+	#[inline(always)]
+	fn process_point_to_point_protocol_over_ethernet_payload<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(layer_3_packet: &Layer3Packet, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, layer_3_length: u16, ethernet_addresses: &'ethernet_addresses EthernetAddresses, potentially_invalid_ether_type: EtherType)
+	{
+		let point_to_point_protocol_over_ethernet_packet_processing = match packet_processing.point_to_point_protocol_over_ethernet_packet_processing()
+		{
+			None => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, potentially_invalid_ether_type), packet_processing, packet),
+			
+			Some(point_to_point_protocol_over_ethernet_packet_processing) => point_to_point_protocol_over_ethernet_packet_processing,
+		};
+		
+		let (source_ethernet_address, _destination_ethernet_address) = ethernet_addresses.addresses();
+		
+		if potentially_invalid_ether_type == EtherType::PointToPointProtocolOverEthernetDiscovery
+		{
+			if let Err(reason) = point_to_point_protocol_over_ethernet_packet_processing.process_discovery(source_ethernet_address, layer_3_packet, layer_3_length)
+			{
+				drop!(ProblematicPointToPointProtocolOverEthernetPacket { now, ethernet_addresses, reason }, packet_processing, packet)
+			}
+			return packet.free_direct_contiguous_packet()
+		}
+		
+		let (payload_offset, payload_length, payload_ether_type) = match point_to_point_protocol_over_ethernet_packet_processing.process_session(source_ethernet_address, layer_3_packet, layer_3_length)
+		{
+			Err(reason) => drop!(ProblematicPointToPointProtocolOverEthernetPacket { now, ethernet_addresses, reason }, packet_processing, packet),
+			
+			Ok(decapsulated) => decapsulated,
+		};
+		
+		Self::process_internet_protocol_payload(layer_3_packet, payload_offset, now, packet, packet_processing, payload_length, ethernet_addresses, payload_ether_type)
+	}
+	
+	/// Processes an Extensible Authentication Protocol over LAN (EAPOL) frame; this is the only ether type accepted from unauthorised source ethernet addresses.
+	#[inline(always)]
+	fn process_extensible_authentication_protocol_over_lan_frame<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(layer_3_packet: &Layer3Packet, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, layer_3_length: u16, ethernet_addresses: &'ethernet_addresses EthernetAddresses, potentially_invalid_ether_type: EtherType)
+	{
+		let port_based_network_access_control = match packet_processing.port_based_network_access_control()
+		{
+			None => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, potentially_invalid_ether_type), packet_processing, packet),
+			
+			Some(port_based_network_access_control) => port_based_network_access_control,
+		};
+		
+		let (source_ethernet_address, destination_ethernet_address) = ethernet_addresses.addresses();
+		
+		if unlikely!(source_ethernet_address.is_not_valid_unicast())
+		{
+			drop!(SourceEthernetAddressIsNotValidUnicast { now, ethernet_addresses }, packet_processing, packet)
+		}
+		
+		let is_for_us = destination_ethernet_address == &MediaAccessControlAddress::PortAccessEntityGroupAddress || (destination_ethernet_address.is_valid_unicast() && packet_processing.is_ethernet_address_our_valid_unicast_ethernet_address(destination_ethernet_address));
+		if unlikely!(!is_for_us)
+		{
+			drop!(ProblematicExtensibleAuthenticationProtocolOverLanPacket { now, ethernet_addresses, reason: PortBasedNetworkAccessControlDropReason::DestinationEthernetAddressIsNotPortAccessEntityGroupAddressOrOurs }, packet_processing, packet)
+		}
+		
+		if let Err(reason) = port_based_network_access_control.process(now, source_ethernet_address, layer_3_packet, layer_3_length)
+		{
+			drop!(ProblematicExtensibleAuthenticationProtocolOverLanPacket { now, ethernet_addresses, reason }, packet_processing, packet)
+		}
+		
+		packet.free_direct_contiguous_packet()
+	}
+	
+	/// Processes a Precision Time Protocol (PTP) message using the hardware receive timestamp of the packet, if any.
+	#[inline(always)]
+	fn process_precision_time_protocol_message<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(layer_3_packet: &Layer3Packet, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, layer_3_length: u16, ethernet_addresses: &'ethernet_addresses EthernetAddresses, potentially_invalid_ether_type: EtherType)
+	{
+		let precision_time_protocol_packet_processing = match packet_processing.precision_time_protocol_packet_processing()
+		{
+			None => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, potentially_invalid_ether_type), packet_processing, packet),
+			
+			Some(precision_time_protocol_packet_processing) => precision_time_protocol_packet_processing,
+		};
+		
+		if let Err(reason) = precision_time_protocol_packet_processing.process(layer_3_packet, layer_3_length, packet.hardware_receive_timestamp())
+		{
+			drop!(ProblematicPrecisionTimeProtocolPacket { now, ethernet_addresses, reason }, packet_processing, packet)
+		}
+		
+		packet.free_direct_contiguous_packet()
+	}
+	
+	/// Processes the internet protocol (IP) version 4 or version 6 packet found `payload_offset` bytes into `layer_3_packet`, eg after a label stack.
+	///
+	/// Any other `payload_ether_type` is dropped as unsupported.
+	#[inline(always)]
+	fn process_internet_protocol_payload<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(layer_3_packet: &Layer3Packet, payload_offset: u16, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, payload_length: u16, ethernet_addresses: &'ethernet_addresses EthernetAddresses, payload_ether_type: EtherType)
+	{
+		let payload_packet: &Layer3Packet = unsafe { &*((layer_3_packet as *const Layer3Packet as *const u8).add(payload_offset as usize) as *const Layer3Packet) };
+		
+		match payload_ether_type
+		{
+			EtherType::InternetProtocolVersion4 =>
+			{
+				let check_sum_validated_in_hardware = validate_internet_protocol_version_4_and_layer_4_check_sums!(now, ethernet_addresses, packet, packet_processing);
+				packet_processing.process_internet_protocol_version_4(now, packet, payload_packet, payload_length, ethernet_addresses, check_sum_validated_in_hardware)
+			}
+			
+			EtherType::InternetProtocolVersion6 =>
+			{
+				let layer_4_check_sum_validated_in_hardware = internet_protocol_version_6_validate_layer_4_check_sum!(now, ethernet_addresses, packet, packet_processing);
+				packet_processing.process_internet_protocol_version_6(now, packet, payload_packet, payload_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+			}
+			
+			_ => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, payload_ether_type), packet_processing, packet),
+		}
+	}
+	
+	/// Class of service of the inner (IEEE 802.1Q) Virtual LAN tag, if any, whether stripped by hardware or not.
+	///
+	/// Only call once the packet's length has been validated for its Virtual LAN tags.
+	#[inline(always)]
+	fn innermost_class_of_service(packet: impl EthernetIncomingNetworkPacket) -> Option<ClassOfService>
+	{
+		const OuterTagControlInformationOffset: usize = EthernetPacketHeader::SizeU32 as usize;
+		const InnerTagControlInformationOffset: usize = OuterTagControlInformationOffset + VirtualLanPacketHeader::IEEE_802_1ad_Size;
+		
+		let tag_control_information = if packet.was_vlan_qinq_tag_control_information_stripped()
+		{
+			packet.stripped_vlan_qinq_tag_control_information().1
+		}
+		else if packet.was_vlan_tag_control_information_stripped()
+		{
+			packet.stripped_vlan_tag_control_information()
+		}
+		else
+		{
+			match packet.ethernet_packet().potentially_invalid_ether_type()
+			{
+				EtherType::VlanTagging => *packet.offset_into_data_reference::<TagControlInformation>(OuterTagControlInformationOffset),
+				
+				EtherType::QinQVlanTagging => *packet.offset_into_data_reference::<TagControlInformation>(InnerTagControlInformationOffset),
+				
+				_ => return None,
+			}
+		};
+		
+		Some(tag_control_information.class_of_service())
+	}
+	
+	/// Differentiated Service Code Point (DSCP) of an Internet Protocol (IP) version 4 or version 6 packet.
+	#[inline(always)]
+	fn differentiated_service_code_point(layer_3_packet: &Layer3Packet, layer_3_length: u16, potentially_invalid_ether_type: EtherType) -> Option<DifferentiatedServiceCodePoint>
+	{
+		// Both IPv4 (type of service) and IPv6 (traffic class) place the DSCP in the first two bytes.
+		if unlikely!(layer_3_length < 2)
+		{
+			return None
+		}
+		
+		let first_two_bytes: &[u8; 2] = layer_3_packet.as_type();
+		
+		match potentially_invalid_ether_type
+		{
+			EtherType::InternetProtocolVersion4 => Some(DifferentiatedServiceCodePoint::from(first_two_bytes[1] >> 2)),
+			
+			EtherType::InternetProtocolVersion6 => Some(DifferentiatedServiceCodePoint::from(((first_two_bytes[0] & 0b0000_1111) << 2) | (first_two_bytes[1] >> 6))),
+			
+			_ => None,
+		}
+	}
+	
 	#[inline(always)]
 	fn process_internet_protocol_version_4<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
 	{
@@ -561,6 +1289,141 @@ impl EthernetPacket
 		packet_processing.process_address_resolution_protocol(now, packet, layer_3_packet, layer_3_length, ethernet_addresses)
 	}
 	
+	#[inline(always)]
+	fn process_extensible_authentication_protocol_over_lan<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let layer_3_length = packet.packet_length_if_contiguous_less_ethernet_packet_header();
+		Self::process_extensible_authentication_protocol_over_lan_frame(self.layer_3_packet(), now, packet, &packet_processing_by_virtual_lan.none, layer_3_length, self.ethernet_addresses(), self.potentially_invalid_ether_type())
+	}
+	
+	/// Media access control (MAC) control frames are never Virtual LAN tagged.
+	#[inline(always)]
+	fn process_media_access_control_control<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		let (packet_processing, layer_3_length, layer_3_packet) = guard_ethernet_addresses_and_compute_packet_length!(now, self, ethernet_addresses, packet, packet_processing_by_virtual_lan);
+		Self::process_media_access_control_control_frame(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses)
+	}
+	
+	/// Processes an IEEE 802.3x PAUSE or IEEE 802.1Qbb Priority-based Flow Control (PFC) frame.
+	#[inline(always)]
+	fn process_media_access_control_control_frame<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(layer_3_packet: &Layer3Packet, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>, layer_3_length: u16, ethernet_addresses: &'ethernet_addresses EthernetAddresses)
+	{
+		let (_source_ethernet_address, destination_ethernet_address) = ethernet_addresses.addresses();
+		
+		if let Err(reason) = packet_processing.flow_control().process(destination_ethernet_address, layer_3_packet, layer_3_length)
+		{
+			drop!(ProblematicFlowControlPacket { now, ethernet_addresses, reason }, packet_processing, packet)
+		}
+		
+		packet.free_direct_contiguous_packet()
+	}
+	
+	/// The inner frame is decrypted in place and then processed as if it had been received; hardware can not have stripped its Virtual LAN tags, nor categorised it.
+	#[inline(always)]
+	fn process_media_access_control_security<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		
+		let media_access_control_security = match packet_processing_by_virtual_lan.media_access_control_security
+		{
+			None => drop!(Self::unsupported_ether_type::<EINPDO, ARP, IPV4, IPV6>(now, ethernet_addresses, self.potentially_invalid_ether_type()), packet_processing_by_virtual_lan, packet),
+			
+			Some(ref media_access_control_security) => media_access_control_security,
+		};
+		
+		let packet_processing = &packet_processing_by_virtual_lan.none;
+		guard_ethernet_addresses!(now, ethernet_addresses, packet, packet_processing);
+		
+		let inner_packet = match media_access_control_security.decapsulate(packet)
+		{
+			Err(reason) => drop!(ProblematicMediaAccessControlSecurityPacket { now, ethernet_addresses, reason }, packet_processing_by_virtual_lan, packet),
+			
+			Ok(inner_packet) => inner_packet,
+		};
+		
+		// The inner frame is protected, so it is not subject to `guard_media_access_control_security_is_not_required!()`.
+		let inner_ethernet_packet = inner_packet.ethernet_packet();
+		guard_is_valid_ethernet_packet!(now, inner_ethernet_packet, packet_processing_by_virtual_lan, inner_packet);
+		inner_ethernet_packet.process_without_offloaded_vlan_stripping(now, inner_packet, packet_processing_by_virtual_lan)
+	}
+	
+	#[inline(always)]
+	fn process_multiprotocol_label_switching<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		let (packet_processing, layer_3_length, layer_3_packet) = guard_ethernet_addresses_and_compute_packet_length!(now, self, ethernet_addresses, packet, packet_processing_by_virtual_lan);
+		Self::process_multiprotocol_label_switching_label_stack(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, self.potentially_invalid_ether_type())
+	}
+	
+	#[inline(always)]
+	fn process_point_to_point_protocol_over_ethernet<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		let (packet_processing, layer_3_length, layer_3_packet) = guard_ethernet_addresses_and_compute_packet_length!(now, self, ethernet_addresses, packet, packet_processing_by_virtual_lan);
+		Self::process_point_to_point_protocol_over_ethernet_payload(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, self.potentially_invalid_ether_type())
+	}
+	
+	#[inline(always)]
+	fn process_precision_time_protocol<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		let (packet_processing, layer_3_length, layer_3_packet) = guard_ethernet_addresses_and_compute_packet_length!(now, self, ethernet_addresses, packet, packet_processing_by_virtual_lan);
+		Self::process_precision_time_protocol_message(layer_3_packet, now, packet, packet_processing, layer_3_length, ethernet_addresses, self.potentially_invalid_ether_type())
+	}
+	
+	#[inline(always)]
+	fn process_slow_protocols<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(&'ethernet_addresses self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing_by_virtual_lan: &VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>)
+	{
+		let ethernet_addresses = self.ethernet_addresses();
+		let (source_ethernet_address, destination_ethernet_address) = ethernet_addresses.addresses();
+		
+		if unlikely!(source_ethernet_address.is_not_valid_unicast())
+		{
+			drop!(SourceEthernetAddressIsNotValidUnicast { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		if unlikely!(destination_ethernet_address != &MediaAccessControlAddress::SlowProtocolsMulticast)
+		{
+			drop!(SlowProtocolsDestinationEthernetAddressIsNotSlowProtocolsMulticast { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		let link_aggregation_member = match packet_processing_by_virtual_lan.link_aggregation_member
+		{
+			None => drop!(LinkAggregationIsNotConfigured { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet),
+			
+			Some(ref link_aggregation_member) => link_aggregation_member,
+		};
+		
+		let layer_3_length = packet.packet_length_if_contiguous_less_ethernet_packet_header();
+		if unlikely!(layer_3_length == 0)
+		{
+			drop!(SlowProtocolsPacketIsTooShort { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		let layer_3_packet = self.layer_3_packet();
+		
+		let subtype = *layer_3_packet.as_type::<u8>();
+		if unlikely!(subtype != LinkAggregationControlProtocolDataUnit::Subtype)
+		{
+			drop!(UnsupportedSlowProtocolsSubtype { now, ethernet_addresses, subtype }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		if unlikely!(layer_3_length < LinkAggregationControlProtocolDataUnit::SizeU16)
+		{
+			drop!(SlowProtocolsPacketIsTooShort { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		let link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit = layer_3_packet.as_type();
+		if unlikely!(link_aggregation_control_protocol_data_unit.is_invalid())
+		{
+			drop!(LinkAggregationControlProtocolDataUnitIsInvalid { now, ethernet_addresses }, packet_processing_by_virtual_lan, packet)
+		}
+		
+		link_aggregation_member.process_link_aggregation_control_protocol_data_unit(now, link_aggregation_control_protocol_data_unit);
+		packet.free_direct_contiguous_packet()
+	}
+	
 	#[inline(always)]
 	fn unsupported_ether_type<'ethernet_addresses, EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>(now: MonotonicMillisecondTimestamp, ethernet_addresses: &'ethernet_addresses EthernetAddresses, potentially_invalid_ether_type: EtherType) -> EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, EINPDO::ARPINPDR, EINPDO::IPV4INPDR, EINPDO::IPV6INPDR>
 	{
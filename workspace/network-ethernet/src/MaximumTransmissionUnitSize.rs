@@ -159,20 +159,11 @@ impl MaximumTransmissionUnitSize
 		MaximumTransmissionUnitSize(self.0 - 8)
 	}
 	
-	/// Decrease for PPPoE header.
+	/// Decrease for PPPoE header and the Point-to-Point Protocol (PPP) protocol field, ie by 8 bytes.
 	#[inline(always)]
 	pub const fn decrease_for_pppoe_header(self) -> Self
 	{
-		#[repr(C, packed)]
-		struct PointToPointProtocolOverEthernetHeader
-		{
-			version_and_type: u8, // 4 bit version 0x1, 4 bit type, 0x1.
-			code: u8,
-			session_id: NetworkEndianU16,
-			length: NetworkEndianU16,
-		}
-		
-		MaximumTransmissionUnitSize(self.0 - size_of::<PointToPointProtocolOverEthernetHeader>() as u16)
+		MaximumTransmissionUnitSize(self.0 - PointToPointProtocolOverEthernetHeader::SessionOverheadSizeU16)
 	}
 	
 	/// Decrease for DS-Lite Internet Protocol Version 6 header.
@@ -301,6 +301,26 @@ impl MediaAccessControlAddress
 	/// An address that is all zeros.
 	pub const Zero: Self = MediaAccessControlAddress([0; Self::Size]);
 	
+	/// Bridge group address, used by the Spanning Tree Protocol (STP) and Rapid Spanning Tree Protocol (RSTP).
+	///
+	/// IEEE Std 802.1D-2004, Section 7.12.3, Table 7-10.
+	pub const BridgeGroupAddress: Self = MediaAccessControlAddress([0x01, 0x80, 0xC2, 0x00, 0x00, 0x00]);
+	
+	/// Media access control (MAC) control multicast address, used by IEEE 802.3x PAUSE and IEEE 802.1Qbb Priority-based Flow Control (PFC).
+	///
+	/// IEEE Std 802.3-2015, Annex 31B.
+	pub const MediaAccessControlControlMulticast: Self = MediaAccessControlAddress([0x01, 0x80, 0xC2, 0x00, 0x00, 0x01]);
+	
+	/// Port access entity (PAE) group address, used by IEEE 802.1X Extensible Authentication Protocol over LAN (EAPOL).
+	///
+	/// IEEE Std 802.1X-2010, Section 11.1.1, Table 11-1.
+	pub const PortAccessEntityGroupAddress: Self = MediaAccessControlAddress([0x01, 0x80, 0xC2, 0x00, 0x00, 0x03]);
+	
+	/// Slow protocols multicast address, used by the Link Aggregation Control Protocol (LACP).
+	///
+	/// IEEE Std 802.3-2015, Annex 57B.
+	pub const SlowProtocolsMulticast: Self = MediaAccessControlAddress([0x01, 0x80, 0xC2, 0x00, 0x00, 0x02]);
+	
 	/// Alternative formatting to debug and display format.
 	///
 	/// As per IEEE standard 802 (2001), ISBN 0-7381-2941-0.
@@ -492,6 +512,18 @@ impl MediaAccessControlAddress
 		self.get_first_four_bytes_network_endian() != 0xFFFF_FFFF || self.get_last_two_bytes_network_endian() != 0xFFFF
 	}
 	
+	/// Is this one of the sixteen bridge filtered reserved group addresses, `01:80:C2:00:00:00` to `01:80:C2:00:00:0F`?
+	///
+	/// Frames sent to these addresses (eg for Spanning Tree, Slow Protocols and PAUSE) are never forwarded by a bridge.
+	///
+	/// IEEE Std 802.1Q-2014, Section 8.6.3, Table 8-1.
+	#[inline(always)]
+	pub fn is_bridge_filtered_reserved_group_address(&self) -> bool
+	{
+		let octets = &self.0;
+		octets[0] == 0x01 && octets[1] == 0x80 && octets[2] == 0xC2 && octets[3] == 0x00 && octets[4] == 0x00 && (octets[5] & 0xF0) == 0x00
+	}
+	
 	/// Is this an universally administered address?
 	#[inline(always)]
 	pub fn is_universally_administered(&self) -> bool
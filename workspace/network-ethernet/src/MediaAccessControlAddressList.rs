@@ -11,7 +11,7 @@ pub enum MediaAccessControlAddressList
 {
 	/// Blacklist.
 	///
-	/// If the media access control address occurs in *either* of `organizationally_unique_identifiers_in_network_byte_order` or `other_media_access_control_addresses`, then it is blacklisted.
+	/// If the media access control address occurs in *any* of `organizationally_unique_identifiers_in_network_byte_order`, `unicast_media_access_control_addresses` or `prefixes`, then it is blacklisted.
 	Blacklist
 	{
 		/// Organizationally Unique Identifiers (OUIs), universally administered.
@@ -22,12 +22,18 @@ pub enum MediaAccessControlAddressList
 		///
 		/// There is no need to include the addresses of our ethernet interface.
 		#[serde(default)]
-		unicast_media_access_control_addresses: HashSet<MediaAccessControlAddress>
+		unicast_media_access_control_addresses: HashSet<MediaAccessControlAddress>,
+		
+		/// Prefixes of any length, eg IEEE MA-M (28 bit) or MA-S (36 bit) assignments, or ranges of locally administered addresses.
+		///
+		/// Unlike `organizationally_unique_identifiers_in_network_byte_order`, these also match locally administered addresses.
+		#[serde(default)]
+		prefixes: MediaAccessControlAddressPrefixTrie,
 	},
 	
 	/// Whitelist.
 	///
-	/// If the media access control address occurs in *either* of `organizationally_unique_identifiers_in_network_byte_order` or `other_media_access_control_addresses`, then it is whitelisted.
+	/// If the media access control address occurs in *any* of `organizationally_unique_identifiers_in_network_byte_order`, `unicast_media_access_control_addresses` or `prefixes`, then it is whitelisted.
 	Whitelist
 	{
 		/// Organizationally Unique Identifiers (OUIs), universally administered.
@@ -38,7 +44,13 @@ pub enum MediaAccessControlAddressList
 		///
 		/// There is no need to include the addresses of our ethernet interface.
 		#[serde(default)]
-		unicast_media_access_control_addresses: HashSet<MediaAccessControlAddress>
+		unicast_media_access_control_addresses: HashSet<MediaAccessControlAddress>,
+		
+		/// Prefixes of any length, eg IEEE MA-M (28 bit) or MA-S (36 bit) assignments, or ranges of locally administered addresses.
+		///
+		/// Unlike `organizationally_unique_identifiers_in_network_byte_order`, these also match locally administered addresses.
+		#[serde(default)]
+		prefixes: MediaAccessControlAddressPrefixTrie,
 	}
 }
 
@@ -51,6 +63,7 @@ impl Default for MediaAccessControlAddressList
 		{
 			organizationally_unique_identifiers_in_network_byte_order: Default::default(),
 			unicast_media_access_control_addresses: Default::default(),
+			prefixes: Default::default(),
 		}
 	}
 }
@@ -63,21 +76,20 @@ impl MediaAccessControlAddressList
 	{
 		use self::MediaAccessControlAddressList::*;
 		
-		if let Some((organizationally_unique_identifier, _)) = media_access_control_address.universally_administered_organizationally_unique_identifier()
+		let (is_blacklist, organizationally_unique_identifiers_in_network_byte_order, unicast_media_access_control_addresses, prefixes) = match *self
 		{
-			match *self
-			{
-				Blacklist { ref organizationally_unique_identifiers_in_network_byte_order, ref unicast_media_access_control_addresses } => organizationally_unique_identifiers_in_network_byte_order.contains(organizationally_unique_identifier) || unicast_media_access_control_addresses.contains(media_access_control_address),
-				Whitelist { ref organizationally_unique_identifiers_in_network_byte_order, ref unicast_media_access_control_addresses } => !(organizationally_unique_identifiers_in_network_byte_order.contains(organizationally_unique_identifier) || unicast_media_access_control_addresses.contains(media_access_control_address)),
-			}
-		}
-		else
+			Blacklist { ref organizationally_unique_identifiers_in_network_byte_order, ref unicast_media_access_control_addresses, ref prefixes } => (true, organizationally_unique_identifiers_in_network_byte_order, unicast_media_access_control_addresses, prefixes),
+			Whitelist { ref organizationally_unique_identifiers_in_network_byte_order, ref unicast_media_access_control_addresses, ref prefixes } => (false, organizationally_unique_identifiers_in_network_byte_order, unicast_media_access_control_addresses, prefixes),
+		};
+		
+		let is_organizationally_unique_identifier_listed = match media_access_control_address.universally_administered_organizationally_unique_identifier()
 		{
-			match *self
-			{
-				Blacklist { ref unicast_media_access_control_addresses, .. } => unicast_media_access_control_addresses.contains(media_access_control_address),
-				Whitelist { ref unicast_media_access_control_addresses, .. } => !(unicast_media_access_control_addresses.contains(media_access_control_address)),
-			}
-		}
+			Some((organizationally_unique_identifier, _)) => organizationally_unique_identifiers_in_network_byte_order.contains(organizationally_unique_identifier),
+			None => false,
+		};
+		
+		let is_listed = is_organizationally_unique_identifier_listed || unicast_media_access_control_addresses.contains(media_access_control_address) || prefixes.matches(media_access_control_address);
+		
+		is_blacklist == is_listed
 	}
 }
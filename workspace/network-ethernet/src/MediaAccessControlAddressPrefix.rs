@@ -0,0 +1,55 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A prefix of a media access control address of any length in bits, eg an IEEE MA-L (24 bits), MA-M (28 bits) or MA-S (36 bits) assignment, or a range of locally administered addresses.
+///
+/// Bits of `address` after `length` are ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct MediaAccessControlAddressPrefix
+{
+	/// Address.
+	pub address: MediaAccessControlAddress,
+	
+	/// Length in bits, 0 to 48 inclusive.
+	pub length: u8,
+}
+
+impl Display for MediaAccessControlAddressPrefix
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}/{}", self.address, self.length)
+	}
+}
+
+impl MediaAccessControlAddressPrefix
+{
+	/// Maximum length in bits.
+	pub const MaximumLength: u8 = (MediaAccessControlAddress::Size * 8) as u8;
+	
+	/// Creates a new instance.
+	///
+	/// Returns an error if `length` exceeds 48.
+	#[inline(always)]
+	pub fn new(address: MediaAccessControlAddress, length: u8) -> Result<Self, ()>
+	{
+		if length > Self::MaximumLength
+		{
+			Err(())
+		}
+		else
+		{
+			Ok
+			(
+				Self
+				{
+					address,
+					length,
+				}
+			)
+		}
+	}
+}
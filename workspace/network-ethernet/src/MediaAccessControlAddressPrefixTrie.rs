@@ -0,0 +1,187 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A set of media access control address prefixes of any length, held as a trie with a stride of 4 bits.
+///
+/// A lookup visits at most 12 nodes; prefixes whose length is not a multiple of 4 bits are expanded into the nibbles they cover.
+///
+/// Serialized as a list of `MediaAccessControlAddressPrefix`.
+#[derive(Debug, Clone)]
+pub struct MediaAccessControlAddressPrefixTrie
+{
+	prefixes: Vec<MediaAccessControlAddressPrefix>,
+	nodes: Vec<MediaAccessControlAddressPrefixTrieNode>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MediaAccessControlAddressPrefixTrieNode
+{
+	children: [u32; 16],
+	matching_nibbles: u16,
+	matches_all: bool,
+}
+
+impl Default for MediaAccessControlAddressPrefixTrie
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::new(Vec::new()).unwrap()
+	}
+}
+
+impl Serialize for MediaAccessControlAddressPrefixTrie
+{
+	#[inline(always)]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		self.prefixes.serialize(serializer)
+	}
+}
+
+impl<'deserialize> Deserialize<'deserialize> for MediaAccessControlAddressPrefixTrie
+{
+	#[inline(always)]
+	fn deserialize<D: Deserializer<'deserialize>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		let prefixes = Vec::<MediaAccessControlAddressPrefix>::deserialize(deserializer)?;
+		Self::new(prefixes).map_err(|_| <D::Error as de::Error>::custom("MediaAccessControlAddressPrefix length exceeds 48 bits"))
+	}
+}
+
+impl MediaAccessControlAddressPrefixTrie
+{
+	const BitsPerNibble: u8 = 4;
+	
+	const Nibbles: u8 = MediaAccessControlAddressPrefix::MaximumLength / Self::BitsPerNibble;
+	
+	const Root: usize = 0;
+	
+	const NoChild: u32 = 0;
+	
+	/// Creates a new instance.
+	///
+	/// Returns an error if a prefix's length exceeds 48 bits.
+	#[inline(always)]
+	pub fn new(prefixes: Vec<MediaAccessControlAddressPrefix>) -> Result<Self, ()>
+	{
+		let mut this = Self
+		{
+			prefixes: Vec::with_capacity(prefixes.len()),
+			nodes: vec![MediaAccessControlAddressPrefixTrieNode::default()],
+		};
+		
+		for prefix in prefixes
+		{
+			this.insert(prefix)?
+		}
+		
+		Ok(this)
+	}
+	
+	/// Inserts a prefix.
+	///
+	/// Returns an error if the prefix's length exceeds 48 bits.
+	#[inline(always)]
+	pub fn insert(&mut self, prefix: MediaAccessControlAddressPrefix) -> Result<(), ()>
+	{
+		if prefix.length > MediaAccessControlAddressPrefix::MaximumLength
+		{
+			return Err(())
+		}
+		
+		let address = Self::address_to_u64(&prefix.address);
+		let whole_nibbles = prefix.length / Self::BitsPerNibble;
+		let remaining_bits = prefix.length % Self::BitsPerNibble;
+		
+		let mut node_index = Self::Root;
+		for depth in 0 .. whole_nibbles
+		{
+			let nibble = Self::nibble(address, depth);
+			let child = self.nodes[node_index].children[nibble];
+			node_index = if child == Self::NoChild
+			{
+				let new_child = self.nodes.len();
+				self.nodes.push(MediaAccessControlAddressPrefixTrieNode::default());
+				self.nodes[node_index].children[nibble] = new_child as u32;
+				new_child
+			}
+			else
+			{
+				child as usize
+			};
+		}
+		
+		let node = &mut self.nodes[node_index];
+		if remaining_bits == 0
+		{
+			node.matches_all = true
+		}
+		else
+		{
+			let mask = (0b1111 << (Self::BitsPerNibble - remaining_bits)) & 0b1111;
+			let masked_nibble = Self::nibble(address, whole_nibbles) & mask;
+			for nibble in 0 .. 16
+			{
+				if nibble & mask == masked_nibble
+				{
+					node.matching_nibbles |= 1 << nibble
+				}
+			}
+		}
+		
+		self.prefixes.push(prefix);
+		Ok(())
+	}
+	
+	/// Is this empty?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.prefixes.is_empty()
+	}
+	
+	/// Does any prefix match `media_access_control_address`?
+	#[inline(always)]
+	pub fn matches(&self, media_access_control_address: &MediaAccessControlAddress) -> bool
+	{
+		let address = Self::address_to_u64(media_access_control_address);
+		
+		let mut node = unsafe { self.nodes.get_unchecked(Self::Root) };
+		for depth in 0 .. Self::Nibbles
+		{
+			if node.matches_all
+			{
+				return true
+			}
+			
+			let nibble = Self::nibble(address, depth);
+			
+			if node.matching_nibbles & (1 << nibble) != 0
+			{
+				return true
+			}
+			
+			match unsafe { *node.children.get_unchecked(nibble) }
+			{
+				Self::NoChild => return false,
+				child @ _ => node = unsafe { self.nodes.get_unchecked(child as usize) },
+			}
+		}
+		node.matches_all
+	}
+	
+	#[inline(always)]
+	fn address_to_u64(media_access_control_address: &MediaAccessControlAddress) -> u64
+	{
+		media_access_control_address.to_octets_reference().iter().fold(0, |value, &octet| (value << 8) | (octet as u64))
+	}
+	
+	#[inline(always)]
+	fn nibble(address: u64, depth: u8) -> usize
+	{
+		let shift = (Self::Nibbles - 1 - depth) * Self::BitsPerNibble;
+		((address >> shift) & 0b1111) as usize
+	}
+}
@@ -0,0 +1,250 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A transparent learning bridge (IEEE Std 802.1D / IEEE Std 802.1Q) between a number of bridge ports.
+///
+/// Each Virtual LAN has its own forwarding database (independent virtual LAN learning).
+///
+/// Frames in a Virtual LAN without a learning bridge configuration are not bridged and are always processed locally.
+///
+/// If a spanning tree is configured, it is common to all Virtual LANs; the bridge is then driven by calling `poll()` regularly and `link_up()` and `link_down()` as bridge port links change.
+#[derive(Debug)]
+pub struct LearningBridge<BFT: LearningBridgeFrameTransmitter>
+{
+	transmitter: BFT,
+	virtual_lans: HashMap<VirtualLanIdentifier, LearningBridgeVirtualLan>,
+	none: Option<LearningBridgeVirtualLan>,
+	spanning_tree: Option<SpanningTreeBridge>,
+}
+
+impl<BFT: LearningBridgeFrameTransmitter> LearningBridge<BFT>
+{
+	#[inline(always)]
+	pub(crate) fn new(transmitter: BFT, virtual_lans: HashMap<VirtualLanIdentifier, LearningBridgeVirtualLan>, none: Option<LearningBridgeVirtualLan>, spanning_tree: Option<SpanningTreeBridge>) -> Self
+	{
+		let this = Self
+		{
+			transmitter,
+			virtual_lans,
+			none,
+			spanning_tree,
+		};
+		this.update_flood_port_numbers();
+		this
+	}
+
+	/// Frame transmitter.
+	#[inline(always)]
+	pub fn transmitter(&self) -> &BFT
+	{
+		&self.transmitter
+	}
+
+	/// Spanning tree, if configured.
+	#[inline(always)]
+	pub fn spanning_tree(&self) -> Option<&SpanningTreeBridge>
+	{
+		self.spanning_tree.as_ref()
+	}
+
+	/// Learning bridge state for a Virtual LAN; `None` for the `virtual_lan_identifier` is untagged (or priority-tagged) frames.
+	#[inline(always)]
+	pub fn virtual_lan(&self, virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Option<&LearningBridgeVirtualLan>
+	{
+		match virtual_lan_identifier
+		{
+			None => self.none.as_ref(),
+			Some(virtual_lan_identifier) => self.virtual_lans.get(&virtual_lan_identifier),
+		}
+	}
+
+	/// State of a bridge port for a Virtual LAN.
+	///
+	/// Ports that are not members of the Virtual LAN are discarding; ports that are not participating in the spanning tree (or if there is no spanning tree) are otherwise forwarding.
+	#[inline(always)]
+	pub fn port_state(&self, virtual_lan_identifier: Option<VirtualLanIdentifier>, port_number: u16) -> SpanningTreePortState
+	{
+		match self.virtual_lan(virtual_lan_identifier)
+		{
+			Some(virtual_lan) if virtual_lan.is_member(port_number) => self.spanning_tree_port_state(port_number),
+			_ => SpanningTreePortState::Discarding,
+		}
+	}
+
+	/// Discards all learned entries in all Virtual LANs.
+	#[inline(always)]
+	pub fn flush_learned_entries(&self)
+	{
+		if let Some(ref none) = self.none
+		{
+			none.flush_learned_entries()
+		}
+
+		for virtual_lan in self.virtual_lans.values()
+		{
+			virtual_lan.flush_learned_entries()
+		}
+	}
+
+	/// The physical link of a bridge port came up.
+	#[inline(always)]
+	pub fn link_up(&self, now: MonotonicMillisecondTimestamp, port_number: u16)
+	{
+		if let Some(ref spanning_tree) = self.spanning_tree
+		{
+			let changes = spanning_tree.link_up(now, port_number);
+			self.apply_spanning_tree_changes(changes)
+		}
+	}
+
+	/// The physical link of a bridge port went down.
+	#[inline(always)]
+	pub fn link_down(&self, now: MonotonicMillisecondTimestamp, port_number: u16)
+	{
+		if let Some(ref spanning_tree) = self.spanning_tree
+		{
+			let changes = spanning_tree.link_down(now, port_number);
+			self.apply_spanning_tree_changes(changes)
+		}
+	}
+
+	/// Runs spanning tree timers and state machines, and transmits any Bridge Protocol Data Units (BPDUs) that need to be sent.
+	#[inline(always)]
+	pub fn poll(&self, now: MonotonicMillisecondTimestamp, transmitter: &impl BridgeProtocolDataUnitTransmitter)
+	{
+		if let Some(ref spanning_tree) = self.spanning_tree
+		{
+			let changes = spanning_tree.poll(now, transmitter);
+			self.apply_spanning_tree_changes(changes)
+		}
+	}
+
+	/// Returns `false` if there is no spanning tree.
+	#[inline(always)]
+	pub(crate) fn process_bridge_protocol_data_unit(&self, now: MonotonicMillisecondTimestamp, ingress_port_number: u16, bridge_protocol_data_unit: &BridgeProtocolDataUnit, kind: BridgeProtocolDataUnitKind) -> bool
+	{
+		match self.spanning_tree
+		{
+			None => false,
+
+			Some(ref spanning_tree) =>
+			{
+				let changes = spanning_tree.process_bridge_protocol_data_unit(now, ingress_port_number, bridge_protocol_data_unit, kind);
+				self.apply_spanning_tree_changes(changes);
+				true
+			}
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn bridge(&self, now: MonotonicMillisecondTimestamp, ingress_port_number: u16, virtual_lan_identifier: Option<VirtualLanIdentifier>, source_may_be_learned: bool, destination_is_ours: bool, packet: impl EthernetIncomingNetworkPacket) -> LearningBridgeOutcome
+	{
+		use self::LearningBridgeOutcome::*;
+
+		let virtual_lan = match self.virtual_lan(virtual_lan_identifier)
+		{
+			None => return DeliverLocally,
+			Some(virtual_lan) => virtual_lan,
+		};
+
+		if unlikely!(!virtual_lan.is_member(ingress_port_number))
+		{
+			return IngressPortIsNotAMemberOfVirtualLan
+		}
+
+		let (source_ethernet_address, destination_ethernet_address) = packet.ethernet_packet().ethernet_addresses().addresses();
+
+		// Bridge filtered reserved group addresses are for the bridge itself (or a protocol entity on the port) whatever the port state.
+		if unlikely!(destination_ethernet_address.is_bridge_filtered_reserved_group_address())
+		{
+			return DeliverLocally
+		}
+
+		let ingress_port_state = self.spanning_tree_port_state(ingress_port_number);
+
+		if likely!(source_may_be_learned && ingress_port_state.is_learning())
+		{
+			if unlikely!(!virtual_lan.learn(now, source_ethernet_address, ingress_port_number))
+			{
+				return SourceEthernetAddressIsStaticallyBoundToAnotherPort
+			}
+		}
+
+		if unlikely!(!ingress_port_state.is_forwarding())
+		{
+			return IngressPortIsNotForwarding
+		}
+
+		if destination_ethernet_address.is_multicast_or_broadcast()
+		{
+			virtual_lan.with_flood_port_numbers(ingress_port_number, |flood_port_numbers| self.transmitter.flood(flood_port_numbers, packet));
+			return DeliverLocally
+		}
+
+		if destination_is_ours
+		{
+			return DeliverLocally
+		}
+
+		match virtual_lan.look_up(now, destination_ethernet_address)
+		{
+			Some(egress_port_number) if self.spanning_tree_port_state(egress_port_number).is_forwarding() => if unlikely!(egress_port_number == ingress_port_number)
+			{
+				DestinationEthernetAddressIsOnIngressPort
+			}
+			else
+			{
+				self.transmitter.forward(egress_port_number, packet);
+				Consumed
+			},
+
+			_ =>
+			{
+				virtual_lan.with_flood_port_numbers(ingress_port_number, |flood_port_numbers| self.transmitter.flood(flood_port_numbers, packet));
+				packet.free_direct_contiguous_packet();
+				Consumed
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn spanning_tree_port_state(&self, port_number: u16) -> SpanningTreePortState
+	{
+		match self.spanning_tree
+		{
+			None => SpanningTreePortState::Forwarding,
+			Some(ref spanning_tree) => spanning_tree.port_state(port_number).unwrap_or(SpanningTreePortState::Forwarding),
+		}
+	}
+
+	#[inline(always)]
+	fn apply_spanning_tree_changes(&self, changes: SpanningTreeChanges)
+	{
+		if changes.port_states_changed
+		{
+			self.update_flood_port_numbers()
+		}
+
+		if changes.topology_changed
+		{
+			self.flush_learned_entries()
+		}
+	}
+
+	#[inline(always)]
+	fn update_flood_port_numbers(&self)
+	{
+		let is_forwarding = |port_number| self.spanning_tree_port_state(port_number).is_forwarding();
+
+		if let Some(ref none) = self.none
+		{
+			none.update_flood_port_numbers(is_forwarding)
+		}
+
+		for virtual_lan in self.virtual_lans.values()
+		{
+			virtual_lan.update_flood_port_numbers(is_forwarding)
+		}
+	}
+}
@@ -0,0 +1,42 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Learning bridge configuration.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct LearningBridgeConfiguration
+{
+	/// Learning bridge configuration for IEEE 802.1Q Virtual LANs (or the outer Virtual LAN of IEEE 802.1ad QinQ Virtual LANs).
+	///
+	/// Frames in a Virtual LAN not present here are not bridged.
+	#[serde(default)] pub virtual_lans: HashMap<VirtualLanIdentifier, LearningBridgeVirtualLanConfiguration>,
+
+	/// Learning bridge configuration for untagged (and priority-tagged) frames.
+	///
+	/// If absent, untagged frames are not bridged.
+	#[serde(default)] pub none: Option<LearningBridgeVirtualLanConfiguration>,
+
+	/// Spanning tree, common to all Virtual LANs.
+	///
+	/// If absent, there is no loop prevention.
+	#[serde(default)] pub spanning_tree: Option<SpanningTreeBridgeConfiguration>,
+}
+
+impl LearningBridgeConfiguration
+{
+	/// Configure.
+	///
+	/// `bridge_address` is used to identify this bridge in the spanning tree; see `SpanningTreeBridgeConfiguration::configure()`.
+	#[inline(always)]
+	pub fn configure<BFT: LearningBridgeFrameTransmitter>(self, transmitter: BFT, bridge_address: MediaAccessControlAddress) -> LearningBridge<BFT>
+	{
+		let virtual_lans = self.virtual_lans.into_iter().map(|(virtual_lan_identifier, virtual_lan_configuration)| (virtual_lan_identifier, virtual_lan_configuration.configure())).collect();
+
+		let none = self.none.map(|virtual_lan_configuration| virtual_lan_configuration.configure());
+
+		let spanning_tree = self.spanning_tree.map(|spanning_tree_configuration| spanning_tree_configuration.configure(bridge_address));
+
+		LearningBridge::new(transmitter, virtual_lans, none, spanning_tree)
+	}
+}
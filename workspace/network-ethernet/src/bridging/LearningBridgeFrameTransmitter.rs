@@ -0,0 +1,23 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Transmits frames forwarded or flooded by a `LearningBridge`.
+///
+/// Frames are transmitted unaltered, including any Virtual LAN tags that were not stripped by hardware.
+pub trait LearningBridgeFrameTransmitter: Debug
+{
+	/// Implement this to transmit `packet` out of the bridge port `egress_port_number`.
+	///
+	/// The implementation takes responsibility for the packet; it must eventually transmit or free it.
+	#[inline(always)]
+	fn forward(&self, egress_port_number: u16, packet: impl EthernetIncomingNetworkPacket);
+
+	/// Implement this to transmit a copy of `packet` out of each of the bridge ports `egress_port_numbers`.
+	///
+	/// The implementation must *not* free the packet; it remains the responsibility of the caller.
+	///
+	/// With DPDK, a copy is normally made by incrementing the reference count of the packet or by using an indirect packet buffer.
+	#[inline(always)]
+	fn flood(&self, egress_port_numbers: &[u16], packet: impl EthernetIncomingNetworkPacket);
+}
@@ -0,0 +1,26 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Outcome of bridging a frame.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub(crate) enum LearningBridgeOutcome
+{
+	/// The frame was not forwarded and should be processed locally; it may also have been flooded.
+	DeliverLocally,
+
+	/// The frame was forwarded or flooded and is no longer the responsibility of the caller.
+	Consumed,
+
+	/// The ingress port is not a member of the frame's Virtual LAN.
+	IngressPortIsNotAMemberOfVirtualLan,
+
+	/// The ingress port is not forwarding (it is discarding or learning) because of the spanning tree.
+	IngressPortIsNotForwarding,
+
+	/// The frame's source ethernet address has a static entry for a different port.
+	SourceEthernetAddressIsStaticallyBoundToAnotherPort,
+
+	/// The frame's destination ethernet address is known to be on the ingress port, so there is no need to forward it.
+	DestinationEthernetAddressIsOnIngressPort,
+}
@@ -0,0 +1,123 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// The member ports and forwarding database of one Virtual LAN (or of untagged frames) in a learning bridge.
+#[derive(Debug)]
+pub struct LearningBridgeVirtualLan
+{
+	member_port_numbers: HashSet<u16>,
+	flood_port_numbers_by_ingress_port_number: RefCell<HashMap<u16, Box<[u16]>>>,
+	static_entries: HashMap<MediaAccessControlAddress, u16>,
+	learned_entries: RefCell<LeastRecentlyUsedCacheWithExpiry<MediaAccessControlAddress, u16>>,
+	maximum_learned_entries: usize,
+	ageing_time: MillisecondDuration,
+}
+
+impl LearningBridgeVirtualLan
+{
+	#[inline(always)]
+	pub(crate) fn new(member_port_numbers: HashSet<u16>, static_entries: HashMap<MediaAccessControlAddress, u16>, maximum_learned_entries: usize, ageing_time: MillisecondDuration) -> Self
+	{
+		let flood_port_numbers_by_ingress_port_number = Self::flood_port_numbers_by_ingress_port_number(&member_port_numbers, |_| true);
+
+		Self
+		{
+			member_port_numbers,
+			flood_port_numbers_by_ingress_port_number: RefCell::new(flood_port_numbers_by_ingress_port_number),
+			static_entries,
+			learned_entries: RefCell::new(LeastRecentlyUsedCacheWithExpiry::new(maximum_learned_entries, ageing_time)),
+			maximum_learned_entries,
+			ageing_time,
+		}
+	}
+
+	/// Looks up the port a destination ethernet address is on.
+	///
+	/// Static entries take precedence over learned entries.
+	#[inline(always)]
+	pub fn look_up(&self, now: MonotonicMillisecondTimestamp, destination_ethernet_address: &MediaAccessControlAddress) -> Option<u16>
+	{
+		match self.static_entries.get(destination_ethernet_address)
+		{
+			Some(&port_number) => Some(port_number),
+			None => self.learned_entries.borrow_mut().get(now, destination_ethernet_address).cloned(),
+		}
+	}
+
+	/// Discards all learned entries; static entries are retained.
+	#[inline(always)]
+	pub fn flush_learned_entries(&self)
+	{
+		*self.learned_entries.borrow_mut() = LeastRecentlyUsedCacheWithExpiry::new(self.maximum_learned_entries, self.ageing_time)
+	}
+
+	/// Is `port_number` a member of this Virtual LAN?
+	#[inline(always)]
+	pub fn is_member(&self, port_number: u16) -> bool
+	{
+		self.member_port_numbers.contains(&port_number)
+	}
+
+	/// Calls `callback` with the member ports a frame received on `ingress_port_number` should be flooded to.
+	#[inline(always)]
+	pub(crate) fn with_flood_port_numbers<R>(&self, ingress_port_number: u16, callback: impl FnOnce(&[u16]) -> R) -> R
+	{
+		let flood_port_numbers_by_ingress_port_number = self.flood_port_numbers_by_ingress_port_number.borrow();
+		let flood_port_numbers = match flood_port_numbers_by_ingress_port_number.get(&ingress_port_number)
+		{
+			None => &[],
+			Some(flood_port_numbers) => &flood_port_numbers[..],
+		};
+		callback(flood_port_numbers)
+	}
+
+	/// Recalculates the ports frames are flooded to after the forwarding state of ports has changed.
+	#[inline(always)]
+	pub(crate) fn update_flood_port_numbers(&self, is_forwarding: impl Fn(u16) -> bool)
+	{
+		*self.flood_port_numbers_by_ingress_port_number.borrow_mut() = Self::flood_port_numbers_by_ingress_port_number(&self.member_port_numbers, is_forwarding)
+	}
+
+	#[inline(always)]
+	fn flood_port_numbers_by_ingress_port_number(member_port_numbers: &HashSet<u16>, is_forwarding: impl Fn(u16) -> bool) -> HashMap<u16, Box<[u16]>>
+	{
+		member_port_numbers.iter().map(|&ingress_port_number|
+		{
+			let mut flood_port_numbers: Vec<u16> = member_port_numbers.iter().cloned().filter(|&port_number| port_number != ingress_port_number && is_forwarding(port_number)).collect();
+			flood_port_numbers.sort();
+			(ingress_port_number, flood_port_numbers.into_boxed_slice())
+		}).collect()
+	}
+
+	/// Returns `false` if the source ethernet address has a static entry for a different port.
+	#[inline(always)]
+	pub(crate) fn learn(&self, now: MonotonicMillisecondTimestamp, source_ethernet_address: &MediaAccessControlAddress, ingress_port_number: u16) -> bool
+	{
+		if let Some(&port_number) = self.static_entries.get(source_ethernet_address)
+		{
+			return port_number == ingress_port_number
+		}
+
+		let mut learned_entries = self.learned_entries.borrow_mut();
+
+		let already_learned = match learned_entries.get_mut(now, source_ethernet_address)
+		{
+			None => false,
+
+			Some(port_number) =>
+			{
+				// Station has moved.
+				*port_number = ingress_port_number;
+				true
+			}
+		};
+
+		if !already_learned
+		{
+			learned_entries.insert(now, *source_ethernet_address, ingress_port_number)
+		}
+
+		true
+	}
+}
@@ -0,0 +1,44 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Learning bridge configuration for one Virtual LAN (or for untagged frames).
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct LearningBridgeVirtualLanConfiguration
+{
+	/// Bridge ports that are members of this Virtual LAN; frames are only forwarded or flooded between member ports.
+	pub member_port_numbers: HashSet<u16>,
+
+	/// Static entries in the forwarding database; these are never aged and can not be overridden by learning.
+	#[serde(default)] pub static_entries: HashMap<MediaAccessControlAddress, u16>,
+
+	/// Maximum number of learned entries in the forwarding database; when full, the least recently used entry is discarded.
+	#[serde(default = "LearningBridgeVirtualLanConfiguration::maximum_learned_entries_default")] pub maximum_learned_entries: usize,
+
+	/// Ageing time of learned entries.
+	#[serde(default = "LearningBridgeVirtualLanConfiguration::ageing_time_default")] pub ageing_time: MillisecondDuration,
+}
+
+impl LearningBridgeVirtualLanConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self) -> LearningBridgeVirtualLan
+	{
+		LearningBridgeVirtualLan::new(self.member_port_numbers, self.static_entries, self.maximum_learned_entries, self.ageing_time)
+	}
+
+	#[inline(always)]
+	fn maximum_learned_entries_default() -> usize
+	{
+		4096
+	}
+
+	/// IEEE Std 802.1D-2004, Section 7.9.2, recommended default.
+	#[inline(always)]
+	fn ageing_time_default() -> MillisecondDuration
+	{
+		MillisecondDuration::FiveMinutes
+	}
+}
@@ -0,0 +1,156 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Flow control state for a link, maintained from received IEEE 802.3x PAUSE and IEEE 802.1Qbb Priority-based Flow Control (PFC) frames.
+///
+/// A transmit scheduler should not transmit packets of a class of service until its `paused_until()` time has passed.
+///
+/// Times are in nanoseconds since boot, as returned by `MonotonicMillisecondTimestamp::nanoseconds_since_boot()`; at 100 gigabits per second, the longest pause is only about 336 microseconds.
+#[derive(Debug)]
+pub struct FlowControl
+{
+	link_speed_in_megabits_per_second: u64,
+	
+	counters: Cell<FlowControlCounters>,
+	
+	class_of_service_counters: [Cell<FlowControlClassOfServiceCounters>; ClassOfService::NumberOfClassesOfService],
+	
+	paused_until: [Cell<u64>; ClassOfService::NumberOfClassesOfService],
+}
+
+impl FlowControl
+{
+	const BitTimesPerPauseQuantum: u64 = 512;
+	
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(link_speed_in_megabits_per_second: u32) -> Self
+	{
+		debug_assert_ne!(link_speed_in_megabits_per_second, 0, "link_speed_in_megabits_per_second can not be zero");
+		
+		macro_rules! eight
+		{
+			($value: expr) =>
+			{
+				[Cell::new($value), Cell::new($value), Cell::new($value), Cell::new($value), Cell::new($value), Cell::new($value), Cell::new($value), Cell::new($value)]
+			}
+		}
+		
+		Self
+		{
+			link_speed_in_megabits_per_second: link_speed_in_megabits_per_second as u64,
+			counters: Cell::default(),
+			class_of_service_counters: eight!(FlowControlClassOfServiceCounters::default()),
+			paused_until: eight!(0),
+		}
+	}
+	
+	/// Counters of flow control frames received.
+	#[inline(always)]
+	pub fn counters(&self) -> FlowControlCounters
+	{
+		self.counters.get()
+	}
+	
+	/// Counters of flow control requests received for `class_of_service`.
+	#[inline(always)]
+	pub fn class_of_service_counters(&self, class_of_service: ClassOfService) -> FlowControlClassOfServiceCounters
+	{
+		unsafe { self.class_of_service_counters.get_unchecked(class_of_service as u8 as usize) }.get()
+	}
+	
+	/// Transmission of `class_of_service` is paused until this time, in nanoseconds since boot.
+	#[inline(always)]
+	pub fn paused_until(&self, class_of_service: ClassOfService) -> u64
+	{
+		unsafe { self.paused_until.get_unchecked(class_of_service as u8 as usize) }.get()
+	}
+	
+	/// Is transmission of `class_of_service` paused at `now`, in nanoseconds since boot?
+	#[inline(always)]
+	pub fn is_paused(&self, now: u64, class_of_service: ClassOfService) -> bool
+	{
+		self.paused_until(class_of_service) > now
+	}
+	
+	/// Processes a parsed flow control frame received at `now`, in nanoseconds since boot.
+	#[inline]
+	pub fn process_frame(&self, now: u64, frame: FlowControlFrame)
+	{
+		use self::FlowControlFrame::*;
+		
+		let mut counters = self.counters.get();
+		match frame
+		{
+			Pause { pause_quanta } =>
+			{
+				counters.pause_frames += 1;
+				for priority in 0 .. ClassOfService::NumberOfClassesOfService
+				{
+					self.pause(now, priority, pause_quanta)
+				}
+			}
+			
+			PriorityFlowControl { class_enable_vector, pause_quanta } =>
+			{
+				counters.priority_flow_control_frames += 1;
+				for priority in 0 .. ClassOfService::NumberOfClassesOfService
+				{
+					if class_enable_vector & (1 << priority) != 0
+					{
+						self.pause(now, priority, pause_quanta[priority])
+					}
+				}
+			}
+		}
+		self.counters.set(counters);
+	}
+	
+	/// Processes the bytes following the ether type of a media access control (MAC) control frame.
+	///
+	/// The time of receipt is read from the cycle counter rather than using the millisecond `now` of packet processing, which is too coarse for pause times.
+	#[inline(always)]
+	pub(crate) fn process(&self, destination_ethernet_address: &MediaAccessControlAddress, layer_3_packet: &Layer3Packet, layer_3_length: u16) -> Result<(), FlowControlDropReason>
+	{
+		if unlikely!(destination_ethernet_address.is_multicast_or_broadcast() && destination_ethernet_address != &MediaAccessControlAddress::MediaAccessControlControlMulticast)
+		{
+			return Err(FlowControlDropReason::DestinationEthernetAddressIsNotMediaAccessControlControlMulticast)
+		}
+		
+		let bytes = unsafe { from_raw_parts(layer_3_packet as *const Layer3Packet as *const u8, layer_3_length as usize) };
+		let frame = FlowControlFrame::parse(bytes)?;
+		self.process_frame(MonotonicMillisecondTimestamp::nanoseconds_since_boot(), frame);
+		Ok(())
+	}
+	
+	/// `priority` is the same as the value of a `ClassOfService`.
+	#[inline(always)]
+	fn pause(&self, now: u64, priority: usize, pause_quanta: u16)
+	{
+		let counters = unsafe { self.class_of_service_counters.get_unchecked(priority) };
+		let mut class_of_service_counters = counters.get();
+		if pause_quanta == 0
+		{
+			class_of_service_counters.resume_requests += 1;
+		}
+		else
+		{
+			class_of_service_counters.pause_requests += 1;
+		}
+		counters.set(class_of_service_counters);
+		
+		// A new request replaces any existing pause, including shortening it.
+		unsafe { self.paused_until.get_unchecked(priority) }.set(now + self.pause_duration(pause_quanta))
+	}
+	
+	/// In nanoseconds, rounded up.
+	#[inline(always)]
+	fn pause_duration(&self, pause_quanta: u16) -> u64
+	{
+		const NanosecondsPerMicrosecond: u64 = 1_000;
+		
+		let bit_times = (pause_quanta as u64) * Self::BitTimesPerPauseQuantum;
+		(bit_times * NanosecondsPerMicrosecond + self.link_speed_in_megabits_per_second - 1) / self.link_speed_in_megabits_per_second
+	}
+}
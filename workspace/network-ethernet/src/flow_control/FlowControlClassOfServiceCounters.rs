@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Counters of flow control requests received for a class of service, from either PAUSE or Priority-based Flow Control (PFC) frames.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct FlowControlClassOfServiceCounters
+{
+	/// Requests with non-zero pause quanta.
+	pub pause_requests: u64,
+	
+	/// Requests with zero pause quanta, ie to resume transmission immediately.
+	pub resume_requests: u64,
+}
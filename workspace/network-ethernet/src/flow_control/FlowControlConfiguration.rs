@@ -0,0 +1,49 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Flow control configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct FlowControlConfiguration
+{
+	/// Link speed, used to convert pause quanta (512 bit times) to a duration.
+	///
+	/// Defaults to 10,000 (10 Gigabit ethernet); can not be zero.
+	#[serde(default = "FlowControlConfiguration::link_speed_in_megabits_per_second_default")] pub link_speed_in_megabits_per_second: u32,
+}
+
+impl Default for FlowControlConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			link_speed_in_megabits_per_second: Self::link_speed_in_megabits_per_second_default(),
+		}
+	}
+}
+
+impl FlowControlConfiguration
+{
+	/// Configure.
+	///
+	/// Fails if the link speed is zero.
+	#[inline(always)]
+	pub fn configure(self) -> Result<FlowControl, FlowControlConfigurationError>
+	{
+		if self.link_speed_in_megabits_per_second == 0
+		{
+			return Err(FlowControlConfigurationError::LinkSpeedIsZero)
+		}
+		
+		Ok(FlowControl::new(self.link_speed_in_megabits_per_second))
+	}
+	
+	#[inline(always)]
+	fn link_speed_in_megabits_per_second_default() -> u32
+	{
+		10_000
+	}
+}
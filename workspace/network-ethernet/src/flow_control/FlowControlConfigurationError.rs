@@ -0,0 +1,24 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Cause of failure to configure flow control.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FlowControlConfigurationError
+{
+	/// The link speed was zero; pause quanta can not be converted to a duration.
+	LinkSpeedIsZero,
+}
+
+impl Display for FlowControlConfigurationError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl ::std::error::Error for FlowControlConfigurationError
+{
+}
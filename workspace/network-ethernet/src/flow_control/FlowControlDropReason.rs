@@ -0,0 +1,22 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a media access control (MAC) control frame was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum FlowControlDropReason
+{
+	/// A multicast frame was not sent to the media access control (MAC) control multicast ethernet address, `01:80:C2:00:00:01`.
+	DestinationEthernetAddressIsNotMediaAccessControlControlMulticast,
+	
+	/// The frame was too short for its opcode.
+	PacketIsTooShort,
+	
+	/// Only PAUSE and Priority-based Flow Control (PFC) are supported.
+	UnsupportedOpcode
+	{
+		/// Opcode.
+		opcode: u16,
+	},
+}
@@ -0,0 +1,93 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A parsed media access control (MAC) control flow control frame (ether type `0x8808`).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum FlowControlFrame
+{
+	/// IEEE 802.3x PAUSE (IEEE Std 802.3-2015, Annex 31B); applies to all classes of service.
+	Pause
+	{
+		/// Pause quanta; each quantum is 512 bit times; zero resumes transmission.
+		pause_quanta: u16,
+	},
+	
+	/// IEEE 802.1Qbb Priority-based Flow Control (PFC) (IEEE Std 802.3-2015, Annex 31D).
+	PriorityFlowControl
+	{
+		/// Bit `n` is set if `pause_quanta[n]` applies to priority `n`.
+		class_enable_vector: u8,
+		
+		/// Pause quanta for each priority, indexed by priority code point; each quantum is 512 bit times; zero resumes transmission.
+		pause_quanta: [u16; ClassOfService::NumberOfClassesOfService],
+	},
+}
+
+impl FlowControlFrame
+{
+	/// PAUSE opcode.
+	pub const PauseOpcode: u16 = 0x0001;
+	
+	/// Priority-based Flow Control (PFC) opcode.
+	pub const PriorityFlowControlOpcode: u16 = 0x0101;
+	
+	const OpcodeSize: usize = 2;
+	
+	const PauseSize: usize = Self::OpcodeSize + 2;
+	
+	const PriorityFlowControlSize: usize = Self::OpcodeSize + 2 + ClassOfService::NumberOfClassesOfService * 2;
+	
+	/// Parse the bytes following the ether type.
+	#[inline]
+	pub fn parse(bytes: &[u8]) -> Result<Self, FlowControlDropReason>
+	{
+		use self::FlowControlDropReason::*;
+		
+		#[inline(always)]
+		fn read_u16(bytes: &[u8], offset: usize) -> u16
+		{
+			((bytes[offset] as u16) << 8) | (bytes[offset + 1] as u16)
+		}
+		
+		if unlikely!(bytes.len() < Self::OpcodeSize)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		match read_u16(bytes, 0)
+		{
+			Self::PauseOpcode =>
+			{
+				if unlikely!(bytes.len() < Self::PauseSize)
+				{
+					return Err(PacketIsTooShort)
+				}
+				
+				Ok(FlowControlFrame::Pause { pause_quanta: read_u16(bytes, 2) })
+			}
+			
+			Self::PriorityFlowControlOpcode =>
+			{
+				if unlikely!(bytes.len() < Self::PriorityFlowControlSize)
+				{
+					return Err(PacketIsTooShort)
+				}
+				
+				// The upper octet of the class enable vector is reserved.
+				let class_enable_vector = bytes[3];
+				
+				let mut pause_quanta = [0u16; ClassOfService::NumberOfClassesOfService];
+				for (priority, quanta) in pause_quanta.iter_mut().enumerate()
+				{
+					*quanta = read_u16(bytes, 4 + priority * 2);
+				}
+				
+				Ok(FlowControlFrame::PriorityFlowControl { class_enable_vector, pause_quanta })
+			}
+			
+			opcode @ _ => Err(UnsupportedOpcode { opcode }),
+		}
+	}
+}
@@ -16,12 +16,14 @@
 //! It has an experimental feature `libc`, which does not compile as of libc 0.2.42 (libc is missing essential definitions).
 
 
+extern crate aes_gcm;
 #[macro_use] extern crate arrayref;
 #[cfg(feature = "dpdk-sys")] extern crate dpdk_sys;
 extern crate hashbrown;
 extern crate hyper_thread_random;
 #[cfg(feature = "libc")] extern crate libc;
 #[macro_use] extern crate likely;
+extern crate network_collections;
 extern crate network_endian;
 extern crate network_packet;
 extern crate network_time;
@@ -29,16 +31,36 @@ extern crate serde;
 #[macro_use] extern crate serde_derive;
 
 
+use self::bridging::*;
+use self::flow_control::*;
+use self::link_aggregation::*;
+use self::media_access_control_security::*;
+use self::multiprotocol_label_switching::*;
 use self::packet_processing::*;
 use self::packet_processing::EthernetIncomingNetworkPacketDropReason::*;
+use self::point_to_point_protocol_over_ethernet::*;
+use self::port_based_network_access_control::*;
+use self::precision_time_protocol::*;
+use self::spanning_tree::*;
+use self::tunnels::*;
 use self::virtual_lans::*;
+use ::aes_gcm::AeadInPlace;
+use ::aes_gcm::Aes128Gcm;
+use ::aes_gcm::Aes256Gcm;
+use ::aes_gcm::Key;
+use ::aes_gcm::KeyInit;
+use ::aes_gcm::Nonce;
+use ::aes_gcm::Tag;
 #[cfg(feature = "dpdk-sys")] use ::dpdk_sys::*;
 use ::hashbrown::HashMap;
 use ::hashbrown::HashSet;
 use ::hyper_thread_random::generate_hyper_thread_safe_random_u64;
+use ::network_collections::least_recently_used_cache::LeastRecentlyUsedCacheWithExpiry;
 use ::network_endian::NetworkEndian;
 use ::network_endian::NetworkEndianU16;
+use ::network_endian::NetworkEndianU32;
 use ::network_packet::*;
+use ::network_time::MillisecondDuration;
 use ::network_time::MonotonicMillisecondTimestamp;
 use ::serde::Deserialize;
 use ::serde::Deserializer;
@@ -46,6 +68,10 @@ use ::serde::Serialize;
 use ::serde::Serializer;
 use ::serde::de;
 use ::serde::de::Visitor;
+use ::std::cell::Cell;
+use ::std::cell::Ref;
+use ::std::cell::RefCell;
+use ::std::cmp::min;
 use ::std::cmp::Ordering;
 use ::std::convert::TryFrom;
 use ::std::fmt;
@@ -55,22 +81,67 @@ use ::std::fmt::Formatter;
 use ::std::hash::Hash;
 use ::std::hash::Hasher;
 use ::std::marker::PhantomData;
-use ::std::mem::size_of;
+use ::std::mem::replace;
 use ::std::mem::transmute;
 use ::std::mem::uninitialized;
+use ::std::net::Ipv4Addr;
+use ::std::net::Ipv6Addr;
+use ::std::ptr::copy;
 use ::std::ptr::copy_nonoverlapping;
-#[cfg(feature = "dpdk-sys")] use ::std::ptr::NonNull;
+use ::std::ptr::NonNull;
 use ::std::rc::Rc;
+use ::std::slice::from_raw_parts;
+use ::std::slice::from_raw_parts_mut;
 use ::std::str::SplitN;
 
 
 include!("drop.rs");
 
 
+/// Learning bridge.
+pub mod bridging;
+
+
+/// Flow control using IEEE 802.3x PAUSE and IEEE 802.1Qbb Priority-based Flow Control (PFC).
+pub mod flow_control;
+
+
+/// Link aggregation using the Link Aggregation Control Protocol (LACP).
+pub mod link_aggregation;
+
+
+/// MACsec (IEEE 802.1AE) frame validation and decryption.
+pub mod media_access_control_security;
+
+
+/// Multiprotocol Label Switching (MPLS).
+pub mod multiprotocol_label_switching;
+
+
 /// Packet processing.
 pub mod packet_processing;
 
 
+/// Point-to-Point Protocol over Ethernet (PPPoE).
+pub mod point_to_point_protocol_over_ethernet;
+
+
+/// IEEE 802.1X port-based network access control using the Extensible Authentication Protocol over LAN (EAPOL).
+pub mod port_based_network_access_control;
+
+
+/// Precision Time Protocol (PTP) message parsing, best master clock comparison and offset and path delay computation.
+pub mod precision_time_protocol;
+
+
+/// Spanning tree using the Rapid Spanning Tree Protocol (RSTP).
+pub mod spanning_tree;
+
+
+/// Tunnels, such as Virtual eXtensible Local Area Network (VXLAN), Generic Routing Encapsulation (GRE), Network Virtualization using Generic Routing Encapsulation (NVGRE) and Generic Network Virtualization Encapsulation (Geneve).
+pub mod tunnels;
+
+
 /// Virtual LANs (VLANs).
 pub mod virtual_lans;
 
@@ -88,5 +159,7 @@ include!("LegacyEthernetFrameSize.rs");
 include!("MaximumTransmissionUnitSize.rs");
 include!("MediaAccessControlAddress.rs");
 include!("MediaAccessControlAddressList.rs");
+include!("MediaAccessControlAddressPrefix.rs");
+include!("MediaAccessControlAddressPrefixTrie.rs");
 include!("OrganizationallyUniqueIdentifier.rs");
 include!("SizeU16OfEthernetCyclicRedundancyCheck.rs");
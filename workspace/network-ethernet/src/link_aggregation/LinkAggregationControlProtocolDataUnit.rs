@@ -0,0 +1,162 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Link Aggregation Control Protocol Data Unit (LACPDU), version 1.
+///
+/// This is the payload of a slow protocols ethernet frame (ie immediately after the ether type); it is always 110 bytes long.
+///
+/// IEEE Std 802.1AX-2014, Section 6.4.2.3, Figure 6-7.
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct LinkAggregationControlProtocolDataUnit
+{
+	/// Slow protocols subtype; always `LinkAggregationControlProtocolDataUnit::Subtype`.
+	pub subtype: u8,
+
+	/// Version number.
+	pub version_number: u8,
+
+	/// Type-Length-Value (TLV) type of actor information.
+	pub actor_information_type: u8,
+
+	/// Type-Length-Value (TLV) length of actor information.
+	pub actor_information_length: u8,
+
+	/// Actor information.
+	pub actor: LinkAggregationControlProtocolParticipantInformation,
+
+	/// Reserved.
+	pub actor_reserved: [u8; 3],
+
+	/// Type-Length-Value (TLV) type of partner information.
+	pub partner_information_type: u8,
+
+	/// Type-Length-Value (TLV) length of partner information.
+	pub partner_information_length: u8,
+
+	/// Partner information.
+	pub partner: LinkAggregationControlProtocolParticipantInformation,
+
+	/// Reserved.
+	pub partner_reserved: [u8; 3],
+
+	/// Type-Length-Value (TLV) type of collector information.
+	pub collector_information_type: u8,
+
+	/// Type-Length-Value (TLV) length of collector information.
+	pub collector_information_length: u8,
+
+	/// Collector maximum delay in tens of microseconds.
+	pub collector_maximum_delay: NetworkEndianU16,
+
+	/// Reserved.
+	pub collector_reserved: [u8; 12],
+
+	/// Type-Length-Value (TLV) type of terminator.
+	pub terminator_type: u8,
+
+	/// Type-Length-Value (TLV) length of terminator.
+	pub terminator_length: u8,
+
+	/// Reserved.
+	pub reserved: [u8; 50],
+}
+
+impl Debug for LinkAggregationControlProtocolDataUnit
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "LinkAggregationControlProtocolDataUnit {{ subtype: {}, version_number: {}, actor: {:?}, partner: {:?}, collector_maximum_delay: {:?} }}", self.subtype, self.version_number, self.actor, self.partner, self.collector_maximum_delay)
+	}
+}
+
+impl Display for LinkAggregationControlProtocolDataUnit
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl LinkAggregationControlProtocolDataUnit
+{
+	/// Size of a Link Aggregation Control Protocol Data Unit (LACPDU).
+	pub const Size: usize = 110;
+
+	/// Size of a Link Aggregation Control Protocol Data Unit (LACPDU) (as an u16).
+	pub const SizeU16: u16 = Self::Size as u16;
+
+	/// Slow protocols subtype for Link Aggregation Control Protocol (LACP).
+	pub const Subtype: u8 = 0x01;
+
+	/// Version number we generate.
+	pub const VersionNumber: u8 = 0x01;
+
+	const ActorInformationType: u8 = 0x01;
+
+	const PartnerInformationType: u8 = 0x02;
+
+	const CollectorInformationType: u8 = 0x03;
+
+	const TerminatorType: u8 = 0x00;
+
+	const ParticipantInformationLength: u8 = 20;
+
+	const CollectorInformationLength: u8 = 16;
+
+	const TerminatorLength: u8 = 0;
+
+	/// Creates a new instance suitable for transmission.
+	#[inline(always)]
+	pub fn new(actor: LinkAggregationControlProtocolParticipantInformation, partner: LinkAggregationControlProtocolParticipantInformation, collector_maximum_delay: u16) -> Self
+	{
+		Self
+		{
+			subtype: Self::Subtype,
+			version_number: Self::VersionNumber,
+			actor_information_type: Self::ActorInformationType,
+			actor_information_length: Self::ParticipantInformationLength,
+			actor,
+			actor_reserved: [0; 3],
+			partner_information_type: Self::PartnerInformationType,
+			partner_information_length: Self::ParticipantInformationLength,
+			partner,
+			partner_reserved: [0; 3],
+			collector_information_type: Self::CollectorInformationType,
+			collector_information_length: Self::CollectorInformationLength,
+			collector_maximum_delay: NetworkEndianU16::from_native_endian(collector_maximum_delay),
+			collector_reserved: [0; 12],
+			terminator_type: Self::TerminatorType,
+			terminator_length: Self::TerminatorLength,
+			reserved: [0; 50],
+		}
+	}
+
+	/// Is this a valid Link Aggregation Control Protocol Data Unit (LACPDU)?
+	///
+	/// Reserved fields are ignored and versions greater than 1 are accepted, as required by IEEE Std 802.1AX-2014, Section 6.4.2.3.
+	#[inline(always)]
+	pub fn is_valid(&self) -> bool
+	{
+		self.subtype == Self::Subtype
+		&& self.version_number >= Self::VersionNumber
+		&& self.actor_information_type == Self::ActorInformationType
+		&& self.actor_information_length == Self::ParticipantInformationLength
+		&& self.partner_information_type == Self::PartnerInformationType
+		&& self.partner_information_length == Self::ParticipantInformationLength
+		&& self.collector_information_type == Self::CollectorInformationType
+		&& self.collector_information_length == Self::CollectorInformationLength
+		&& self.terminator_type == Self::TerminatorType
+		&& self.terminator_length == Self::TerminatorLength
+	}
+
+	/// Is this not a valid Link Aggregation Control Protocol Data Unit (LACPDU)?
+	#[inline(always)]
+	pub fn is_invalid(&self) -> bool
+	{
+		!self.is_valid()
+	}
+}
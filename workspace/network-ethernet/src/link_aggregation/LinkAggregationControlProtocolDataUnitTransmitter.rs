@@ -0,0 +1,13 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Transmits Link Aggregation Control Protocol Data Units (LACPDUs) generated by a `LinkAggregationGroup`.
+pub trait LinkAggregationControlProtocolDataUnitTransmitter: Debug
+{
+	/// Implement this to transmit `link_aggregation_control_protocol_data_unit` out of the member port `port_number`.
+	///
+	/// The frame should have a destination address of `MediaAccessControlAddress::SlowProtocolsMulticast`, a source address of the member port's own ethernet address and an ether type of `EtherType::Slow`.
+	#[inline(always)]
+	fn transmit(&self, port_number: u16, link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit);
+}
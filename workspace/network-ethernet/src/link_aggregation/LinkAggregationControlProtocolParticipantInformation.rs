@@ -0,0 +1,89 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Actor or partner information as it appears on the wire in a Link Aggregation Control Protocol Data Unit (LACPDU).
+///
+/// IEEE Std 802.1AX-2014, Section 6.4.2.3, items `d` to `m`.
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct LinkAggregationControlProtocolParticipantInformation
+{
+	/// System priority.
+	pub system_priority: NetworkEndianU16,
+
+	/// System identifier; usually an ethernet address of the system.
+	pub system: MediaAccessControlAddress,
+
+	/// Operational key.
+	pub key: NetworkEndianU16,
+
+	/// Port priority.
+	pub port_priority: NetworkEndianU16,
+
+	/// Port number.
+	pub port: NetworkEndianU16,
+
+	/// State.
+	pub state: LinkAggregationControlProtocolState,
+}
+
+impl Display for LinkAggregationControlProtocolParticipantInformation
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl Default for LinkAggregationControlProtocolParticipantInformation
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			system_priority: NetworkEndianU16::from_native_endian(LinkAggregationGroupConfiguration::DefaultSystemPriority),
+			system: MediaAccessControlAddress::Zero,
+			key: NetworkEndianU16::default(),
+			port_priority: NetworkEndianU16::from_native_endian(LinkAggregationPortConfiguration::DefaultPortPriority),
+			port: NetworkEndianU16::default(),
+			state: LinkAggregationControlProtocolState::default(),
+		}
+	}
+}
+
+impl LinkAggregationControlProtocolParticipantInformation
+{
+	/// Size of this structure.
+	pub const Size: usize = 15;
+
+	/// Do these two participants have identical parameters (system priority, system, key, port priority and port) and an identical aggregation state?
+	///
+	/// Other state bits are ignored.
+	///
+	/// IEEE Std 802.1AX-2014, Section 6.4.9, `update_Selected` and `update_NTT`.
+	#[inline(always)]
+	pub fn has_same_parameters_as(&self, other: &Self) -> bool
+	{
+		self.has_same_system_and_key_as(other) && self.port_priority == other.port_priority && self.port == other.port && self.state.is(LinkAggregationControlProtocolState::Aggregation) == other.state.is(LinkAggregationControlProtocolState::Aggregation)
+	}
+
+	/// Are these two participants part of the same system and do they have the same operational key?
+	#[inline(always)]
+	pub fn has_same_system_and_key_as(&self, other: &Self) -> bool
+	{
+		self.system_priority == other.system_priority && self.system == other.system && self.key == other.key
+	}
+
+	/// Port identifier used to choose a port; a lower value is a better choice.
+	///
+	/// IEEE Std 802.1AX-2014, Section 6.3.4.
+	#[inline(always)]
+	pub fn port_identifier(&self) -> u32
+	{
+		((self.port_priority.to_native_endian() as u32) << 16) | (self.port.to_native_endian() as u32)
+	}
+}
@@ -0,0 +1,93 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Link Aggregation Control Protocol (LACP) actor or partner state, as a bit set.
+///
+/// IEEE Std 802.1AX-2014, Section 6.4.2.3, item `m`.
+#[repr(C, packed)]
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct LinkAggregationControlProtocolState(u8);
+
+impl Display for LinkAggregationControlProtocolState
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "0x{:02X}", self.0)
+	}
+}
+
+impl Into<u8> for LinkAggregationControlProtocolState
+{
+	#[inline(always)]
+	fn into(self) -> u8
+	{
+		self.0
+	}
+}
+
+impl From<u8> for LinkAggregationControlProtocolState
+{
+	#[inline(always)]
+	fn from(value: u8) -> Self
+	{
+		LinkAggregationControlProtocolState(value)
+	}
+}
+
+impl LinkAggregationControlProtocolState
+{
+	/// Active (set) or passive (clear) Link Aggregation Control Protocol (LACP).
+	pub const LacpActivity: u8 = 0x01;
+
+	/// Short (set) or long (clear) time out.
+	pub const LacpTimeout: u8 = 0x02;
+
+	/// Aggregatable (set) or individual (clear).
+	pub const Aggregation: u8 = 0x04;
+
+	/// In sync (set) or out of sync (clear).
+	pub const Synchronization: u8 = 0x08;
+
+	/// Collection of incoming frames is enabled.
+	pub const Collecting: u8 = 0x10;
+
+	/// Distribution of outgoing frames is enabled.
+	pub const Distributing: u8 = 0x20;
+
+	/// Partner information in use is administratively configured default information rather than received information.
+	pub const Defaulted: u8 = 0x40;
+
+	/// Receive machine is in the expired state.
+	pub const Expired: u8 = 0x80;
+
+	/// Is `flag` set?
+	#[inline(always)]
+	pub fn is(self, flag: u8) -> bool
+	{
+		self.0 & flag != 0
+	}
+
+	/// Is `flag` clear?
+	#[inline(always)]
+	pub fn is_not(self, flag: u8) -> bool
+	{
+		self.0 & flag == 0
+	}
+
+	/// Set or clear `flag`.
+	#[inline(always)]
+	pub fn set(&mut self, flag: u8, value: bool)
+	{
+		if value
+		{
+			self.0 |= flag
+		}
+		else
+		{
+			self.0 &= !flag
+		}
+	}
+}
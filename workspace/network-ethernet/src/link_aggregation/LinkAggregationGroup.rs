@@ -0,0 +1,167 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A link aggregation group (a 'bond' or 'port channel') using the Link Aggregation Control Protocol (LACP).
+///
+/// There is one aggregator per group; member ports whose partner has the same system and key as the partner of the best (lowest port identifier) eligible member port are selected for it.
+///
+/// The group is driven by calling `poll()` regularly (at least several times a second) and by passing received Link Aggregation Control Protocol Data Units (LACPDUs) to `process_link_aggregation_control_protocol_data_unit()`.
+#[derive(Debug)]
+pub struct LinkAggregationGroup
+{
+	collector_maximum_delay: u16,
+	ports: RefCell<Vec<LinkAggregationPort>>,
+	distributing_port_numbers: RefCell<Vec<u16>>,
+}
+
+impl LinkAggregationGroup
+{
+	#[inline(always)]
+	pub(crate) fn new(collector_maximum_delay: u16, ports: Vec<LinkAggregationPort>) -> Self
+	{
+		let number_of_ports = ports.len();
+
+		Self
+		{
+			collector_maximum_delay,
+			ports: RefCell::new(ports),
+			distributing_port_numbers: RefCell::new(Vec::with_capacity(number_of_ports)),
+		}
+	}
+
+	/// The physical link of a member port came up.
+	#[inline(always)]
+	pub fn link_up(&self, now: MonotonicMillisecondTimestamp, port_number: u16)
+	{
+		self.with_port(port_number, |port| port.enable(now));
+	}
+
+	/// The physical link of a member port went down.
+	#[inline(always)]
+	pub fn link_down(&self, now: MonotonicMillisecondTimestamp, port_number: u16)
+	{
+		self.with_port(port_number, |port| port.disable());
+		self.select_and_run_mux_machines(now)
+	}
+
+	/// Process a received, valid Link Aggregation Control Protocol Data Unit (LACPDU).
+	///
+	/// Any response is transmitted on the next call to `poll()`.
+	#[inline(always)]
+	pub fn process_link_aggregation_control_protocol_data_unit(&self, now: MonotonicMillisecondTimestamp, port_number: u16, link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit)
+	{
+		debug_assert!(link_aggregation_control_protocol_data_unit.is_valid(), "link_aggregation_control_protocol_data_unit '{:?}' is not valid", link_aggregation_control_protocol_data_unit);
+
+		self.with_port(port_number, |port| port.receive(now, link_aggregation_control_protocol_data_unit));
+		self.select_and_run_mux_machines(now)
+	}
+
+	/// Runs timers and state machines, and transmits any Link Aggregation Control Protocol Data Units (LACPDUs) that need to be sent.
+	#[inline(always)]
+	pub fn poll(&self, now: MonotonicMillisecondTimestamp, transmitter: &impl LinkAggregationControlProtocolDataUnitTransmitter)
+	{
+		for port in self.ports.borrow_mut().iter_mut()
+		{
+			port.expire_timers(now)
+		}
+
+		self.select_and_run_mux_machines(now);
+
+		// Collected first, so that `transmitter` can call back into this group without the ports being borrowed.
+		let link_aggregation_control_protocol_data_units: Vec<(u16, LinkAggregationControlProtocolDataUnit)> = self.ports.borrow_mut().iter_mut().filter_map(|port| port.transmit_if_needed(now, self.collector_maximum_delay).map(|link_aggregation_control_protocol_data_unit| (port.port_number(), link_aggregation_control_protocol_data_unit))).collect();
+
+		for (port_number, link_aggregation_control_protocol_data_unit) in link_aggregation_control_protocol_data_units
+		{
+			transmitter.transmit(port_number, &link_aggregation_control_protocol_data_unit)
+		}
+	}
+
+	/// Is the member port `port_number` collecting incoming frames?
+	///
+	/// Frames other than slow protocol frames received on a port that is not collecting should be discarded.
+	#[inline(always)]
+	pub fn is_collecting(&self, port_number: u16) -> bool
+	{
+		self.ports.borrow().iter().find(|port| port.port_number() == port_number).map(|port| port.is_collecting()).unwrap_or(false)
+	}
+
+	/// Is the member port `port_number` distributing outgoing frames?
+	#[inline(always)]
+	pub fn is_distributing(&self, port_number: u16) -> bool
+	{
+		self.distributing_port_numbers.borrow().contains(&port_number)
+	}
+
+	/// Number of member ports that are distributing outgoing frames.
+	#[inline(always)]
+	pub fn number_of_distributing_ports(&self) -> usize
+	{
+		self.distributing_port_numbers.borrow().len()
+	}
+
+	/// Chooses a member port to transmit a flow with the hash `flow_hash` on.
+	///
+	/// Returns `None` if no member ports are distributing.
+	///
+	/// All frames of a conversation (flow) must use the same `flow_hash` so that they are not reordered.
+	#[inline(always)]
+	pub fn distributing_port_for_flow(&self, flow_hash: u32) -> Option<u16>
+	{
+		let distributing_port_numbers = self.distributing_port_numbers.borrow();
+		let number_of_distributing_ports = distributing_port_numbers.len();
+		if unlikely!(number_of_distributing_ports == 0)
+		{
+			None
+		}
+		else
+		{
+			Some(unsafe { *distributing_port_numbers.get_unchecked((flow_hash as usize) % number_of_distributing_ports) })
+		}
+	}
+
+	/// Receive machine state and mux machine state of a member port.
+	#[inline(always)]
+	pub fn port_states(&self, port_number: u16) -> Option<(LinkAggregationReceiveMachineState, LinkAggregationMuxMachineState)>
+	{
+		self.ports.borrow().iter().find(|port| port.port_number() == port_number).map(|port| (port.receive_machine_state(), port.mux_machine_state()))
+	}
+
+	/// IEEE Std 802.1AX-2014, Section 6.4.14.
+	#[inline(always)]
+	fn select_and_run_mux_machines(&self, now: MonotonicMillisecondTimestamp)
+	{
+		let mut ports = self.ports.borrow_mut();
+
+		let chosen_partner = ports.iter().filter(|port| port.is_eligible_for_selection()).min_by_key(|port| port.actor().port_identifier()).map(|port| *port.partner());
+
+		let mut collecting_or_distributing_changed = false;
+		for port in ports.iter_mut()
+		{
+			let selected = match chosen_partner
+			{
+				None => false,
+				Some(ref chosen_partner) => port.is_eligible_for_selection() && port.partner().has_same_system_and_key_as(chosen_partner),
+			};
+			port.set_selected(selected);
+
+			collecting_or_distributing_changed |= port.run_mux_machine(now);
+		}
+
+		if collecting_or_distributing_changed
+		{
+			let mut distributing_port_numbers = self.distributing_port_numbers.borrow_mut();
+			distributing_port_numbers.clear();
+			distributing_port_numbers.extend(ports.iter().filter(|port| port.is_distributing()).map(|port| port.port_number()));
+		}
+	}
+
+	#[inline(always)]
+	fn with_port(&self, port_number: u16, callback: impl FnOnce(&mut LinkAggregationPort))
+	{
+		if let Some(port) = self.ports.borrow_mut().iter_mut().find(|port| port.port_number() == port_number)
+		{
+			callback(port)
+		}
+	}
+}
@@ -0,0 +1,78 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Link aggregation group configuration.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct LinkAggregationGroupConfiguration
+{
+	/// System priority; a lower value is a higher priority.
+	#[serde(default = "LinkAggregationGroupConfiguration::system_priority_default")] pub system_priority: u16,
+
+	/// Operational key shared by all member ports.
+	pub key: u16,
+
+	/// Active (`true`) or passive (`false`) Link Aggregation Control Protocol (LACP).
+	///
+	/// At least one end of a link must be active.
+	#[serde(default = "LinkAggregationGroupConfiguration::active_default")] pub active: bool,
+
+	/// Ask the partner to use a short time out (3 seconds) rather than a long one (90 seconds).
+	#[serde(default)] pub short_timeout: bool,
+
+	/// Collector maximum delay in tens of microseconds.
+	#[serde(default)] pub collector_maximum_delay: u16,
+
+	/// Member ports.
+	pub ports: Vec<LinkAggregationPortConfiguration>,
+}
+
+impl LinkAggregationGroupConfiguration
+{
+	/// Default system priority.
+	pub const DefaultSystemPriority: u16 = 0x8000;
+
+	/// Configure.
+	///
+	/// `system` is the ethernet address used to identify this system to partners; it is normally the ethernet address of one of the member ports.
+	#[inline(always)]
+	pub fn configure(self, system: MediaAccessControlAddress) -> LinkAggregationGroup
+	{
+		let mut state = LinkAggregationControlProtocolState::default();
+		state.set(LinkAggregationControlProtocolState::LacpActivity, self.active);
+		state.set(LinkAggregationControlProtocolState::LacpTimeout, self.short_timeout);
+		state.set(LinkAggregationControlProtocolState::Aggregation, true);
+
+		let system_priority = NetworkEndianU16::from_native_endian(self.system_priority);
+		let key = NetworkEndianU16::from_native_endian(self.key);
+
+		let ports = self.ports.iter().map(|port_configuration|
+		{
+			let actor = LinkAggregationControlProtocolParticipantInformation
+			{
+				system_priority,
+				system,
+				key,
+				port_priority: NetworkEndianU16::from_native_endian(port_configuration.port_priority),
+				port: NetworkEndianU16::from_native_endian(port_configuration.port_number),
+				state,
+			};
+			LinkAggregationPort::new(actor)
+		}).collect();
+
+		LinkAggregationGroup::new(self.collector_maximum_delay, ports)
+	}
+
+	#[inline(always)]
+	fn system_priority_default() -> u16
+	{
+		Self::DefaultSystemPriority
+	}
+
+	#[inline(always)]
+	fn active_default() -> bool
+	{
+		true
+	}
+}
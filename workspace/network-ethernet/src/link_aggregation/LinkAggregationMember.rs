@@ -0,0 +1,29 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Binds a network interface to a member port of a link aggregation group.
+#[derive(Debug, Clone)]
+pub struct LinkAggregationMember
+{
+	/// Link aggregation group.
+	pub link_aggregation_group: Rc<LinkAggregationGroup>,
+
+	/// Port number of the member port in the link aggregation group.
+	pub port_number: u16,
+}
+
+impl LinkAggregationMember
+{
+	#[inline(always)]
+	pub(crate) fn is_not_collecting(&self) -> bool
+	{
+		!self.link_aggregation_group.is_collecting(self.port_number)
+	}
+
+	#[inline(always)]
+	pub(crate) fn process_link_aggregation_control_protocol_data_unit(&self, now: MonotonicMillisecondTimestamp, link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit)
+	{
+		self.link_aggregation_group.process_link_aggregation_control_protocol_data_unit(now, self.port_number, link_aggregation_control_protocol_data_unit)
+	}
+}
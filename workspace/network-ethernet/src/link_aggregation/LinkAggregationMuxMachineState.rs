@@ -0,0 +1,58 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Mux machine state, using independent control of collection and distribution.
+///
+/// IEEE Std 802.1AX-2014, Section 6.4.15, Figure 6-21.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum LinkAggregationMuxMachineState
+{
+	/// Not attached to the aggregator.
+	Detached,
+
+	/// Selected; waiting for the aggregate wait time to elapse so that other ports can join at the same time.
+	Waiting,
+
+	/// Attached to the aggregator but neither collecting nor distributing.
+	Attached,
+
+	/// Collecting but not distributing.
+	Collecting,
+
+	/// Collecting and distributing.
+	Distributing,
+}
+
+impl Display for LinkAggregationMuxMachineState
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl LinkAggregationMuxMachineState
+{
+	/// Is collection of incoming frames enabled in this state?
+	#[inline(always)]
+	pub fn is_collecting(self) -> bool
+	{
+		use self::LinkAggregationMuxMachineState::*;
+
+		match self
+		{
+			Collecting | Distributing => true,
+			_ => false,
+		}
+	}
+
+	/// Is distribution of outgoing frames enabled in this state?
+	#[inline(always)]
+	pub fn is_distributing(self) -> bool
+	{
+		self == LinkAggregationMuxMachineState::Distributing
+	}
+}
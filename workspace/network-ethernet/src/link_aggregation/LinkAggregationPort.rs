@@ -0,0 +1,476 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A member port of a link aggregation group, with its receive, periodic transmission and mux machines.
+///
+/// The mux machine uses independent control of collection and distribution (IEEE Std 802.1AX-2014, Section 6.4.15).
+#[derive(Debug)]
+pub struct LinkAggregationPort
+{
+	actor: LinkAggregationControlProtocolParticipantInformation,
+	partner: LinkAggregationControlProtocolParticipantInformation,
+	partner_administrative_default: LinkAggregationControlProtocolParticipantInformation,
+	port_enabled: bool,
+	selected: bool,
+	receive_machine_state: LinkAggregationReceiveMachineState,
+	mux_machine_state: LinkAggregationMuxMachineState,
+	current_while_timer_expires_at: Option<MonotonicMillisecondTimestamp>,
+	wait_while_timer_expires_at: Option<MonotonicMillisecondTimestamp>,
+	periodic_timer_expires_at: Option<MonotonicMillisecondTimestamp>,
+	periodic_time: Option<MillisecondDuration>,
+	need_to_transmit: bool,
+	transmissions_in_current_interval: u8,
+	transmission_interval_started_at: MonotonicMillisecondTimestamp,
+}
+
+impl LinkAggregationPort
+{
+	/// Fast periodic time.
+	///
+	/// IEEE Std 802.1AX-2014, Section 6.4.4.
+	pub const FastPeriodicTime: MillisecondDuration = MillisecondDuration::from_seconds(1);
+
+	/// Slow periodic time.
+	pub const SlowPeriodicTime: MillisecondDuration = MillisecondDuration::from_seconds(30);
+
+	/// Short time out time.
+	pub const ShortTimeoutTime: MillisecondDuration = MillisecondDuration::from_seconds(3);
+
+	/// Long time out time.
+	pub const LongTimeoutTime: MillisecondDuration = MillisecondDuration::from_seconds(90);
+
+	/// Aggregate wait time.
+	pub const AggregateWaitTime: MillisecondDuration = MillisecondDuration::from_seconds(2);
+
+	/// No more than this number of Link Aggregation Control Protocol Data Units (LACPDUs) are transmitted in any `FastPeriodicTime` interval.
+	///
+	/// IEEE Std 802.1AX-2014, Section 6.4.16.
+	pub const MaximumTransmissionsPerFastPeriodicTime: u8 = 3;
+
+	#[inline(always)]
+	pub(crate) fn new(actor: LinkAggregationControlProtocolParticipantInformation) -> Self
+	{
+		let mut this = Self
+		{
+			actor,
+			partner: LinkAggregationControlProtocolParticipantInformation::default(),
+			partner_administrative_default: LinkAggregationControlProtocolParticipantInformation::default(),
+			port_enabled: false,
+			selected: false,
+			receive_machine_state: LinkAggregationReceiveMachineState::PortDisabled,
+			mux_machine_state: LinkAggregationMuxMachineState::Detached,
+			current_while_timer_expires_at: None,
+			wait_while_timer_expires_at: None,
+			periodic_timer_expires_at: None,
+			periodic_time: None,
+			need_to_transmit: false,
+			transmissions_in_current_interval: 0,
+			transmission_interval_started_at: MonotonicMillisecondTimestamp::Zero,
+		};
+		this.record_default();
+		this
+	}
+
+	/// Port number.
+	#[inline(always)]
+	pub fn port_number(&self) -> u16
+	{
+		self.actor.port.to_native_endian()
+	}
+
+	/// Our (actor) operational information for this port.
+	#[inline(always)]
+	pub fn actor(&self) -> &LinkAggregationControlProtocolParticipantInformation
+	{
+		&self.actor
+	}
+
+	/// Partner operational information for this port.
+	#[inline(always)]
+	pub fn partner(&self) -> &LinkAggregationControlProtocolParticipantInformation
+	{
+		&self.partner
+	}
+
+	/// Receive machine state.
+	#[inline(always)]
+	pub fn receive_machine_state(&self) -> LinkAggregationReceiveMachineState
+	{
+		self.receive_machine_state
+	}
+
+	/// Mux machine state.
+	#[inline(always)]
+	pub fn mux_machine_state(&self) -> LinkAggregationMuxMachineState
+	{
+		self.mux_machine_state
+	}
+
+	/// Is collection of incoming frames enabled?
+	#[inline(always)]
+	pub fn is_collecting(&self) -> bool
+	{
+		self.mux_machine_state.is_collecting()
+	}
+
+	/// Is distribution of outgoing frames enabled?
+	#[inline(always)]
+	pub fn is_distributing(&self) -> bool
+	{
+		self.mux_machine_state.is_distributing()
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_eligible_for_selection(&self) -> bool
+	{
+		self.port_enabled && self.receive_machine_state == LinkAggregationReceiveMachineState::Current && self.actor.state.is(LinkAggregationControlProtocolState::Aggregation) && self.partner.state.is(LinkAggregationControlProtocolState::Aggregation)
+	}
+
+	#[inline(always)]
+	pub(crate) fn set_selected(&mut self, selected: bool)
+	{
+		self.selected = selected
+	}
+
+	#[inline(always)]
+	pub(crate) fn enable(&mut self, now: MonotonicMillisecondTimestamp)
+	{
+		if self.port_enabled
+		{
+			return
+		}
+
+		self.port_enabled = true;
+		self.enter_expired(now)
+	}
+
+	#[inline(always)]
+	pub(crate) fn disable(&mut self)
+	{
+		if !self.port_enabled
+		{
+			return
+		}
+
+		self.port_enabled = false;
+		self.selected = false;
+		self.partner.state.set(LinkAggregationControlProtocolState::Synchronization, false);
+		self.receive_machine_state = LinkAggregationReceiveMachineState::PortDisabled;
+		self.current_while_timer_expires_at = None;
+		self.periodic_timer_expires_at = None;
+		self.periodic_time = None;
+		self.need_to_transmit = false;
+	}
+
+	/// IEEE Std 802.1AX-2014, Section 6.4.12, state `CURRENT`.
+	#[inline(always)]
+	pub(crate) fn receive(&mut self, now: MonotonicMillisecondTimestamp, link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit)
+	{
+		use self::LinkAggregationControlProtocolState as State;
+
+		if unlikely!(!self.port_enabled)
+		{
+			return
+		}
+
+		let received_actor = link_aggregation_control_protocol_data_unit.actor;
+		let received_partner = link_aggregation_control_protocol_data_unit.partner;
+
+		// update_Selected.
+		if !self.partner.has_same_parameters_as(&received_actor)
+		{
+			self.selected = false;
+			self.detach();
+		}
+
+		// update_NTT.
+		const StateBitsOfInterest: [u8; 4] = [State::LacpActivity, State::LacpTimeout, State::Synchronization, State::Aggregation];
+		let partners_view_of_us_is_stale = !received_partner.has_same_parameters_as(&self.actor) || StateBitsOfInterest.iter().any(|&flag| received_partner.state.is(flag) != self.actor.state.is(flag));
+		if partners_view_of_us_is_stale
+		{
+			self.need_to_transmit = true;
+		}
+
+		// recordPDU.
+		let partner_is_active = received_actor.state.is(State::LacpActivity) || (self.actor.state.is(State::LacpActivity) && received_partner.state.is(State::LacpActivity));
+		let partner_matches_us = received_partner.has_same_parameters_as(&self.actor) || received_actor.state.is_not(State::Aggregation);
+		let partner_is_in_sync = received_actor.state.is(State::Synchronization) && partner_matches_us && partner_is_active;
+
+		self.partner = received_actor;
+		self.partner.state.set(State::Synchronization, partner_is_in_sync);
+		self.actor.state.set(State::Defaulted, false);
+		self.actor.state.set(State::Expired, false);
+
+		let current_while_time = if self.actor.state.is(State::LacpTimeout)
+		{
+			Self::ShortTimeoutTime
+		}
+		else
+		{
+			Self::LongTimeoutTime
+		};
+		self.current_while_timer_expires_at = Some(now + current_while_time);
+		self.receive_machine_state = LinkAggregationReceiveMachineState::Current;
+	}
+
+	/// Runs the timer-driven parts of the receive and periodic transmission machines.
+	#[inline(always)]
+	pub(crate) fn expire_timers(&mut self, now: MonotonicMillisecondTimestamp)
+	{
+		use self::LinkAggregationReceiveMachineState::*;
+
+		if let Some(expires_at) = self.current_while_timer_expires_at
+		{
+			if now >= expires_at
+			{
+				match self.receive_machine_state
+				{
+					Current => self.enter_expired(now),
+					Expired => self.enter_defaulted(),
+					PortDisabled | Defaulted => self.current_while_timer_expires_at = None,
+				}
+			}
+		}
+
+		self.run_periodic_machine(now)
+	}
+
+	/// Returns `true` if the collecting or distributing state changed.
+	///
+	/// IEEE Std 802.1AX-2014, Section 6.4.15, Figure 6-22.
+	#[inline(always)]
+	pub(crate) fn run_mux_machine(&mut self, now: MonotonicMillisecondTimestamp) -> bool
+	{
+		use self::LinkAggregationMuxMachineState::*;
+		use self::LinkAggregationControlProtocolState as State;
+
+		let was_collecting = self.is_collecting();
+		let was_distributing = self.is_distributing();
+
+		loop
+		{
+			let partner_is_in_sync = self.partner.state.is(State::Synchronization);
+			let partner_is_collecting = self.partner.state.is(State::Collecting);
+
+			let next_state = match self.mux_machine_state
+			{
+				Detached => if self.selected
+				{
+					self.wait_while_timer_expires_at = Some(now + Self::AggregateWaitTime);
+					Waiting
+				}
+				else
+				{
+					break
+				},
+
+				Waiting => if !self.selected
+				{
+					self.detach();
+					break
+				}
+				else if self.wait_while_timer_expires_at.map(|expires_at| now >= expires_at).unwrap_or(true)
+				{
+					self.wait_while_timer_expires_at = None;
+					self.set_actor_state(State::Synchronization, true);
+					Attached
+				}
+				else
+				{
+					break
+				},
+
+				Attached => if !self.selected
+				{
+					self.detach();
+					break
+				}
+				else if partner_is_in_sync
+				{
+					self.set_actor_state(State::Collecting, true);
+					Collecting
+				}
+				else
+				{
+					break
+				},
+
+				Collecting => if !self.selected || !partner_is_in_sync
+				{
+					self.set_actor_state(State::Collecting, false);
+					Attached
+				}
+				else if partner_is_collecting
+				{
+					self.set_actor_state(State::Distributing, true);
+					Distributing
+				}
+				else
+				{
+					break
+				},
+
+				Distributing => if !self.selected || !partner_is_in_sync || !partner_is_collecting
+				{
+					self.set_actor_state(State::Distributing, false);
+					Collecting
+				}
+				else
+				{
+					break
+				},
+			};
+
+			self.mux_machine_state = next_state;
+		}
+
+		was_collecting != self.is_collecting() || was_distributing != self.is_distributing()
+	}
+
+	/// IEEE Std 802.1AX-2014, Section 6.4.16.
+	#[inline(always)]
+	pub(crate) fn transmit_if_needed(&mut self, now: MonotonicMillisecondTimestamp, collector_maximum_delay: u16) -> Option<LinkAggregationControlProtocolDataUnit>
+	{
+		if !self.need_to_transmit || !self.port_enabled
+		{
+			return None
+		}
+
+		if self.both_actor_and_partner_are_passive()
+		{
+			self.need_to_transmit = false;
+			return None
+		}
+
+		if now - self.transmission_interval_started_at >= Self::FastPeriodicTime
+		{
+			self.transmission_interval_started_at = now;
+			self.transmissions_in_current_interval = 0;
+		}
+
+		if self.transmissions_in_current_interval == Self::MaximumTransmissionsPerFastPeriodicTime
+		{
+			return None
+		}
+
+		self.transmissions_in_current_interval += 1;
+		self.need_to_transmit = false;
+
+		Some(LinkAggregationControlProtocolDataUnit::new(self.actor, self.partner, collector_maximum_delay))
+	}
+
+	/// IEEE Std 802.1AX-2014, Section 6.4.13, Figure 6-19.
+	#[inline(always)]
+	fn run_periodic_machine(&mut self, now: MonotonicMillisecondTimestamp)
+	{
+		let periodic_time = if !self.port_enabled || self.both_actor_and_partner_are_passive()
+		{
+			None
+		}
+		else if self.partner.state.is(LinkAggregationControlProtocolState::LacpTimeout)
+		{
+			Some(Self::FastPeriodicTime)
+		}
+		else
+		{
+			Some(Self::SlowPeriodicTime)
+		};
+
+		match periodic_time
+		{
+			None =>
+			{
+				self.periodic_time = None;
+				self.periodic_timer_expires_at = None;
+			}
+
+			Some(periodic_time) =>
+			{
+				if self.periodic_time != Some(periodic_time)
+				{
+					// Switching from slow to fast transmits immediately; the partner wants to know about us sooner.
+					if periodic_time == Self::FastPeriodicTime && self.periodic_time.is_some()
+					{
+						self.need_to_transmit = true;
+					}
+					self.periodic_time = Some(periodic_time);
+					self.periodic_timer_expires_at = Some(now + periodic_time);
+				}
+				else if let Some(expires_at) = self.periodic_timer_expires_at
+				{
+					if now >= expires_at
+					{
+						self.need_to_transmit = true;
+						self.periodic_timer_expires_at = Some(now + periodic_time);
+					}
+				}
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn enter_expired(&mut self, now: MonotonicMillisecondTimestamp)
+	{
+		use self::LinkAggregationControlProtocolState as State;
+
+		self.partner.state.set(State::Synchronization, false);
+		self.partner.state.set(State::LacpTimeout, true);
+		self.actor.state.set(State::Expired, true);
+		self.current_while_timer_expires_at = Some(now + Self::ShortTimeoutTime);
+		self.receive_machine_state = LinkAggregationReceiveMachineState::Expired;
+	}
+
+	#[inline(always)]
+	fn enter_defaulted(&mut self)
+	{
+		// update_Default_Selected.
+		if !self.partner.has_same_parameters_as(&self.partner_administrative_default)
+		{
+			self.selected = false;
+		}
+
+		self.record_default();
+		self.actor.state.set(LinkAggregationControlProtocolState::Expired, false);
+		self.current_while_timer_expires_at = None;
+		self.receive_machine_state = LinkAggregationReceiveMachineState::Defaulted;
+	}
+
+	#[inline(always)]
+	fn record_default(&mut self)
+	{
+		self.partner = self.partner_administrative_default;
+		self.actor.state.set(LinkAggregationControlProtocolState::Defaulted, true);
+	}
+
+	#[inline(always)]
+	fn detach(&mut self)
+	{
+		use self::LinkAggregationControlProtocolState as State;
+
+		if self.mux_machine_state == LinkAggregationMuxMachineState::Detached
+		{
+			return
+		}
+
+		self.actor.state.set(State::Synchronization, false);
+		self.actor.state.set(State::Collecting, false);
+		self.actor.state.set(State::Distributing, false);
+		self.wait_while_timer_expires_at = None;
+		self.mux_machine_state = LinkAggregationMuxMachineState::Detached;
+		self.need_to_transmit = true;
+	}
+
+	#[inline(always)]
+	fn set_actor_state(&mut self, flag: u8, value: bool)
+	{
+		self.actor.state.set(flag, value);
+		self.need_to_transmit = true;
+	}
+
+	#[inline(always)]
+	fn both_actor_and_partner_are_passive(&self) -> bool
+	{
+		self.actor.state.is_not(LinkAggregationControlProtocolState::LacpActivity) && self.partner.state.is_not(LinkAggregationControlProtocolState::LacpActivity)
+	}
+}
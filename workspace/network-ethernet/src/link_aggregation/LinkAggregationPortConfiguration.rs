@@ -0,0 +1,27 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Link aggregation member port configuration.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct LinkAggregationPortConfiguration
+{
+	/// Port number; must be unique within a system and must not be zero.
+	pub port_number: u16,
+
+	/// Port priority; a lower value is a higher priority.
+	#[serde(default = "LinkAggregationPortConfiguration::port_priority_default")] pub port_priority: u16,
+}
+
+impl LinkAggregationPortConfiguration
+{
+	/// Default port priority.
+	pub const DefaultPortPriority: u16 = 0x8000;
+
+	#[inline(always)]
+	fn port_priority_default() -> u16
+	{
+		Self::DefaultPortPriority
+	}
+}
@@ -0,0 +1,32 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Receive machine state.
+///
+/// IEEE Std 802.1AX-2014, Section 6.4.12, Figure 6-18.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum LinkAggregationReceiveMachineState
+{
+	/// The port is disabled (eg the physical link is down).
+	PortDisabled,
+
+	/// Partner information has timed out once; a short time out is in use to recover quickly.
+	Expired,
+
+	/// No partner information has been received; administrative defaults are in use.
+	Defaulted,
+
+	/// Partner information is current.
+	Current,
+}
+
+impl Display for LinkAggregationReceiveMachineState
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("LinkAggregationControlProtocolDataUnit.rs");
+include!("LinkAggregationControlProtocolDataUnitTransmitter.rs");
+include!("LinkAggregationControlProtocolParticipantInformation.rs");
+include!("LinkAggregationControlProtocolState.rs");
+include!("LinkAggregationGroup.rs");
+include!("LinkAggregationGroupConfiguration.rs");
+include!("LinkAggregationMember.rs");
+include!("LinkAggregationMuxMachineState.rs");
+include!("LinkAggregationPort.rs");
+include!("LinkAggregationPortConfiguration.rs");
+include!("LinkAggregationReceiveMachineState.rs");
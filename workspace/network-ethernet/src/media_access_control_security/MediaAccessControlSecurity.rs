@@ -0,0 +1,245 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// MACsec (IEEE Std 802.1AE-2006) receive processing in software, using GCM-AES-128 or GCM-AES-256.
+///
+/// Frames are validated against receive secure channels, keyed by Secure Channel Identifier (SCI), and their secure associations, keyed by Association Number (AN).
+///
+/// How strictly frames are validated, and whether frames without a SecTAG are delivered, is controlled by `MediaAccessControlSecurityValidateFrames`. Confidentiality offsets are not supported.
+#[derive(Debug)]
+pub struct MediaAccessControlSecurity
+{
+	validate_frames: MediaAccessControlSecurityValidateFrames,
+	
+	replay_protect: bool,
+	
+	replay_window: u32,
+	
+	secure_channels: RefCell<HashMap<MediaAccessControlSecuritySecureChannelIdentifier, MediaAccessControlSecuritySecureChannel>>,
+}
+
+impl MediaAccessControlSecurity
+{
+	/// Creates a new instance without any secure channels.
+	#[inline(always)]
+	pub fn new(validate_frames: MediaAccessControlSecurityValidateFrames, replay_protect: bool, replay_window: u32) -> Self
+	{
+		Self
+		{
+			validate_frames,
+			replay_protect,
+			replay_window,
+			secure_channels: RefCell::new(HashMap::default()),
+		}
+	}
+	
+	/// Installs (or replaces) a receive secure association, creating its secure channel if necessary.
+	///
+	/// `lowest_packet_number` is the lowest acceptable Packet Number (PN); it is usually 1.
+	#[inline(always)]
+	pub fn install_secure_association(&self, secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier, association_number: u8, key: &MediaAccessControlSecuritySecureAssociationKey, lowest_packet_number: u32) -> Result<(), MediaAccessControlSecurityConfigurationError>
+	{
+		Self::guard_association_number(secure_channel_identifier, association_number)?;
+		
+		let mut secure_channels = self.secure_channels.borrow_mut();
+		let secure_channel = secure_channels.entry(secure_channel_identifier).or_insert_with(MediaAccessControlSecuritySecureChannel::default);
+		secure_channel.install(association_number, Some(MediaAccessControlSecuritySecureAssociation::new(key, lowest_packet_number)));
+		Ok(())
+	}
+	
+	/// Removes a receive secure association; the secure channel remains.
+	#[inline(always)]
+	pub fn remove_secure_association(&self, secure_channel_identifier: &MediaAccessControlSecuritySecureChannelIdentifier, association_number: u8) -> Result<(), MediaAccessControlSecurityConfigurationError>
+	{
+		Self::guard_association_number(*secure_channel_identifier, association_number)?;
+		
+		if let Some(secure_channel) = self.secure_channels.borrow_mut().get_mut(secure_channel_identifier)
+		{
+			secure_channel.install(association_number, None)
+		}
+		Ok(())
+	}
+	
+	/// Removes a receive secure channel and all of its secure associations.
+	#[inline(always)]
+	pub fn remove_secure_channel(&self, secure_channel_identifier: &MediaAccessControlSecuritySecureChannelIdentifier)
+	{
+		self.secure_channels.borrow_mut().remove(secure_channel_identifier);
+	}
+	
+	/// Are frames with this ether type, which do not have a SecTAG, delivered?
+	#[inline(always)]
+	pub(crate) fn permits_unprotected_frame(&self, potentially_invalid_ether_type: EtherType) -> bool
+	{
+		self.validate_frames.permits_unprotected_frame(potentially_invalid_ether_type)
+	}
+	
+	/// Validates and, if encrypted, decrypts in place a complete MACsec ethernet frame, starting with the destination ethernet address.
+	///
+	/// On success, returns the length of the SecTAG and the length of the secure data, which starts with the ether type of the inner frame and is followed by the Integrity Check Value (ICV) and any ethernet padding.
+	///
+	/// Unless validation is `Strict`, integrity protected frames may be returned without having been validated.
+	#[inline]
+	pub fn validate_and_decrypt(&self, frame: &mut [u8]) -> Result<(usize, usize), MediaAccessControlSecurityDropReason>
+	{
+		use self::MediaAccessControlSecurityDropReason::*;
+		
+		const SecureTagOffset: usize = EthernetPacketHeader::SizeU16 as usize;
+		const IntegrityCheckValueSize: usize = MediaAccessControlSecurityCipher::IntegrityCheckValueSize;
+		
+		if unlikely!(frame.len() < SecureTagOffset)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let secure_tag = MediaAccessControlSecuritySecureTag::parse(&frame[SecureTagOffset .. ])?;
+		
+		let secure_data_offset = SecureTagOffset + secure_tag.length();
+		if unlikely!(frame.len() < secure_data_offset + IntegrityCheckValueSize)
+		{
+			return Err(PacketIsTooShort)
+		}
+		let maximum_secure_data_length = frame.len() - secure_data_offset - IntegrityCheckValueSize;
+		
+		let short_length = secure_tag.short_length;
+		let secure_data_length = if short_length == 0
+		{
+			if unlikely!(maximum_secure_data_length < MediaAccessControlSecuritySecureTag::ShortLengthMaximumExclusive as usize)
+			{
+				return Err(ShortLengthIsInvalid { short_length })
+			}
+			maximum_secure_data_length
+		}
+		else
+		{
+			if unlikely!(short_length as usize > maximum_secure_data_length)
+			{
+				return Err(ShortLengthIsInvalid { short_length })
+			}
+			short_length as usize
+		};
+		
+		let is_encrypted = secure_tag.is_encrypted();
+		let validation_is_strict = is_encrypted || self.validate_frames == MediaAccessControlSecurityValidateFrames::Strict;
+		
+		if !is_encrypted && self.validate_frames == MediaAccessControlSecurityValidateFrames::Disabled
+		{
+			return Self::secure_tag_and_secure_data_lengths(&secure_tag, secure_data_length)
+		}
+		
+		let source_ethernet_address = MediaAccessControlAddress::from_octets(*array_ref![frame, MediaAccessControlAddress::Size, MediaAccessControlAddress::Size]);
+		let secure_channel_identifier = secure_tag.secure_channel_identifier(&source_ethernet_address);
+		let association_number = secure_tag.association_number();
+		let packet_number = secure_tag.packet_number;
+		
+		let mut secure_channels = self.secure_channels.borrow_mut();
+		
+		let secure_channel = match secure_channels.get_mut(&secure_channel_identifier)
+		{
+			None if validation_is_strict => return Err(UnknownSecureChannelIdentifier { secure_channel_identifier }),
+			
+			None => return Self::secure_tag_and_secure_data_lengths(&secure_tag, secure_data_length),
+			
+			Some(secure_channel) => secure_channel,
+		};
+		
+		let secure_association = match secure_channel.secure_association(association_number)
+		{
+			None if validation_is_strict => return Err(SecureAssociationIsNotInUse { secure_channel_identifier, association_number }),
+			
+			None => return Self::secure_tag_and_secure_data_lengths(&secure_tag, secure_data_length),
+			
+			Some(secure_association) => secure_association,
+		};
+		
+		if self.replay_protect
+		{
+			let lowest_acceptable_packet_number = secure_association.lowest_acceptable_packet_number(self.replay_window);
+			if unlikely!(packet_number < lowest_acceptable_packet_number)
+			{
+				return Err(PacketNumberIsReplayed { secure_channel_identifier, association_number, packet_number, lowest_acceptable_packet_number })
+			}
+		}
+		
+		// GCM-AES Initialization Vector (IV): the Secure Channel Identifier (SCI) followed by the Packet Number (PN) (IEEE Std 802.1AE-2006, Section 14.5).
+		let mut initialization_vector = [0u8; MediaAccessControlSecurityCipher::InitializationVectorSize];
+		initialization_vector[.. MediaAccessControlSecuritySecureChannelIdentifier::Size].copy_from_slice(&secure_channel_identifier.to_octets());
+		initialization_vector[MediaAccessControlSecuritySecureChannelIdentifier::Size .. ].copy_from_slice(&[(packet_number >> 24) as u8, (packet_number >> 16) as u8, (packet_number >> 8) as u8, packet_number as u8]);
+		
+		let integrity_check_value_offset = secure_data_offset + secure_data_length;
+		let (protected, trailer) = frame.split_at_mut(integrity_check_value_offset);
+		let integrity_check_value = &trailer[.. IntegrityCheckValueSize];
+		
+		let result = if is_encrypted
+		{
+			let (additional_authenticated_data, secure_data) = protected.split_at_mut(secure_data_offset);
+			secure_association.cipher().decrypt(&initialization_vector, additional_authenticated_data, secure_data, integrity_check_value)
+		}
+		else
+		{
+			secure_association.cipher().decrypt(&initialization_vector, protected, &mut [], integrity_check_value)
+		};
+		
+		if unlikely!(result.is_err())
+		{
+			if validation_is_strict
+			{
+				return Err(IntegrityCheckValueIsInvalid { secure_channel_identifier, association_number, packet_number })
+			}
+			
+			// Integrity protected data is not altered by a failed validation.
+			return Self::secure_tag_and_secure_data_lengths(&secure_tag, secure_data_length)
+		}
+		
+		secure_association.received(packet_number);
+		
+		Self::secure_tag_and_secure_data_lengths(&secure_tag, secure_data_length)
+	}
+	
+	/// Validates and decrypts a MACsec packet, then removes the ether type and SecTAG, and the Integrity Check Value (ICV) and any padding, leaving the inner ethernet frame.
+	///
+	/// Hardware can not parse beyond the SecTAG, so the inner frame has no hardware offload categorisation or check sum status.
+	#[inline(always)]
+	pub(crate) fn decapsulate<EINP: EthernetIncomingNetworkPacket>(&self, packet: EINP) -> Result<MediaAccessControlSecurityIncomingNetworkPacket<EINP>, MediaAccessControlSecurityDropReason>
+	{
+		let frame_length = packet.packet_length_if_contiguous() as usize;
+		let frame_pointer = packet.offset_into_data::<u8>(0).as_ptr();
+		
+		let (secure_tag_length, secure_data_length) = self.validate_and_decrypt(unsafe { from_raw_parts_mut(frame_pointer, frame_length) })?;
+		
+		// The secure data starts with the inner ether type, so the ethernet addresses are moved to immediately precede it.
+		let outer_headers_length = EtherTypeOrLegacyEthernetFrameSize::SizeU32 as usize + secure_tag_length;
+		unsafe { copy(frame_pointer, frame_pointer.add(outer_headers_length), EthernetPacketHeader::SizeLessEtherTypeU32 as usize) };
+		
+		let trailer_length = frame_length - EthernetPacketHeader::SizeU16 as usize - secure_tag_length - secure_data_length;
+		
+		packet.remove_from_start(outer_headers_length as u16);
+		packet.remove_from_end(trailer_length as u16);
+		Ok(MediaAccessControlSecurityIncomingNetworkPacket(packet))
+	}
+	
+	#[inline(always)]
+	fn secure_tag_and_secure_data_lengths(secure_tag: &MediaAccessControlSecuritySecureTag, secure_data_length: usize) -> Result<(usize, usize), MediaAccessControlSecurityDropReason>
+	{
+		if unlikely!(secure_data_length < EtherTypeOrLegacyEthernetFrameSize::SizeU32 as usize)
+		{
+			return Err(MediaAccessControlSecurityDropReason::InnerFrameIsTooShort)
+		}
+		
+		Ok((secure_tag.length(), secure_data_length))
+	}
+	
+	#[inline(always)]
+	fn guard_association_number(secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier, association_number: u8) -> Result<(), MediaAccessControlSecurityConfigurationError>
+	{
+		if unlikely!(association_number as usize >= MediaAccessControlSecuritySecureChannel::NumberOfAssociationNumbers)
+		{
+			Err(MediaAccessControlSecurityConfigurationError::AssociationNumberIsTooLarge { secure_channel_identifier, association_number })
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,67 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+pub(crate) enum MediaAccessControlSecurityCipher
+{
+	GcmAes128(Aes128Gcm),
+	
+	GcmAes256(Aes256Gcm),
+}
+
+impl Debug for MediaAccessControlSecurityCipher
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::MediaAccessControlSecurityCipher::*;
+		
+		// Never reveal key material.
+		match *self
+		{
+			GcmAes128(_) => write!(f, "GcmAes128"),
+			GcmAes256(_) => write!(f, "GcmAes256"),
+		}
+	}
+}
+
+impl<'a> From<&'a MediaAccessControlSecuritySecureAssociationKey> for MediaAccessControlSecurityCipher
+{
+	#[inline(always)]
+	fn from(key: &'a MediaAccessControlSecuritySecureAssociationKey) -> Self
+	{
+		use self::MediaAccessControlSecurityCipher::*;
+		
+		match *key
+		{
+			MediaAccessControlSecuritySecureAssociationKey::GcmAes128(ref key) => GcmAes128(Aes128Gcm::new(Key::<Aes128Gcm>::from_slice(key))),
+			MediaAccessControlSecuritySecureAssociationKey::GcmAes256(ref key) => GcmAes256(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key))),
+		}
+	}
+}
+
+impl MediaAccessControlSecurityCipher
+{
+	pub(crate) const IntegrityCheckValueSize: usize = 16;
+	
+	pub(crate) const InitializationVectorSize: usize = 12;
+	
+	/// Validates the Integrity Check Value (ICV) and, if it is valid, decrypts `secure_data` in place.
+	///
+	/// For integrity protection only, `secure_data` should be empty and `additional_authenticated_data` should include the secure data.
+	#[inline(always)]
+	pub(crate) fn decrypt(&self, initialization_vector: &[u8; Self::InitializationVectorSize], additional_authenticated_data: &[u8], secure_data: &mut [u8], integrity_check_value: &[u8]) -> Result<(), ()>
+	{
+		use self::MediaAccessControlSecurityCipher::*;
+		
+		let nonce = Nonce::from_slice(initialization_vector);
+		let tag = Tag::from_slice(integrity_check_value);
+		
+		let result = match *self
+		{
+			GcmAes128(ref cipher) => cipher.decrypt_in_place_detached(nonce, additional_authenticated_data, secure_data, tag),
+			GcmAes256(ref cipher) => cipher.decrypt_in_place_detached(nonce, additional_authenticated_data, secure_data, tag),
+		};
+		result.map_err(|_| ())
+	}
+}
@@ -0,0 +1,51 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// MACsec configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct MediaAccessControlSecurityConfiguration
+{
+	/// How strictly received frames are validated.
+	///
+	/// Defaults to `Strict`.
+	#[serde(default)] pub validate_frames: MediaAccessControlSecurityValidateFrames,
+	
+	/// Drop frames with a Packet Number (PN) below the replay window.
+	///
+	/// Defaults to true.
+	#[serde(default = "MediaAccessControlSecurityConfiguration::replay_protect_default")] pub replay_protect: bool,
+	
+	/// Replay window; zero requires strictly in-order delivery.
+	///
+	/// Defaults to zero.
+	#[serde(default)] pub replay_window: u32,
+	
+	/// Receive secure channels, keyed by Secure Channel Identifier (SCI).
+	#[serde(default)] pub secure_channels: HashMap<MediaAccessControlSecuritySecureChannelIdentifier, MediaAccessControlSecuritySecureChannelConfiguration>,
+}
+
+impl MediaAccessControlSecurityConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self) -> Result<MediaAccessControlSecurity, MediaAccessControlSecurityConfigurationError>
+	{
+		let media_access_control_security = MediaAccessControlSecurity::new(self.validate_frames, self.replay_protect, self.replay_window);
+		for (secure_channel_identifier, secure_channel) in self.secure_channels
+		{
+			for (association_number, secure_association) in secure_channel.secure_associations
+			{
+				media_access_control_security.install_secure_association(secure_channel_identifier, association_number, &secure_association.key, secure_association.lowest_packet_number)?
+			}
+		}
+		Ok(media_access_control_security)
+	}
+	
+	#[inline(always)]
+	fn replay_protect_default() -> bool
+	{
+		true
+	}
+}
@@ -0,0 +1,31 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Cause of failure to configure MACsec.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MediaAccessControlSecurityConfigurationError
+{
+	/// An Association Number (AN) was 4 or more; it is only two bits wide.
+	AssociationNumberIsTooLarge
+	{
+		/// Secure Channel Identifier (SCI).
+		secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier,
+		
+		/// Association Number (AN).
+		association_number: u8,
+	},
+}
+
+impl Display for MediaAccessControlSecurityConfigurationError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl ::std::error::Error for MediaAccessControlSecurityConfigurationError
+{
+}
@@ -0,0 +1,81 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a MACsec frame was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum MediaAccessControlSecurityDropReason
+{
+	/// The frame was too short for its SecTAG, secure data and Integrity Check Value (ICV).
+	PacketIsTooShort,
+	
+	/// The version (`V`) bit was set.
+	VersionIsNotZero,
+	
+	/// The Tag Control Information (TCI) had an invalid combination of bits, eg both `ES` and `SC` set, or `E` set without `C`.
+	TagControlInformationIsInvalid
+	{
+		/// Tag Control Information (TCI) and Association Number (AN).
+		tag_control_information_and_association_number: u8,
+	},
+	
+	/// The Short Length (SL) was 48 or more, did not fit the frame or was zero for secure data shorter than 48 bytes.
+	ShortLengthIsInvalid
+	{
+		/// Short Length (SL).
+		short_length: u8,
+	},
+	
+	/// The Packet Number (PN) was zero.
+	PacketNumberIsZero,
+	
+	/// There is no receive secure channel for this Secure Channel Identifier (SCI).
+	UnknownSecureChannelIdentifier
+	{
+		/// Secure Channel Identifier (SCI).
+		secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier,
+	},
+	
+	/// There is no secure association in use for this Association Number (AN).
+	SecureAssociationIsNotInUse
+	{
+		/// Secure Channel Identifier (SCI).
+		secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier,
+		
+		/// Association Number (AN).
+		association_number: u8,
+	},
+	
+	/// The Packet Number (PN) was below the replay window.
+	PacketNumberIsReplayed
+	{
+		/// Secure Channel Identifier (SCI).
+		secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier,
+		
+		/// Association Number (AN).
+		association_number: u8,
+		
+		/// Packet Number (PN).
+		packet_number: u32,
+		
+		/// Lowest acceptable Packet Number (PN).
+		lowest_acceptable_packet_number: u32,
+	},
+	
+	/// The Integrity Check Value (ICV) did not validate, ie the frame was altered or the key is wrong.
+	IntegrityCheckValueIsInvalid
+	{
+		/// Secure Channel Identifier (SCI).
+		secure_channel_identifier: MediaAccessControlSecuritySecureChannelIdentifier,
+		
+		/// Association Number (AN).
+		association_number: u8,
+		
+		/// Packet Number (PN).
+		packet_number: u32,
+	},
+	
+	/// The decrypted frame was too short to contain an ether type.
+	InnerFrameIsTooShort,
+}
@@ -0,0 +1,183 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// The inner ethernet frame of a MACsec packet, once the SecTAG, Integrity Check Value (ICV) and any padding have been removed.
+///
+/// Hardware can not parse beyond the SecTAG, so nothing is known of the inner frame: hardware offload categorisations are unknown or uncategorised and check sum statuses are `NoInformationKnown`.
+///
+/// Virtual LAN tags are inside the secure data and so can not have been stripped by hardware.
+#[derive(Debug, Copy, Clone)]
+pub struct MediaAccessControlSecurityIncomingNetworkPacket<EINP: EthernetIncomingNetworkPacket>(EINP);
+
+impl<EINP: EthernetIncomingNetworkPacket> IncomingNetworkPacket for MediaAccessControlSecurityIncomingNetworkPacket<EINP>
+{
+	#[inline(always)]
+	fn free_direct_contiguous_packet(self)
+	{
+		self.0.free_direct_contiguous_packet()
+	}
+	
+	#[inline(always)]
+	fn packet_length_if_contiguous(self) -> u16
+	{
+		self.0.packet_length_if_contiguous()
+	}
+	
+	#[inline(always)]
+	fn offset_into_data<T>(self, offset: usize) -> NonNull<T>
+	{
+		self.0.offset_into_data(offset)
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_layer_2_packet_type(self) -> HardwareOffloadLayer2PacketType
+	{
+		HardwareOffloadLayer2PacketType::Unknown
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_layer_3_packet_type(self) -> HardwareOffloadLayer3PacketType
+	{
+		HardwareOffloadLayer3PacketType::UncategorisedOrAbsent
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_layer_4_packet_type(self) -> HardwareOffloadLayer4PacketType
+	{
+		HardwareOffloadLayer4PacketType::UncategorisedOrAbsent
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_tunnel_packet_type(self) -> HardwareOffloadTunnelPacketType
+	{
+		HardwareOffloadTunnelPacketType::Uncategorised
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_tunnel_inner_layer_2_packet_type(self) -> HardwareOffloadLayer2PacketType
+	{
+		HardwareOffloadLayer2PacketType::Unknown
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_tunnel_inner_layer_3_packet_type(self) -> HardwareOffloadLayer3PacketType
+	{
+		HardwareOffloadLayer3PacketType::UncategorisedOrAbsent
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_tunnel_inner_layer_4_packet_type(self) -> HardwareOffloadLayer4PacketType
+	{
+		HardwareOffloadLayer4PacketType::UncategorisedOrAbsent
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_internet_protocol_version_4_check_sum_status(self) -> HardwareOffloadCheckSumStatus
+	{
+		HardwareOffloadCheckSumStatus::NoInformationKnown
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_layer_4_check_sum_status(self) -> HardwareOffloadCheckSumStatus
+	{
+		HardwareOffloadCheckSumStatus::NoInformationKnown
+	}
+	
+	#[inline(always)]
+	fn hardware_receive_timestamp(self) -> Option<u64>
+	{
+		self.0.hardware_receive_timestamp()
+	}
+	
+	#[inline(always)]
+	fn hardware_offload_categorisation_indicates_an_unwanted_packet(self) -> bool
+	{
+		false
+	}
+}
+
+impl<EINP: EthernetIncomingNetworkPacket> EthernetIncomingNetworkPacket for MediaAccessControlSecurityIncomingNetworkPacket<EINP>
+{
+	#[inline(always)]
+	fn was_vlan_tag_control_information_stripped(self) -> bool
+	{
+		false
+	}
+	
+	#[inline(always)]
+	fn stripped_vlan_tag_control_information(self) -> TagControlInformation
+	{
+		unreachable!("was_vlan_tag_control_information_stripped() is always false")
+	}
+	
+	#[inline(always)]
+	fn was_vlan_qinq_tag_control_information_stripped(self) -> bool
+	{
+		false
+	}
+	
+	#[inline(always)]
+	fn stripped_vlan_qinq_tag_control_information(self) -> (TagControlInformation, TagControlInformation)
+	{
+		unreachable!("was_vlan_qinq_tag_control_information_stripped() is always false")
+	}
+	
+	#[inline(always)]
+	fn set_stripped_vlan_tag_control_information(self, _tag_control_information: TagControlInformation)
+	{
+		unreachable!("was_vlan_tag_control_information_stripped() is always false")
+	}
+	
+	#[inline(always)]
+	fn set_stripped_vlan_qinq_tag_control_information(self, _outer_tag_control_information: TagControlInformation, _inner_tag_control_information: TagControlInformation)
+	{
+		unreachable!("was_vlan_qinq_tag_control_information_stripped() is always false")
+	}
+	
+	#[inline(always)]
+	fn internal_priority(self) -> InternalPriority
+	{
+		self.0.internal_priority()
+	}
+	
+	#[inline(always)]
+	fn set_internal_priority(self, internal_priority: InternalPriority)
+	{
+		self.0.set_internal_priority(internal_priority)
+	}
+	
+	#[inline(always)]
+	fn clear_stripped_vlan_tag_control_information(self)
+	{
+		self.0.clear_stripped_vlan_tag_control_information()
+	}
+	
+	#[inline(always)]
+	fn prepend(self, length: u16) -> bool
+	{
+		self.0.prepend(length)
+	}
+	
+	#[inline(always)]
+	fn remove_from_start(self, length: u16)
+	{
+		self.0.remove_from_start(length)
+	}
+	
+	#[inline(always)]
+	fn remove_from_end(self, length: u16)
+	{
+		self.0.remove_from_end(length)
+	}
+}
+
+impl<EINP: EthernetIncomingNetworkPacket> MediaAccessControlSecurityIncomingNetworkPacket<EINP>
+{
+	/// The underlying packet.
+	#[inline(always)]
+	pub fn outer(self) -> EINP
+	{
+		self.0
+	}
+}
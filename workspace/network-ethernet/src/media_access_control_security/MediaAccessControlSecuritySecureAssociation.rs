@@ -0,0 +1,46 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct MediaAccessControlSecuritySecureAssociation
+{
+	cipher: MediaAccessControlSecurityCipher,
+	
+	next_packet_number: u32,
+}
+
+impl MediaAccessControlSecuritySecureAssociation
+{
+	#[inline(always)]
+	pub(crate) fn new(key: &MediaAccessControlSecuritySecureAssociationKey, lowest_packet_number: u32) -> Self
+	{
+		Self
+		{
+			cipher: MediaAccessControlSecurityCipher::from(key),
+			next_packet_number: lowest_packet_number,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn cipher(&self) -> &MediaAccessControlSecurityCipher
+	{
+		&self.cipher
+	}
+	
+	#[inline(always)]
+	pub(crate) fn lowest_acceptable_packet_number(&self, replay_window: u32) -> u32
+	{
+		self.next_packet_number.saturating_sub(replay_window)
+	}
+	
+	/// Only called after the Integrity Check Value (ICV) has been validated.
+	#[inline(always)]
+	pub(crate) fn received(&mut self, packet_number: u32)
+	{
+		if packet_number >= self.next_packet_number
+		{
+			self.next_packet_number = packet_number.saturating_add(1)
+		}
+	}
+}
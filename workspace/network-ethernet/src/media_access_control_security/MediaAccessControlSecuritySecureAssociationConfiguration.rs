@@ -0,0 +1,26 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// MACsec receive secure association configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct MediaAccessControlSecuritySecureAssociationConfiguration
+{
+	/// Secure Association Key (SAK).
+	pub key: MediaAccessControlSecuritySecureAssociationKey,
+	
+	/// Lowest acceptable Packet Number (PN).
+	///
+	/// Defaults to 1.
+	#[serde(default = "MediaAccessControlSecuritySecureAssociationConfiguration::lowest_packet_number_default")] pub lowest_packet_number: u32,
+}
+
+impl MediaAccessControlSecuritySecureAssociationConfiguration
+{
+	#[inline(always)]
+	fn lowest_packet_number_default() -> u32
+	{
+		1
+	}
+}
@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A MACsec Secure Association Key (SAK) and its cipher suite (IEEE Std 802.1AE-2006, Section 14).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum MediaAccessControlSecuritySecureAssociationKey
+{
+	/// GCM-AES-128, the default cipher suite.
+	GcmAes128([u8; 16]),
+	
+	/// GCM-AES-256 (IEEE Std 802.1AEbn-2011).
+	GcmAes256([u8; 32]),
+}
@@ -0,0 +1,28 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#[derive(Debug, Default)]
+pub(crate) struct MediaAccessControlSecuritySecureChannel
+{
+	secure_associations: [Option<MediaAccessControlSecuritySecureAssociation>; MediaAccessControlSecuritySecureChannel::NumberOfAssociationNumbers],
+}
+
+impl MediaAccessControlSecuritySecureChannel
+{
+	pub(crate) const NumberOfAssociationNumbers: usize = 4;
+	
+	#[inline(always)]
+	pub(crate) fn secure_association(&mut self, association_number: u8) -> Option<&mut MediaAccessControlSecuritySecureAssociation>
+	{
+		unsafe { self.secure_associations.get_unchecked_mut(association_number as usize) }.as_mut()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn install(&mut self, association_number: u8, secure_association: Option<MediaAccessControlSecuritySecureAssociation>)
+	{
+		debug_assert!((association_number as usize) < Self::NumberOfAssociationNumbers, "association_number '{}' is too large", association_number);
+		
+		self.secure_associations[association_number as usize] = secure_association
+	}
+}
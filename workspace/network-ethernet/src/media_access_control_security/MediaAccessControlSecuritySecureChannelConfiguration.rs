@@ -0,0 +1,12 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// MACsec receive secure channel configuration.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct MediaAccessControlSecuritySecureChannelConfiguration
+{
+	/// Secure associations, keyed by Association Number (AN), 0 - 3 inclusive.
+	#[serde(default)] pub secure_associations: HashMap<u8, MediaAccessControlSecuritySecureAssociationConfiguration>,
+}
@@ -0,0 +1,52 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A MACsec Secure Channel Identifier (SCI) (IEEE Std 802.1AE-2006, Section 7.1.2).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct MediaAccessControlSecuritySecureChannelIdentifier
+{
+	/// Ethernet address of the transmitting system.
+	pub ethernet_address: MediaAccessControlAddress,
+	
+	/// Port identifier within the transmitting system.
+	pub port_identifier: u16,
+}
+
+impl MediaAccessControlSecuritySecureChannelIdentifier
+{
+	/// Size.
+	pub const Size: usize = 8;
+	
+	/// Port identifier used by end stations that omit the Secure Channel Identifier (SCI) from the SecTAG.
+	pub const EndStationPortIdentifier: u16 = 0x0001;
+	
+	/// The implicit Secure Channel Identifier (SCI) of a frame without an explicit one.
+	#[inline(always)]
+	pub fn end_station(source_ethernet_address: MediaAccessControlAddress) -> Self
+	{
+		Self
+		{
+			ethernet_address: source_ethernet_address,
+			port_identifier: Self::EndStationPortIdentifier,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn from_octets(octets: &[u8; Self::Size]) -> Self
+	{
+		Self
+		{
+			ethernet_address: MediaAccessControlAddress::from_octets(*array_ref![octets, 0, MediaAccessControlAddress::Size]),
+			port_identifier: ((octets[6] as u16) << 8) | (octets[7] as u16),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn to_octets(&self) -> [u8; Self::Size]
+	{
+		let ethernet_address = self.ethernet_address.to_octets_reference();
+		[ethernet_address[0], ethernet_address[1], ethernet_address[2], ethernet_address[3], ethernet_address[4], ethernet_address[5], (self.port_identifier >> 8) as u8, self.port_identifier as u8]
+	}
+}
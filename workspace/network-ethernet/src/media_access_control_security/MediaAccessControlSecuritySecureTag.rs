@@ -0,0 +1,161 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A parsed MACsec Security TAG (SecTAG) (IEEE Std 802.1AE-2006, Section 9).
+///
+/// The SecTAG follows the MACsec ether type, `0x88E5`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct MediaAccessControlSecuritySecureTag
+{
+	/// Tag Control Information (TCI) and Association Number (AN).
+	pub tag_control_information_and_association_number: u8,
+	
+	/// Short Length (SL); non-zero if the secure data is less than 48 bytes long.
+	pub short_length: u8,
+	
+	/// Packet Number (PN).
+	pub packet_number: u32,
+	
+	/// Explicit Secure Channel Identifier (SCI), if the `SC` bit is set.
+	pub secure_channel_identifier: Option<MediaAccessControlSecuritySecureChannelIdentifier>,
+}
+
+impl MediaAccessControlSecuritySecureTag
+{
+	/// Size without a Secure Channel Identifier (SCI).
+	pub const MinimumSize: usize = 6;
+	
+	/// Size with a Secure Channel Identifier (SCI).
+	pub const MaximumSize: usize = Self::MinimumSize + MediaAccessControlSecuritySecureChannelIdentifier::Size;
+	
+	/// Version (`V`) bit; must be clear.
+	pub const VersionBit: u8 = 0x80;
+	
+	/// End Station (`ES`) bit.
+	pub const EndStationBit: u8 = 0x40;
+	
+	/// Secure Channel (`SC`) bit; set if the Secure Channel Identifier (SCI) is present.
+	pub const SecureChannelBit: u8 = 0x20;
+	
+	/// Single Copy Broadcast (`SCB`) bit.
+	pub const SingleCopyBroadcastBit: u8 = 0x10;
+	
+	/// Encryption (`E`) bit; set if the secure data is encrypted.
+	pub const EncryptionBit: u8 = 0x08;
+	
+	/// Changed Text (`C`) bit.
+	pub const ChangedTextBit: u8 = 0x04;
+	
+	/// Association Number (AN) mask.
+	pub const AssociationNumberMask: u8 = 0x03;
+	
+	/// Secure data this short or longer has a Short Length (SL) of zero.
+	pub const ShortLengthMaximumExclusive: u8 = 48;
+	
+	/// Parse the bytes following the ether type.
+	#[inline]
+	pub fn parse(bytes: &[u8]) -> Result<Self, MediaAccessControlSecurityDropReason>
+	{
+		use self::MediaAccessControlSecurityDropReason::*;
+		
+		if unlikely!(bytes.len() < Self::MinimumSize)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let tag_control_information_and_association_number = bytes[0];
+		
+		if unlikely!(tag_control_information_and_association_number & Self::VersionBit != 0)
+		{
+			return Err(VersionIsNotZero)
+		}
+		
+		let is_invalid = match (tag_control_information_and_association_number & Self::EndStationBit != 0, tag_control_information_and_association_number & Self::SecureChannelBit != 0, tag_control_information_and_association_number & Self::SingleCopyBroadcastBit != 0)
+		{
+			(true, true, _) => true,
+			(_, true, true) => true,
+			_ => tag_control_information_and_association_number & (Self::EncryptionBit | Self::ChangedTextBit) == Self::EncryptionBit,
+		};
+		if unlikely!(is_invalid)
+		{
+			return Err(TagControlInformationIsInvalid { tag_control_information_and_association_number })
+		}
+		
+		let short_length = bytes[1];
+		if unlikely!(short_length >= Self::ShortLengthMaximumExclusive)
+		{
+			return Err(ShortLengthIsInvalid { short_length })
+		}
+		
+		let packet_number = ((bytes[2] as u32) << 24) | ((bytes[3] as u32) << 16) | ((bytes[4] as u32) << 8) | (bytes[5] as u32);
+		if unlikely!(packet_number == 0)
+		{
+			return Err(PacketNumberIsZero)
+		}
+		
+		let secure_channel_identifier = if tag_control_information_and_association_number & Self::SecureChannelBit != 0
+		{
+			if unlikely!(bytes.len() < Self::MaximumSize)
+			{
+				return Err(PacketIsTooShort)
+			}
+			Some(MediaAccessControlSecuritySecureChannelIdentifier::from_octets(array_ref![bytes, Self::MinimumSize, MediaAccessControlSecuritySecureChannelIdentifier::Size]))
+		}
+		else
+		{
+			None
+		};
+		
+		Ok
+		(
+			Self
+			{
+				tag_control_information_and_association_number,
+				short_length,
+				packet_number,
+				secure_channel_identifier,
+			}
+		)
+	}
+	
+	/// Length of this SecTAG.
+	#[inline(always)]
+	pub fn length(&self) -> usize
+	{
+		if self.secure_channel_identifier.is_some()
+		{
+			Self::MaximumSize
+		}
+		else
+		{
+			Self::MinimumSize
+		}
+	}
+	
+	/// Association Number (AN).
+	#[inline(always)]
+	pub fn association_number(&self) -> u8
+	{
+		self.tag_control_information_and_association_number & Self::AssociationNumberMask
+	}
+	
+	/// Is the secure data encrypted (confidentiality), rather than only integrity protected?
+	#[inline(always)]
+	pub fn is_encrypted(&self) -> bool
+	{
+		self.tag_control_information_and_association_number & Self::EncryptionBit != 0
+	}
+	
+	/// Secure Channel Identifier (SCI), either explicit or implied by the source ethernet address.
+	#[inline(always)]
+	pub fn secure_channel_identifier(&self, source_ethernet_address: &MediaAccessControlAddress) -> MediaAccessControlSecuritySecureChannelIdentifier
+	{
+		match self.secure_channel_identifier
+		{
+			None => MediaAccessControlSecuritySecureChannelIdentifier::end_station(*source_ethernet_address),
+			
+			Some(secure_channel_identifier) => secure_channel_identifier,
+		}
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// How strictly received frames are validated, the `validateFrames` control of IEEE Std 802.1AE-2006.
+///
+/// Encrypted frames can only be delivered once their Integrity Check Value (ICV) has been validated, so they are always validated strictly.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum MediaAccessControlSecurityValidateFrames
+{
+	/// Integrity protected frames are delivered with their SecTAG and Integrity Check Value (ICV) removed but without validation.
+	///
+	/// Frames without a SecTAG are delivered.
+	Disabled,
+	
+	/// Integrity protected frames are validated but are delivered even if there is no secure association for them or their Integrity Check Value (ICV) is invalid.
+	///
+	/// Frames without a SecTAG are delivered.
+	Check,
+	
+	/// Frames that fail validation are dropped.
+	///
+	/// Frames without a SecTAG are dropped, apart from Extensible Authentication Protocol over LAN (EAPOL), which carries the MACsec Key Agreement (MKA) protocol, and media access control (MAC) control frames, which are never protected.
+	Strict,
+}
+
+impl Default for MediaAccessControlSecurityValidateFrames
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		MediaAccessControlSecurityValidateFrames::Strict
+	}
+}
+
+impl MediaAccessControlSecurityValidateFrames
+{
+	/// Is a frame with this ether type, which does not have a SecTAG, delivered?
+	#[inline(always)]
+	pub fn permits_unprotected_frame(self, potentially_invalid_ether_type: EtherType) -> bool
+	{
+		use self::MediaAccessControlSecurityValidateFrames::*;
+		
+		match self
+		{
+			Disabled | Check => true,
+			
+			Strict => match potentially_invalid_ether_type
+			{
+				EtherType::MediaAccessControlSecurity | EtherType::ExtensibleAuthenticationProtocolOverLan | EtherType::MediaAccessControlControl => true,
+				
+				_ => false,
+			}
+		}
+	}
+}
@@ -0,0 +1,43 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Multiprotocol Label Switching (MPLS) packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum MultiprotocolLabelSwitchingDropReason
+{
+	/// The packet ended before the bottom of the label stack (or before the first byte of the payload).
+	PacketIsTooShort,
+	
+	/// The label stack is deeper than the configured maximum.
+	LabelStackIsTooDeep
+	{
+		/// Maximum label stack depth.
+		maximum_label_stack_depth: u8,
+	},
+	
+	/// There is no configuration for this label.
+	NoConfigurationForLabel
+	{
+		/// Label.
+		label: MultiprotocolLabelSwitchingLabel,
+	},
+	
+	/// The configured action for this label is to drop.
+	LabelIsDropped
+	{
+		/// Label.
+		label: MultiprotocolLabelSwitchingLabel,
+	},
+	
+	/// The payload after the bottom of the label stack is not internet protocol (IP) version 4 or version 6.
+	PayloadIsNotInternetProtocol
+	{
+		/// Label at the bottom of the stack.
+		label: MultiprotocolLabelSwitchingLabel,
+		
+		/// The first nibble of the payload, which would be the internet protocol (IP) version.
+		first_nibble: u8,
+	},
+}
@@ -0,0 +1,78 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Multiprotocol Label Switching (MPLS) label, 0 - 1048575 inclusive.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct MultiprotocolLabelSwitchingLabel(u32);
+
+impl Display for MultiprotocolLabelSwitchingLabel
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.0)
+	}
+}
+
+impl Into<u32> for MultiprotocolLabelSwitchingLabel
+{
+	#[inline(always)]
+	fn into(self) -> u32
+	{
+		self.0
+	}
+}
+
+impl TryFrom<u32> for MultiprotocolLabelSwitchingLabel
+{
+	type Error = ();
+	
+	#[inline(always)]
+	fn try_from(value: u32) -> Result<Self, Self::Error>
+	{
+		Self::new(value)
+	}
+}
+
+impl MultiprotocolLabelSwitchingLabel
+{
+	/// Maximum.
+	pub const Maximum: u32 = 0x000F_FFFF;
+	
+	/// Internet protocol (IP) version 4 explicit NULL label (RFC 3032, Section 2.1).
+	pub const InternetProtocolVersion4ExplicitNull: Self = MultiprotocolLabelSwitchingLabel(0);
+	
+	/// Router alert label (RFC 3032, Section 2.1).
+	pub const RouterAlert: Self = MultiprotocolLabelSwitchingLabel(1);
+	
+	/// Internet protocol (IP) version 6 explicit NULL label (RFC 3032, Section 2.1).
+	pub const InternetProtocolVersion6ExplicitNull: Self = MultiprotocolLabelSwitchingLabel(2);
+	
+	/// Implicit NULL label (RFC 3032, Section 2.1); this is only ever advertised and never appears in a label stack.
+	pub const ImplicitNull: Self = MultiprotocolLabelSwitchingLabel(3);
+	
+	/// Parse.
+	///
+	/// Returns an error if > 1048575.
+	#[inline(always)]
+	pub fn new(value: u32) -> Result<Self, ()>
+	{
+		if value <= Self::Maximum
+		{
+			Ok(MultiprotocolLabelSwitchingLabel(value))
+		}
+		else
+		{
+			Err(())
+		}
+	}
+	
+	/// Is this one of the reserved labels 0 - 15 inclusive?
+	#[inline(always)]
+	pub fn is_reserved(self) -> bool
+	{
+		self.0 < 16
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// What to do with a packet whose label stack contains a particular label.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum MultiprotocolLabelSwitchingLabelAction
+{
+	/// Pop the label.
+	///
+	/// If the label is at the bottom of the stack, the payload is delivered to internet protocol (IP) version 4 or version 6 packet processing; otherwise the next label is processed.
+	PopAndDeliver,
+	
+	/// Drop the packet.
+	Drop,
+}
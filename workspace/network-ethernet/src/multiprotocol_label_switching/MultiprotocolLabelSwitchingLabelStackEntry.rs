@@ -0,0 +1,49 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Multiprotocol Label Switching (MPLS) label stack entry (RFC 3032, Section 2.1).
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct MultiprotocolLabelSwitchingLabelStackEntry
+{
+	/// Label (20 bits), traffic class (3 bits), bottom of stack (1 bit) and time to live (8 bits), in network byte order.
+	pub bytes: [u8; 4],
+}
+
+impl MultiprotocolLabelSwitchingLabelStackEntry
+{
+	/// Size of a label stack entry.
+	pub const Size: usize = 4;
+	
+	/// Size of a label stack entry.
+	pub const SizeU16: u16 = Self::Size as u16;
+	
+	/// Label.
+	#[inline(always)]
+	pub fn label(&self) -> MultiprotocolLabelSwitchingLabel
+	{
+		MultiprotocolLabelSwitchingLabel(((self.bytes[0] as u32) << 12) | ((self.bytes[1] as u32) << 4) | ((self.bytes[2] as u32) >> 4))
+	}
+	
+	/// Traffic class (formerly experimental), 0 - 7 inclusive (RFC 5462).
+	#[inline(always)]
+	pub fn traffic_class(&self) -> u8
+	{
+		(self.bytes[2] >> 1) & 0b0000_0111
+	}
+	
+	/// Is this the last label stack entry?
+	#[inline(always)]
+	pub fn is_bottom_of_stack(&self) -> bool
+	{
+		self.bytes[2] & 0b0000_0001 != 0
+	}
+	
+	/// Time to live.
+	#[inline(always)]
+	pub fn time_to_live(&self) -> u8
+	{
+		self.bytes[3]
+	}
+}
@@ -0,0 +1,91 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Multiprotocol Label Switching (MPLS) packet processing (RFC 3032).
+///
+/// Terminates label switched paths: labels are popped according to their configured action and the payload delivered to internet protocol (IP) version 4 or version 6 packet processing.
+///
+/// Labels are not swapped or forwarded.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct MultiprotocolLabelSwitchingPacketProcessing
+{
+	/// Maximum label stack depth; deeper stacks are dropped.
+	///
+	/// Defaults to 4.
+	#[serde(default = "MultiprotocolLabelSwitchingPacketProcessing::maximum_label_stack_depth_default")] pub maximum_label_stack_depth: u8,
+	
+	/// Actions by label; labels without an action are dropped.
+	pub labels: HashMap<MultiprotocolLabelSwitchingLabel, MultiprotocolLabelSwitchingLabelAction>,
+}
+
+impl MultiprotocolLabelSwitchingPacketProcessing
+{
+	/// Walks the label stack of `layer_3_packet`, returning the length of the label stack and the ether type of the payload.
+	#[inline(always)]
+	pub(crate) fn pop_label_stack(&self, layer_3_packet: &Layer3Packet, layer_3_length: u16) -> Result<(u16, EtherType), MultiprotocolLabelSwitchingDropReason>
+	{
+		use self::MultiprotocolLabelSwitchingDropReason::*;
+		use self::MultiprotocolLabelSwitchingLabelAction::*;
+		
+		const InternetProtocolVersion4: u8 = 4;
+		const InternetProtocolVersion6: u8 = 6;
+		
+		let label_stack_entries = layer_3_packet.as_type::<MultiprotocolLabelSwitchingLabelStackEntry>() as *const MultiprotocolLabelSwitchingLabelStackEntry;
+		
+		let mut label_stack_length = 0;
+		let mut label_stack_depth = 0;
+		loop
+		{
+			if unlikely!(label_stack_depth == self.maximum_label_stack_depth)
+			{
+				return Err(LabelStackIsTooDeep { maximum_label_stack_depth: self.maximum_label_stack_depth })
+			}
+			
+			if unlikely!(layer_3_length - label_stack_length < MultiprotocolLabelSwitchingLabelStackEntry::SizeU16)
+			{
+				return Err(PacketIsTooShort)
+			}
+			
+			let label_stack_entry = unsafe { &*label_stack_entries.add(label_stack_depth as usize) };
+			label_stack_length += MultiprotocolLabelSwitchingLabelStackEntry::SizeU16;
+			label_stack_depth += 1;
+			
+			let label = label_stack_entry.label();
+			match self.labels.get(&label)
+			{
+				None => return Err(NoConfigurationForLabel { label }),
+				
+				Some(&Drop) => return Err(LabelIsDropped { label }),
+				
+				Some(&PopAndDeliver) => (),
+			}
+			
+			if label_stack_entry.is_bottom_of_stack()
+			{
+				if unlikely!(layer_3_length == label_stack_length)
+				{
+					return Err(PacketIsTooShort)
+				}
+				
+				// There is no protocol field; RFC 3032, Section 2.2, relies on the label to imply the payload, but in practice the first nibble (the internet protocol (IP) version) is used.
+				let first_nibble = unsafe { *(label_stack_entries.add(label_stack_depth as usize) as *const u8) } >> 4;
+				return match first_nibble
+				{
+					InternetProtocolVersion4 => Ok((label_stack_length, EtherType::InternetProtocolVersion4)),
+					
+					InternetProtocolVersion6 => Ok((label_stack_length, EtherType::InternetProtocolVersion6)),
+					
+					_ => Err(PayloadIsNotInternetProtocol { label, first_nibble }),
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn maximum_label_stack_depth_default() -> u8
+	{
+		4
+	}
+}
@@ -53,6 +53,54 @@ pub trait EthernetIncomingNetworkPacket: IncomingNetworkPacket
 	#[inline(always)]
 	fn stripped_vlan_qinq_tag_control_information(self) -> (TagControlInformation, TagControlInformation);
 	
+	/// Replace stripped VLAN tag control information (TCI), eg to set the drop eligible indicator.
+	///
+	/// Only called if `was_vlan_tag_control_information_stripped()` is true.
+	#[inline(always)]
+	fn set_stripped_vlan_tag_control_information(self, tag_control_information: TagControlInformation);
+	
+	/// Replace stripped VLAN QinQ tag control information (TCI) (outer and inner), eg to set the drop eligible indicator.
+	///
+	/// Only called if `was_vlan_qinq_tag_control_information_stripped()` is true.
+	#[inline(always)]
+	fn set_stripped_vlan_qinq_tag_control_information(self, outer_tag_control_information: TagControlInformation, inner_tag_control_information: TagControlInformation);
+	
+	/// Internal priority assigned to this packet by quality of service mapping.
+	///
+	/// Set before the packet is passed to layer 3 packet processing, and so available to layer 4 packet processing; defaults to `InternalPriority::Lowest` before then.
+	#[inline(always)]
+	fn internal_priority(self) -> InternalPriority;
+	
+	/// Set the internal priority assigned to this packet by quality of service mapping.
+	///
+	/// With DPDK, this would typically be stored in the packet's private data area.
+	#[inline(always)]
+	fn set_internal_priority(self, internal_priority: InternalPriority);
+	
+	/// Forget any stripped VLAN tag control information (TCI) or VLAN QinQ tag control information (TCI), eg because it has been re-inserted into the packet data.
+	#[inline(always)]
+	fn clear_stripped_vlan_tag_control_information(self);
+	
+	/// Prepends `length` bytes to the start of the packet data, eg to make room for a VLAN tag.
+	///
+	/// Returns `false` if there is insufficient headroom.
+	///
+	/// With DPDK, this is `rte_pktmbuf_prepend()`.
+	#[inline(always)]
+	fn prepend(self, length: u16) -> bool;
+	
+	/// Removes `length` bytes from the start of the packet data, eg to remove a VLAN tag.
+	///
+	/// With DPDK, this is `rte_pktmbuf_adj()`.
+	#[inline(always)]
+	fn remove_from_start(self, length: u16);
+	
+	/// Removes `length` bytes from the end of the packet data, eg to remove an Integrity Check Value (ICV).
+	///
+	/// With DPDK, this is `rte_pktmbuf_trim()`.
+	#[inline(always)]
+	fn remove_from_end(self, length: u16);
+	
 	/// Ethernet packet.
 	///
 	/// No checking of data length is made; be careful dereferencing this value.
@@ -132,6 +132,16 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
 	},
 	
+	/// A packet other than an Extensible Authentication Protocol over LAN (EAPOL) packet had a source address which has not been authorised by IEEE 802.1X port-based network access control.
+	SourceEthernetAddressIsNotAuthorised
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
 	/// A packet should never have a destination ethernet address of zero.
 	DestinationEthernetAddressIsZero
 	{
@@ -152,7 +162,7 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
 	},
 	
-	/// Currently recognised and supported EtherTypes are Internet Protocol (IP) Version 4, Internet Protocol (IP) Version 6, Address Resolution Protocol, Virtual LAN tagging (801.1q) and QinQ Virtual LAN tagging (802.1ad).
+	/// Currently recognised and supported EtherTypes are Internet Protocol (IP) Version 4, Internet Protocol (IP) Version 6, Address Resolution Protocol, Slow Protocols, Virtual LAN tagging (801.1q) and QinQ Virtual LAN tagging (802.1ad).
 	///
 	/// Ether frame sizes are entirely unsupported.
 	UnsupportedEtherType
@@ -167,6 +177,149 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		unsuspported_ether_type_or_legacy_ethernet_frame_size: EtherTypeOrLegacyEthernetFrameSize,
 	},
 	
+	/// A slow protocols packet was not sent to the slow protocols multicast ethernet address.
+	SlowProtocolsDestinationEthernetAddressIsNotSlowProtocolsMulticast
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
+	/// A slow protocols packet was too short to contain a subtype or a Link Aggregation Control Protocol Data Unit (LACPDU).
+	SlowProtocolsPacketIsTooShort
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
+	/// Currently the only supported slow protocols subtype is Link Aggregation Control Protocol (LACP).
+	///
+	/// Marker Protocol and Operations, Administration and Maintenance (OAM) are not supported.
+	UnsupportedSlowProtocolsSubtype
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Slow protocols subtype.
+		subtype: u8,
+	},
+	
+	/// A Link Aggregation Control Protocol Data Unit (LACPDU) had invalid Type-Length-Value (TLV) types or lengths.
+	LinkAggregationControlProtocolDataUnitIsInvalid
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
+	/// A Link Aggregation Control Protocol Data Unit (LACPDU) was received on a network interface that is not a member of a link aggregation group.
+	LinkAggregationIsNotConfigured
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
+	/// A packet was received on a link aggregation group member port that is not (yet) collecting.
+	LinkAggregationMemberPortIsNotCollecting
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
+	/// A packet was received on a learning bridge port that is not a member of the packet's Virtual LAN.
+	BridgeIngressPortIsNotAMemberOfVirtualLan
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Learning bridge port the packet was received on.
+		ingress_port_number: u16,
+	},
+	
+	/// A packet was received on a learning bridge port that the spanning tree has not put into the forwarding state.
+	BridgeIngressPortIsNotForwarding
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Learning bridge port the packet was received on.
+		ingress_port_number: u16,
+	},
+	
+	/// A packet was received on a learning bridge port with a source ethernet address that has a static forwarding database entry for a different port.
+	BridgeSourceEthernetAddressIsStaticallyBoundToAnotherPort
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Learning bridge port the packet was received on.
+		ingress_port_number: u16,
+	},
+	
+	/// A packet was received on a learning bridge port with a destination ethernet address known to be on the same port; it does not need to be forwarded.
+	BridgeDestinationEthernetAddressIsOnIngressPort
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Learning bridge port the packet was received on.
+		ingress_port_number: u16,
+	},
+	
+	/// A packet sent to the bridge group address was received on a learning bridge port but was not a valid Bridge Protocol Data Unit (BPDU).
+	BridgeProtocolDataUnitIsInvalid
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Learning bridge port the packet was received on.
+		ingress_port_number: u16,
+	},
+	
+	/// A Bridge Protocol Data Unit (BPDU) was received on a learning bridge port but the learning bridge does not have a spanning tree.
+	SpanningTreeIsNotConfigured
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Learning bridge port the packet was received on.
+		ingress_port_number: u16,
+	},
+	
 	/// Wrapper around a problematic internet protocol version 4 packet.
 	ProblematicInternetProtocolVersion4Packet
 	{
@@ -176,6 +329,9 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		/// Dropped packet's ethernet addresses.
 		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
 		
+		/// Internal priority assigned to the dropped packet by quality of service mapping.
+		internal_priority: InternalPriority,
+		
 		/// Reason
 		reason: IPV4INPDR,
 	},
@@ -189,6 +345,9 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		/// Dropped packet's ethernet addresses.
 		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
 		
+		/// Internal priority assigned to the dropped packet by quality of service mapping.
+		internal_priority: InternalPriority,
+		
 		/// Reason
 		reason: IPV6INPDR,
 	},
@@ -202,6 +361,9 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		/// Dropped packet's ethernet addresses.
 		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
 		
+		/// Internal priority assigned to the dropped packet by quality of service mapping.
+		internal_priority: InternalPriority,
+		
 		/// Reason
 		reason: ARPINPDR,
 	},
@@ -387,6 +549,135 @@ pub enum EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR:
 		/// Inner Tag Control Information (TCI).
 		inner_tag_control_information: TagControlInformation,
 	},
+	
+	/// A class of service policer marked the packet yellow or red and the action configured for that colour is to drop.
+	PolicedClassOfService
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Tag Control Information (TCI) of the Virtual LAN tag whose class of service was policed.
+		tag_control_information: TagControlInformation,
+		
+		/// Colour the packet was marked.
+		colour: PolicingColour,
+	},
+	
+	/// The Virtual LAN tags of a packet could not be rewritten, either because there was insufficient headroom to push a tag or because an operation required a tag and the packet was untagged.
+	///
+	/// The packet may have been partially rewritten.
+	CouldNotRewriteVirtualLanTags
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
+	
+	/// A tunnel packet, such as a Virtual eXtensible Local Area Network (VXLAN) packet, could not be terminated.
+	InvalidTunnelPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's (outer) ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: InvalidTunnelPacketReason,
+	},
+	
+	/// A Multiprotocol Label Switching (MPLS) packet's label stack could not be popped.
+	ProblematicMultiprotocolLabelSwitchingPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: MultiprotocolLabelSwitchingDropReason,
+	},
+	
+	/// A Point-to-Point Protocol over Ethernet (PPPoE) discovery or session packet was invalid or not for an established session.
+	ProblematicPointToPointProtocolOverEthernetPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: PointToPointProtocolOverEthernetDropReason,
+	},
+	
+	/// A Precision Time Protocol (PTP) message was invalid, unsupported or did not match an exchange in progress.
+	ProblematicPrecisionTimeProtocolPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: PrecisionTimeProtocolDropReason,
+	},
+	
+	/// A media access control (MAC) control frame was invalid or was not PAUSE or Priority-based Flow Control (PFC).
+	ProblematicFlowControlPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: FlowControlDropReason,
+	},
+	
+	/// An Extensible Authentication Protocol over LAN (EAPOL) packet was invalid or unsupported.
+	ProblematicExtensibleAuthenticationProtocolOverLanPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: PortBasedNetworkAccessControlDropReason,
+	},
+	
+	/// A MACsec packet was invalid, failed validation or was replayed.
+	ProblematicMediaAccessControlSecurityPacket
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+		
+		/// Why.
+		reason: MediaAccessControlSecurityDropReason,
+	},
+	
+	/// MACsec validation is strict but the frame did not have a SecTAG, and was not Extensible Authentication Protocol over LAN (EAPOL) or a media access control (MAC) control frame.
+	MediaAccessControlSecurityIsRequired
+	{
+		/// Approximate time this packet arrived at.
+		now: MonotonicMillisecondTimestamp,
+		
+		/// Dropped packet's ethernet addresses.
+		ethernet_addresses: &'ethernet_addresses EthernetAddresses,
+	},
 }
 
 impl<'ethernet_addresses, ARPINPDR: IncomingNetworkPacketProcessingDropReason, IPV4INPDR: IncomingNetworkPacketProcessingDropReason, IPV6INPDR: IncomingNetworkPacketProcessingDropReason> IncomingNetworkPacketProcessingDropReason for EthernetIncomingNetworkPacketDropReason<'ethernet_addresses, ARPINPDR, IPV4INPDR, IPV6INPDR>
@@ -14,6 +14,15 @@ pub struct EthernetPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObs
 	/// Inner 802.1Q Virtual LAN permitted classes of service.
 	inner_permitted_classes_of_service: PermittedClassesOfService,
 	
+	/// Inner 802.1Q Virtual LAN class of service policers.
+	inner_class_of_service_policers: ClassOfServicePolicers,
+	
+	/// Quality of service trust mode and mapping tables.
+	quality_of_service: QualityOfServiceMapping,
+	
+	/// Flow control state from received PAUSE and Priority-based Flow Control (PFC) frames.
+	flow_control: FlowControl,
+	
 	/// Our unicast ethernet addresses valid for this network interface.
 	///
 	/// No sender packet should be received from this address; if it was, it implies loopback on this interface, which is daft.
@@ -21,6 +30,9 @@ pub struct EthernetPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObs
 	
 	/// Blacklist or whitelist of ethernet addresses.
 	source_ethernet_address_blacklist_or_whitelist: MediaAccessControlAddressList,
+	
+	/// IEEE 802.1X port-based network access control, if any.
+	port_based_network_access_control: Option<PortBasedNetworkAccessControl>,
 
 	/// Address Resolution Protocol (ARP) packet processing.
 	address_resolution_protocol_packet_processing: ARP,
@@ -30,6 +42,15 @@ pub struct EthernetPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObs
 	
 	/// Internet Protocol (IP) version 6 packet processing.
 	internet_protocol_version_6_packet_processing: IPV6,
+	
+	/// Multiprotocol Label Switching (MPLS) packet processing, if any.
+	multiprotocol_label_switching_packet_processing: Option<MultiprotocolLabelSwitchingPacketProcessing>,
+	
+	/// Point-to-Point Protocol over Ethernet (PPPoE) packet processing, if any.
+	point_to_point_protocol_over_ethernet_packet_processing: Option<PointToPointProtocolOverEthernetPacketProcessing>,
+	
+	/// Precision Time Protocol (PTP) packet processing, if any.
+	precision_time_protocol_packet_processing: Option<PrecisionTimeProtocolPacketProcessing>,
 }
 
 impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>
@@ -52,6 +73,32 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason,
 		self.inner_permitted_classes_of_service.is_denied(class_of_service)
 	}
 	
+	/// Quality of service trust mode and mapping tables, eg to map an internal priority to a class of service or Differentiated Service Code Point (DSCP) on egress.
+	#[inline(always)]
+	pub fn quality_of_service(&self) -> &QualityOfServiceMapping
+	{
+		&self.quality_of_service
+	}
+	
+	/// Flow control state from received PAUSE and Priority-based Flow Control (PFC) frames, eg so a transmit scheduler can honour them.
+	#[inline(always)]
+	pub fn flow_control(&self) -> &FlowControl
+	{
+		&self.flow_control
+	}
+	
+	#[inline(always)]
+	pub(crate) fn police_class_of_service(&self, now: MonotonicMillisecondTimestamp, class_of_service: ClassOfService, packet_length: u16) -> Option<(PolicingColour, PolicingAction)>
+	{
+		self.inner_class_of_service_policers.police(now, class_of_service, packet_length)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn our_valid_unicast_ethernet_address(&self) -> &MediaAccessControlAddress
+	{
+		&self.our_valid_unicast_ethernet_address
+	}
+	
 	#[inline(always)]
 	pub(crate) fn is_ethernet_address_our_valid_unicast_ethernet_address(&self, destination_ethernet_address: &MediaAccessControlAddress) -> bool
 	{
@@ -76,6 +123,44 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason,
 		self.source_ethernet_address_blacklist_or_whitelist.is_denied(&source_ethernet_address)
 	}
 	
+	#[inline(always)]
+	pub(crate) fn is_unauthorised_source_ethernet_address(&self, source_ethernet_address: &MediaAccessControlAddress) -> bool
+	{
+		match self.port_based_network_access_control
+		{
+			None => false,
+			
+			Some(ref port_based_network_access_control) => port_based_network_access_control.is_not_authorised(source_ethernet_address),
+		}
+	}
+	
+	/// IEEE 802.1X port-based network access control, if any, eg to set an authenticator.
+	#[inline(always)]
+	pub fn port_based_network_access_control(&self) -> Option<&PortBasedNetworkAccessControl>
+	{
+		self.port_based_network_access_control.as_ref()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn multiprotocol_label_switching_packet_processing(&self) -> Option<&MultiprotocolLabelSwitchingPacketProcessing>
+	{
+		self.multiprotocol_label_switching_packet_processing.as_ref()
+	}
+	
+	/// Point-to-Point Protocol over Ethernet (PPPoE) packet processing, if any, eg to inspect or establish sessions.
+	#[inline(always)]
+	pub fn point_to_point_protocol_over_ethernet_packet_processing(&self) -> Option<&PointToPointProtocolOverEthernetPacketProcessing>
+	{
+		self.point_to_point_protocol_over_ethernet_packet_processing.as_ref()
+	}
+	
+	/// Precision Time Protocol (PTP) packet processing, if any, eg to obtain measurements or to process messages received over User Datagram Protocol (UDP).
+	#[inline(always)]
+	pub fn precision_time_protocol_packet_processing(&self) -> Option<&PrecisionTimeProtocolPacketProcessing>
+	{
+		self.precision_time_protocol_packet_processing.as_ref()
+	}
+	
 	#[inline(always)]
 	pub(crate) fn process_address_resolution_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_3_packet: &'lifetime Layer3Packet, layer_3_length: u16, ethernet_addresses: &'lifetime EthernetAddresses)
 	{
@@ -14,9 +14,25 @@ where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : La
 	/// Inner 802.1Q Virtual LAN permitted classes of service.
 	#[serde(default)] pub inner_permitted_classes_of_service: PermittedClassesOfService,
 	
+	/// Inner 802.1Q Virtual LAN class of service policers.
+	///
+	/// If this configuration is shared by several Virtual LANs (eg by a range), so are the policers.
+	#[serde(default)] pub inner_class_of_service_policers: HashMap<ClassOfService, ClassOfServicePolicerConfiguration>,
+	
+	/// Quality of service trust mode and mapping tables.
+	#[serde(default)] pub quality_of_service: QualityOfServiceConfiguration,
+	
+	/// Flow control using PAUSE and Priority-based Flow Control (PFC) frames.
+	#[serde(default)] pub flow_control: FlowControlConfiguration,
+	
 	/// Blacklist or whitelist of ethernet addresses.
 	#[serde(default)] pub source_ethernet_address_blacklist_or_whitelist: MediaAccessControlAddressList,
 	
+	/// IEEE 802.1X port-based network access control configuration.
+	///
+	/// If present, only Extensible Authentication Protocol over LAN (EAPOL) packets are accepted from unauthorised source ethernet addresses; if absent, Extensible Authentication Protocol over LAN (EAPOL) packets are dropped as an unsupported ether type.
+	#[serde(default)] pub port_based_network_access_control_configuration: Option<PortBasedNetworkAccessControlConfiguration>,
+	
 	/// Address Resolution Protocol (ARP) packet processing configuration.
 	#[serde(default)] pub address_resolution_protocol_packet_processing_configuration: ARP,
 	
@@ -25,26 +41,53 @@ where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : La
 	
 	/// Internet Protocol (IP) version 6 packet processing configuration.
 	#[serde(default)] pub internet_protocol_version_6_packet_processing_configuration: IPV6,
+	
+	/// Multiprotocol Label Switching (MPLS) packet processing.
+	///
+	/// If absent, Multiprotocol Label Switching (MPLS) packets are dropped as an unsupported ether type.
+	#[serde(default)] pub multiprotocol_label_switching_packet_processing: Option<MultiprotocolLabelSwitchingPacketProcessing>,
+	
+	/// Point-to-Point Protocol over Ethernet (PPPoE) packet processing configuration.
+	///
+	/// If absent, Point-to-Point Protocol over Ethernet (PPPoE) packets are dropped as an unsupported ether type.
+	#[serde(default)] pub point_to_point_protocol_over_ethernet_packet_processing_configuration: Option<PointToPointProtocolOverEthernetPacketProcessingConfiguration>,
+	
+	/// Precision Time Protocol (PTP) packet processing configuration.
+	///
+	/// If absent, Precision Time Protocol (PTP) packets are dropped as an unsupported ether type.
+	#[serde(default)] pub precision_time_protocol_packet_processing_configuration: Option<PrecisionTimeProtocolPacketProcessingConfiguration>,
 }
 
 impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration> EthernetPacketProcessingConfiguration<ARP, IPV4, IPV6>
 where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
 {
 	/// Configure.
+	///
+	/// Fails if flow control is misconfigured or if a statically configured Point-to-Point Protocol over Ethernet (PPPoE) session can not be established.
 	#[inline(always)]
-	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_unicast_ethernet_address: MediaAccessControlAddress) -> EthernetPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_unicast_ethernet_address: MediaAccessControlAddress) -> Result<EthernetPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
 	{
-		EthernetPacketProcessing
-		{
-			dropped_packet_reporting: dropped_packet_reporting.clone(),
-			inner_honour_drop_eligible_indicator: self.inner_honour_drop_eligible_indicator,
-			inner_permitted_classes_of_service: self.inner_permitted_classes_of_service,
-			our_valid_unicast_ethernet_address,
-			source_ethernet_address_blacklist_or_whitelist: self.source_ethernet_address_blacklist_or_whitelist,
-			address_resolution_protocol_packet_processing: self.address_resolution_protocol_packet_processing_configuration.configure(dropped_packet_reporting),
-			internet_protocol_version_4_packet_processing: self.internet_protocol_version_4_packet_processing_configuration.configure(dropped_packet_reporting),
-			internet_protocol_version_6_packet_processing: self.internet_protocol_version_6_packet_processing_configuration.configure(dropped_packet_reporting),
-		}
+		Ok
+		(
+			EthernetPacketProcessing
+			{
+				dropped_packet_reporting: dropped_packet_reporting.clone(),
+				inner_honour_drop_eligible_indicator: self.inner_honour_drop_eligible_indicator,
+				inner_permitted_classes_of_service: self.inner_permitted_classes_of_service,
+				inner_class_of_service_policers: ClassOfServicePolicers::new(self.inner_class_of_service_policers),
+				quality_of_service: self.quality_of_service.configure(),
+				flow_control: self.flow_control.configure()?,
+				our_valid_unicast_ethernet_address,
+				source_ethernet_address_blacklist_or_whitelist: self.source_ethernet_address_blacklist_or_whitelist,
+				port_based_network_access_control: self.port_based_network_access_control_configuration.map(|configuration| configuration.configure()),
+				address_resolution_protocol_packet_processing: self.address_resolution_protocol_packet_processing_configuration.configure(dropped_packet_reporting),
+				internet_protocol_version_4_packet_processing: self.internet_protocol_version_4_packet_processing_configuration.configure(dropped_packet_reporting),
+				internet_protocol_version_6_packet_processing: self.internet_protocol_version_6_packet_processing_configuration.configure(dropped_packet_reporting),
+				multiprotocol_label_switching_packet_processing: self.multiprotocol_label_switching_packet_processing,
+				point_to_point_protocol_over_ethernet_packet_processing: self.point_to_point_protocol_over_ethernet_packet_processing_configuration.map(|configuration| configuration.configure()).map_or(Ok(None), |result| result.map(Some))?,
+				precision_time_protocol_packet_processing: self.precision_time_protocol_packet_processing_configuration.map(|configuration| configuration.configure(&our_valid_unicast_ethernet_address)),
+			}
+		)
 	}
 	
 	#[inline(always)]
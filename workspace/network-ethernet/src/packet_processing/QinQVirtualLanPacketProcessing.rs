@@ -38,4 +38,16 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason,
 	{
 		self.inner_packet_processing.drop_packets_of_class_of_service(inner_class_of_service)
 	}
+	
+	#[inline(always)]
+	pub(crate) fn police_outer_class_of_service(&self, now: MonotonicMillisecondTimestamp, outer_class_of_service: ClassOfService, packet_length: u16) -> Option<(PolicingColour, PolicingAction)>
+	{
+		self.outer_packet_processing.police_class_of_service(now, outer_class_of_service, packet_length)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn police_inner_class_of_service(&self, now: MonotonicMillisecondTimestamp, inner_class_of_service: ClassOfService, packet_length: u16) -> Option<(PolicingColour, PolicingAction)>
+	{
+		self.inner_packet_processing.police_class_of_service(now, inner_class_of_service, packet_length)
+	}
 }
@@ -19,13 +19,18 @@ impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfi
 	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
 {
 	/// Configure.
+	///
+	/// Fails if a statically configured Point-to-Point Protocol over Ethernet (PPPoE) session can not be established.
 	#[inline(always)]
-	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_unicast_ethernet_address: MediaAccessControlAddress) -> QinQVirtualLanPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_unicast_ethernet_address: MediaAccessControlAddress) -> Result<QinQVirtualLanPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
 	{
-		QinQVirtualLanPacketProcessing
-		{
-			outer_packet_processing: self.outer_packet_processing.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address),
-			inner_packet_processing: self.inner_packet_processing.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address),
-		}
+		Ok
+		(
+			QinQVirtualLanPacketProcessing
+			{
+				outer_packet_processing: self.outer_packet_processing.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?,
+				inner_packet_processing: self.inner_packet_processing.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?,
+			}
+		)
 	}
 }
@@ -6,14 +6,51 @@
 #[derive(Debug)]
 pub struct VirtualLanPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>
 {
-	/// Outer QinQ Virtual LAN.
-	pub outer: HashMap<(Option<VirtualLanIdentifier>, Option<VirtualLanIdentifier>), QinQVirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+	/// Outer QinQ Virtual LAN, looked up by (outer, inner) Virtual LAN identifiers.
+	pub outer: VirtualLanIdentifierPairTable<QinQVirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
 	
 	/// Inner 802.1Q Virtual LAN.
-	pub inner: HashMap<VirtualLanIdentifier, EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+	pub inner: VirtualLanIdentifierTable<EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
 	
 	/// No virtual LANs.
 	pub none: EthernetPacketProcessing<EINPDO, ARP, IPV4, IPV6>,
+	
+	/// Virtual LAN tag rewrites for outer QinQ Virtual LANs, keyed by (inner, outer) Virtual LAN identifiers.
+	pub outer_rewrites: HashMap<(Option<VirtualLanIdentifier>, Option<VirtualLanIdentifier>), VirtualLanTagRewrite>,
+	
+	/// Virtual LAN tag rewrites for inner 802.1Q Virtual LANs.
+	pub inner_rewrites: HashMap<VirtualLanIdentifier, VirtualLanTagRewrite>,
+	
+	/// Virtual LAN tag rewrite for packets without virtual LANs.
+	pub none_rewrite: Option<VirtualLanTagRewrite>,
+	
+	/// Link aggregation group membership, if this network interface is a member port of a link aggregation group.
+	pub link_aggregation_member: Option<LinkAggregationMember>,
+	
+	/// MACsec (IEEE 802.1AE) receive processing, if any.
+	///
+	/// Only untagged MACsec frames are processed; the decrypted inner frame, which may be Virtual LAN tagged, is then processed as if it had been received.
+	pub media_access_control_security: Option<MediaAccessControlSecurity>,
+	
+	/// Virtual eXtensible Local Area Network (VXLAN) tunnel termination, if any.
+	///
+	/// Only used by `EthernetPacket::process_terminating_tunnels()`.
+	pub virtual_extensible_local_area_network: Option<VirtualExtensibleLocalAreaNetworkPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+	
+	/// Generic Routing Encapsulation (GRE) tunnel termination, if any.
+	///
+	/// Only used by `EthernetPacket::process_terminating_tunnels()`.
+	pub generic_routing_encapsulation: Option<GenericRoutingEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+	
+	/// Network Virtualization using Generic Routing Encapsulation (NVGRE) tunnel termination, if any.
+	///
+	/// Only used by `EthernetPacket::process_terminating_tunnels()`.
+	pub network_virtualization_using_generic_routing_encapsulation: Option<NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+	
+	/// Generic Network Virtualization Encapsulation (Geneve) tunnel termination, if any.
+	///
+	/// Only used by `EthernetPacket::process_terminating_tunnels()`.
+	pub generic_network_virtualization_encapsulation: Option<GenericNetworkVirtualizationEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
 }
 
 impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>
@@ -24,10 +61,91 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason,
 		self.none.dropped_packet(reason)
 	}
 	
+	/// The tunnel, if any, configured for this packet's hardware offload categorisation.
+	///
+	/// Many poll mode drivers categorise VXLAN, NVGRE and GRE packets only as `TeredoOrGenericRoutingEncapsulationOrVirtualExtensibleLocalAreaNetwork` (DPDK's `RTE_PTYPE_TUNNEL_GRENAT`); these are classified by their outer User Datagram Protocol (UDP) destination port or Generic Routing Encapsulation (GRE) header.
+	#[inline(always)]
+	pub(crate) fn configured_tunnel(&self, packet: impl EthernetIncomingNetworkPacket) -> Option<ConfiguredTunnel<EINPDO, ARP, IPV4, IPV6>>
+	{
+		use self::HardwareOffloadTunnelPacketType::*;
+		
+		match packet.hardware_offload_tunnel_packet_type()
+		{
+			VirtualExtensibleLocalAreaNetwork => self.virtual_extensible_local_area_network.as_ref().map(ConfiguredTunnel::VirtualExtensibleLocalAreaNetwork),
+			
+			GenericRoutingEncapsulation => self.generic_routing_encapsulation.as_ref().map(ConfiguredTunnel::GenericRoutingEncapsulation),
+			
+			NetworkVirtualizationUsingGenericRoutingEncapsulation => self.network_virtualization_using_generic_routing_encapsulation.as_ref().map(ConfiguredTunnel::NetworkVirtualizationUsingGenericRoutingEncapsulation),
+			
+			GenericNetworkVirtualizationEncapsulation => self.generic_network_virtualization_encapsulation.as_ref().map(ConfiguredTunnel::GenericNetworkVirtualizationEncapsulation),
+			
+			TeredoOrGenericRoutingEncapsulationOrVirtualExtensibleLocalAreaNetwork => self.classify_configured_tunnel(packet),
+			
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn classify_configured_tunnel(&self, packet: impl EthernetIncomingNetworkPacket) -> Option<ConfiguredTunnel<EINPDO, ARP, IPV4, IPV6>>
+	{
+		let (layer_4_offset, layer_4_length, layer_4_protocol) = TunnelOuterHeaders::peek_layer_4(packet)?;
+		
+		match layer_4_protocol
+		{
+			TunnelOuterHeaders::UserDatagramProtocol =>
+			{
+				let destination_port = TunnelOuterHeaders::peek_user_datagram_protocol_destination_port(packet, layer_4_offset, layer_4_length)?;
+				
+				if let Some(ref virtual_extensible_local_area_network) = self.virtual_extensible_local_area_network
+				{
+					if virtual_extensible_local_area_network.user_datagram_protocol_destination_port == destination_port
+					{
+						return Some(ConfiguredTunnel::VirtualExtensibleLocalAreaNetwork(virtual_extensible_local_area_network))
+					}
+				}
+				
+				if let Some(ref generic_network_virtualization_encapsulation) = self.generic_network_virtualization_encapsulation
+				{
+					if generic_network_virtualization_encapsulation.user_datagram_protocol_destination_port == destination_port
+					{
+						return Some(ConfiguredTunnel::GenericNetworkVirtualizationEncapsulation(generic_network_virtualization_encapsulation))
+					}
+				}
+				
+				None
+			}
+			
+			TunnelOuterHeaders::GenericRoutingEncapsulation =>
+			{
+				if GenericRoutingEncapsulationHeader::peek_is_keyed_transparent_ethernet_bridging(packet, layer_4_offset, layer_4_length)
+				{
+					if let Some(ref network_virtualization_using_generic_routing_encapsulation) = self.network_virtualization_using_generic_routing_encapsulation
+					{
+						return Some(ConfiguredTunnel::NetworkVirtualizationUsingGenericRoutingEncapsulation(network_virtualization_using_generic_routing_encapsulation))
+					}
+				}
+				
+				self.generic_routing_encapsulation.as_ref().map(ConfiguredTunnel::GenericRoutingEncapsulation)
+			}
+			
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn is_link_aggregation_member_not_collecting(&self) -> bool
+	{
+		match self.link_aggregation_member
+		{
+			None => false,
+			Some(ref link_aggregation_member) => link_aggregation_member.is_not_collecting(),
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn get_packet_processing_for_outer_virtual_lan(&self, outer_virtual_lan_identifier: Option<VirtualLanIdentifier>, inner_virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Option<&QinQVirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>>
 	{
-		self.outer.get(&(inner_virtual_lan_identifier, outer_virtual_lan_identifier))
+		self.outer.get(outer_virtual_lan_identifier, inner_virtual_lan_identifier)
 	}
 	
 	#[inline(always)]
@@ -36,7 +154,23 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason,
 		match inner_virtual_lan_identifier
 		{
 			None => Some(&self.none),
-			Some(ref virtual_lan_identifier) => self.inner.get(virtual_lan_identifier),
+			Some(_) => self.inner.get(inner_virtual_lan_identifier),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn get_virtual_lan_tag_rewrite_for_outer_virtual_lan(&self, outer_virtual_lan_identifier: Option<VirtualLanIdentifier>, inner_virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Option<&VirtualLanTagRewrite>
+	{
+		self.outer_rewrites.get(&(inner_virtual_lan_identifier, outer_virtual_lan_identifier))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn get_virtual_lan_tag_rewrite_for_inner_virtual_lan(&self, inner_virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Option<&VirtualLanTagRewrite>
+	{
+		match inner_virtual_lan_identifier
+		{
+			None => self.none_rewrite.as_ref(),
+			Some(ref virtual_lan_identifier) => self.inner_rewrites.get(virtual_lan_identifier),
 		}
 	}
 }
@@ -8,30 +8,122 @@
 pub struct VirtualLanPacketProcessingConfiguration<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration>
 	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
 {
-	/// Outer QinQ Virtual LAN.
+	/// Outer QinQ Virtual LAN, keyed by (inner, outer) Virtual LAN identifiers.
 	pub outer: HashMap<(Option<VirtualLanIdentifier>, Option<VirtualLanIdentifier>), QinQVirtualLanPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
 	
+	/// Outer QinQ Virtual LANs matched by ranges or wildcards of (inner, outer) Virtual LAN identifiers, in the same order as the keys of `outer`.
+	///
+	/// All the Virtual LANs matched by an entry share one instance of packet processing.
+	/// Entries in `outer` take precedence; thereafter, the first matching entry is used.
+	#[serde(default)]
+	pub outer_matches: Vec<((VirtualLanIdentifierMatch, VirtualLanIdentifierMatch), QinQVirtualLanPacketProcessingConfiguration<ARP, IPV4, IPV6>)>,
+	
 	/// Inner 802.1Q Virtual LAN.
 	pub inner: HashMap<VirtualLanIdentifier, EthernetPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
 	
+	/// Inner 802.1Q Virtual LANs matched by ranges or wildcards of Virtual LAN identifiers.
+	///
+	/// All the Virtual LANs matched by an entry share one instance of packet processing.
+	/// Entries in `inner` take precedence; thereafter, the first matching entry is used.
+	/// Priority tagged packets are always processed by `none`.
+	#[serde(default)]
+	pub inner_matches: Vec<(VirtualLanIdentifierMatch, EthernetPacketProcessingConfiguration<ARP, IPV4, IPV6>)>,
+	
 	/// No virtual LANs.
 	pub none: EthernetPacketProcessingConfiguration<ARP, IPV4, IPV6>,
+	
+	/// Virtual LAN tag rewrites for outer QinQ Virtual LANs, keyed by (inner, outer) Virtual LAN identifiers, in the same order as the keys of `outer`.
+	#[serde(default)]
+	pub outer_rewrites: HashMap<(Option<VirtualLanIdentifier>, Option<VirtualLanIdentifier>), VirtualLanTagRewrite>,
+	
+	/// Virtual LAN tag rewrites for inner 802.1Q Virtual LANs.
+	#[serde(default)]
+	pub inner_rewrites: HashMap<VirtualLanIdentifier, VirtualLanTagRewrite>,
+	
+	/// Virtual LAN tag rewrite for packets without virtual LANs.
+	#[serde(default)]
+	pub none_rewrite: Option<VirtualLanTagRewrite>,
+	
+	/// MACsec (IEEE 802.1AE) receive processing.
+	#[serde(default)]
+	pub media_access_control_security: Option<MediaAccessControlSecurityConfiguration>,
+	
+	/// Virtual eXtensible Local Area Network (VXLAN) tunnel termination.
+	#[serde(default)]
+	pub virtual_extensible_local_area_network: Option<VirtualExtensibleLocalAreaNetworkPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
+	
+	/// Generic Routing Encapsulation (GRE) tunnel termination.
+	#[serde(default)]
+	pub generic_routing_encapsulation: Option<GenericRoutingEncapsulationPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
+	
+	/// Network Virtualization using Generic Routing Encapsulation (NVGRE) tunnel termination.
+	#[serde(default)]
+	pub network_virtualization_using_generic_routing_encapsulation: Option<NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
+	
+	/// Generic Network Virtualization Encapsulation (Geneve) tunnel termination.
+	#[serde(default)]
+	pub generic_network_virtualization_encapsulation: Option<GenericNetworkVirtualizationEncapsulationPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
 }
 
 impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration> VirtualLanPacketProcessingConfiguration<ARP, IPV4, IPV6>
 	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
 {
 	/// Configure.
+	///
+	/// `link_aggregation_member` should be provided if this network interface is a member port of a link aggregation group.
+	///
+	/// Fails if a Virtual LAN identifier range is inverted or if a statically configured Point-to-Point Protocol over Ethernet (PPPoE) session can not be established.
 	#[inline(always)]
-	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(mut self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_unicast_ethernet_address: MediaAccessControlAddress) -> VirtualLanPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(mut self, dropped_packet_reporting: &Rc<EINPDO>, our_valid_unicast_ethernet_address: MediaAccessControlAddress, link_aggregation_member: Option<LinkAggregationMember>) -> Result<VirtualLanPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
 	{
-		VirtualLanPacketProcessing
+		let mut outer = VirtualLanIdentifierPairTable::default();
+		for ((inner_virtual_lan_identifier, outer_virtual_lan_identifier), value) in self.outer.drain()
+		{
+			outer.insert(&VirtualLanIdentifierMatch::from(outer_virtual_lan_identifier), &VirtualLanIdentifierMatch::from(inner_virtual_lan_identifier), value.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?)?
+		}
+		for ((inner_virtual_lan_identifier_match, outer_virtual_lan_identifier_match), value) in self.outer_matches.drain(..)
+		{
+			outer.insert(&outer_virtual_lan_identifier_match, &inner_virtual_lan_identifier_match, value.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?)?
+		}
+		
+		let mut inner = VirtualLanIdentifierTable::default();
+		for (virtual_lan_identifier, value) in self.inner.drain()
+		{
+			inner.insert(&VirtualLanIdentifierMatch::Exactly(virtual_lan_identifier), value.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?)?
+		}
+		for (virtual_lan_identifier_match, value) in self.inner_matches.drain(..)
 		{
-			outer: self.outer.drain().map(|(key, value)| (key, value.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address))).collect(),
-			
-			inner: self.inner.drain().map(|(key, value)| (key, value.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address))).collect(),
-			
-			none: self.none.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address),
+			inner.insert(&virtual_lan_identifier_match, value.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?)?
 		}
+		
+		Ok
+		(
+			VirtualLanPacketProcessing
+			{
+				outer,
+				
+				inner,
+				
+				none: self.none.configure(dropped_packet_reporting, our_valid_unicast_ethernet_address)?,
+				
+				outer_rewrites: self.outer_rewrites,
+				
+				inner_rewrites: self.inner_rewrites,
+				
+				none_rewrite: self.none_rewrite,
+				
+				link_aggregation_member,
+				
+				media_access_control_security: self.media_access_control_security.map(|media_access_control_security| media_access_control_security.configure()).map_or(Ok(None), |result| result.map(Some))?,
+				
+				virtual_extensible_local_area_network: self.virtual_extensible_local_area_network.map(|virtual_extensible_local_area_network| virtual_extensible_local_area_network.configure(dropped_packet_reporting)).map_or(Ok(None), |result| result.map(Some))?,
+				
+				generic_routing_encapsulation: self.generic_routing_encapsulation.map(|generic_routing_encapsulation| generic_routing_encapsulation.configure(dropped_packet_reporting)).map_or(Ok(None), |result| result.map(Some))?,
+				
+				network_virtualization_using_generic_routing_encapsulation: self.network_virtualization_using_generic_routing_encapsulation.map(|network_virtualization_using_generic_routing_encapsulation| network_virtualization_using_generic_routing_encapsulation.configure(dropped_packet_reporting)).map_or(Ok(None), |result| result.map(Some))?,
+				
+				generic_network_virtualization_encapsulation: self.generic_network_virtualization_encapsulation.map(|generic_network_virtualization_encapsulation| generic_network_virtualization_encapsulation.configure(dropped_packet_reporting)).map_or(Ok(None), |result| result.map(Some))?,
+			}
+		)
 	}
 }
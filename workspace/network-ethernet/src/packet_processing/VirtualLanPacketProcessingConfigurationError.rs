@@ -0,0 +1,60 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Cause of failure to configure Virtual LAN packet processing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VirtualLanPacketProcessingConfigurationError
+{
+	/// A Virtual LAN identifier range's first Virtual LAN identifier is after its last.
+	VirtualLanIdentifierRangeIsInverted(VirtualLanIdentifierMatch),
+	
+	/// Flow control could not be configured.
+	FlowControl(FlowControlConfigurationError),
+	
+	/// A statically configured Point-to-Point Protocol over Ethernet (PPPoE) session could not be established.
+	PointToPointProtocolOverEthernetSession(PointToPointProtocolOverEthernetSessionError),
+	
+	/// MACsec could not be configured.
+	MediaAccessControlSecurity(MediaAccessControlSecurityConfigurationError),
+}
+
+impl Display for VirtualLanPacketProcessingConfigurationError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl ::std::error::Error for VirtualLanPacketProcessingConfigurationError
+{
+}
+
+impl From<FlowControlConfigurationError> for VirtualLanPacketProcessingConfigurationError
+{
+	#[inline(always)]
+	fn from(error: FlowControlConfigurationError) -> Self
+	{
+		VirtualLanPacketProcessingConfigurationError::FlowControl(error)
+	}
+}
+
+impl From<PointToPointProtocolOverEthernetSessionError> for VirtualLanPacketProcessingConfigurationError
+{
+	#[inline(always)]
+	fn from(error: PointToPointProtocolOverEthernetSessionError) -> Self
+	{
+		VirtualLanPacketProcessingConfigurationError::PointToPointProtocolOverEthernetSession(error)
+	}
+}
+
+impl From<MediaAccessControlSecurityConfigurationError> for VirtualLanPacketProcessingConfigurationError
+{
+	#[inline(always)]
+	fn from(error: MediaAccessControlSecurityConfigurationError) -> Self
+	{
+		VirtualLanPacketProcessingConfigurationError::MediaAccessControlSecurity(error)
+	}
+}
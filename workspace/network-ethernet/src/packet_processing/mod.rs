@@ -16,3 +16,4 @@ include!("QinQVirtualLanPacketProcessing.rs");
 include!("QinQVirtualLanPacketProcessingConfiguration.rs");
 include!("VirtualLanPacketProcessing.rs");
 include!("VirtualLanPacketProcessingConfiguration.rs");
+include!("VirtualLanPacketProcessingConfigurationError.rs");
@@ -0,0 +1,49 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Point-to-Point Protocol over Ethernet (PPPoE) code (RFC 2516).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+#[repr(u8)]
+pub enum PointToPointProtocolOverEthernetCode
+{
+	/// Session data; only valid with the session ether type.
+	SessionData = 0x00,
+	
+	/// PPPoE Active Discovery Offer (PADO).
+	ActiveDiscoveryOffer = 0x07,
+	
+	/// PPPoE Active Discovery Initiation (PADI).
+	ActiveDiscoveryInitiation = 0x09,
+	
+	/// PPPoE Active Discovery Request (PADR).
+	ActiveDiscoveryRequest = 0x19,
+	
+	/// PPPoE Active Discovery Session-confirmation (PADS).
+	ActiveDiscoverySessionConfirmation = 0x65,
+	
+	/// PPPoE Active Discovery Terminate (PADT).
+	ActiveDiscoveryTerminate = 0xA7,
+}
+
+impl PointToPointProtocolOverEthernetCode
+{
+	/// Parse.
+	#[inline(always)]
+	pub fn parse(code: u8) -> Option<Self>
+	{
+		use self::PointToPointProtocolOverEthernetCode::*;
+		
+		match code
+		{
+			0x00 => Some(SessionData),
+			0x07 => Some(ActiveDiscoveryOffer),
+			0x09 => Some(ActiveDiscoveryInitiation),
+			0x19 => Some(ActiveDiscoveryRequest),
+			0x65 => Some(ActiveDiscoverySessionConfirmation),
+			0xA7 => Some(ActiveDiscoveryTerminate),
+			_ => None,
+		}
+	}
+}
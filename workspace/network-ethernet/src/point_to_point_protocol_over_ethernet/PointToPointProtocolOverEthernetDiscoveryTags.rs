@@ -0,0 +1,120 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Point-to-Point Protocol over Ethernet (PPPoE) discovery tags (RFC 2516, Appendix A, and RFC 4638).
+///
+/// Only the last instance of a repeated tag is retained; vendor specific tags are ignored.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PointToPointProtocolOverEthernetDiscoveryTags<'a>
+{
+	/// Service-Name.
+	pub service_name: Option<&'a [u8]>,
+	
+	/// AC-Name.
+	pub access_concentrator_name: Option<&'a [u8]>,
+	
+	/// Host-Uniq.
+	pub host_unique: Option<&'a [u8]>,
+	
+	/// AC-Cookie.
+	pub access_concentrator_cookie: Option<&'a [u8]>,
+	
+	/// Relay-Session-Id.
+	pub relay_session_identifier: Option<&'a [u8]>,
+	
+	/// PPP-Max-Payload (RFC 4638).
+	pub point_to_point_protocol_maximum_payload: Option<u16>,
+	
+	/// Service-Name-Error, AC-System-Error or Generic-Error tag type, if any was present.
+	pub error_tag_type: Option<u16>,
+}
+
+impl<'a> PointToPointProtocolOverEthernetDiscoveryTags<'a>
+{
+	const TagHeaderSize: usize = 4;
+	
+	const EndOfList: u16 = 0x0000;
+	
+	const ServiceName: u16 = 0x0101;
+	
+	const AccessConcentratorName: u16 = 0x0102;
+	
+	const HostUnique: u16 = 0x0103;
+	
+	const AccessConcentratorCookie: u16 = 0x0104;
+	
+	const RelaySessionIdentifier: u16 = 0x0110;
+	
+	const PointToPointProtocolMaximumPayload: u16 = 0x0120;
+	
+	const ServiceNameError: u16 = 0x0201;
+	
+	const AccessConcentratorSystemError: u16 = 0x0202;
+	
+	const GenericError: u16 = 0x0203;
+	
+	/// Parses the tags in `payload`, which must exactly contain them (or be terminated by an End-Of-List tag).
+	#[inline(always)]
+	pub fn parse(payload: &'a [u8]) -> Result<Self, ()>
+	{
+		#[inline(always)]
+		fn network_endian_u16(bytes: &[u8], index: usize) -> u16
+		{
+			((bytes[index] as u16) << 8) | (bytes[index + 1] as u16)
+		}
+		
+		let mut tags = Self::default();
+		
+		let mut remaining = payload;
+		while !remaining.is_empty()
+		{
+			if unlikely!(remaining.len() < Self::TagHeaderSize)
+			{
+				return Err(())
+			}
+			
+			let tag_type = network_endian_u16(remaining, 0);
+			let tag_length = network_endian_u16(remaining, 2) as usize;
+			
+			let value_end = Self::TagHeaderSize + tag_length;
+			if unlikely!(remaining.len() < value_end)
+			{
+				return Err(())
+			}
+			let value = &remaining[Self::TagHeaderSize .. value_end];
+			
+			match tag_type
+			{
+				Self::EndOfList => break,
+				
+				Self::ServiceName => tags.service_name = Some(value),
+				
+				Self::AccessConcentratorName => tags.access_concentrator_name = Some(value),
+				
+				Self::HostUnique => tags.host_unique = Some(value),
+				
+				Self::AccessConcentratorCookie => tags.access_concentrator_cookie = Some(value),
+				
+				Self::RelaySessionIdentifier => tags.relay_session_identifier = Some(value),
+				
+				Self::PointToPointProtocolMaximumPayload =>
+				{
+					if unlikely!(tag_length != 2)
+					{
+						return Err(())
+					}
+					tags.point_to_point_protocol_maximum_payload = Some(network_endian_u16(value, 0))
+				}
+				
+				Self::ServiceNameError | Self::AccessConcentratorSystemError | Self::GenericError => tags.error_tag_type = Some(tag_type),
+				
+				_ => (),
+			}
+			
+			remaining = &remaining[value_end .. ];
+		}
+		
+		Ok(tags)
+	}
+}
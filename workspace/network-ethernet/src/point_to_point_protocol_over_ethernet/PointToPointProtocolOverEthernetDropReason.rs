@@ -0,0 +1,99 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Point-to-Point Protocol over Ethernet (PPPoE) packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PointToPointProtocolOverEthernetDropReason
+{
+	/// The packet is too short for its header or for the length in its header.
+	PacketIsTooShort,
+	
+	/// The version or type is not 1.
+	VersionOrTypeIsNotOne
+	{
+		/// Version and type.
+		version_and_type: u8,
+	},
+	
+	/// The code is not known or not valid for the ether type (discovery or session).
+	UnsupportedCode
+	{
+		/// Code.
+		code: u8,
+	},
+	
+	/// The session identifier is zero when it should not be, or non-zero when it should be zero.
+	DiscoverySessionIdentifierIsInvalid
+	{
+		/// Code.
+		code: PointToPointProtocolOverEthernetCode,
+		
+		/// Session identifier.
+		session_identifier: u16,
+	},
+	
+	/// The discovery tags are malformed.
+	DiscoveryTagsAreInvalid
+	{
+		/// Code.
+		code: PointToPointProtocolOverEthernetCode,
+	},
+	
+	/// The discovery packet contained a Service-Name-Error, AC-System-Error or Generic-Error tag.
+	DiscoveryError
+	{
+		/// Code.
+		code: PointToPointProtocolOverEthernetCode,
+		
+		/// Error tag type.
+		error_tag_type: u16,
+	},
+	
+	/// The PPPoE Active Discovery Session-confirmation (PADS) does not match an outstanding PPPoE Active Discovery Request (PADR) for its source ethernet address and Service-Name.
+	SessionConfirmationWasNotRequested
+	{
+		/// Session identifier.
+		session_identifier: u16,
+	},
+	
+	/// The session confirmed by a PPPoE Active Discovery Session-confirmation (PADS) could not be established.
+	SessionCouldNotBeEstablished
+	{
+		/// Session identifier.
+		session_identifier: u16,
+		
+		/// Why.
+		error: PointToPointProtocolOverEthernetSessionError,
+	},
+	
+	/// There is no established session for this session identifier and source ethernet address.
+	SessionIsNotEstablished
+	{
+		/// Session identifier.
+		session_identifier: u16,
+	},
+	
+	/// The Point-to-Point Protocol (PPP) protocol is not internet protocol (IP) version 4 or version 6.
+	///
+	/// Link control and network control protocols are not supported.
+	UnsupportedPointToPointProtocolProtocol
+	{
+		/// Session identifier.
+		session_identifier: u16,
+		
+		/// Point-to-Point Protocol (PPP) protocol.
+		protocol: u16,
+	},
+	
+	/// The Point-to-Point Protocol (PPP) payload exceeds the session's maximum receive unit.
+	PayloadExceedsMaximumReceiveUnit
+	{
+		/// Session identifier.
+		session_identifier: u16,
+		
+		/// Point-to-Point Protocol (PPP) payload length.
+		length: u16,
+	},
+}
@@ -0,0 +1,61 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Point-to-Point Protocol over Ethernet (PPPoE) header (RFC 2516, Section 4).
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct PointToPointProtocolOverEthernetHeader
+{
+	/// 4-bit version, which must be 1, and 4-bit type, which must be 1.
+	pub version_and_type: u8,
+	
+	/// Code.
+	pub code: u8,
+	
+	/// Session identifier; zero during discovery until a session is confirmed.
+	pub session_identifier: NetworkEndianU16,
+	
+	/// Length of the payload, excluding this header and any ethernet padding.
+	pub length: NetworkEndianU16,
+}
+
+impl PointToPointProtocolOverEthernetHeader
+{
+	/// Size of header.
+	pub const Size: usize = 6;
+	
+	/// Size of header.
+	pub const SizeU16: u16 = Self::Size as u16;
+	
+	/// Size of the Point-to-Point Protocol (PPP) protocol field which starts the payload of session packets.
+	pub const PointToPointProtocolProtocolSizeU16: u16 = 2;
+	
+	/// Overhead of a session packet; this header and the Point-to-Point Protocol (PPP) protocol field.
+	///
+	/// This is the amount by which Maximum Transmission Unit (MTU) sizes are reduced, eg from 1500 to 1492.
+	pub const SessionOverheadSizeU16: u16 = Self::SizeU16 + Self::PointToPointProtocolProtocolSizeU16;
+	
+	/// Version 1 and type 1.
+	pub const VersionAndType: u8 = 0x11;
+	
+	/// Point-to-Point Protocol (PPP) protocol for internet protocol (IP) version 4.
+	pub const InternetProtocolVersion4PointToPointProtocolProtocol: u16 = 0x0021;
+	
+	/// Point-to-Point Protocol (PPP) protocol for internet protocol (IP) version 6.
+	pub const InternetProtocolVersion6PointToPointProtocolProtocol: u16 = 0x0057;
+	
+	/// Session identifier.
+	#[inline(always)]
+	pub fn session_identifier(&self) -> u16
+	{
+		self.session_identifier.to_native_endian()
+	}
+	
+	/// Length.
+	#[inline(always)]
+	pub fn length(&self) -> u16
+	{
+		self.length.to_native_endian()
+	}
+}
@@ -0,0 +1,262 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Point-to-Point Protocol over Ethernet (PPPoE) packet processing (RFC 2516).
+///
+/// Discovery packets are validated, including their tags; a PPPoE Active Discovery Session-confirmation (PADS) establishes a session with its source if it matches an outstanding PPPoE Active Discovery Request (PADR) (see `record_active_discovery_request()`), and a PPPoE Active Discovery Terminate (PADT) tears a session down. Other discovery packets are not answered.
+///
+/// Session packets are accepted only for established sessions, identified by session identifier and peer ethernet address; Point-to-Point Protocol (PPP) frames carrying internet protocol (IP) version 4 or version 6 are decapsulated.
+#[derive(Debug)]
+pub struct PointToPointProtocolOverEthernetPacketProcessing
+{
+	maximum_receive_unit: MaximumTransmissionUnitSize,
+	
+	maximum_sessions: usize,
+	
+	sessions: RefCell<HashMap<(u16, MediaAccessControlAddress), PointToPointProtocolOverEthernetSession>>,
+	
+	outstanding_active_discovery_requests: RefCell<HashSet<(MediaAccessControlAddress, Box<[u8]>)>>,
+}
+
+impl PointToPointProtocolOverEthernetPacketProcessing
+{
+	/// Creates a new instance.
+	///
+	/// `maximum_receive_unit` is used for sessions established without a PPP-Max-Payload tag (RFC 4638).
+	///
+	/// `maximum_sessions` limits the number of sessions which can be established at once.
+	#[inline(always)]
+	pub fn new(maximum_receive_unit: MaximumTransmissionUnitSize, maximum_sessions: usize) -> Self
+	{
+		Self
+		{
+			maximum_receive_unit,
+			maximum_sessions,
+			sessions: RefCell::new(HashMap::default()),
+			outstanding_active_discovery_requests: RefCell::new(HashSet::default()),
+		}
+	}
+	
+	/// Records a PPPoE Active Discovery Request (PADR) sent to `peer` for `service_name`, so that the PPPoE Active Discovery Session-confirmation (PADS) in reply establishes a session.
+	///
+	/// A PADS which does not match an outstanding PADR is dropped.
+	#[inline(always)]
+	pub fn record_active_discovery_request(&self, peer: MediaAccessControlAddress, service_name: &[u8])
+	{
+		self.outstanding_active_discovery_requests.borrow_mut().insert((peer, service_name.to_vec().into_boxed_slice()));
+	}
+	
+	/// Forgets a PPPoE Active Discovery Request (PADR) sent to `peer` for `service_name`, eg because it timed out; returns whether it was outstanding.
+	#[inline(always)]
+	pub fn forget_active_discovery_request(&self, peer: MediaAccessControlAddress, service_name: &[u8]) -> bool
+	{
+		self.outstanding_active_discovery_requests.borrow_mut().remove(&(peer, service_name.to_vec().into_boxed_slice()))
+	}
+	
+	/// Established session, if any.
+	#[inline(always)]
+	pub fn session(&self, session_identifier: u16, peer: &MediaAccessControlAddress) -> Option<PointToPointProtocolOverEthernetSession>
+	{
+		self.sessions.borrow().get(&(session_identifier, *peer)).cloned()
+	}
+	
+	/// Establishes (or replaces) a session.
+	///
+	/// Fails if `session_identifier` is zero or if the maximum number of sessions are already established; replacing a session always succeeds.
+	#[inline(always)]
+	pub fn establish_session(&self, session_identifier: u16, peer: MediaAccessControlAddress, session: PointToPointProtocolOverEthernetSession) -> Result<(), PointToPointProtocolOverEthernetSessionError>
+	{
+		use self::PointToPointProtocolOverEthernetSessionError::*;
+		
+		if unlikely!(session_identifier == 0)
+		{
+			return Err(SessionIdentifierIsZero)
+		}
+		
+		let mut sessions = self.sessions.borrow_mut();
+		let key = (session_identifier, peer);
+		if unlikely!(sessions.len() >= self.maximum_sessions && !sessions.contains_key(&key))
+		{
+			return Err(TooManySessions)
+		}
+		
+		sessions.insert(key, session);
+		Ok(())
+	}
+	
+	/// Tears down a session, returning it if it was established.
+	#[inline(always)]
+	pub fn terminate_session(&self, session_identifier: u16, peer: &MediaAccessControlAddress) -> Option<PointToPointProtocolOverEthernetSession>
+	{
+		self.sessions.borrow_mut().remove(&(session_identifier, *peer))
+	}
+	
+	/// Processes a discovery packet (ether type `0x8863`).
+	#[inline(always)]
+	pub(crate) fn process_discovery(&self, source_ethernet_address: &MediaAccessControlAddress, layer_3_packet: &Layer3Packet, layer_3_length: u16) -> Result<(), PointToPointProtocolOverEthernetDropReason>
+	{
+		use self::PointToPointProtocolOverEthernetCode::*;
+		use self::PointToPointProtocolOverEthernetDropReason::*;
+		
+		let (header, payload) = Self::header_and_payload(layer_3_packet, layer_3_length)?;
+		
+		let code = match PointToPointProtocolOverEthernetCode::parse(header.code)
+		{
+			None | Some(SessionData) => return Err(UnsupportedCode { code: header.code }),
+			
+			Some(code) => code,
+		};
+		
+		let session_identifier = header.session_identifier();
+		
+		let tags = match PointToPointProtocolOverEthernetDiscoveryTags::parse(payload)
+		{
+			Err(()) => return Err(DiscoveryTagsAreInvalid { code }),
+			
+			Ok(tags) => tags,
+		};
+		
+		match code
+		{
+			ActiveDiscoveryInitiation | ActiveDiscoveryOffer | ActiveDiscoveryRequest =>
+			{
+				if unlikely!(session_identifier != 0)
+				{
+					return Err(DiscoverySessionIdentifierIsInvalid { code, session_identifier })
+				}
+				
+				if let Some(error_tag_type) = tags.error_tag_type
+				{
+					return Err(DiscoveryError { code, error_tag_type })
+				}
+				
+				Ok(())
+			}
+			
+			ActiveDiscoverySessionConfirmation =>
+			{
+				if let Some(error_tag_type) = tags.error_tag_type
+				{
+					return Err(DiscoveryError { code, error_tag_type })
+				}
+				
+				if unlikely!(session_identifier == 0)
+				{
+					return Err(DiscoverySessionIdentifierIsInvalid { code, session_identifier })
+				}
+				
+				// A PADS contains exactly one Service-Name tag (RFC 2516, Section 5.4).
+				let was_requested = match tags.service_name
+				{
+					None => false,
+					
+					Some(service_name) => self.forget_active_discovery_request(*source_ethernet_address, service_name),
+				};
+				if unlikely!(!was_requested)
+				{
+					return Err(SessionConfirmationWasNotRequested { session_identifier })
+				}
+				
+				let maximum_receive_unit = match tags.point_to_point_protocol_maximum_payload
+				{
+					None => self.maximum_receive_unit,
+					
+					Some(point_to_point_protocol_maximum_payload) => MaximumTransmissionUnitSize::try_from(point_to_point_protocol_maximum_payload).unwrap_or(self.maximum_receive_unit),
+				};
+				
+				self.establish_session(session_identifier, *source_ethernet_address, PointToPointProtocolOverEthernetSession { maximum_receive_unit }).map_err(|error| SessionCouldNotBeEstablished { session_identifier, error })
+			}
+			
+			// Error tags are permitted in a PADT.
+			ActiveDiscoveryTerminate =>
+			{
+				if unlikely!(session_identifier == 0)
+				{
+					return Err(DiscoverySessionIdentifierIsInvalid { code, session_identifier })
+				}
+				
+				self.terminate_session(session_identifier, source_ethernet_address);
+				Ok(())
+			}
+			
+			SessionData => unreachable!(),
+		}
+	}
+	
+	/// Processes a session packet (ether type `0x8864`), returning the offset, length and ether type of the Point-to-Point Protocol (PPP) payload.
+	#[inline(always)]
+	pub(crate) fn process_session(&self, source_ethernet_address: &MediaAccessControlAddress, layer_3_packet: &Layer3Packet, layer_3_length: u16) -> Result<(u16, u16, EtherType), PointToPointProtocolOverEthernetDropReason>
+	{
+		use self::PointToPointProtocolOverEthernetDropReason::*;
+		
+		let (header, payload) = Self::header_and_payload(layer_3_packet, layer_3_length)?;
+		
+		if unlikely!(header.code != PointToPointProtocolOverEthernetCode::SessionData as u8)
+		{
+			return Err(UnsupportedCode { code: header.code })
+		}
+		
+		let session_identifier = header.session_identifier();
+		let session = match self.session(session_identifier, source_ethernet_address)
+		{
+			None => return Err(SessionIsNotEstablished { session_identifier }),
+			
+			Some(session) => session,
+		};
+		
+		if unlikely!(payload.len() < PointToPointProtocolOverEthernetHeader::PointToPointProtocolProtocolSizeU16 as usize)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let length = (payload.len() as u16) - PointToPointProtocolOverEthernetHeader::PointToPointProtocolProtocolSizeU16;
+		let maximum_receive_unit: u16 = session.maximum_receive_unit.into();
+		if unlikely!(length > maximum_receive_unit)
+		{
+			return Err(PayloadExceedsMaximumReceiveUnit { session_identifier, length })
+		}
+		
+		let protocol = ((payload[0] as u16) << 8) | (payload[1] as u16);
+		let ether_type = match protocol
+		{
+			PointToPointProtocolOverEthernetHeader::InternetProtocolVersion4PointToPointProtocolProtocol => EtherType::InternetProtocolVersion4,
+			
+			PointToPointProtocolOverEthernetHeader::InternetProtocolVersion6PointToPointProtocolProtocol => EtherType::InternetProtocolVersion6,
+			
+			_ => return Err(UnsupportedPointToPointProtocolProtocol { session_identifier, protocol }),
+		};
+		
+		Ok((PointToPointProtocolOverEthernetHeader::SessionOverheadSizeU16, length, ether_type))
+	}
+	
+	/// The payload excludes any ethernet padding.
+	#[inline(always)]
+	fn header_and_payload(layer_3_packet: &Layer3Packet, layer_3_length: u16) -> Result<(&PointToPointProtocolOverEthernetHeader, &[u8]), PointToPointProtocolOverEthernetDropReason>
+	{
+		use self::PointToPointProtocolOverEthernetDropReason::*;
+		
+		if unlikely!(layer_3_length < PointToPointProtocolOverEthernetHeader::SizeU16)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let header: &PointToPointProtocolOverEthernetHeader = layer_3_packet.as_type();
+		
+		let version_and_type = header.version_and_type;
+		if unlikely!(version_and_type != PointToPointProtocolOverEthernetHeader::VersionAndType)
+		{
+			return Err(VersionOrTypeIsNotOne { version_and_type })
+		}
+		
+		let length = header.length();
+		if unlikely!(length > layer_3_length - PointToPointProtocolOverEthernetHeader::SizeU16)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let payload = unsafe { from_raw_parts((layer_3_packet as *const Layer3Packet as *const u8).add(PointToPointProtocolOverEthernetHeader::Size), length as usize) };
+		
+		Ok((header, payload))
+	}
+}
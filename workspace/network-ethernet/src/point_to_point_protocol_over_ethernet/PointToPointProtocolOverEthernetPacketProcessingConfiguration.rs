@@ -0,0 +1,52 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Point-to-Point Protocol over Ethernet (PPPoE) packet processing configuration.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct PointToPointProtocolOverEthernetPacketProcessingConfiguration
+{
+	/// Maximum receive unit for sessions established without a PPP-Max-Payload tag (RFC 4638).
+	///
+	/// Defaults to 1492, ie an ethernet MTU of 1500 less 8 bytes of PPPoE overhead.
+	#[serde(default = "PointToPointProtocolOverEthernetPacketProcessingConfiguration::maximum_receive_unit_default")] pub maximum_receive_unit: MaximumTransmissionUnitSize,
+	
+	/// Maximum number of sessions which can be established at once, including statically configured sessions.
+	///
+	/// Defaults to 64.
+	#[serde(default = "PointToPointProtocolOverEthernetPacketProcessingConfiguration::maximum_sessions_default")] pub maximum_sessions: usize,
+	
+	/// Statically configured sessions, eg for sessions established before a restart.
+	#[serde(default)] pub sessions: Vec<PointToPointProtocolOverEthernetSessionConfiguration>,
+}
+
+impl PointToPointProtocolOverEthernetPacketProcessingConfiguration
+{
+	/// Configure.
+	///
+	/// Fails if a statically configured session can not be established.
+	#[inline(always)]
+	pub fn configure(self) -> Result<PointToPointProtocolOverEthernetPacketProcessing, PointToPointProtocolOverEthernetSessionError>
+	{
+		let packet_processing = PointToPointProtocolOverEthernetPacketProcessing::new(self.maximum_receive_unit, self.maximum_sessions);
+		for session in self.sessions
+		{
+			let maximum_receive_unit = session.maximum_receive_unit.unwrap_or(self.maximum_receive_unit);
+			packet_processing.establish_session(session.session_identifier, session.peer, PointToPointProtocolOverEthernetSession { maximum_receive_unit })?
+		}
+		Ok(packet_processing)
+	}
+	
+	#[inline(always)]
+	fn maximum_receive_unit_default() -> MaximumTransmissionUnitSize
+	{
+		MaximumTransmissionUnitSize::PPPoEOverEthernetV2
+	}
+	
+	#[inline(always)]
+	fn maximum_sessions_default() -> usize
+	{
+		64
+	}
+}
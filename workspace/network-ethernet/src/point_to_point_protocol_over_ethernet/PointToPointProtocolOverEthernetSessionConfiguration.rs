@@ -0,0 +1,18 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A statically configured Point-to-Point Protocol over Ethernet (PPPoE) session.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct PointToPointProtocolOverEthernetSessionConfiguration
+{
+	/// Session identifier; must not be zero.
+	pub session_identifier: u16,
+	
+	/// Peer's ethernet address.
+	pub peer: MediaAccessControlAddress,
+	
+	/// Maximum receive unit; defaults to that of the packet processing.
+	#[serde(default)] pub maximum_receive_unit: Option<MaximumTransmissionUnitSize>,
+}
@@ -0,0 +1,28 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Point-to-Point Protocol over Ethernet (PPPoE) session could not be established.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PointToPointProtocolOverEthernetSessionError
+{
+	/// The session identifier is zero, which is reserved for discovery (RFC 2516, Section 4).
+	SessionIdentifierIsZero,
+	
+	/// The maximum number of sessions are already established.
+	TooManySessions,
+}
+
+impl Display for PointToPointProtocolOverEthernetSessionError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl ::std::error::Error for PointToPointProtocolOverEthernetSessionError
+{
+}
@@ -0,0 +1,16 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("PointToPointProtocolOverEthernetCode.rs");
+include!("PointToPointProtocolOverEthernetDiscoveryTags.rs");
+include!("PointToPointProtocolOverEthernetDropReason.rs");
+include!("PointToPointProtocolOverEthernetHeader.rs");
+include!("PointToPointProtocolOverEthernetPacketProcessing.rs");
+include!("PointToPointProtocolOverEthernetPacketProcessingConfiguration.rs");
+include!("PointToPointProtocolOverEthernetSession.rs");
+include!("PointToPointProtocolOverEthernetSessionConfiguration.rs");
+include!("PointToPointProtocolOverEthernetSessionError.rs");
@@ -0,0 +1,54 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Extensible Authentication Protocol (EAP) code (RFC 3748, Section 4).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+#[repr(u8)]
+pub enum ExtensibleAuthenticationProtocolCode
+{
+	/// Request.
+	Request = 1,
+	
+	/// Response.
+	Response = 2,
+	
+	/// Success.
+	Success = 3,
+	
+	/// Failure.
+	Failure = 4,
+}
+
+impl ExtensibleAuthenticationProtocolCode
+{
+	/// Parse.
+	#[inline(always)]
+	pub fn parse(code: u8) -> Option<Self>
+	{
+		use self::ExtensibleAuthenticationProtocolCode::*;
+		
+		match code
+		{
+			1 => Some(Request),
+			2 => Some(Response),
+			3 => Some(Success),
+			4 => Some(Failure),
+			_ => None,
+		}
+	}
+	
+	/// Requests and responses have a type field.
+	#[inline(always)]
+	pub fn has_type(self) -> bool
+	{
+		use self::ExtensibleAuthenticationProtocolCode::*;
+		
+		match self
+		{
+			Request | Response => true,
+			Success | Failure => false,
+		}
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A parsed Extensible Authentication Protocol over LAN (EAPOL) frame (IEEE Std 802.1X-2010, Section 11.3).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ExtensibleAuthenticationProtocolOverLanFrame<'a>
+{
+	/// EAPOL-EAP.
+	ExtensibleAuthenticationProtocolPacket
+	{
+		/// Extensible Authentication Protocol (EAP) packet.
+		packet: ExtensibleAuthenticationProtocolPacket<'a>,
+	},
+	
+	/// EAPOL-Start; a supplicant wishes to authenticate.
+	Start,
+	
+	/// EAPOL-Logoff; a supplicant no longer wishes to be authorised.
+	Logoff,
+	
+	/// EAPOL-Key.
+	Key
+	{
+		/// Descriptor type, eg 2 for IEEE 802.11.
+		descriptor_type: u8,
+		
+		/// Descriptor body.
+		descriptor_body: &'a [u8],
+	},
+}
+
+impl<'a> ExtensibleAuthenticationProtocolOverLanFrame<'a>
+{
+	/// Parse the bytes following the ether type; ethernet padding is permitted.
+	#[inline]
+	pub fn parse(bytes: &'a [u8]) -> Result<Self, PortBasedNetworkAccessControlDropReason>
+	{
+		use self::PortBasedNetworkAccessControlDropReason::*;
+		
+		if unlikely!(bytes.len() < ExtensibleAuthenticationProtocolOverLanHeader::Size)
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let protocol_version = bytes[0];
+		if unlikely!(protocol_version == 0)
+		{
+			return Err(UnsupportedProtocolVersion { protocol_version })
+		}
+		
+		let packet_type = match ExtensibleAuthenticationProtocolOverLanPacketType::parse(bytes[1])
+		{
+			None => return Err(UnsupportedPacketType { packet_type: bytes[1] }),
+			
+			Some(packet_type) => packet_type,
+		};
+		
+		let packet_body_length = (((bytes[2] as u16) << 8) | (bytes[3] as u16)) as usize;
+		let packet_body_end = ExtensibleAuthenticationProtocolOverLanHeader::Size + packet_body_length;
+		if unlikely!(packet_body_end > bytes.len())
+		{
+			return Err(PacketIsTooShort)
+		}
+		let packet_body = &bytes[ExtensibleAuthenticationProtocolOverLanHeader::Size .. packet_body_end];
+		
+		let frame = match packet_type
+		{
+			ExtensibleAuthenticationProtocolOverLanPacketType::ExtensibleAuthenticationProtocolPacket => ExtensibleAuthenticationProtocolOverLanFrame::ExtensibleAuthenticationProtocolPacket { packet: ExtensibleAuthenticationProtocolPacket::parse(packet_body)? },
+			
+			ExtensibleAuthenticationProtocolOverLanPacketType::Start => ExtensibleAuthenticationProtocolOverLanFrame::Start,
+			
+			ExtensibleAuthenticationProtocolOverLanPacketType::Logoff => ExtensibleAuthenticationProtocolOverLanFrame::Logoff,
+			
+			ExtensibleAuthenticationProtocolOverLanPacketType::Key =>
+			{
+				if unlikely!(packet_body.is_empty())
+				{
+					return Err(PacketIsTooShort)
+				}
+				ExtensibleAuthenticationProtocolOverLanFrame::Key { descriptor_type: packet_body[0], descriptor_body: &packet_body[1 ..] }
+			}
+		};
+		
+		Ok(frame)
+	}
+}
@@ -0,0 +1,34 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// An Extensible Authentication Protocol over LAN (EAPOL) header (IEEE Std 802.1X-2010, Section 11.3).
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExtensibleAuthenticationProtocolOverLanHeader
+{
+	/// Protocol version; 1 (802.1X-2001), 2 (802.1X-2004) or 3 (802.1X-2010).
+	pub protocol_version: u8,
+	
+	/// Packet type.
+	pub packet_type: u8,
+	
+	/// Length of the packet body, excluding this header and any ethernet padding.
+	pub packet_body_length: NetworkEndianU16,
+}
+
+impl ExtensibleAuthenticationProtocolOverLanHeader
+{
+	/// Size of header.
+	pub const Size: usize = 4;
+	
+	/// Size of header.
+	pub const SizeU16: u16 = Self::Size as u16;
+	
+	/// Packet body length.
+	#[inline(always)]
+	pub fn packet_body_length(&self) -> u16
+	{
+		self.packet_body_length.to_native_endian()
+	}
+}
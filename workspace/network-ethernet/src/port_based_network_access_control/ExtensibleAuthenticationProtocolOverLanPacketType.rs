@@ -0,0 +1,43 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Extensible Authentication Protocol over LAN (EAPOL) packet type (IEEE Std 802.1X-2010, Section 11.3.2).
+///
+/// Only those used for port authentication are supported.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+#[repr(u8)]
+pub enum ExtensibleAuthenticationProtocolOverLanPacketType
+{
+	/// EAPOL-EAP; carries an Extensible Authentication Protocol (EAP) packet.
+	ExtensibleAuthenticationProtocolPacket = 0x00,
+	
+	/// EAPOL-Start.
+	Start = 0x01,
+	
+	/// EAPOL-Logoff.
+	Logoff = 0x02,
+	
+	/// EAPOL-Key.
+	Key = 0x03,
+}
+
+impl ExtensibleAuthenticationProtocolOverLanPacketType
+{
+	/// Parse.
+	#[inline(always)]
+	pub fn parse(packet_type: u8) -> Option<Self>
+	{
+		use self::ExtensibleAuthenticationProtocolOverLanPacketType::*;
+		
+		match packet_type
+		{
+			0x00 => Some(ExtensibleAuthenticationProtocolPacket),
+			0x01 => Some(Start),
+			0x02 => Some(Logoff),
+			0x03 => Some(Key),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,78 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A parsed Extensible Authentication Protocol (EAP) packet (RFC 3748, Section 4).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ExtensibleAuthenticationProtocolPacket<'a>
+{
+	/// Code.
+	pub code: ExtensibleAuthenticationProtocolCode,
+	
+	/// Identifier, used to match responses to requests.
+	pub identifier: u8,
+	
+	/// Type, eg 1 for Identity; only present for requests and responses.
+	pub type_: Option<u8>,
+	
+	/// Type data; empty for success and failure.
+	pub type_data: &'a [u8],
+}
+
+impl<'a> ExtensibleAuthenticationProtocolPacket<'a>
+{
+	const HeaderSize: usize = 4;
+	
+	/// Parse.
+	#[inline]
+	pub fn parse(bytes: &'a [u8]) -> Result<Self, PortBasedNetworkAccessControlDropReason>
+	{
+		use self::PortBasedNetworkAccessControlDropReason::*;
+		
+		if unlikely!(bytes.len() < Self::HeaderSize)
+		{
+			return Err(ExtensibleAuthenticationProtocolPacketIsInvalid)
+		}
+		
+		let code = match ExtensibleAuthenticationProtocolCode::parse(bytes[0])
+		{
+			None => return Err(UnsupportedExtensibleAuthenticationProtocolCode { code: bytes[0] }),
+			
+			Some(code) => code,
+		};
+		
+		let length = (((bytes[2] as u16) << 8) | (bytes[3] as u16)) as usize;
+		if unlikely!(length < Self::HeaderSize || length > bytes.len())
+		{
+			return Err(ExtensibleAuthenticationProtocolPacketIsInvalid)
+		}
+		
+		let (type_, type_data) = if code.has_type()
+		{
+			if unlikely!(length == Self::HeaderSize)
+			{
+				return Err(ExtensibleAuthenticationProtocolPacketIsInvalid)
+			}
+			(Some(bytes[Self::HeaderSize]), &bytes[Self::HeaderSize + 1 .. length])
+		}
+		else
+		{
+			if unlikely!(length != Self::HeaderSize)
+			{
+				return Err(ExtensibleAuthenticationProtocolPacketIsInvalid)
+			}
+			(None, &bytes[length .. length])
+		};
+		
+		Ok
+		(
+			Self
+			{
+				code,
+				identifier: bytes[1],
+				type_,
+				type_data,
+			}
+		)
+	}
+}
@@ -0,0 +1,100 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// IEEE 802.1X port-based network access control, from the authenticator's side.
+///
+/// Each source ethernet address (supplicant) is either authorised or unauthorised; only Extensible Authentication Protocol over LAN (EAPOL) frames are accepted from unauthorised supplicants.
+#[derive(Debug)]
+pub struct PortBasedNetworkAccessControl
+{
+	statically_authorised: HashSet<MediaAccessControlAddress>,
+	
+	authorised: RefCell<HashSet<MediaAccessControlAddress>>,
+	
+	authenticator: RefCell<Option<Rc<dyn PortBasedNetworkAccessControlAuthenticator>>>,
+}
+
+impl PortBasedNetworkAccessControl
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(statically_authorised: HashSet<MediaAccessControlAddress>) -> Self
+	{
+		Self
+		{
+			statically_authorised,
+			authorised: RefCell::new(HashSet::default()),
+			authenticator: RefCell::new(None),
+		}
+	}
+	
+	/// Sets (or replaces) the authenticator that makes authentication decisions.
+	#[inline(always)]
+	pub fn set_authenticator(&self, authenticator: Rc<dyn PortBasedNetworkAccessControlAuthenticator>)
+	{
+		*self.authenticator.borrow_mut() = Some(authenticator)
+	}
+	
+	/// Is `supplicant` authorised?
+	#[inline(always)]
+	pub fn is_authorised(&self, supplicant: &MediaAccessControlAddress) -> bool
+	{
+		self.statically_authorised.contains(supplicant) || self.authorised.borrow().contains(supplicant)
+	}
+	
+	/// Is `supplicant` not authorised?
+	#[inline(always)]
+	pub fn is_not_authorised(&self, supplicant: &MediaAccessControlAddress) -> bool
+	{
+		!self.is_authorised(supplicant)
+	}
+	
+	/// Applies a decision, eg from an out-of-band source.
+	///
+	/// Statically authorised supplicants can not be unauthorised.
+	#[inline(always)]
+	pub fn decide(&self, supplicant: MediaAccessControlAddress, decision: PortBasedNetworkAccessControlDecision)
+	{
+		use self::PortBasedNetworkAccessControlDecision::*;
+		
+		match decision
+		{
+			Authorise => self.authorised.borrow_mut().insert(supplicant),
+			
+			Unauthorise => self.authorised.borrow_mut().remove(&supplicant),
+		};
+	}
+	
+	/// Processes a parsed frame from `supplicant`.
+	#[inline]
+	pub fn process_frame(&self, now: MonotonicMillisecondTimestamp, supplicant: &MediaAccessControlAddress, frame: &ExtensibleAuthenticationProtocolOverLanFrame)
+	{
+		if let &ExtensibleAuthenticationProtocolOverLanFrame::Logoff = frame
+		{
+			self.decide(*supplicant, PortBasedNetworkAccessControlDecision::Unauthorise)
+		}
+		
+		let authenticator = match *self.authenticator.borrow()
+		{
+			None => return,
+			
+			Some(ref authenticator) => authenticator.clone(),
+		};
+		
+		if let Some(decision) = authenticator.authenticate(now, supplicant, frame)
+		{
+			self.decide(*supplicant, decision)
+		}
+	}
+	
+	/// Processes the bytes following the ether type of an Extensible Authentication Protocol over LAN (EAPOL) frame.
+	#[inline(always)]
+	pub(crate) fn process(&self, now: MonotonicMillisecondTimestamp, supplicant: &MediaAccessControlAddress, layer_3_packet: &Layer3Packet, layer_3_length: u16) -> Result<(), PortBasedNetworkAccessControlDropReason>
+	{
+		let bytes = unsafe { from_raw_parts(layer_3_packet as *const Layer3Packet as *const u8, layer_3_length as usize) };
+		let frame = ExtensibleAuthenticationProtocolOverLanFrame::parse(bytes)?;
+		self.process_frame(now, supplicant, &frame);
+		Ok(())
+	}
+}
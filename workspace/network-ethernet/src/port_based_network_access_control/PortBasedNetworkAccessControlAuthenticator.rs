@@ -0,0 +1,14 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Makes authentication decisions for supplicants, eg by relaying Extensible Authentication Protocol (EAP) packets to a RADIUS server.
+pub trait PortBasedNetworkAccessControlAuthenticator: Debug
+{
+	/// Called for each valid Extensible Authentication Protocol over LAN (EAPOL) frame received from `supplicant`.
+	///
+	/// Returns a decision if the supplicant's authorisation should change, or `None` if authentication is still in progress.
+	///
+	/// EAPOL-Logoff always unauthorises the supplicant before this is called.
+	fn authenticate(&self, now: MonotonicMillisecondTimestamp, supplicant: &MediaAccessControlAddress, frame: &ExtensibleAuthenticationProtocolOverLanFrame) -> Option<PortBasedNetworkAccessControlDecision>;
+}
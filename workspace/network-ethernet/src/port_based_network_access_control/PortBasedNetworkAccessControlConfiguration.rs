@@ -0,0 +1,24 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// IEEE 802.1X port-based network access control configuration.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct PortBasedNetworkAccessControlConfiguration
+{
+	/// Supplicants that are always authorised, eg printers that can not authenticate.
+	#[serde(default)] pub statically_authorised: HashSet<MediaAccessControlAddress>,
+}
+
+impl PortBasedNetworkAccessControlConfiguration
+{
+	/// Configure.
+	///
+	/// Until an authenticator is set, only statically authorised supplicants are authorised.
+	#[inline(always)]
+	pub fn configure(self) -> PortBasedNetworkAccessControl
+	{
+		PortBasedNetworkAccessControl::new(self.statically_authorised)
+	}
+}
@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// An authentication decision for a supplicant.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PortBasedNetworkAccessControlDecision
+{
+	/// Permit traffic from the supplicant.
+	Authorise,
+	
+	/// Deny all but Extensible Authentication Protocol over LAN (EAPOL) traffic from the supplicant.
+	Unauthorise,
+}
@@ -0,0 +1,39 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why an Extensible Authentication Protocol over LAN (EAPOL) frame was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PortBasedNetworkAccessControlDropReason
+{
+	/// The destination ethernet address was neither the port access entity (PAE) group address, `01:80:C2:00:00:03`, nor ours.
+	DestinationEthernetAddressIsNotPortAccessEntityGroupAddressOrOurs,
+	
+	/// The frame was shorter than its header or packet body length.
+	PacketIsTooShort,
+	
+	/// Protocol version zero is invalid.
+	UnsupportedProtocolVersion
+	{
+		/// Protocol version.
+		protocol_version: u8,
+	},
+	
+	/// Only EAPOL-EAP, EAPOL-Start, EAPOL-Logoff and EAPOL-Key are supported.
+	UnsupportedPacketType
+	{
+		/// Packet type.
+		packet_type: u8,
+	},
+	
+	/// The Extensible Authentication Protocol (EAP) packet length was invalid for its code.
+	ExtensibleAuthenticationProtocolPacketIsInvalid,
+	
+	/// The Extensible Authentication Protocol (EAP) code was not request, response, success or failure.
+	UnsupportedExtensibleAuthenticationProtocolCode
+	{
+		/// Code.
+		code: u8,
+	},
+}
@@ -0,0 +1,60 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// The body of a Precision Time Protocol (PTP) Announce message (IEEE 1588-2008, Section 13.5).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolAnnounce
+{
+	/// Origin timestamp.
+	pub origin_timestamp: PrecisionTimeProtocolTimestamp,
+	
+	/// Current UTC offset, in seconds.
+	pub current_utc_offset: i16,
+	
+	/// Grandmaster priority 1; lower is better.
+	pub grandmaster_priority_1: u8,
+	
+	/// Grandmaster clock quality.
+	pub grandmaster_clock_quality: PrecisionTimeProtocolClockQuality,
+	
+	/// Grandmaster priority 2; lower is better.
+	pub grandmaster_priority_2: u8,
+	
+	/// Grandmaster identity.
+	pub grandmaster_identity: [u8; 8],
+	
+	/// Number of boundary clocks between the grandmaster and the sender.
+	pub steps_removed: u16,
+	
+	/// Time source.
+	pub time_source: u8,
+}
+
+impl PrecisionTimeProtocolAnnounce
+{
+	/// Size of message, including the common header.
+	pub const Size: usize = 64;
+	
+	#[inline(always)]
+	pub(crate) fn parse(reader: PrecisionTimeProtocolBytes) -> Self
+	{
+		Self
+		{
+			origin_timestamp: reader.timestamp(34),
+			current_utc_offset: reader.i16(44),
+			grandmaster_priority_1: reader.u8(47),
+			grandmaster_clock_quality: PrecisionTimeProtocolClockQuality
+			{
+				clock_class: reader.u8(48),
+				clock_accuracy: reader.u8(49),
+				offset_scaled_log_variance: reader.u16(50),
+			},
+			grandmaster_priority_2: reader.u8(52),
+			grandmaster_identity: reader.clock_identity(53),
+			steps_removed: reader.u16(61),
+			time_source: reader.u8(63),
+		}
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Network endian reads from a Precision Time Protocol (PTP) message.
+///
+/// Lengths must be checked before reading.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PrecisionTimeProtocolBytes<'a>(&'a [u8]);
+
+impl<'a> PrecisionTimeProtocolBytes<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(bytes: &'a [u8]) -> Self
+	{
+		PrecisionTimeProtocolBytes(bytes)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn u8(self, offset: usize) -> u8
+	{
+		self.0[offset]
+	}
+	
+	#[inline(always)]
+	pub(crate) fn i8(self, offset: usize) -> i8
+	{
+		self.0[offset] as i8
+	}
+	
+	#[inline(always)]
+	pub(crate) fn u16(self, offset: usize) -> u16
+	{
+		((self.0[offset] as u16) << 8) | (self.0[offset + 1] as u16)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn i16(self, offset: usize) -> i16
+	{
+		self.u16(offset) as i16
+	}
+	
+	#[inline(always)]
+	pub(crate) fn u32(self, offset: usize) -> u32
+	{
+		((self.u16(offset) as u32) << 16) | (self.u16(offset + 2) as u32)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn u48(self, offset: usize) -> u64
+	{
+		((self.u16(offset) as u64) << 32) | (self.u32(offset + 2) as u64)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn i64(self, offset: usize) -> i64
+	{
+		(((self.u32(offset) as u64) << 32) | (self.u32(offset + 4) as u64)) as i64
+	}
+	
+	#[inline(always)]
+	pub(crate) fn clock_identity(self, offset: usize) -> [u8; 8]
+	{
+		let mut clock_identity = [0u8; 8];
+		clock_identity.copy_from_slice(&self.0[offset .. offset + 8]);
+		clock_identity
+	}
+	
+	#[inline(always)]
+	pub(crate) fn port_identity(self, offset: usize) -> PrecisionTimeProtocolPortIdentity
+	{
+		PrecisionTimeProtocolPortIdentity
+		{
+			clock_identity: self.clock_identity(offset),
+			port_number: self.u16(offset + 8),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn timestamp(self, offset: usize) -> PrecisionTimeProtocolTimestamp
+	{
+		PrecisionTimeProtocolTimestamp
+		{
+			seconds: self.u48(offset),
+			nanoseconds: self.u32(offset + 6),
+		}
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Precision Time Protocol (PTP) clock quality (IEEE 1588-2008, Section 5.3.7).
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolClockQuality
+{
+	/// Clock class; lower is better.
+	pub clock_class: u8,
+	
+	/// Clock accuracy; lower is better.
+	pub clock_accuracy: u8,
+	
+	/// Offset scaled log variance; lower is better.
+	pub offset_scaled_log_variance: u16,
+}
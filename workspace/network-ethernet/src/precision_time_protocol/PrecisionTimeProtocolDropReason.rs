@@ -0,0 +1,71 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Precision Time Protocol (PTP) message was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PrecisionTimeProtocolDropReason
+{
+	/// The message is shorter than the common header.
+	MessageIsTooShort
+	{
+		/// Length of the payload.
+		length: usize,
+	},
+	
+	/// Only version 2 (IEEE 1588-2008) is supported.
+	UnsupportedVersion
+	{
+		/// Version.
+		version: u8,
+	},
+	
+	/// The message length is shorter than its message type requires or longer than the payload.
+	MessageLengthIsInvalid
+	{
+		/// Message length in the header.
+		message_length: u16,
+		
+		/// Length of the payload.
+		length: usize,
+	},
+	
+	/// Management, signaling and peer delay messages are not supported.
+	UnsupportedMessageType
+	{
+		/// Message type.
+		message_type: u8,
+	},
+	
+	/// The message's timestamp has nanoseconds of one second or more.
+	TimestampIsInvalid
+	{
+		/// Message type.
+		message_type: u8,
+	},
+	
+	/// The message is for a different domain.
+	WrongDomain
+	{
+		/// Domain number.
+		domain_number: u8,
+	},
+	
+	/// An event message was received without a hardware receive timestamp.
+	HardwareReceiveTimestampIsAbsent,
+	
+	/// A Follow_Up did not match the last two step Sync.
+	FollowUpDoesNotMatchSync
+	{
+		/// Sequence identifier.
+		sequence_id: u16,
+	},
+	
+	/// A Delay_Resp was for another port or did not match our last Delay_Req.
+	DelayResponseIsNotForUs
+	{
+		/// Sequence identifier.
+		sequence_id: u16,
+	},
+}
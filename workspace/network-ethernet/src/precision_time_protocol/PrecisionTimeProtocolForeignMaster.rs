@@ -0,0 +1,50 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Precision Time Protocol (PTP) foreign master, as learnt from an Announce message.
+///
+/// This is the data set compared by the best master clock algorithm (IEEE 1588-2008, Section 9.3.4).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolForeignMaster
+{
+	/// Announce message body.
+	pub announce: PrecisionTimeProtocolAnnounce,
+	
+	/// Port identity of the sender of the Announce message.
+	pub sender_port_identity: PrecisionTimeProtocolPortIdentity,
+	
+	/// Port identity of the receiver of the Announce message, ie ours.
+	pub receiver_port_identity: PrecisionTimeProtocolPortIdentity,
+}
+
+impl PrecisionTimeProtocolForeignMaster
+{
+	/// Data set comparison (IEEE 1588-2008, Section 9.3.4, Figures 27 and 28).
+	///
+	/// Returns `Less` if `a` is the better master, `Greater` if `b` is the better master and `Equal` if they are the same.
+	///
+	/// This is a pure function.
+	#[inline]
+	pub fn compare(a: &Self, b: &Self) -> Ordering
+	{
+		let a_announce = &a.announce;
+		let b_announce = &b.announce;
+		
+		if a_announce.grandmaster_identity != b_announce.grandmaster_identity
+		{
+			return a_announce.grandmaster_priority_1.cmp(&b_announce.grandmaster_priority_1)
+			.then(a_announce.grandmaster_clock_quality.clock_class.cmp(&b_announce.grandmaster_clock_quality.clock_class))
+			.then(a_announce.grandmaster_clock_quality.clock_accuracy.cmp(&b_announce.grandmaster_clock_quality.clock_accuracy))
+			.then(a_announce.grandmaster_clock_quality.offset_scaled_log_variance.cmp(&b_announce.grandmaster_clock_quality.offset_scaled_log_variance))
+			.then(a_announce.grandmaster_priority_2.cmp(&b_announce.grandmaster_priority_2))
+			.then(a_announce.grandmaster_identity.cmp(&b_announce.grandmaster_identity))
+		}
+		
+		// Same grandmaster; the better path is the one with fewer boundary clocks, then the one via the lower sender and receiver port identities.
+		a_announce.steps_removed.cmp(&b_announce.steps_removed)
+		.then(a.sender_port_identity.cmp(&b.sender_port_identity))
+		.then(a.receiver_port_identity.port_number.cmp(&b.receiver_port_identity.port_number))
+	}
+}
@@ -0,0 +1,41 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Precision Time Protocol (PTP) measurement computed from a complete Sync and Delay_Req exchange (IEEE 1588-2008, Section 11.3).
+///
+/// All values are in nanoseconds and are corrected using the correction fields of the messages.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolMeasurement
+{
+	/// `t2 - t1`, ie Sync receive time less Sync origin time.
+	pub master_to_slave_delay: i64,
+	
+	/// `t4 - t3`, ie Delay_Req receive time at the master less Delay_Req transmit time.
+	pub slave_to_master_delay: i64,
+	
+	/// Mean path delay; assumes a symmetric path.
+	pub mean_path_delay: i64,
+	
+	/// Offset of our clock from the master; positive if our clock is ahead.
+	pub offset_from_master: i64,
+}
+
+impl PrecisionTimeProtocolMeasurement
+{
+	/// Computes a measurement.
+	#[inline(always)]
+	pub fn compute(master_to_slave_delay: i64, slave_to_master_delay: i64) -> Self
+	{
+		let mean_path_delay = (((master_to_slave_delay as i128) + (slave_to_master_delay as i128)) / 2) as i64;
+		
+		Self
+		{
+			master_to_slave_delay,
+			slave_to_master_delay,
+			mean_path_delay,
+			offset_from_master: master_to_slave_delay.saturating_sub(mean_path_delay),
+		}
+	}
+}
@@ -0,0 +1,156 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A parsed Precision Time Protocol (PTP) message.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PrecisionTimeProtocolMessage
+{
+	/// Sync (event).
+	Sync
+	{
+		/// Header.
+		header: PrecisionTimeProtocolMessageHeader,
+		
+		/// Origin timestamp; only approximate if the two step flag is set.
+		origin_timestamp: PrecisionTimeProtocolTimestamp,
+	},
+	
+	/// Delay_Req (event).
+	DelayRequest
+	{
+		/// Header.
+		header: PrecisionTimeProtocolMessageHeader,
+		
+		/// Origin timestamp.
+		origin_timestamp: PrecisionTimeProtocolTimestamp,
+	},
+	
+	/// Follow_Up (general).
+	FollowUp
+	{
+		/// Header.
+		header: PrecisionTimeProtocolMessageHeader,
+		
+		/// Precise origin timestamp of the matching Sync.
+		precise_origin_timestamp: PrecisionTimeProtocolTimestamp,
+	},
+	
+	/// Delay_Resp (general).
+	DelayResponse
+	{
+		/// Header.
+		header: PrecisionTimeProtocolMessageHeader,
+		
+		/// Time the master received the matching Delay_Req.
+		receive_timestamp: PrecisionTimeProtocolTimestamp,
+		
+		/// Port identity that sent the matching Delay_Req.
+		requesting_port_identity: PrecisionTimeProtocolPortIdentity,
+	},
+	
+	/// Announce (general).
+	Announce
+	{
+		/// Header.
+		header: PrecisionTimeProtocolMessageHeader,
+		
+		/// Body.
+		announce: PrecisionTimeProtocolAnnounce,
+	},
+}
+
+impl PrecisionTimeProtocolMessage
+{
+	const TimestampMessageSize: usize = 44;
+	
+	const DelayResponseMessageSize: usize = 54;
+	
+	/// Parse a message from the bytes of an ethernet or User Datagram Protocol (UDP) payload.
+	///
+	/// Message types other than Sync, Delay_Req, Follow_Up, Delay_Resp and Announce are rejected.
+	#[inline]
+	pub fn parse(bytes: &[u8]) -> Result<Self, PrecisionTimeProtocolDropReason>
+	{
+		use self::PrecisionTimeProtocolMessage::*;
+		
+		let header = PrecisionTimeProtocolMessageHeader::parse(bytes)?;
+		let message_length = header.message_length;
+		let reader = PrecisionTimeProtocolBytes::new(&bytes[.. message_length as usize]);
+		
+		let minimum_message_length = match header.message_type
+		{
+			PrecisionTimeProtocolMessageHeader::SyncMessageType | PrecisionTimeProtocolMessageHeader::DelayRequestMessageType | PrecisionTimeProtocolMessageHeader::FollowUpMessageType => Self::TimestampMessageSize,
+			PrecisionTimeProtocolMessageHeader::DelayResponseMessageType => Self::DelayResponseMessageSize,
+			PrecisionTimeProtocolMessageHeader::AnnounceMessageType => PrecisionTimeProtocolAnnounce::Size,
+			message_type => return Err(PrecisionTimeProtocolDropReason::UnsupportedMessageType { message_type }),
+		};
+		
+		if unlikely!((message_length as usize) < minimum_message_length)
+		{
+			return Err(PrecisionTimeProtocolDropReason::MessageLengthIsInvalid { message_length, length: bytes.len() })
+		}
+		
+		let message = match header.message_type
+		{
+			PrecisionTimeProtocolMessageHeader::SyncMessageType => Sync { header, origin_timestamp: reader.timestamp(34) },
+			PrecisionTimeProtocolMessageHeader::DelayRequestMessageType => DelayRequest { header, origin_timestamp: reader.timestamp(34) },
+			PrecisionTimeProtocolMessageHeader::FollowUpMessageType => FollowUp { header, precise_origin_timestamp: reader.timestamp(34) },
+			PrecisionTimeProtocolMessageHeader::DelayResponseMessageType => DelayResponse { header, receive_timestamp: reader.timestamp(34), requesting_port_identity: reader.port_identity(44) },
+			_ => Announce { header, announce: PrecisionTimeProtocolAnnounce::parse(reader) },
+		};
+		
+		if unlikely!(!message.timestamp().is_valid())
+		{
+			return Err(PrecisionTimeProtocolDropReason::TimestampIsInvalid { message_type: header.message_type })
+		}
+		
+		Ok(message)
+	}
+	
+	/// Timestamp carried by the message; the origin timestamp of an Announce.
+	#[inline(always)]
+	pub fn timestamp(&self) -> PrecisionTimeProtocolTimestamp
+	{
+		use self::PrecisionTimeProtocolMessage::*;
+		
+		match *self
+		{
+			Sync { origin_timestamp, .. } => origin_timestamp,
+			DelayRequest { origin_timestamp, .. } => origin_timestamp,
+			FollowUp { precise_origin_timestamp, .. } => precise_origin_timestamp,
+			DelayResponse { receive_timestamp, .. } => receive_timestamp,
+			Announce { announce, .. } => announce.origin_timestamp,
+		}
+	}
+	
+	/// Header.
+	#[inline(always)]
+	pub fn header(&self) -> &PrecisionTimeProtocolMessageHeader
+	{
+		use self::PrecisionTimeProtocolMessage::*;
+		
+		match *self
+		{
+			Sync { ref header, .. } => header,
+			DelayRequest { ref header, .. } => header,
+			FollowUp { ref header, .. } => header,
+			DelayResponse { ref header, .. } => header,
+			Announce { ref header, .. } => header,
+		}
+	}
+	
+	/// Is this an event message, ie one that requires a hardware receive timestamp?
+	#[inline(always)]
+	pub fn is_event(&self) -> bool
+	{
+		use self::PrecisionTimeProtocolMessage::*;
+		
+		match *self
+		{
+			Sync { .. } | DelayRequest { .. } => true,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,114 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Precision Time Protocol (PTP) common message header (IEEE 1588-2008, Section 13.3).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolMessageHeader
+{
+	/// Message type (lower nibble of the first octet).
+	pub message_type: u8,
+	
+	/// Message length, including this header.
+	pub message_length: u16,
+	
+	/// Domain number.
+	pub domain_number: u8,
+	
+	/// Flags.
+	pub flags: u16,
+	
+	/// Correction field, in nanoseconds multiplied by 2^16.
+	pub correction_field: i64,
+	
+	/// Source port identity.
+	pub source_port_identity: PrecisionTimeProtocolPortIdentity,
+	
+	/// Sequence identifier.
+	pub sequence_id: u16,
+	
+	/// Log message interval.
+	pub log_message_interval: i8,
+}
+
+impl PrecisionTimeProtocolMessageHeader
+{
+	/// Size of header.
+	pub const Size: usize = 34;
+	
+	/// Only version 2 (IEEE 1588-2008) is supported.
+	pub const Version: u8 = 2;
+	
+	/// `twoStepFlag`; if set on a Sync, a Follow_Up carries the precise origin timestamp.
+	pub const TwoStepFlag: u16 = 0x0200;
+	
+	/// Sync message type (event).
+	pub const SyncMessageType: u8 = 0x0;
+	
+	/// Delay_Req message type (event).
+	pub const DelayRequestMessageType: u8 = 0x1;
+	
+	/// Follow_Up message type (general).
+	pub const FollowUpMessageType: u8 = 0x8;
+	
+	/// Delay_Resp message type (general).
+	pub const DelayResponseMessageType: u8 = 0x9;
+	
+	/// Announce message type (general).
+	pub const AnnounceMessageType: u8 = 0xB;
+	
+	/// Parse.
+	#[inline]
+	pub fn parse(bytes: &[u8]) -> Result<Self, PrecisionTimeProtocolDropReason>
+	{
+		let length = bytes.len();
+		if unlikely!(length < Self::Size)
+		{
+			return Err(PrecisionTimeProtocolDropReason::MessageIsTooShort { length })
+		}
+		
+		let reader = PrecisionTimeProtocolBytes::new(bytes);
+		
+		let version = reader.u8(1) & 0x0F;
+		if unlikely!(version != Self::Version)
+		{
+			return Err(PrecisionTimeProtocolDropReason::UnsupportedVersion { version })
+		}
+		
+		let message_length = reader.u16(2);
+		if unlikely!((message_length as usize) < Self::Size || (message_length as usize) > length)
+		{
+			return Err(PrecisionTimeProtocolDropReason::MessageLengthIsInvalid { message_length, length })
+		}
+		
+		Ok
+		(
+			Self
+			{
+				message_type: reader.u8(0) & 0x0F,
+				message_length,
+				domain_number: reader.u8(4),
+				flags: reader.u16(6),
+				correction_field: reader.i64(8),
+				source_port_identity: reader.port_identity(20),
+				sequence_id: reader.u16(30),
+				log_message_interval: reader.i8(33),
+			}
+		)
+	}
+	
+	/// Is the two step flag set?
+	#[inline(always)]
+	pub fn is_two_step(&self) -> bool
+	{
+		self.flags & Self::TwoStepFlag != 0
+	}
+	
+	/// Correction field in whole nanoseconds (sub-nanosecond precision is discarded).
+	#[inline(always)]
+	pub fn correction_nanoseconds(&self) -> i64
+	{
+		self.correction_field >> 16
+	}
+}
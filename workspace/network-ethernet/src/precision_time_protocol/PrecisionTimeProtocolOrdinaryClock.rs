@@ -0,0 +1,218 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// The slave side of a Precision Time Protocol (PTP) ordinary clock using the delay request-response mechanism.
+///
+/// Independent of packets, so it can be driven by a sequence of parsed messages and timestamps.
+#[derive(Debug, Clone)]
+pub struct PrecisionTimeProtocolOrdinaryClock
+{
+	port_identity: PrecisionTimeProtocolPortIdentity,
+	
+	domain_number: u8,
+	
+	best_master: Option<PrecisionTimeProtocolForeignMaster>,
+	
+	// (sequence_id, sender, t2, correction).
+	awaiting_follow_up: Option<(u16, PrecisionTimeProtocolPortIdentity, PrecisionTimeProtocolTimestamp, i64)>,
+	
+	master_to_slave_delay: Option<i64>,
+	
+	// (sequence_id, t3).
+	awaiting_delay_response: Option<(u16, PrecisionTimeProtocolTimestamp)>,
+	
+	measurement: Option<PrecisionTimeProtocolMeasurement>,
+}
+
+impl PrecisionTimeProtocolOrdinaryClock
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(port_identity: PrecisionTimeProtocolPortIdentity, domain_number: u8) -> Self
+	{
+		Self
+		{
+			port_identity,
+			domain_number,
+			best_master: None,
+			awaiting_follow_up: None,
+			master_to_slave_delay: None,
+			awaiting_delay_response: None,
+			measurement: None,
+		}
+	}
+	
+	/// Our port identity.
+	#[inline(always)]
+	pub fn port_identity(&self) -> PrecisionTimeProtocolPortIdentity
+	{
+		self.port_identity
+	}
+	
+	/// Best master learnt from Announce messages, if any.
+	#[inline(always)]
+	pub fn best_master(&self) -> Option<PrecisionTimeProtocolForeignMaster>
+	{
+		self.best_master
+	}
+	
+	/// Most recent measurement, if any.
+	#[inline(always)]
+	pub fn measurement(&self) -> Option<PrecisionTimeProtocolMeasurement>
+	{
+		self.measurement
+	}
+	
+	/// Records the hardware transmit timestamp, `t3`, of a Delay_Req we sent.
+	#[inline(always)]
+	pub fn delay_request_transmitted(&mut self, sequence_id: u16, transmit_timestamp: PrecisionTimeProtocolTimestamp)
+	{
+		self.awaiting_delay_response = Some((sequence_id, transmit_timestamp));
+	}
+	
+	/// Processes a message.
+	///
+	/// `receive_timestamp` is required for Sync messages; it is ignored for general messages.
+	///
+	/// Returns a new measurement when a Delay_Resp completes an exchange.
+	#[inline]
+	pub fn process_message(&mut self, message: &PrecisionTimeProtocolMessage, receive_timestamp: Option<PrecisionTimeProtocolTimestamp>) -> Result<Option<PrecisionTimeProtocolMeasurement>, PrecisionTimeProtocolDropReason>
+	{
+		use self::PrecisionTimeProtocolDropReason::*;
+		use self::PrecisionTimeProtocolMessage::*;
+		
+		let header = message.header();
+		if unlikely!(header.domain_number != self.domain_number)
+		{
+			return Err(WrongDomain { domain_number: header.domain_number })
+		}
+		
+		match *message
+		{
+			Announce { ref header, announce } =>
+			{
+				self.announce(header, announce);
+				Ok(None)
+			}
+			
+			Sync { ref header, origin_timestamp } =>
+			{
+				if !self.is_from_best_master(header)
+				{
+					return Ok(None)
+				}
+				
+				let t2 = match receive_timestamp
+				{
+					None => return Err(HardwareReceiveTimestampIsAbsent),
+					
+					Some(t2) => t2,
+				};
+				
+				if header.is_two_step()
+				{
+					self.awaiting_follow_up = Some((header.sequence_id, header.source_port_identity, t2, header.correction_nanoseconds()));
+				}
+				else
+				{
+					self.awaiting_follow_up = None;
+					self.master_to_slave_delay = Some(t2.nanoseconds_since(origin_timestamp).saturating_sub(header.correction_nanoseconds()));
+				}
+				Ok(None)
+			}
+			
+			FollowUp { ref header, precise_origin_timestamp } =>
+			{
+				match self.awaiting_follow_up.take()
+				{
+					Some((sequence_id, sender, t2, sync_correction)) if sequence_id == header.sequence_id && sender == header.source_port_identity =>
+					{
+						self.master_to_slave_delay = Some(t2.nanoseconds_since(precise_origin_timestamp).saturating_sub(sync_correction).saturating_sub(header.correction_nanoseconds()));
+						Ok(None)
+					}
+					
+					_ => Err(FollowUpDoesNotMatchSync { sequence_id: header.sequence_id }),
+				}
+			}
+			
+			// Sent by other slaves to the master.
+			DelayRequest { .. } => Ok(None),
+			
+			DelayResponse { ref header, receive_timestamp, requesting_port_identity } =>
+			{
+				if unlikely!(requesting_port_identity != self.port_identity)
+				{
+					return Err(DelayResponseIsNotForUs { sequence_id: header.sequence_id })
+				}
+				
+				let t3 = match self.awaiting_delay_response
+				{
+					Some((sequence_id, t3)) if sequence_id == header.sequence_id => t3,
+					
+					_ => return Err(DelayResponseIsNotForUs { sequence_id: header.sequence_id }),
+				};
+				self.awaiting_delay_response = None;
+				
+				let slave_to_master_delay = receive_timestamp.nanoseconds_since(t3).saturating_sub(header.correction_nanoseconds());
+				
+				match self.master_to_slave_delay
+				{
+					None => Ok(None),
+					
+					Some(master_to_slave_delay) =>
+					{
+						let measurement = PrecisionTimeProtocolMeasurement::compute(master_to_slave_delay, slave_to_master_delay);
+						self.measurement = Some(measurement);
+						Ok(Some(measurement))
+					}
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn announce(&mut self, header: &PrecisionTimeProtocolMessageHeader, announce: PrecisionTimeProtocolAnnounce)
+	{
+		let candidate = PrecisionTimeProtocolForeignMaster
+		{
+			announce,
+			sender_port_identity: header.source_port_identity,
+			receiver_port_identity: self.port_identity,
+		};
+		
+		let is_better = match self.best_master
+		{
+			None => true,
+			
+			// The current best master may announce a worse data set.
+			Some(ref best_master) => best_master.sender_port_identity == candidate.sender_port_identity || PrecisionTimeProtocolForeignMaster::compare(&candidate, best_master) == Ordering::Less,
+		};
+		
+		if is_better
+		{
+			if let Some(ref best_master) = self.best_master
+			{
+				if best_master.sender_port_identity != candidate.sender_port_identity
+				{
+					self.awaiting_follow_up = None;
+					self.master_to_slave_delay = None;
+					self.awaiting_delay_response = None;
+				}
+			}
+			self.best_master = Some(candidate);
+		}
+	}
+	
+	/// Until a best master is known, Sync messages from any master are accepted.
+	#[inline(always)]
+	fn is_from_best_master(&self, header: &PrecisionTimeProtocolMessageHeader) -> bool
+	{
+		match self.best_master
+		{
+			None => true,
+			
+			Some(ref best_master) => best_master.sender_port_identity == header.source_port_identity,
+		}
+	}
+}
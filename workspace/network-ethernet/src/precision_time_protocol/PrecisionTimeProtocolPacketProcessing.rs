@@ -0,0 +1,66 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Precision Time Protocol (PTP) packet processing (IEEE 1588-2008), for messages carried directly over ethernet (ether type `0x88F7`, Annex F) or over User Datagram Protocol (UDP) ports 319 and 320 (Annexes D and E).
+///
+/// Sync, Follow_Up, Delay_Req, Delay_Resp and Announce messages are parsed; event messages use the hardware receive timestamp of their packet.
+#[derive(Debug)]
+pub struct PrecisionTimeProtocolPacketProcessing
+{
+	ordinary_clock: RefCell<PrecisionTimeProtocolOrdinaryClock>,
+}
+
+impl PrecisionTimeProtocolPacketProcessing
+{
+	/// User Datagram Protocol (UDP) port for event messages (Sync and Delay_Req).
+	pub const EventUserDatagramProtocolPort: u16 = 319;
+	
+	/// User Datagram Protocol (UDP) port for general messages (Follow_Up, Delay_Resp and Announce).
+	pub const GeneralUserDatagramProtocolPort: u16 = 320;
+	
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(ordinary_clock: PrecisionTimeProtocolOrdinaryClock) -> Self
+	{
+		Self
+		{
+			ordinary_clock: RefCell::new(ordinary_clock),
+		}
+	}
+	
+	/// Ordinary clock.
+	#[inline(always)]
+	pub fn ordinary_clock(&self) -> Ref<PrecisionTimeProtocolOrdinaryClock>
+	{
+		self.ordinary_clock.borrow()
+	}
+	
+	/// Records the hardware transmit timestamp of a Delay_Req we sent.
+	#[inline(always)]
+	pub fn delay_request_transmitted(&self, sequence_id: u16, hardware_transmit_timestamp: u64)
+	{
+		self.ordinary_clock.borrow_mut().delay_request_transmitted(sequence_id, PrecisionTimeProtocolTimestamp::from_nanoseconds(hardware_transmit_timestamp))
+	}
+	
+	/// Processes a message, eg the payload of a User Datagram Protocol (UDP) packet to port 319 or 320.
+	///
+	/// `hardware_receive_timestamp` is in nanoseconds and is required for event messages.
+	#[inline]
+	pub fn process_message(&self, message: &[u8], hardware_receive_timestamp: Option<u64>) -> Result<Option<PrecisionTimeProtocolMeasurement>, PrecisionTimeProtocolDropReason>
+	{
+		let message = PrecisionTimeProtocolMessage::parse(message)?;
+		
+		let receive_timestamp = hardware_receive_timestamp.map(PrecisionTimeProtocolTimestamp::from_nanoseconds);
+		
+		self.ordinary_clock.borrow_mut().process_message(&message, receive_timestamp)
+	}
+	
+	/// Processes a message carried directly over ethernet.
+	#[inline(always)]
+	pub(crate) fn process(&self, layer_3_packet: &Layer3Packet, layer_3_length: u16, hardware_receive_timestamp: Option<u64>) -> Result<Option<PrecisionTimeProtocolMeasurement>, PrecisionTimeProtocolDropReason>
+	{
+		let message = unsafe { from_raw_parts(layer_3_packet as *const Layer3Packet as *const u8, layer_3_length as usize) };
+		self.process_message(message, hardware_receive_timestamp)
+	}
+}
@@ -0,0 +1,47 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Precision Time Protocol (PTP) packet processing configuration.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolPacketProcessingConfiguration
+{
+	/// Domain number.
+	///
+	/// Defaults to 0, the default domain.
+	#[serde(default)] pub domain_number: u8,
+	
+	/// Clock identity.
+	///
+	/// Defaults to one derived from our ethernet address.
+	#[serde(default)] pub clock_identity: Option<[u8; 8]>,
+	
+	/// Port number.
+	///
+	/// Defaults to 1.
+	#[serde(default = "PrecisionTimeProtocolPacketProcessingConfiguration::port_number_default")] pub port_number: u16,
+}
+
+impl PrecisionTimeProtocolPacketProcessingConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self, our_valid_unicast_ethernet_address: &MediaAccessControlAddress) -> PrecisionTimeProtocolPacketProcessing
+	{
+		let port_identity = match self.clock_identity
+		{
+			None => PrecisionTimeProtocolPortIdentity::from_ethernet_address(our_valid_unicast_ethernet_address, self.port_number),
+			
+			Some(clock_identity) => PrecisionTimeProtocolPortIdentity { clock_identity, port_number: self.port_number },
+		};
+		
+		PrecisionTimeProtocolPacketProcessing::new(PrecisionTimeProtocolOrdinaryClock::new(port_identity, self.domain_number))
+	}
+	
+	#[inline(always)]
+	fn port_number_default() -> u16
+	{
+		1
+	}
+}
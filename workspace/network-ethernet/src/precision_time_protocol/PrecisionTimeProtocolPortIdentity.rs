@@ -0,0 +1,33 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Precision Time Protocol (PTP) port identity (IEEE 1588-2008, Section 5.3.5).
+///
+/// Ordering is that used by the best master clock algorithm, ie by clock identity then port number.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolPortIdentity
+{
+	/// Clock identity, usually an EUI-64 derived from an ethernet address.
+	pub clock_identity: [u8; 8],
+	
+	/// Port number, 1 - 65,534 inclusive.
+	pub port_number: u16,
+}
+
+impl PrecisionTimeProtocolPortIdentity
+{
+	/// Derives the clock identity from an ethernet address (IEEE 1588-2008, Section 7.5.2.2.2).
+	#[inline(always)]
+	pub fn from_ethernet_address(ethernet_address: &MediaAccessControlAddress, port_number: u16) -> Self
+	{
+		let octets = ethernet_address.to_octets_reference();
+		
+		Self
+		{
+			clock_identity: [octets[0], octets[1], octets[2], 0xFF, 0xFE, octets[3], octets[4], octets[5]],
+			port_number,
+		}
+	}
+}
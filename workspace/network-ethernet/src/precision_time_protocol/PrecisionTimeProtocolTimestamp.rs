@@ -0,0 +1,67 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Precision Time Protocol (PTP) timestamp (IEEE 1588-2008, Section 5.3.3).
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct PrecisionTimeProtocolTimestamp
+{
+	/// Seconds; only the lower 48 bits are used.
+	pub seconds: u64,
+	
+	/// Nanoseconds, 0 - 999,999,999 inclusive.
+	pub nanoseconds: u32,
+}
+
+impl PrecisionTimeProtocolTimestamp
+{
+	const NanosecondsPerSecond: u64 = 1_000_000_000;
+	
+	/// From nanoseconds, eg a hardware receive timestamp.
+	#[inline(always)]
+	pub fn from_nanoseconds(nanoseconds: u64) -> Self
+	{
+		Self
+		{
+			seconds: nanoseconds / Self::NanosecondsPerSecond,
+			nanoseconds: (nanoseconds % Self::NanosecondsPerSecond) as u32,
+		}
+	}
+	
+	/// Nanoseconds; `None` if they do not fit in 64 bits, ie for timestamps after the year 2554.
+	#[inline(always)]
+	pub fn to_nanoseconds(self) -> Option<u64>
+	{
+		self.seconds.checked_mul(Self::NanosecondsPerSecond).and_then(|nanoseconds| nanoseconds.checked_add(self.nanoseconds as u64))
+	}
+	
+	/// Signed difference, `self - other`, in nanoseconds, saturating at the limits of an `i64` (about 292 years).
+	///
+	/// Computed with 128-bit arithmetic, so it does not overflow for any two timestamps with 48-bit seconds.
+	#[inline(always)]
+	pub fn nanoseconds_since(self, other: Self) -> i64
+	{
+		let difference = ((self.seconds as i128) - (other.seconds as i128)) * (Self::NanosecondsPerSecond as i128) + ((self.nanoseconds as i128) - (other.nanoseconds as i128));
+		
+		if unlikely!(difference > (i64::max_value() as i128))
+		{
+			i64::max_value()
+		}
+		else if unlikely!(difference < (i64::min_value() as i128))
+		{
+			i64::min_value()
+		}
+		else
+		{
+			difference as i64
+		}
+	}
+	
+	/// Is this a valid timestamp, ie are the nanoseconds less than one second?
+	#[inline(always)]
+	pub fn is_valid(self) -> bool
+	{
+		(self.nanoseconds as u64) < Self::NanosecondsPerSecond && self.seconds < (1 << 48)
+	}
+}
@@ -0,0 +1,46 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A bridge identifier, as it appears on the wire in a Bridge Protocol Data Unit (BPDU).
+///
+/// A lower bridge identifier is a higher priority.
+///
+/// IEEE Std 802.1D-2004, Section 9.2.5.
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct BridgeIdentifier
+{
+	/// Bridge priority; the least significant 12 bits are always zero for the Rapid Spanning Tree Protocol (RSTP).
+	pub priority: NetworkEndianU16,
+
+	/// Bridge address; an ethernet address of the bridge.
+	pub address: MediaAccessControlAddress,
+}
+
+impl Display for BridgeIdentifier
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl BridgeIdentifier
+{
+	/// Bridge priorities must be a multiple of this value.
+	pub const PriorityStep: u16 = 4096;
+
+	/// Creates a new instance; `priority` is rounded down to a multiple of `PriorityStep`.
+	#[inline(always)]
+	pub fn new(priority: u16, address: MediaAccessControlAddress) -> Self
+	{
+		Self
+		{
+			priority: NetworkEndianU16::from_native_endian(priority & 0xF000),
+			address,
+		}
+	}
+}
@@ -0,0 +1,267 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Bridge Protocol Data Unit (BPDU), preceded by its IEEE 802.2 Logical Link Control (LLC) header.
+///
+/// This is the payload of a legacy (length rather than ether type) ethernet frame sent to `MediaAccessControlAddress::BridgeGroupAddress`.
+///
+/// Only the first 4 bytes after the LLC header are present in a Topology Change Notification BPDU and only the first 35 bytes in a Configuration BPDU; use `validate()` before accessing other fields.
+///
+/// IEEE Std 802.1D-2004, Section 9.3.
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct BridgeProtocolDataUnit
+{
+	/// LLC destination service access point; always `BridgeProtocolDataUnit::ServiceAccessPoint`.
+	pub destination_service_access_point: u8,
+
+	/// LLC source service access point; always `BridgeProtocolDataUnit::ServiceAccessPoint`.
+	pub source_service_access_point: u8,
+
+	/// LLC control; always `BridgeProtocolDataUnit::UnnumberedInformationControl`.
+	pub control: u8,
+
+	/// Protocol identifier; always zero.
+	pub protocol_identifier: NetworkEndianU16,
+
+	/// Protocol version identifier.
+	pub protocol_version_identifier: u8,
+
+	/// BPDU type.
+	pub bridge_protocol_data_unit_type: u8,
+
+	/// Flags.
+	pub flags: BridgeProtocolDataUnitFlags,
+
+	/// Root bridge identifier.
+	pub root_bridge_identifier: BridgeIdentifier,
+
+	/// Root path cost.
+	pub root_path_cost: NetworkEndianU32,
+
+	/// Bridge identifier of the transmitting bridge.
+	pub bridge_identifier: BridgeIdentifier,
+
+	/// Port identifier of the transmitting port.
+	pub port_identifier: NetworkEndianU16,
+
+	/// Message age, in units of 1/256th of a second.
+	pub message_age: NetworkEndianU16,
+
+	/// Maximum age, in units of 1/256th of a second.
+	pub maximum_age: NetworkEndianU16,
+
+	/// Hello time, in units of 1/256th of a second.
+	pub hello_time: NetworkEndianU16,
+
+	/// Forward delay, in units of 1/256th of a second.
+	pub forward_delay: NetworkEndianU16,
+
+	/// Version 1 length; always zero.
+	pub version_1_length: u8,
+}
+
+impl Debug for BridgeProtocolDataUnit
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "BridgeProtocolDataUnit {{ protocol_version_identifier: {}, bridge_protocol_data_unit_type: 0x{:02X}, flags: {}, root_bridge_identifier: {:?}, root_path_cost: {}, bridge_identifier: {:?}, port_identifier: {} }}", self.protocol_version_identifier, self.bridge_protocol_data_unit_type, self.flags, self.root_bridge_identifier, self.root_path_cost.to_native_endian(), self.bridge_identifier, self.port_identifier.to_native_endian())
+	}
+}
+
+impl Display for BridgeProtocolDataUnit
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl BridgeProtocolDataUnit
+{
+	/// IEEE 802.2 Logical Link Control (LLC) service access point for the Spanning Tree Protocol.
+	pub const ServiceAccessPoint: u8 = 0x42;
+
+	/// IEEE 802.2 Logical Link Control (LLC) unnumbered information (UI) control.
+	pub const UnnumberedInformationControl: u8 = 0x03;
+
+	/// Size of the Logical Link Control (LLC) header.
+	pub const LogicalLinkControlHeaderSizeU16: u16 = 3;
+
+	/// Minimum size of a Topology Change Notification BPDU, including the Logical Link Control (LLC) header.
+	pub const TopologyChangeNotificationSizeU16: u16 = Self::LogicalLinkControlHeaderSizeU16 + 4;
+
+	/// Minimum size of a Configuration BPDU, including the Logical Link Control (LLC) header.
+	pub const ConfigurationSizeU16: u16 = Self::LogicalLinkControlHeaderSizeU16 + 35;
+
+	/// Minimum size of a Rapid Spanning Tree BPDU, including the Logical Link Control (LLC) header.
+	pub const RapidSpanningTreeSizeU16: u16 = Self::LogicalLinkControlHeaderSizeU16 + 36;
+
+	/// Spanning Tree Protocol (STP) protocol version identifier.
+	pub const SpanningTreeProtocolVersion: u8 = 0;
+
+	/// Rapid Spanning Tree Protocol (RSTP) protocol version identifier.
+	pub const RapidSpanningTreeProtocolVersion: u8 = 2;
+
+	const ConfigurationType: u8 = 0x00;
+
+	const TopologyChangeNotificationType: u8 = 0x80;
+
+	const RapidSpanningTreeType: u8 = 0x02;
+
+	/// Validates a received BPDU of `length` bytes (including the Logical Link Control (LLC) header).
+	///
+	/// IEEE Std 802.1D-2004, Section 9.3.4; the check for a Configuration BPDU transmitted by the receiving port is made by the receiving port.
+	#[inline(always)]
+	pub fn validate(&self, length: u16) -> Result<BridgeProtocolDataUnitKind, ()>
+	{
+		use self::BridgeProtocolDataUnitKind::*;
+
+		if unlikely!(length < Self::TopologyChangeNotificationSizeU16)
+		{
+			return Err(())
+		}
+
+		if unlikely!(self.destination_service_access_point != Self::ServiceAccessPoint || self.source_service_access_point != Self::ServiceAccessPoint || self.control != Self::UnnumberedInformationControl)
+		{
+			return Err(())
+		}
+
+		if unlikely!(self.protocol_identifier.is_not_zero())
+		{
+			return Err(())
+		}
+
+		match self.bridge_protocol_data_unit_type
+		{
+			Self::ConfigurationType if length >= Self::ConfigurationSizeU16 =>
+			{
+				if unlikely!(self.message_age >= self.maximum_age)
+				{
+					Err(())
+				}
+				else
+				{
+					Ok(Configuration)
+				}
+			}
+
+			Self::TopologyChangeNotificationType => Ok(TopologyChangeNotification),
+
+			Self::RapidSpanningTreeType if self.protocol_version_identifier >= Self::RapidSpanningTreeProtocolVersion && length >= Self::RapidSpanningTreeSizeU16 => Ok(RapidSpanningTree),
+
+			_ => Err(()),
+		}
+	}
+
+	/// Creates a new Configuration or Rapid Spanning Tree BPDU suitable for transmission; returns it and its length.
+	#[inline(always)]
+	pub fn new(rapid: bool, flags: BridgeProtocolDataUnitFlags, priority_vector: &SpanningTreePriorityVector, times: &SpanningTreeTimes) -> (Self, u16)
+	{
+		let (protocol_version_identifier, bridge_protocol_data_unit_type, length) = if rapid
+		{
+			(Self::RapidSpanningTreeProtocolVersion, Self::RapidSpanningTreeType, Self::RapidSpanningTreeSizeU16)
+		}
+		else
+		{
+			(Self::SpanningTreeProtocolVersion, Self::ConfigurationType, Self::ConfigurationSizeU16)
+		};
+
+		let this = Self
+		{
+			destination_service_access_point: Self::ServiceAccessPoint,
+			source_service_access_point: Self::ServiceAccessPoint,
+			control: Self::UnnumberedInformationControl,
+			protocol_identifier: NetworkEndianU16::Zero,
+			protocol_version_identifier,
+			bridge_protocol_data_unit_type,
+			flags,
+			root_bridge_identifier: priority_vector.root_bridge_identifier,
+			root_path_cost: NetworkEndianU32::from_native_endian(priority_vector.root_path_cost),
+			bridge_identifier: priority_vector.designated_bridge_identifier,
+			port_identifier: NetworkEndianU16::from_native_endian(priority_vector.designated_port_identifier),
+			message_age: Self::encode_time(times.message_age),
+			maximum_age: Self::encode_time(times.maximum_age),
+			hello_time: Self::encode_time(times.hello_time),
+			forward_delay: Self::encode_time(times.forward_delay),
+			version_1_length: 0,
+		};
+
+		(this, length)
+	}
+
+	/// Creates a new Topology Change Notification BPDU suitable for transmission; returns it and its length.
+	///
+	/// Fields after the BPDU type are zero and are not transmitted.
+	#[inline(always)]
+	pub fn new_topology_change_notification() -> (Self, u16)
+	{
+		let zero_bridge_identifier = BridgeIdentifier::new(0, MediaAccessControlAddress::Zero);
+
+		let this = Self
+		{
+			destination_service_access_point: Self::ServiceAccessPoint,
+			source_service_access_point: Self::ServiceAccessPoint,
+			control: Self::UnnumberedInformationControl,
+			protocol_identifier: NetworkEndianU16::Zero,
+			protocol_version_identifier: Self::SpanningTreeProtocolVersion,
+			bridge_protocol_data_unit_type: Self::TopologyChangeNotificationType,
+			flags: BridgeProtocolDataUnitFlags::default(),
+			root_bridge_identifier: zero_bridge_identifier,
+			root_path_cost: NetworkEndianU32::Zero,
+			bridge_identifier: zero_bridge_identifier,
+			port_identifier: NetworkEndianU16::Zero,
+			message_age: NetworkEndianU16::Zero,
+			maximum_age: NetworkEndianU16::Zero,
+			hello_time: NetworkEndianU16::Zero,
+			forward_delay: NetworkEndianU16::Zero,
+			version_1_length: 0,
+		};
+
+		(this, Self::TopologyChangeNotificationSizeU16)
+	}
+
+	/// Message priority vector, as received on the port with identifier `bridge_port_identifier`.
+	#[inline(always)]
+	pub fn message_priority_vector(&self, bridge_port_identifier: u16) -> SpanningTreePriorityVector
+	{
+		SpanningTreePriorityVector
+		{
+			root_bridge_identifier: self.root_bridge_identifier,
+			root_path_cost: self.root_path_cost.to_native_endian(),
+			designated_bridge_identifier: self.bridge_identifier,
+			designated_port_identifier: self.port_identifier.to_native_endian(),
+			bridge_port_identifier,
+		}
+	}
+
+	/// Message times.
+	#[inline(always)]
+	pub fn message_times(&self) -> SpanningTreeTimes
+	{
+		SpanningTreeTimes
+		{
+			message_age: Self::decode_time(self.message_age),
+			maximum_age: Self::decode_time(self.maximum_age),
+			hello_time: Self::decode_time(self.hello_time),
+			forward_delay: Self::decode_time(self.forward_delay),
+		}
+	}
+
+	#[inline(always)]
+	fn decode_time(time: NetworkEndianU16) -> MillisecondDuration
+	{
+		MillisecondDuration::from_milliseconds((time.to_native_endian() as u64) * 1000 / 256)
+	}
+
+	#[inline(always)]
+	fn encode_time(time: MillisecondDuration) -> NetworkEndianU16
+	{
+		let milliseconds: u64 = time.into();
+		let encoded = milliseconds * 256 / 1000;
+		NetworkEndianU16::from_native_endian(if encoded > 0xFFFF { 0xFFFF } else { encoded as u16 })
+	}
+}
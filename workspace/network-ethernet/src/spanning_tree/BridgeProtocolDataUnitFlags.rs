@@ -0,0 +1,131 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Bridge Protocol Data Unit (BPDU) flags, as a bit set.
+///
+/// IEEE Std 802.1D-2004, Section 9.3.3; only `TopologyChange` and `TopologyChangeAcknowledgment` are used in Configuration BPDUs.
+#[repr(C, packed)]
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct BridgeProtocolDataUnitFlags(u8);
+
+impl Display for BridgeProtocolDataUnitFlags
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "0x{:02X}", self.0)
+	}
+}
+
+impl Into<u8> for BridgeProtocolDataUnitFlags
+{
+	#[inline(always)]
+	fn into(self) -> u8
+	{
+		self.0
+	}
+}
+
+impl From<u8> for BridgeProtocolDataUnitFlags
+{
+	#[inline(always)]
+	fn from(value: u8) -> Self
+	{
+		BridgeProtocolDataUnitFlags(value)
+	}
+}
+
+impl BridgeProtocolDataUnitFlags
+{
+	/// Topology change.
+	pub const TopologyChange: u8 = 0x01;
+
+	/// Proposal.
+	pub const Proposal: u8 = 0x02;
+
+	/// Learning.
+	pub const Learning: u8 = 0x10;
+
+	/// Forwarding.
+	pub const Forwarding: u8 = 0x20;
+
+	/// Agreement.
+	pub const Agreement: u8 = 0x40;
+
+	/// Topology change acknowledgment.
+	pub const TopologyChangeAcknowledgment: u8 = 0x80;
+
+	const PortRoleMask: u8 = 0x0C;
+
+	const PortRoleShift: u8 = 2;
+
+	const PortRoleUnknown: u8 = 0;
+
+	const PortRoleAlternateOrBackup: u8 = 1;
+
+	const PortRoleRoot: u8 = 2;
+
+	const PortRoleDesignated: u8 = 3;
+
+	/// Is `flag` set?
+	#[inline(always)]
+	pub fn is(self, flag: u8) -> bool
+	{
+		self.0 & flag != 0
+	}
+
+	/// Is `flag` clear?
+	#[inline(always)]
+	pub fn is_not(self, flag: u8) -> bool
+	{
+		self.0 & flag == 0
+	}
+
+	/// Set or clear `flag`.
+	#[inline(always)]
+	pub fn set(&mut self, flag: u8, value: bool)
+	{
+		if value
+		{
+			self.0 |= flag
+		}
+		else
+		{
+			self.0 &= !flag
+		}
+	}
+
+	/// Port role of the transmitting port.
+	///
+	/// Returns `None` if the role is unknown; `Alternate` is returned for both alternate and backup ports.
+	#[inline(always)]
+	pub fn port_role(self) -> Option<SpanningTreePortRole>
+	{
+		match (self.0 & Self::PortRoleMask) >> Self::PortRoleShift
+		{
+			Self::PortRoleAlternateOrBackup => Some(SpanningTreePortRole::Alternate),
+			Self::PortRoleRoot => Some(SpanningTreePortRole::Root),
+			Self::PortRoleDesignated => Some(SpanningTreePortRole::Designated),
+			_ => None,
+		}
+	}
+
+	/// Set port role of the transmitting port.
+	#[inline(always)]
+	pub fn set_port_role(&mut self, port_role: SpanningTreePortRole)
+	{
+		use self::SpanningTreePortRole::*;
+
+		let encoded = match port_role
+		{
+			Disabled => Self::PortRoleUnknown,
+			Root => Self::PortRoleRoot,
+			Designated => Self::PortRoleDesignated,
+			Alternate | Backup => Self::PortRoleAlternateOrBackup,
+		};
+
+		self.0 = (self.0 & !Self::PortRoleMask) | (encoded << Self::PortRoleShift)
+	}
+}
@@ -0,0 +1,29 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Kind of a validated Bridge Protocol Data Unit (BPDU).
+///
+/// IEEE Std 802.1D-2004, Section 9.3.4.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum BridgeProtocolDataUnitKind
+{
+	/// A Spanning Tree Protocol (STP) Configuration BPDU.
+	Configuration,
+
+	/// A Spanning Tree Protocol (STP) Topology Change Notification (TCN) BPDU.
+	TopologyChangeNotification,
+
+	/// A Rapid Spanning Tree Protocol (RSTP) BPDU.
+	RapidSpanningTree,
+}
+
+impl Display for BridgeProtocolDataUnitKind
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
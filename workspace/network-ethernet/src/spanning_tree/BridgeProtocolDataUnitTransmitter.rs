@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Transmits Bridge Protocol Data Units (BPDUs) generated by a `SpanningTreeBridge`.
+pub trait BridgeProtocolDataUnitTransmitter: Debug
+{
+	/// Implement this to transmit the first `length` bytes of `bridge_protocol_data_unit` out of the bridge port `port_number`.
+	///
+	/// `length` includes the IEEE 802.2 Logical Link Control (LLC) header at the start of `bridge_protocol_data_unit`.
+	///
+	/// The frame should have a destination address of `MediaAccessControlAddress::BridgeGroupAddress`, a source address of the bridge port's own ethernet address and a legacy ethernet frame size of `length`; it is never Virtual LAN tagged.
+	#[inline(always)]
+	fn transmit(&self, port_number: u16, bridge_protocol_data_unit: &BridgeProtocolDataUnit, length: u16);
+}
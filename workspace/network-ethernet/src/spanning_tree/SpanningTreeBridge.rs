@@ -0,0 +1,226 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A bridge participating in a (common) spanning tree using the Rapid Spanning Tree Protocol (RSTP), interoperating with bridges using the legacy Spanning Tree Protocol (STP).
+///
+/// The spanning tree is driven by calling `poll()` regularly (at least several times a second) and by passing received Bridge Protocol Data Units (BPDUs) to `process_bridge_protocol_data_unit()`; normally this is done by a `LearningBridge`.
+///
+/// IEEE Std 802.1D-2004, Section 17.
+#[derive(Debug)]
+pub struct SpanningTreeBridge
+{
+	bridge_identifier: BridgeIdentifier,
+	bridge_times: SpanningTreeTimes,
+	ports: RefCell<Vec<SpanningTreePort>>,
+	root_priority_vector: RefCell<SpanningTreePriorityVector>,
+	root_times: RefCell<SpanningTreeTimes>,
+	root_port_number: RefCell<Option<u16>>,
+}
+
+impl SpanningTreeBridge
+{
+	#[inline(always)]
+	pub(crate) fn new(bridge_identifier: BridgeIdentifier, bridge_times: SpanningTreeTimes, ports: Vec<SpanningTreePort>) -> Self
+	{
+		Self
+		{
+			bridge_identifier,
+			bridge_times,
+			ports: RefCell::new(ports),
+			root_priority_vector: RefCell::new(SpanningTreePriorityVector::bridge_priority_vector(bridge_identifier)),
+			root_times: RefCell::new(bridge_times),
+			root_port_number: RefCell::new(None),
+		}
+	}
+
+	/// Bridge identifier.
+	#[inline(always)]
+	pub fn bridge_identifier(&self) -> BridgeIdentifier
+	{
+		self.bridge_identifier
+	}
+
+	/// Root bridge identifier.
+	#[inline(always)]
+	pub fn root_bridge_identifier(&self) -> BridgeIdentifier
+	{
+		self.root_priority_vector.borrow().root_bridge_identifier
+	}
+
+	/// Is this bridge the root bridge?
+	#[inline(always)]
+	pub fn is_root_bridge(&self) -> bool
+	{
+		self.root_port_number.borrow().is_none()
+	}
+
+	/// Port number of the root port, if any.
+	#[inline(always)]
+	pub fn root_port_number(&self) -> Option<u16>
+	{
+		*self.root_port_number.borrow()
+	}
+
+	/// Is `port_number` a participating port?
+	#[inline(always)]
+	pub fn is_participating(&self, port_number: u16) -> bool
+	{
+		self.ports.borrow().iter().any(|port| port.port_number() == port_number)
+	}
+
+	/// Role of a participating port.
+	#[inline(always)]
+	pub fn port_role(&self, port_number: u16) -> Option<SpanningTreePortRole>
+	{
+		self.ports.borrow().iter().find(|port| port.port_number() == port_number).map(|port| port.role())
+	}
+
+	/// State of a participating port.
+	#[inline(always)]
+	pub fn port_state(&self, port_number: u16) -> Option<SpanningTreePortState>
+	{
+		self.ports.borrow().iter().find(|port| port.port_number() == port_number).map(|port| port.state())
+	}
+
+	#[inline(always)]
+	pub(crate) fn link_up(&self, now: MonotonicMillisecondTimestamp, port_number: u16) -> SpanningTreeChanges
+	{
+		self.with_port(port_number, |port| port.enable());
+		self.update_roles_and_states(now)
+	}
+
+	#[inline(always)]
+	pub(crate) fn link_down(&self, now: MonotonicMillisecondTimestamp, port_number: u16) -> SpanningTreeChanges
+	{
+		self.with_port(port_number, |port| port.disable());
+		self.update_roles_and_states(now)
+	}
+
+	/// Any response is transmitted on the next call to `poll()`.
+	#[inline(always)]
+	pub(crate) fn process_bridge_protocol_data_unit(&self, now: MonotonicMillisecondTimestamp, port_number: u16, bridge_protocol_data_unit: &BridgeProtocolDataUnit, kind: BridgeProtocolDataUnitKind) -> SpanningTreeChanges
+	{
+		let bridge_identifier = self.bridge_identifier;
+		let mut topology_change_received = false;
+		self.with_port(port_number, |port| topology_change_received = port.receive(now, bridge_protocol_data_unit, kind, &bridge_identifier));
+
+		let mut changes = self.update_roles_and_states(now);
+
+		if topology_change_received
+		{
+			// Propagate to all other ports (IEEE Std 802.1D-2004, Section 17.21.18, `setTcPropTree`).
+			let root_times = *self.root_times.borrow();
+			for port in self.ports.borrow_mut().iter_mut().filter(|port| port.port_number() != port_number)
+			{
+				port.start_propagating_topology_change(now, &root_times)
+			}
+			changes.topology_changed = true;
+		}
+
+		changes
+	}
+
+	#[inline(always)]
+	pub(crate) fn poll(&self, now: MonotonicMillisecondTimestamp, transmitter: &impl BridgeProtocolDataUnitTransmitter) -> SpanningTreeChanges
+	{
+		for port in self.ports.borrow_mut().iter_mut()
+		{
+			port.expire_received_information(now);
+			port.expire_topology_change(now);
+		}
+
+		let changes = self.update_roles_and_states(now);
+
+		let root_times = *self.root_times.borrow();
+		for port in self.ports.borrow_mut().iter_mut()
+		{
+			port.transmit_if_needed(now, &root_times, transmitter)
+		}
+
+		changes
+	}
+
+	#[inline(always)]
+	fn update_roles_and_states(&self, now: MonotonicMillisecondTimestamp) -> SpanningTreeChanges
+	{
+		let mut ports = self.ports.borrow_mut();
+
+		// Port Role Selection (IEEE Std 802.1D-2004, Section 17.21.25, `updtRolesTree`).
+		let mut root_priority_vector = SpanningTreePriorityVector::bridge_priority_vector(self.bridge_identifier);
+		let mut root_times = self.bridge_times;
+		let mut root_port_number = None;
+		for port in ports.iter().filter(|port| port.is_enabled() && port.has_received_information())
+		{
+			// Information transmitted by this bridge on another port.
+			if port.port_priority_vector().designated_bridge_identifier.address == self.bridge_identifier.address
+			{
+				continue
+			}
+
+			let root_path_priority_vector = port.root_path_priority_vector();
+			if root_path_priority_vector < root_priority_vector
+			{
+				root_priority_vector = root_path_priority_vector;
+				root_times = port.port_times().aged();
+				root_port_number = Some(port.port_number());
+			}
+		}
+
+		for port in ports.iter_mut()
+		{
+			port.update_role(&root_priority_vector, &root_times, root_port_number, self.bridge_identifier)
+		}
+
+		*self.root_priority_vector.borrow_mut() = root_priority_vector;
+		*self.root_times.borrow_mut() = root_times;
+		*self.root_port_number.borrow_mut() = root_port_number;
+
+		let mut changes = SpanningTreeChanges::default();
+
+		// A proposal received on the root port is agreed to once all other designated ports are synchronised (discarding or agreed).
+		if ports.iter().any(|port| port.is_proposed_and_not_yet_agreed())
+		{
+			for port in ports.iter_mut()
+			{
+				changes.port_states_changed |= port.synchronise()
+			}
+
+			for port in ports.iter_mut().filter(|port| port.is_proposed_and_not_yet_agreed())
+			{
+				port.agree_to_proposal()
+			}
+		}
+
+		let forward_delay = root_times.forward_delay;
+		for port in ports.iter_mut()
+		{
+			let previous_state = port.state();
+			if port.run_role_and_state_transitions(now, forward_delay)
+			{
+				changes.topology_changed = true;
+			}
+			changes.port_states_changed |= previous_state != port.state();
+		}
+
+		// Topology Change Detection (IEEE Std 802.1D-2004, Section 17.31).
+		if changes.topology_changed
+		{
+			for port in ports.iter_mut()
+			{
+				port.start_propagating_topology_change(now, &root_times)
+			}
+		}
+
+		changes
+	}
+
+	#[inline(always)]
+	fn with_port(&self, port_number: u16, callback: impl FnOnce(&mut SpanningTreePort))
+	{
+		if let Some(port) = self.ports.borrow_mut().iter_mut().find(|port| port.port_number() == port_number)
+		{
+			callback(port)
+		}
+	}
+}
@@ -0,0 +1,78 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Spanning tree bridge configuration.
+#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct SpanningTreeBridgeConfiguration
+{
+	/// Bridge priority; a lower value is a higher priority; rounded down to a multiple of `BridgeIdentifier::PriorityStep`.
+	#[serde(default = "SpanningTreeBridgeConfiguration::bridge_priority_default")] pub bridge_priority: u16,
+
+	/// Hello time used when this bridge is the root bridge.
+	#[serde(default = "SpanningTreeBridgeConfiguration::hello_time_default")] pub hello_time: MillisecondDuration,
+
+	/// Maximum age used when this bridge is the root bridge.
+	#[serde(default = "SpanningTreeBridgeConfiguration::maximum_age_default")] pub maximum_age: MillisecondDuration,
+
+	/// Forward delay used when this bridge is the root bridge.
+	#[serde(default = "SpanningTreeBridgeConfiguration::forward_delay_default")] pub forward_delay: MillisecondDuration,
+
+	/// Participating ports.
+	///
+	/// Bridge ports that are not participating are always forwarding.
+	pub ports: Vec<SpanningTreePortConfiguration>,
+}
+
+impl SpanningTreeBridgeConfiguration
+{
+	/// Default bridge priority.
+	pub const DefaultBridgePriority: u16 = 0x8000;
+
+	/// Configure.
+	///
+	/// `bridge_address` is the ethernet address used to identify this bridge; it is normally the lowest ethernet address of the bridge ports.
+	#[inline(always)]
+	pub fn configure(self, bridge_address: MediaAccessControlAddress) -> SpanningTreeBridge
+	{
+		let bridge_identifier = BridgeIdentifier::new(self.bridge_priority, bridge_address);
+
+		let bridge_times = SpanningTreeTimes
+		{
+			message_age: MillisecondDuration::Zero,
+			maximum_age: self.maximum_age,
+			hello_time: self.hello_time,
+			forward_delay: self.forward_delay,
+		};
+
+		let ports = self.ports.iter().map(|port_configuration| SpanningTreePort::new(port_configuration.port_number, port_configuration.port_priority, port_configuration.path_cost, port_configuration.edge, bridge_identifier, bridge_times)).collect();
+
+		SpanningTreeBridge::new(bridge_identifier, bridge_times, ports)
+	}
+
+	#[inline(always)]
+	fn bridge_priority_default() -> u16
+	{
+		Self::DefaultBridgePriority
+	}
+
+	/// IEEE Std 802.1D-2004, Section 17.14, Table 17-1.
+	#[inline(always)]
+	fn hello_time_default() -> MillisecondDuration
+	{
+		MillisecondDuration::from_seconds(2)
+	}
+
+	#[inline(always)]
+	fn maximum_age_default() -> MillisecondDuration
+	{
+		MillisecondDuration::from_seconds(20)
+	}
+
+	#[inline(always)]
+	fn forward_delay_default() -> MillisecondDuration
+	{
+		MillisecondDuration::FifteenSeconds
+	}
+}
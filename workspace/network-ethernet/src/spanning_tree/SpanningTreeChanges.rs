@@ -0,0 +1,14 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Changes caused by running the spanning tree state machines that a bridge needs to act upon.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub(crate) struct SpanningTreeChanges
+{
+	/// The state of at least one port changed.
+	pub(crate) port_states_changed: bool,
+
+	/// A topology change was detected or received; learned entries should be flushed.
+	pub(crate) topology_changed: bool,
+}
@@ -0,0 +1,619 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A port of a spanning tree bridge, with its port information, role transition, state transition, topology change and transmission state.
+///
+/// This is a simplification of the Rapid Spanning Tree Protocol (RSTP) state machines of IEEE Std 802.1D-2004, Section 17: all links are treated as point-to-point, so the proposal and agreement mechanism is always used; otherwise ports advance through learning to forwarding after the forward delay.
+#[derive(Debug)]
+pub struct SpanningTreePort
+{
+	port_number: u16,
+	port_identifier: u16,
+	path_cost: u32,
+	administrative_edge: bool,
+	operational_edge: bool,
+	enabled: bool,
+	role: SpanningTreePortRole,
+	state: SpanningTreePortState,
+	information_origin: SpanningTreePortInformationOrigin,
+	port_priority_vector: SpanningTreePriorityVector,
+	port_times: SpanningTreeTimes,
+	designated_priority_vector: SpanningTreePriorityVector,
+	designated_times: SpanningTreeTimes,
+	received_information_expires_at: Option<MonotonicMillisecondTimestamp>,
+	forward_delay_expires_at: Option<MonotonicMillisecondTimestamp>,
+	topology_change_expires_at: Option<MonotonicMillisecondTimestamp>,
+	hello_expires_at: Option<MonotonicMillisecondTimestamp>,
+	proposing: bool,
+	proposed: bool,
+	agree: bool,
+	agreed: bool,
+	send_topology_change_acknowledgment: bool,
+	send_rapid_spanning_tree: bool,
+	need_to_transmit: bool,
+	transmissions_in_current_interval: u8,
+	transmission_interval_started_at: MonotonicMillisecondTimestamp,
+}
+
+impl SpanningTreePort
+{
+	/// Bits of a port identifier that are the port number; the remaining (most significant) bits are the port priority.
+	pub const PortNumberMask: u16 = 0x0FFF;
+
+	/// No more than this number of Bridge Protocol Data Units (BPDUs) are transmitted in any one second interval (`TxHoldCount`).
+	///
+	/// IEEE Std 802.1D-2004, Section 17.13.12.
+	pub const TransmitHoldCount: u8 = 6;
+
+	const TransmitHoldInterval: MillisecondDuration = MillisecondDuration::OneSecond;
+
+	#[inline(always)]
+	pub(crate) fn new(port_number: u16, port_priority: u8, path_cost: u32, administrative_edge: bool, bridge_identifier: BridgeIdentifier, bridge_times: SpanningTreeTimes) -> Self
+	{
+		Self
+		{
+			port_number,
+			port_identifier: ((port_priority as u16 & 0xF0) << 8) | (port_number & Self::PortNumberMask),
+			path_cost,
+			administrative_edge,
+			operational_edge: administrative_edge,
+			enabled: false,
+			role: SpanningTreePortRole::Disabled,
+			state: SpanningTreePortState::Discarding,
+			information_origin: SpanningTreePortInformationOrigin::Disabled,
+			port_priority_vector: SpanningTreePriorityVector::bridge_priority_vector(bridge_identifier),
+			port_times: bridge_times,
+			designated_priority_vector: SpanningTreePriorityVector::bridge_priority_vector(bridge_identifier),
+			designated_times: bridge_times,
+			received_information_expires_at: None,
+			forward_delay_expires_at: None,
+			topology_change_expires_at: None,
+			hello_expires_at: None,
+			proposing: false,
+			proposed: false,
+			agree: false,
+			agreed: false,
+			send_topology_change_acknowledgment: false,
+			send_rapid_spanning_tree: true,
+			need_to_transmit: false,
+			transmissions_in_current_interval: 0,
+			transmission_interval_started_at: MonotonicMillisecondTimestamp::Zero,
+		}
+	}
+
+	/// Port number.
+	#[inline(always)]
+	pub fn port_number(&self) -> u16
+	{
+		self.port_number
+	}
+
+	/// Port identifier (port priority and port number).
+	#[inline(always)]
+	pub fn port_identifier(&self) -> u16
+	{
+		self.port_identifier
+	}
+
+	/// Role.
+	#[inline(always)]
+	pub fn role(&self) -> SpanningTreePortRole
+	{
+		self.role
+	}
+
+	/// State.
+	#[inline(always)]
+	pub fn state(&self) -> SpanningTreePortState
+	{
+		self.state
+	}
+
+	/// Origin of the port priority vector.
+	#[inline(always)]
+	pub fn information_origin(&self) -> SpanningTreePortInformationOrigin
+	{
+		self.information_origin
+	}
+
+	/// Port priority vector.
+	#[inline(always)]
+	pub fn port_priority_vector(&self) -> &SpanningTreePriorityVector
+	{
+		&self.port_priority_vector
+	}
+
+	/// Is this port operating as an edge port (ie no bridges are attached to it)?
+	#[inline(always)]
+	pub fn is_operational_edge(&self) -> bool
+	{
+		self.operational_edge
+	}
+
+	/// Is the link partner using the Rapid Spanning Tree Protocol (RSTP) rather than the legacy Spanning Tree Protocol (STP)?
+	#[inline(always)]
+	pub fn is_sending_rapid_spanning_tree(&self) -> bool
+	{
+		self.send_rapid_spanning_tree
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_enabled(&self) -> bool
+	{
+		self.enabled
+	}
+
+	#[inline(always)]
+	pub(crate) fn has_received_information(&self) -> bool
+	{
+		self.information_origin == SpanningTreePortInformationOrigin::Received
+	}
+
+	#[inline(always)]
+	pub(crate) fn root_path_priority_vector(&self) -> SpanningTreePriorityVector
+	{
+		self.port_priority_vector.root_path_priority_vector(self.path_cost, self.port_identifier)
+	}
+
+	#[inline(always)]
+	pub(crate) fn port_times(&self) -> &SpanningTreeTimes
+	{
+		&self.port_times
+	}
+
+	#[inline(always)]
+	pub(crate) fn enable(&mut self)
+	{
+		if self.enabled
+		{
+			return
+		}
+
+		self.enabled = true;
+		self.operational_edge = self.administrative_edge;
+		self.send_rapid_spanning_tree = true;
+		self.information_origin = SpanningTreePortInformationOrigin::Aged;
+	}
+
+	#[inline(always)]
+	pub(crate) fn disable(&mut self)
+	{
+		if !self.enabled
+		{
+			return
+		}
+
+		self.enabled = false;
+		self.information_origin = SpanningTreePortInformationOrigin::Disabled;
+		self.received_information_expires_at = None;
+		self.topology_change_expires_at = None;
+		self.hello_expires_at = None;
+		self.need_to_transmit = false;
+		self.send_topology_change_acknowledgment = false;
+	}
+
+	/// Returns `true` if the received BPDU carried a topology change (or was a Topology Change Notification).
+	///
+	/// IEEE Std 802.1D-2004, Sections 17.21.8 (`rcvInfo`), 17.21.11 (`recordAgreement`), 17.21.12 (`recordProposal`) and 17.21.17 (`setTcFlags`).
+	#[inline(always)]
+	pub(crate) fn receive(&mut self, now: MonotonicMillisecondTimestamp, bridge_protocol_data_unit: &BridgeProtocolDataUnit, kind: BridgeProtocolDataUnitKind, bridge_identifier: &BridgeIdentifier) -> bool
+	{
+		use self::BridgeProtocolDataUnitKind::*;
+		use self::SpanningTreePortRole::*;
+		type Flags = BridgeProtocolDataUnitFlags;
+
+		if unlikely!(!self.enabled)
+		{
+			return false
+		}
+
+		// A BPDU on an edge port means a bridge is attached after all.
+		self.operational_edge = false;
+
+		self.send_rapid_spanning_tree = kind == RapidSpanningTree;
+
+		if kind == TopologyChangeNotification
+		{
+			if self.role == Designated
+			{
+				self.send_topology_change_acknowledgment = true;
+				self.need_to_transmit = true;
+			}
+			return true
+		}
+
+		// A BPDU transmitted by this port and looped back to it.
+		if unlikely!(&bridge_protocol_data_unit.bridge_identifier == bridge_identifier && bridge_protocol_data_unit.port_identifier.to_native_endian() == self.port_identifier)
+		{
+			return false
+		}
+
+		let flags = bridge_protocol_data_unit.flags;
+
+		if flags.is(Flags::TopologyChangeAcknowledgment)
+		{
+			self.topology_change_expires_at = None
+		}
+
+		let message_role = if kind == Configuration
+		{
+			Designated
+		}
+		else
+		{
+			match flags.port_role()
+			{
+				None => return false,
+				Some(message_role) => message_role,
+			}
+		};
+
+		let message_priority_vector = bridge_protocol_data_unit.message_priority_vector(self.port_identifier);
+		let message_times = bridge_protocol_data_unit.message_times();
+
+		match message_role
+		{
+			Designated =>
+			{
+				if message_priority_vector.is_superior_to(&self.port_priority_vector)
+				{
+					if message_priority_vector != self.port_priority_vector
+					{
+						self.agreed = false;
+						self.agree = false;
+						self.proposing = false;
+					}
+
+					self.port_priority_vector = message_priority_vector;
+					self.port_times = message_times;
+					self.information_origin = SpanningTreePortInformationOrigin::Received;
+					self.received_information_expires_at = Some(now + message_times.received_information_lifetime());
+					self.proposed = kind == RapidSpanningTree && flags.is(Flags::Proposal);
+				}
+				else
+				{
+					// Inferior designated information; our information for this LAN is better and the sender needs to know about it.
+					self.need_to_transmit = true;
+				}
+			}
+
+			_ =>
+			{
+				if self.role == Designated && flags.is(Flags::Agreement) && self.port_priority_vector.root_bridge_identifier == message_priority_vector.root_bridge_identifier
+				{
+					self.agreed = true;
+					self.proposing = false;
+				}
+			}
+		}
+
+		flags.is(Flags::TopologyChange) && self.role != Alternate && self.role != Backup
+	}
+
+	#[inline(always)]
+	pub(crate) fn expire_received_information(&mut self, now: MonotonicMillisecondTimestamp)
+	{
+		if let Some(expires_at) = self.received_information_expires_at
+		{
+			if now >= expires_at
+			{
+				self.received_information_expires_at = None;
+				if self.information_origin == SpanningTreePortInformationOrigin::Received
+				{
+					self.information_origin = SpanningTreePortInformationOrigin::Aged;
+					self.proposed = false;
+					self.agreed = false;
+				}
+			}
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn expire_topology_change(&mut self, now: MonotonicMillisecondTimestamp)
+	{
+		if let Some(expires_at) = self.topology_change_expires_at
+		{
+			if now >= expires_at
+			{
+				self.topology_change_expires_at = None
+			}
+		}
+	}
+
+	/// IEEE Std 802.1D-2004, Section 17.21.25 (`updtRolesTree`), for one port.
+	#[inline(always)]
+	pub(crate) fn update_role(&mut self, root_priority_vector: &SpanningTreePriorityVector, root_times: &SpanningTreeTimes, root_port_number: Option<u16>, bridge_identifier: BridgeIdentifier)
+	{
+		use self::SpanningTreePortInformationOrigin as Origin;
+		use self::SpanningTreePortRole::*;
+
+		let designated_priority_vector = root_priority_vector.designated_priority_vector(bridge_identifier, self.port_identifier);
+		self.designated_priority_vector = designated_priority_vector;
+		self.designated_times = *root_times;
+
+		let role = match self.information_origin
+		{
+			Origin::Disabled => Disabled,
+
+			Origin::Received => if root_port_number == Some(self.port_number)
+			{
+				Root
+			}
+			else if designated_priority_vector.is_superior_to(&self.port_priority_vector) && designated_priority_vector != self.port_priority_vector
+			{
+				Designated
+			}
+			else if self.port_priority_vector.designated_bridge_identifier.address == bridge_identifier.address
+			{
+				Backup
+			}
+			else
+			{
+				Alternate
+			},
+
+			Origin::Aged | Origin::Mine => Designated,
+		};
+
+		if role == Designated && (self.port_priority_vector != designated_priority_vector || &self.port_times != root_times || self.information_origin != Origin::Mine)
+		{
+			self.information_origin = Origin::Mine;
+			self.port_priority_vector = designated_priority_vector;
+			self.port_times = *root_times;
+			self.received_information_expires_at = None;
+			self.need_to_transmit = true;
+		}
+
+		if role != self.role
+		{
+			self.role = role;
+			self.proposing = false;
+			self.agree = false;
+			self.agreed = false;
+			self.need_to_transmit = true;
+		}
+	}
+
+	/// Puts a non-edge designated port that is not yet synchronised with the new root information into the discarding state (`sync`); returns `true` if the state changed.
+	///
+	/// IEEE Std 802.1D-2004, Section 17.29.3.
+	#[inline(always)]
+	pub(crate) fn synchronise(&mut self) -> bool
+	{
+		if self.role == SpanningTreePortRole::Designated && !self.operational_edge && !self.agreed && self.state != SpanningTreePortState::Discarding
+		{
+			self.state = SpanningTreePortState::Discarding;
+			self.forward_delay_expires_at = None;
+			true
+		}
+		else
+		{
+			false
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_proposed_and_not_yet_agreed(&self) -> bool
+	{
+		self.role == SpanningTreePortRole::Root && self.proposed && !self.agree
+	}
+
+	/// Returns `true` if the port became forwarding as a non-edge port, which is a topology change.
+	///
+	/// IEEE Std 802.1D-2004, Sections 17.29 (port role transitions) and 17.30 (port state transitions).
+	#[inline(always)]
+	pub(crate) fn run_role_and_state_transitions(&mut self, now: MonotonicMillisecondTimestamp, forward_delay: MillisecondDuration) -> bool
+	{
+		use self::SpanningTreePortRole::*;
+		use self::SpanningTreePortState::*;
+
+		let was_forwarding = self.state == Forwarding;
+
+		match self.role
+		{
+			Disabled | Alternate | Backup =>
+			{
+				self.state = Discarding;
+				self.forward_delay_expires_at = None;
+
+				// A discarding port can safely agree to a proposal immediately.
+				if self.proposed && !self.agree && self.role != Disabled
+				{
+					self.agree = true;
+					self.proposed = false;
+					self.need_to_transmit = true;
+				}
+			}
+
+			Root =>
+			{
+				if self.proposed && self.agree
+				{
+					self.proposed = false;
+					self.need_to_transmit = true;
+				}
+
+				if self.agree
+				{
+					self.state = Forwarding;
+					self.forward_delay_expires_at = None;
+				}
+				else
+				{
+					self.advance_state_after_forward_delay(now, forward_delay)
+				}
+			}
+
+			Designated =>
+			{
+				if self.operational_edge || self.agreed
+				{
+					self.state = Forwarding;
+					self.forward_delay_expires_at = None;
+					self.proposing = false;
+				}
+				else
+				{
+					if self.state != Forwarding && !self.proposing && self.send_rapid_spanning_tree
+					{
+						self.proposing = true;
+						self.need_to_transmit = true;
+					}
+					self.advance_state_after_forward_delay(now, forward_delay)
+				}
+			}
+		}
+
+		!was_forwarding && self.state == Forwarding && !self.operational_edge
+	}
+
+	#[inline(always)]
+	pub(crate) fn agree_to_proposal(&mut self)
+	{
+		self.agree = true
+	}
+
+	/// IEEE Std 802.1D-2004, Section 17.31 (`newTcWhile`).
+	#[inline(always)]
+	pub(crate) fn start_propagating_topology_change(&mut self, now: MonotonicMillisecondTimestamp, root_times: &SpanningTreeTimes)
+	{
+		use self::SpanningTreePortRole::*;
+
+		if !self.enabled || self.operational_edge || (self.role != Root && self.role != Designated)
+		{
+			return
+		}
+
+		if self.topology_change_expires_at.is_none()
+		{
+			let topology_change_time = if self.send_rapid_spanning_tree
+			{
+				root_times.hello_time + root_times.hello_time
+			}
+			else
+			{
+				root_times.maximum_age + root_times.forward_delay
+			};
+			self.topology_change_expires_at = Some(now + topology_change_time);
+			self.need_to_transmit = true;
+		}
+	}
+
+	/// IEEE Std 802.1D-2004, Sections 17.21.19 (`txConfig`), 17.21.20 (`txRstp`) and 17.21.21 (`txTcn`) and 17.26 (Port Transmit state machine).
+	#[inline(always)]
+	pub(crate) fn transmit_if_needed(&mut self, now: MonotonicMillisecondTimestamp, root_times: &SpanningTreeTimes, transmitter: &impl BridgeProtocolDataUnitTransmitter)
+	{
+		use self::SpanningTreePortRole::*;
+		use self::SpanningTreePortState::*;
+		type Flags = BridgeProtocolDataUnitFlags;
+
+		if !self.enabled || self.role == Disabled
+		{
+			return
+		}
+
+		// Designated ports, and root ports of legacy bridges signalling a topology change, transmit every hello time.
+		let transmits_periodically = self.role == Designated || (self.role == Root && !self.send_rapid_spanning_tree && self.topology_change_expires_at.is_some());
+		if transmits_periodically
+		{
+			match self.hello_expires_at
+			{
+				Some(expires_at) if now < expires_at => (),
+				_ =>
+				{
+					self.hello_expires_at = Some(now + root_times.hello_time);
+					self.need_to_transmit = true;
+				}
+			}
+		}
+		else
+		{
+			self.hello_expires_at = None
+		}
+
+		if !self.need_to_transmit
+		{
+			return
+		}
+
+		// Legacy bridges only understand BPDUs from designated ports (and Topology Change Notifications from root ports).
+		if !self.send_rapid_spanning_tree && self.role != Designated && !(self.role == Root && self.topology_change_expires_at.is_some())
+		{
+			self.need_to_transmit = false;
+			return
+		}
+
+		if now - self.transmission_interval_started_at >= Self::TransmitHoldInterval
+		{
+			self.transmission_interval_started_at = now;
+			self.transmissions_in_current_interval = 0;
+		}
+
+		if self.transmissions_in_current_interval == Self::TransmitHoldCount
+		{
+			return
+		}
+
+		let (bridge_protocol_data_unit, length) = if !self.send_rapid_spanning_tree && self.role == Root
+		{
+			BridgeProtocolDataUnit::new_topology_change_notification()
+		}
+		else
+		{
+			let mut flags = Flags::default();
+			flags.set(Flags::TopologyChange, self.topology_change_expires_at.is_some());
+
+			if self.send_rapid_spanning_tree
+			{
+				flags.set_port_role(self.role);
+				flags.set(Flags::Proposal, self.proposing);
+				flags.set(Flags::Agreement, self.agree);
+				flags.set(Flags::Learning, self.state != Discarding);
+				flags.set(Flags::Forwarding, self.state == Forwarding);
+			}
+			else
+			{
+				flags.set(Flags::TopologyChangeAcknowledgment, self.send_topology_change_acknowledgment);
+				self.send_topology_change_acknowledgment = false;
+			}
+
+			BridgeProtocolDataUnit::new(self.send_rapid_spanning_tree, flags, &self.designated_priority_vector, &self.designated_times)
+		};
+
+		transmitter.transmit(self.port_number, &bridge_protocol_data_unit, length);
+
+		self.transmissions_in_current_interval += 1;
+		self.need_to_transmit = false;
+	}
+
+	#[inline(always)]
+	fn advance_state_after_forward_delay(&mut self, now: MonotonicMillisecondTimestamp, forward_delay: MillisecondDuration)
+	{
+		use self::SpanningTreePortState::*;
+
+		if self.state == Forwarding
+		{
+			return
+		}
+
+		match self.forward_delay_expires_at
+		{
+			None => self.forward_delay_expires_at = Some(now + forward_delay),
+
+			Some(expires_at) => if now >= expires_at
+			{
+				self.state = if self.state == Discarding
+				{
+					self.forward_delay_expires_at = Some(now + forward_delay);
+					Learning
+				}
+				else
+				{
+					self.forward_delay_expires_at = None;
+					Forwarding
+				};
+			},
+		}
+	}
+}
@@ -0,0 +1,46 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Spanning tree bridge port configuration.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct SpanningTreePortConfiguration
+{
+	/// Port number; must be unique within the bridge, must not be zero and must fit in 12 bits.
+	pub port_number: u16,
+
+	/// Port priority; a lower value is a higher priority; rounded down to a multiple of 16.
+	#[serde(default = "SpanningTreePortConfiguration::port_priority_default")] pub port_priority: u8,
+
+	/// Port path cost.
+	///
+	/// IEEE Std 802.1D-2004, Section 17.14, Table 17-3, recommends 20,000 for 1 Gb/s, 2,000 for 10 Gb/s and 200 for 100 Gb/s.
+	#[serde(default = "SpanningTreePortConfiguration::path_cost_default")] pub path_cost: u32,
+
+	/// Administratively an edge port, ie no bridges are attached to it; an edge port becomes forwarding immediately.
+	///
+	/// A port stops operating as an edge port if a Bridge Protocol Data Unit (BPDU) is received on it.
+	#[serde(default)] pub edge: bool,
+}
+
+impl SpanningTreePortConfiguration
+{
+	/// Default port priority.
+	pub const DefaultPortPriority: u8 = 128;
+
+	/// Default port path cost (that for 1 Gb/s).
+	pub const DefaultPathCost: u32 = 20_000;
+
+	#[inline(always)]
+	fn port_priority_default() -> u8
+	{
+		Self::DefaultPortPriority
+	}
+
+	#[inline(always)]
+	fn path_cost_default() -> u32
+	{
+		Self::DefaultPathCost
+	}
+}
@@ -0,0 +1,32 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Origin of the port priority vector and times of a spanning tree port.
+///
+/// IEEE Std 802.1D-2004, Section 17.19.10 (`infoIs`).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum SpanningTreePortInformationOrigin
+{
+	/// The port is disabled.
+	Disabled,
+
+	/// Received information has expired (or has never been received).
+	Aged,
+
+	/// Information was derived by this bridge; the port is a designated port.
+	Mine,
+
+	/// Information was received in a Bridge Protocol Data Unit (BPDU) from the designated bridge for the port's LAN.
+	Received,
+}
+
+impl Display for SpanningTreePortInformationOrigin
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
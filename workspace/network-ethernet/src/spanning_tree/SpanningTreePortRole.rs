@@ -0,0 +1,35 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Spanning tree port role.
+///
+/// IEEE Std 802.1D-2004, Section 17.7.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum SpanningTreePortRole
+{
+	/// The port's link is down or the port is not participating.
+	Disabled,
+
+	/// The port offers the lowest cost path towards the root bridge.
+	Root,
+
+	/// The port connects its LAN to the root bridge through this bridge.
+	Designated,
+
+	/// An alternative path towards the root bridge to that provided by the root port.
+	Alternate,
+
+	/// A backup for the path provided by another port of this bridge to the same LAN.
+	Backup,
+}
+
+impl Display for SpanningTreePortRole
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
@@ -0,0 +1,46 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Spanning tree port state.
+///
+/// IEEE Std 802.1D-2004, Section 7.4; the legacy disabled, blocking and listening states are all discarding.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum SpanningTreePortState
+{
+	/// Frames are neither learned from nor forwarded.
+	Discarding,
+
+	/// Frames are learned from but not forwarded.
+	Learning,
+
+	/// Frames are learned from and forwarded.
+	Forwarding,
+}
+
+impl Display for SpanningTreePortState
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl SpanningTreePortState
+{
+	/// Are source ethernet addresses of received frames learned in this state?
+	#[inline(always)]
+	pub fn is_learning(self) -> bool
+	{
+		self != SpanningTreePortState::Discarding
+	}
+
+	/// Are frames forwarded in this state?
+	#[inline(always)]
+	pub fn is_forwarding(self) -> bool
+	{
+		self == SpanningTreePortState::Forwarding
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A spanning tree priority vector.
+///
+/// A lower vector is a better (higher priority) vector; the derived ordering compares fields in declaration order, as required by IEEE Std 802.1D-2004, Section 17.6.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct SpanningTreePriorityVector
+{
+	/// Root bridge identifier.
+	pub root_bridge_identifier: BridgeIdentifier,
+
+	/// Root path cost.
+	pub root_path_cost: u32,
+
+	/// Designated bridge identifier.
+	pub designated_bridge_identifier: BridgeIdentifier,
+
+	/// Designated port identifier.
+	pub designated_port_identifier: u16,
+
+	/// Identifier of the bridge port the vector was received on (or would be transmitted from).
+	pub bridge_port_identifier: u16,
+}
+
+impl Display for SpanningTreePriorityVector
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl SpanningTreePriorityVector
+{
+	#[inline(always)]
+	pub(crate) fn bridge_priority_vector(bridge_identifier: BridgeIdentifier) -> Self
+	{
+		Self
+		{
+			root_bridge_identifier: bridge_identifier,
+			root_path_cost: 0,
+			designated_bridge_identifier: bridge_identifier,
+			designated_port_identifier: 0,
+			bridge_port_identifier: 0,
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn designated_priority_vector(&self, bridge_identifier: BridgeIdentifier, port_identifier: u16) -> Self
+	{
+		Self
+		{
+			root_bridge_identifier: self.root_bridge_identifier,
+			root_path_cost: self.root_path_cost,
+			designated_bridge_identifier: bridge_identifier,
+			designated_port_identifier: port_identifier,
+			bridge_port_identifier: port_identifier,
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn root_path_priority_vector(&self, port_path_cost: u32, port_identifier: u16) -> Self
+	{
+		Self
+		{
+			root_path_cost: self.root_path_cost.saturating_add(port_path_cost),
+			bridge_port_identifier: port_identifier,
+			..*self
+		}
+	}
+
+	/// Is this (received) message priority vector superior to or an update of `port_priority_vector`?
+	///
+	/// Information from the same designated bridge and designated port always replaces what was previously received, even if it is worse.
+	///
+	/// IEEE Std 802.1D-2004, Section 17.6.
+	#[inline(always)]
+	pub fn is_superior_to(&self, port_priority_vector: &Self) -> bool
+	{
+		let is_from_same_designated_bridge_and_port = self.designated_bridge_identifier.address == port_priority_vector.designated_bridge_identifier.address && (self.designated_port_identifier & SpanningTreePort::PortNumberMask) == (port_priority_vector.designated_port_identifier & SpanningTreePort::PortNumberMask);
+
+		is_from_same_designated_bridge_and_port || self.without_bridge_port_identifier() < port_priority_vector.without_bridge_port_identifier()
+	}
+
+	#[inline(always)]
+	fn without_bridge_port_identifier(&self) -> (BridgeIdentifier, u32, BridgeIdentifier, u16)
+	{
+		(self.root_bridge_identifier, self.root_path_cost, self.designated_bridge_identifier, self.designated_port_identifier)
+	}
+}
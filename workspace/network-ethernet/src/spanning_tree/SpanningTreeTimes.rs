@@ -0,0 +1,67 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Spanning tree timer parameter values.
+///
+/// IEEE Std 802.1D-2004, Section 17.6.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct SpanningTreeTimes
+{
+	/// Message age.
+	pub message_age: MillisecondDuration,
+
+	/// Maximum age.
+	pub maximum_age: MillisecondDuration,
+
+	/// Hello time.
+	pub hello_time: MillisecondDuration,
+
+	/// Forward delay.
+	pub forward_delay: MillisecondDuration,
+}
+
+impl Display for SpanningTreeTimes
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl SpanningTreeTimes
+{
+	/// Message age is incremented by this amount at each bridge.
+	///
+	/// IEEE Std 802.1D-2004, Section 17.21.25.
+	pub const MessageAgeIncrement: MillisecondDuration = MillisecondDuration::OneSecond;
+
+	/// Have these times (as received) reached their maximum age?
+	#[inline(always)]
+	pub fn has_reached_maximum_age(&self) -> bool
+	{
+		self.message_age >= self.maximum_age
+	}
+
+	/// Received information expires after this duration (`rcvdInfoWhile`).
+	///
+	/// IEEE Std 802.1D-2004, Section 17.21.23.
+	#[inline(always)]
+	pub fn received_information_lifetime(&self) -> MillisecondDuration
+	{
+		self.hello_time * 3
+	}
+
+	/// Times as they would be for the next bridge on from a root port.
+	#[inline(always)]
+	pub fn aged(&self) -> Self
+	{
+		Self
+		{
+			message_age: self.message_age + Self::MessageAgeIncrement,
+			..*self
+		}
+	}
+}
@@ -0,0 +1,22 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("BridgeIdentifier.rs");
+include!("BridgeProtocolDataUnit.rs");
+include!("BridgeProtocolDataUnitFlags.rs");
+include!("BridgeProtocolDataUnitKind.rs");
+include!("BridgeProtocolDataUnitTransmitter.rs");
+include!("SpanningTreeBridge.rs");
+include!("SpanningTreeBridgeConfiguration.rs");
+include!("SpanningTreeChanges.rs");
+include!("SpanningTreePort.rs");
+include!("SpanningTreePortConfiguration.rs");
+include!("SpanningTreePortInformationOrigin.rs");
+include!("SpanningTreePortRole.rs");
+include!("SpanningTreePortState.rs");
+include!("SpanningTreePriorityVector.rs");
+include!("SpanningTreeTimes.rs");
@@ -0,0 +1,37 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A tunnel which is configured for termination, found from the poll mode driver's hardware offload categorisation of a packet.
+#[derive(Debug)]
+pub(crate) enum ConfiguredTunnel<'a, EINPDO: 'a + EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: 'a + Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: 'a + Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: 'a + Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	VirtualExtensibleLocalAreaNetwork(&'a VirtualExtensibleLocalAreaNetworkPacketProcessing<EINPDO, ARP, IPV4, IPV6>),
+	
+	GenericRoutingEncapsulation(&'a GenericRoutingEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>),
+	
+	NetworkVirtualizationUsingGenericRoutingEncapsulation(&'a NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>),
+	
+	GenericNetworkVirtualizationEncapsulation(&'a GenericNetworkVirtualizationEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>),
+}
+
+impl<'a, EINPDO: 'a + EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: 'a + Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: 'a + Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: 'a + Layer3PacketProcessing<CheckSumsValidated=bool>> ConfiguredTunnel<'a, EINPDO, ARP, IPV4, IPV6>
+{
+	/// Validates the outer and tunnel headers and finds the packet processing for the payload.
+	#[inline(always)]
+	pub(crate) fn terminate(self, packet: impl EthernetIncomingNetworkPacket) -> Result<TerminatedTunnel<'a, EINPDO, ARP, IPV4, IPV6>, InvalidTunnelPacketReason>
+	{
+		use self::ConfiguredTunnel::*;
+		
+		match self
+		{
+			VirtualExtensibleLocalAreaNetwork(virtual_extensible_local_area_network) => virtual_extensible_local_area_network.terminate(packet),
+			
+			GenericRoutingEncapsulation(generic_routing_encapsulation) => generic_routing_encapsulation.terminate(packet),
+			
+			NetworkVirtualizationUsingGenericRoutingEncapsulation(network_virtualization_using_generic_routing_encapsulation) => network_virtualization_using_generic_routing_encapsulation.terminate(packet),
+			
+			GenericNetworkVirtualizationEncapsulation(generic_network_virtualization_encapsulation) => generic_network_virtualization_encapsulation.terminate(packet),
+		}
+	}
+}
@@ -0,0 +1,48 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Generic Network Virtualization Encapsulation (Geneve) packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum GenericNetworkVirtualizationEncapsulationDropReason
+{
+	/// The header is too short.
+	HeaderIsTooShort,
+	
+	/// The version is not zero.
+	VersionIsNotZero,
+	
+	/// The options length exceeds the packet.
+	OptionsAreTooLong,
+	
+	/// An option's length exceeds the remaining options length.
+	OptionLengthIsInvalid,
+	
+	/// A critical option is present; no options are understood, so critical options can not be processed (RFC 8926, Section 3.5).
+	CriticalOptionIsPresent
+	{
+		/// Option class.
+		option_class: u16,
+		
+		/// Option type, including the critical bit.
+		option_type: u8,
+	},
+	
+	/// The `O` bit is set; control packets are not processed.
+	IsControlPacket,
+	
+	/// The protocol type is not transparent ethernet bridging, internet protocol (IP) version 4 or version 6.
+	UnsupportedProtocolType
+	{
+		/// Protocol type.
+		protocol_type: EtherType,
+	},
+	
+	/// There is no configuration for this Virtual Network Identifier (VNI).
+	NoConfigurationForVirtualNetworkIdentifier
+	{
+		/// Virtual Network Identifier (VNI).
+		virtual_network_identifier: VirtualNetworkIdentifier,
+	},
+}
@@ -0,0 +1,75 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Generic Network Virtualization Encapsulation (Geneve) fixed header (RFC 8926, Section 3.4).
+///
+/// Follows the outer User Datagram Protocol (UDP) header and is followed by variable length options and then the payload.
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct GenericNetworkVirtualizationEncapsulationHeader
+{
+	/// Version (2 bits) and options length in 4-byte multiples (6 bits).
+	pub version_and_options_length: u8,
+	
+	/// Flags; `O` (control packet) and `C` (critical options present).
+	pub flags: u8,
+	
+	/// Protocol type of the payload.
+	pub protocol_type: EtherType,
+	
+	/// Virtual Network Identifier (VNI).
+	pub virtual_network_identifier: [u8; 3],
+	
+	/// Reserved; ignored on receipt.
+	pub reserved: u8,
+}
+
+impl GenericNetworkVirtualizationEncapsulationHeader
+{
+	/// Size of header.
+	pub const Size: usize = 8;
+	
+	/// Size of header.
+	pub const SizeU16: u16 = Self::Size as u16;
+	
+	/// Size of an option's header.
+	pub const OptionHeaderSizeU16: u16 = 4;
+	
+	/// The `O` flag, set for control packets.
+	pub const ControlPacketFlag: u8 = 0b1000_0000;
+	
+	/// The critical bit of an option type.
+	pub const CriticalOptionTypeBit: u8 = 0b1000_0000;
+	
+	/// Well-known User Datagram Protocol (UDP) destination port assigned by IANA.
+	pub const UserDatagramProtocolPort: u16 = 6081;
+	
+	/// Version; only version zero is defined.
+	#[inline(always)]
+	pub fn version(&self) -> u8
+	{
+		self.version_and_options_length >> 6
+	}
+	
+	/// Length of options (in bytes).
+	#[inline(always)]
+	pub fn options_length(&self) -> u16
+	{
+		((self.version_and_options_length & 0b0011_1111) as u16) * 4
+	}
+	
+	/// Is this a control packet?
+	#[inline(always)]
+	pub fn is_control_packet(&self) -> bool
+	{
+		self.flags & Self::ControlPacketFlag != 0
+	}
+	
+	/// Virtual Network Identifier (VNI).
+	#[inline(always)]
+	pub fn virtual_network_identifier(&self) -> VirtualNetworkIdentifier
+	{
+		VirtualNetworkIdentifier::from_octets(&self.virtual_network_identifier)
+	}
+}
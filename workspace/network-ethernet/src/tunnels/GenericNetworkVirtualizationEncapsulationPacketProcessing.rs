@@ -0,0 +1,124 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Terminates Generic Network Virtualization Encapsulation (Geneve) tunnels (RFC 8926).
+///
+/// Each Virtual Network Identifier (VNI) has its own Virtual LAN packet processing; payloads may be ethernet frames (transparent ethernet bridging) or internet protocol (IP) version 4 or version 6 packets.
+///
+/// No options are understood; non-critical options are skipped and packets with critical options are dropped.
+#[derive(Debug)]
+pub struct GenericNetworkVirtualizationEncapsulationPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	/// User Datagram Protocol (UDP) destination port.
+	pub user_datagram_protocol_destination_port: u16,
+	
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Virtual networks.
+	pub virtual_networks: HashMap<VirtualNetworkIdentifier, VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+}
+
+impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> GenericNetworkVirtualizationEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>
+{
+	/// Validates the outer and tunnel headers and options and finds the packet processing for the Virtual Network Identifier (VNI).
+	#[inline(always)]
+	pub(crate) fn terminate(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<TerminatedTunnel<EINPDO, ARP, IPV4, IPV6>, InvalidTunnelPacketReason>
+	{
+		use self::GenericNetworkVirtualizationEncapsulationDropReason::*;
+		
+		let tunnel_outer_headers = TunnelOuterHeaders::parse(packet, &self.our_tunnel_end_point_addresses)?;
+		
+		let (header_offset, length) = tunnel_outer_headers.validate_user_datagram_protocol(packet, self.user_datagram_protocol_destination_port)?;
+		
+		let tunnel_drop = |reason| Err(tunnel_outer_headers.tunnel_drop_reason(TunnelDropReason::GenericNetworkVirtualizationEncapsulation { reason }));
+		
+		if unlikely!(length < GenericNetworkVirtualizationEncapsulationHeader::SizeU16)
+		{
+			return tunnel_drop(HeaderIsTooShort)
+		}
+		
+		let header: &GenericNetworkVirtualizationEncapsulationHeader = packet.offset_into_data_reference(header_offset);
+		
+		if unlikely!(header.version() != 0)
+		{
+			return tunnel_drop(VersionIsNotZero)
+		}
+		
+		let options_length = header.options_length();
+		if unlikely!(length - GenericNetworkVirtualizationEncapsulationHeader::SizeU16 < options_length)
+		{
+			return tunnel_drop(OptionsAreTooLong)
+		}
+		
+		if let Err(reason) = Self::validate_options(packet, header_offset + GenericNetworkVirtualizationEncapsulationHeader::Size, options_length)
+		{
+			return tunnel_drop(reason)
+		}
+		
+		if unlikely!(header.is_control_packet())
+		{
+			return tunnel_drop(IsControlPacket)
+		}
+		
+		let protocol_type = header.protocol_type;
+		if unlikely!(!TerminatedTunnel::<EINPDO, ARP, IPV4, IPV6>::is_supported_payload_ether_type(protocol_type))
+		{
+			return tunnel_drop(UnsupportedProtocolType { protocol_type })
+		}
+		
+		let virtual_network_identifier = header.virtual_network_identifier();
+		
+		match self.virtual_networks.get(&virtual_network_identifier)
+		{
+			None => tunnel_drop(NoConfigurationForVirtualNetworkIdentifier { virtual_network_identifier }),
+			
+			Some(packet_processing_by_virtual_lan) => Ok
+			(
+				TerminatedTunnel
+				{
+					packet_processing_by_virtual_lan,
+					payload_offset: (header_offset as u16) + GenericNetworkVirtualizationEncapsulationHeader::SizeU16 + options_length,
+					payload_ether_type: protocol_type,
+				}
+			),
+		}
+	}
+	
+	/// Walks the type-length-value options, which must exactly fill `options_length`.
+	#[inline(always)]
+	fn validate_options(packet: impl EthernetIncomingNetworkPacket, mut option_offset: usize, mut options_length: u16) -> Result<(), GenericNetworkVirtualizationEncapsulationDropReason>
+	{
+		use self::GenericNetworkVirtualizationEncapsulationDropReason::*;
+		
+		const LengthMask: u8 = 0b0001_1111;
+		
+		while options_length != 0
+		{
+			if unlikely!(options_length < GenericNetworkVirtualizationEncapsulationHeader::OptionHeaderSizeU16)
+			{
+				return Err(OptionLengthIsInvalid)
+			}
+			
+			let option_header: &[u8; 4] = packet.offset_into_data_reference(option_offset);
+			
+			let option_length = GenericNetworkVirtualizationEncapsulationHeader::OptionHeaderSizeU16 + ((option_header[3] & LengthMask) as u16) * 4;
+			if unlikely!(option_length > options_length)
+			{
+				return Err(OptionLengthIsInvalid)
+			}
+			
+			let option_type = option_header[2];
+			if unlikely!(option_type & GenericNetworkVirtualizationEncapsulationHeader::CriticalOptionTypeBit != 0)
+			{
+				return Err(CriticalOptionIsPresent { option_class: TunnelOuterHeaders::network_endian_u16(option_header[0], option_header[1]), option_type })
+			}
+			
+			option_offset += option_length as usize;
+			options_length -= option_length;
+		}
+		
+		Ok(())
+	}
+}
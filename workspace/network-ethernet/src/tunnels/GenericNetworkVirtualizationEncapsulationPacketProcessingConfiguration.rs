@@ -0,0 +1,50 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Configuration of Generic Network Virtualization Encapsulation (Geneve) tunnel termination.
+#[derive(Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct GenericNetworkVirtualizationEncapsulationPacketProcessingConfiguration<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// User Datagram Protocol (UDP) destination port.
+	///
+	/// Defaults to 6081 (RFC 8926). The poll mode driver usually needs to be told this port in order to categorise packets as tunnel packets (eg with DPDK's `rte_eth_dev_udp_tunnel_port_add()`).
+	#[serde(default = "GenericNetworkVirtualizationEncapsulationPacketProcessingConfiguration::<ARP, IPV4, IPV6>::user_datagram_protocol_destination_port_default")] pub user_datagram_protocol_destination_port: u16,
+	
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Virtual networks, keyed by Virtual Network Identifier (VNI).
+	pub virtual_networks: HashMap<VirtualNetworkIdentifier, VirtualNetworkPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
+}
+
+impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration> GenericNetworkVirtualizationEncapsulationPacketProcessingConfiguration<ARP, IPV4, IPV6>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Configure.
+	///
+	/// Fails if the Virtual LAN packet processing of a tunnel can not be configured (see `VirtualLanPacketProcessingConfiguration::configure()`).
+	#[inline(always)]
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(mut self, dropped_packet_reporting: &Rc<EINPDO>) -> Result<GenericNetworkVirtualizationEncapsulationPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
+	{
+		Ok
+		(
+			GenericNetworkVirtualizationEncapsulationPacketProcessing
+			{
+				user_datagram_protocol_destination_port: self.user_datagram_protocol_destination_port,
+				
+				our_tunnel_end_point_addresses: self.our_tunnel_end_point_addresses,
+				
+				virtual_networks: self.virtual_networks.drain().map(|(virtual_network_identifier, virtual_network)| virtual_network.configure(dropped_packet_reporting).map(|virtual_network| (virtual_network_identifier, virtual_network))).collect::<Result<_, _>>()?,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn user_datagram_protocol_destination_port_default() -> u16
+	{
+		GenericNetworkVirtualizationEncapsulationHeader::UserDatagramProtocolPort
+	}
+}
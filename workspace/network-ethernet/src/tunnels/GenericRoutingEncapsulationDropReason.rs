@@ -0,0 +1,42 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Generic Routing Encapsulation (GRE) packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum GenericRoutingEncapsulationDropReason
+{
+	/// The header, including the optional check sum, key and sequence number fields its flags indicate, is too short.
+	HeaderIsTooShort,
+	
+	/// Reserved bits, including the obsolete routing present bit (RFC 1701), were set (RFC 2784).
+	ReservedBitsAreSet,
+	
+	/// The version is not zero.
+	VersionIsNotZero,
+	
+	/// The check sum is present and bad.
+	CheckSumIsBad,
+	
+	/// The protocol type is not transparent ethernet bridging, internet protocol (IP) version 4 or version 6.
+	UnsupportedProtocolType
+	{
+		/// Protocol type.
+		protocol_type: EtherType,
+	},
+	
+	/// There is no configuration for this key (or lack of key).
+	NoConfigurationForKey
+	{
+		/// Key (RFC 2890), if present.
+		key: Option<u32>,
+	},
+	
+	/// The sequence number (RFC 2890) is not after that of the last packet received.
+	SequenceNumberIsOutOfOrder
+	{
+		/// Sequence number.
+		sequence_number: u32,
+	},
+}
@@ -0,0 +1,148 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A parsed Generic Routing Encapsulation (GRE) header (RFC 2784), with the key and sequence number extensions (RFC 2890).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct GenericRoutingEncapsulationHeader
+{
+	/// Protocol type of the payload.
+	pub(crate) protocol_type: EtherType,
+	
+	/// Was the check sum present (it will have been validated)?
+	pub(crate) is_check_sum_present: bool,
+	
+	/// Key, if present.
+	pub(crate) key: Option<u32>,
+	
+	/// Sequence number, if present.
+	pub(crate) sequence_number: Option<u32>,
+	
+	/// Length of the header, including optional fields.
+	pub(crate) header_length: u16,
+}
+
+impl GenericRoutingEncapsulationHeader
+{
+	const MinimumSizeU16: u16 = 4;
+	
+	const OptionalFieldSizeU16: u16 = 4;
+	
+	const CheckSumPresentFlag: u8 = 0b1000_0000;
+	
+	const KeyPresentFlag: u8 = 0b0010_0000;
+	
+	const SequenceNumberPresentFlag: u8 = 0b0001_0000;
+	
+	/// Includes the routing present bit, bit 1, and the strict source route and recursion control bits, bits 4 to 7, of RFC 1701.
+	const FirstByteReservedBits: u8 = 0b0100_1111;
+	
+	const SecondByteReservedBits: u8 = 0b1111_1000;
+	
+	const VersionMask: u8 = 0b0000_0111;
+	
+	/// Does the header at `offset` have a key and a protocol type of transparent ethernet bridging, as used by Network Virtualization using Generic Routing Encapsulation (NVGRE)?
+	///
+	/// The header is not otherwise validated.
+	#[inline(always)]
+	pub(crate) fn peek_is_keyed_transparent_ethernet_bridging(packet: impl EthernetIncomingNetworkPacket, offset: usize, length: u16) -> bool
+	{
+		if unlikely!(length < Self::MinimumSizeU16)
+		{
+			return false
+		}
+		
+		let fixed: &[u8; 4] = packet.offset_into_data_reference(offset);
+		let protocol_type = *packet.offset_into_data_reference::<EtherType>(offset + 2);
+		
+		fixed[0] & Self::KeyPresentFlag != 0 && protocol_type == EtherType::TransparentEthernetBridging
+	}
+	
+	/// `length` is the length of the header and its payload.
+	#[inline(always)]
+	pub(crate) fn parse(packet: impl EthernetIncomingNetworkPacket, offset: usize, length: u16) -> Result<Self, GenericRoutingEncapsulationDropReason>
+	{
+		use self::GenericRoutingEncapsulationDropReason::*;
+		
+		if unlikely!(length < Self::MinimumSizeU16)
+		{
+			return Err(HeaderIsTooShort)
+		}
+		
+		let fixed: &[u8; 4] = packet.offset_into_data_reference(offset);
+		let (first_byte, second_byte) = (fixed[0], fixed[1]);
+		
+		if unlikely!(first_byte & Self::FirstByteReservedBits != 0 || second_byte & Self::SecondByteReservedBits != 0)
+		{
+			return Err(ReservedBitsAreSet)
+		}
+		
+		if unlikely!(second_byte & Self::VersionMask != 0)
+		{
+			return Err(VersionIsNotZero)
+		}
+		
+		let protocol_type = *packet.offset_into_data_reference::<EtherType>(offset + 2);
+		
+		let is_check_sum_present = first_byte & Self::CheckSumPresentFlag != 0;
+		let is_key_present = first_byte & Self::KeyPresentFlag != 0;
+		let is_sequence_number_present = first_byte & Self::SequenceNumberPresentFlag != 0;
+		
+		let header_length = Self::MinimumSizeU16 + (is_check_sum_present as u16 + is_key_present as u16 + is_sequence_number_present as u16) * Self::OptionalFieldSizeU16;
+		if unlikely!(length < header_length)
+		{
+			return Err(HeaderIsTooShort)
+		}
+		
+		if is_check_sum_present
+		{
+			let mut sum = OnesComplementSum::default();
+			sum.add_bytes(TunnelOuterHeaders::bytes(packet, offset, length));
+			if unlikely!(!sum.is_valid())
+			{
+				return Err(CheckSumIsBad)
+			}
+		}
+		
+		let mut optional_field_offset = offset + (Self::MinimumSizeU16 as usize) + if is_check_sum_present { Self::OptionalFieldSizeU16 as usize } else { 0 };
+		
+		let key = if is_key_present
+		{
+			let key = Self::network_endian_u32(packet, optional_field_offset);
+			optional_field_offset += Self::OptionalFieldSizeU16 as usize;
+			Some(key)
+		}
+		else
+		{
+			None
+		};
+		
+		let sequence_number = if is_sequence_number_present
+		{
+			Some(Self::network_endian_u32(packet, optional_field_offset))
+		}
+		else
+		{
+			None
+		};
+		
+		Ok
+		(
+			Self
+			{
+				protocol_type,
+				is_check_sum_present,
+				key,
+				sequence_number,
+				header_length,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn network_endian_u32(packet: impl EthernetIncomingNetworkPacket, offset: usize) -> u32
+	{
+		let bytes: &[u8; 4] = packet.offset_into_data_reference(offset);
+		((bytes[0] as u32) << 24) | ((bytes[1] as u32) << 16) | ((bytes[2] as u32) << 8) | (bytes[3] as u32)
+	}
+}
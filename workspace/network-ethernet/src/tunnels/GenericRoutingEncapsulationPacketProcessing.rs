@@ -0,0 +1,74 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Terminates Generic Routing Encapsulation (GRE) tunnels (RFC 2784), with the key and sequence number extensions (RFC 2890).
+///
+/// Each key (or lack of key) has its own tunnel; payloads may be ethernet frames (transparent ethernet bridging) or internet protocol (IP) version 4 or version 6 packets.
+#[derive(Debug)]
+pub struct GenericRoutingEncapsulationPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Drop packets whose sequence number is not after that of the last packet received on the same tunnel.
+	pub drop_out_of_order_sequence_numbers: bool,
+	
+	/// Tunnels, keyed by key; `None` is for packets without a key.
+	pub tunnels: HashMap<Option<u32>, GenericRoutingEncapsulationTunnel<EINPDO, ARP, IPV4, IPV6>>,
+}
+
+impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> GenericRoutingEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>
+{
+	/// Validates the outer and tunnel headers and finds the packet processing for the key.
+	#[inline(always)]
+	pub(crate) fn terminate(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<TerminatedTunnel<EINPDO, ARP, IPV4, IPV6>, InvalidTunnelPacketReason>
+	{
+		use self::GenericRoutingEncapsulationDropReason::*;
+		
+		let tunnel_outer_headers = TunnelOuterHeaders::parse(packet, &self.our_tunnel_end_point_addresses)?;
+		
+		let (header_offset, length) = tunnel_outer_headers.validate_layer_4_protocol(TunnelOuterHeaders::GenericRoutingEncapsulation)?;
+		
+		let tunnel_drop = |reason| Err(tunnel_outer_headers.tunnel_drop_reason(TunnelDropReason::GenericRoutingEncapsulation { reason }));
+		
+		let header = match GenericRoutingEncapsulationHeader::parse(packet, header_offset, length)
+		{
+			Err(reason) => return tunnel_drop(reason),
+			
+			Ok(header) => header,
+		};
+		
+		let protocol_type = header.protocol_type;
+		if unlikely!(!TerminatedTunnel::<EINPDO, ARP, IPV4, IPV6>::is_supported_payload_ether_type(protocol_type))
+		{
+			return tunnel_drop(UnsupportedProtocolType { protocol_type })
+		}
+		
+		let key = header.key;
+		let tunnel = match self.tunnels.get(&key)
+		{
+			None => return tunnel_drop(NoConfigurationForKey { key }),
+			
+			Some(tunnel) => tunnel,
+		};
+		
+		if let Some(sequence_number) = header.sequence_number
+		{
+			if unlikely!(!tunnel.accept_sequence_number(sequence_number) && self.drop_out_of_order_sequence_numbers)
+			{
+				return tunnel_drop(SequenceNumberIsOutOfOrder { sequence_number })
+			}
+		}
+		
+		Ok
+		(
+			TerminatedTunnel
+			{
+				packet_processing_by_virtual_lan: &tunnel.packet_processing_by_virtual_lan,
+				payload_offset: (header_offset as u16) + header.header_length,
+				payload_ether_type: protocol_type,
+			}
+		)
+	}
+}
@@ -0,0 +1,44 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Configuration of Generic Routing Encapsulation (GRE) tunnel termination.
+#[derive(Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct GenericRoutingEncapsulationPacketProcessingConfiguration<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Drop packets whose sequence number is not after that of the last packet received on the same tunnel.
+	///
+	/// Defaults to `false`; packets are delivered regardless of order.
+	#[serde(default)] pub drop_out_of_order_sequence_numbers: bool,
+	
+	/// Tunnels, keyed by key; `None` is for packets without a key.
+	pub tunnels: HashMap<Option<u32>, VirtualNetworkPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
+}
+
+impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration> GenericRoutingEncapsulationPacketProcessingConfiguration<ARP, IPV4, IPV6>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Configure.
+	///
+	/// Fails if the Virtual LAN packet processing of a tunnel can not be configured (see `VirtualLanPacketProcessingConfiguration::configure()`).
+	#[inline(always)]
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(mut self, dropped_packet_reporting: &Rc<EINPDO>) -> Result<GenericRoutingEncapsulationPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
+	{
+		Ok
+		(
+			GenericRoutingEncapsulationPacketProcessing
+			{
+				our_tunnel_end_point_addresses: self.our_tunnel_end_point_addresses,
+				
+				drop_out_of_order_sequence_numbers: self.drop_out_of_order_sequence_numbers,
+				
+				tunnels: self.tunnels.drain().map(|(key, tunnel)| tunnel.configure(dropped_packet_reporting).map(|tunnel| (key, GenericRoutingEncapsulationTunnel::new(tunnel)))).collect::<Result<_, _>>()?,
+			}
+		)
+	}
+}
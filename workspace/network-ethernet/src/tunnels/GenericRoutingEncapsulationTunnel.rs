@@ -0,0 +1,49 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Generic Routing Encapsulation (GRE) tunnel, identified by its key (or lack of key).
+#[derive(Debug)]
+pub struct GenericRoutingEncapsulationTunnel<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	/// Packet processing of the payload.
+	///
+	/// Internet protocol (IP) payloads are processed as if they were untagged ethernet frames.
+	pub packet_processing_by_virtual_lan: VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>,
+	
+	last_sequence_number: Cell<Option<u32>>,
+}
+
+impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> GenericRoutingEncapsulationTunnel<EINPDO, ARP, IPV4, IPV6>
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(packet_processing_by_virtual_lan: VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>) -> Self
+	{
+		Self
+		{
+			packet_processing_by_virtual_lan,
+			last_sequence_number: Cell::new(None),
+		}
+	}
+	
+	/// Is `sequence_number` after that of the last packet accepted, using serial number arithmetic (RFC 2890, Section 2.2)?
+	///
+	/// If it is, it is recorded as that of the last packet accepted.
+	#[inline(always)]
+	pub(crate) fn accept_sequence_number(&self, sequence_number: u32) -> bool
+	{
+		let is_in_order = match self.last_sequence_number.get()
+		{
+			None => true,
+			
+			Some(last_sequence_number) => (sequence_number.wrapping_sub(last_sequence_number) as i32) > 0,
+		};
+		
+		if is_in_order
+		{
+			self.last_sequence_number.set(Some(sequence_number))
+		}
+		is_in_order
+	}
+}
@@ -0,0 +1,63 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a tunnel packet could not be terminated.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum InvalidTunnelPacketReason
+{
+	/// The outer layer 3 protocol is neither internet protocol (IP) version 4 nor version 6.
+	OuterLayer3ProtocolIsNotInternetProtocol,
+	
+	/// The outer internet protocol (IP) version 4 header is too short, has the wrong version, a bad header length or a bad total length.
+	OuterInternetProtocolVersion4HeaderIsInvalid,
+	
+	/// The outer internet protocol (IP) version 4 header check sum is bad.
+	OuterInternetProtocolVersion4CheckSumIsBad,
+	
+	/// The outer internet protocol (IP) version 4 packet is a fragment; fragmented tunnel packets are not reassembled.
+	OuterInternetProtocolVersion4PacketIsAFragment,
+	
+	/// The outer internet protocol (IP) version 6 header is too short, has the wrong version or a bad payload length.
+	OuterInternetProtocolVersion6HeaderIsInvalid,
+	
+	/// The outer destination internet protocol (IP) address is not one of our tunnel end points.
+	OuterDestinationInternetProtocolAddressIsNotOneOfOurs,
+	
+	/// The outer layer 4 protocol (IP protocol number or, for internet protocol (IP) version 6, the first next header) is not that of the tunnel.
+	///
+	/// Internet protocol (IP) version 6 extension headers are not supported in outer headers.
+	UnexpectedOuterLayer4Protocol
+	{
+		/// Protocol number.
+		protocol: u8,
+	},
+	
+	/// The outer User Datagram Protocol (UDP) header is too short or has a bad length.
+	OuterUserDatagramProtocolHeaderIsInvalid,
+	
+	/// The outer User Datagram Protocol (UDP) check sum is non-zero and bad.
+	OuterUserDatagramProtocolCheckSumIsBad,
+	
+	/// The outer User Datagram Protocol (UDP) destination port is not that of the tunnel.
+	OuterUserDatagramProtocolDestinationPortIsNotForTunnel
+	{
+		/// Destination port.
+		destination_port: u16,
+	},
+	
+	/// The tunnel header within an outer internet protocol (IP) version 4 packet was invalid, or the tunnel is not configured.
+	InsideInternetProtocolVersion4
+	{
+		/// Why.
+		reason: TunnelDropReason,
+	},
+	
+	/// The tunnel header within an outer internet protocol (IP) version 6 packet was invalid, or the tunnel is not configured.
+	InsideInternetProtocolVersion6
+	{
+		/// Why.
+		reason: TunnelDropReason,
+	},
+}
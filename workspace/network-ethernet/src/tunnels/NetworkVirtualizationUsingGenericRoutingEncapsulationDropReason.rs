@@ -0,0 +1,36 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Network Virtualization using Generic Routing Encapsulation (NVGRE) packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum NetworkVirtualizationUsingGenericRoutingEncapsulationDropReason
+{
+	/// The underlying Generic Routing Encapsulation (GRE) header was invalid.
+	InvalidGenericRoutingEncapsulationHeader
+	{
+		/// Why.
+		reason: GenericRoutingEncapsulationDropReason,
+	},
+	
+	/// The check sum present or sequence number present flags were set; both must be clear (RFC 7637).
+	CheckSumOrSequenceNumberIsPresent,
+	
+	/// The key present flag was clear; the key holds the Virtual Subnet Identifier (VSID).
+	KeyIsAbsent,
+	
+	/// The protocol type is not transparent ethernet bridging.
+	ProtocolTypeIsNotTransparentEthernetBridging
+	{
+		/// Protocol type.
+		protocol_type: EtherType,
+	},
+	
+	/// There is no configuration for this Virtual Subnet Identifier (VSID).
+	NoConfigurationForVirtualSubnetIdentifier
+	{
+		/// Virtual Subnet Identifier (VSID).
+		virtual_subnet_identifier: VirtualNetworkIdentifier,
+	},
+}
@@ -0,0 +1,75 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Terminates Network Virtualization using Generic Routing Encapsulation (NVGRE) tunnels (RFC 7637).
+///
+/// Each Virtual Subnet Identifier (VSID) has its own Virtual LAN packet processing, used to process the inner ethernet frame.
+#[derive(Debug)]
+pub struct NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Virtual subnets.
+	pub virtual_subnets: HashMap<VirtualNetworkIdentifier, VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+}
+
+impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing<EINPDO, ARP, IPV4, IPV6>
+{
+	/// Validates the outer and tunnel headers and finds the packet processing for the Virtual Subnet Identifier (VSID).
+	#[inline(always)]
+	pub(crate) fn terminate(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<TerminatedTunnel<EINPDO, ARP, IPV4, IPV6>, InvalidTunnelPacketReason>
+	{
+		use self::NetworkVirtualizationUsingGenericRoutingEncapsulationDropReason::*;
+		
+		let tunnel_outer_headers = TunnelOuterHeaders::parse(packet, &self.our_tunnel_end_point_addresses)?;
+		
+		let (header_offset, length) = tunnel_outer_headers.validate_layer_4_protocol(TunnelOuterHeaders::GenericRoutingEncapsulation)?;
+		
+		let tunnel_drop = |reason| Err(tunnel_outer_headers.tunnel_drop_reason(TunnelDropReason::NetworkVirtualizationUsingGenericRoutingEncapsulation { reason }));
+		
+		let header = match GenericRoutingEncapsulationHeader::parse(packet, header_offset, length)
+		{
+			Err(reason) => return tunnel_drop(InvalidGenericRoutingEncapsulationHeader { reason }),
+			
+			Ok(header) => header,
+		};
+		
+		if unlikely!(header.is_check_sum_present || header.sequence_number.is_some())
+		{
+			return tunnel_drop(CheckSumOrSequenceNumberIsPresent)
+		}
+		
+		let key = match header.key
+		{
+			None => return tunnel_drop(KeyIsAbsent),
+			
+			Some(key) => key,
+		};
+		
+		let protocol_type = header.protocol_type;
+		if unlikely!(protocol_type != EtherType::TransparentEthernetBridging)
+		{
+			return tunnel_drop(ProtocolTypeIsNotTransparentEthernetBridging { protocol_type })
+		}
+		
+		// The lower 8 bits of the key are the FlowID, used only for entropy.
+		let virtual_subnet_identifier = VirtualNetworkIdentifier(key >> 8);
+		
+		match self.virtual_subnets.get(&virtual_subnet_identifier)
+		{
+			None => tunnel_drop(NoConfigurationForVirtualSubnetIdentifier { virtual_subnet_identifier }),
+			
+			Some(packet_processing_by_virtual_lan) => Ok
+			(
+				TerminatedTunnel
+				{
+					packet_processing_by_virtual_lan,
+					payload_offset: (header_offset as u16) + header.header_length,
+					payload_ether_type: protocol_type,
+				}
+			),
+		}
+	}
+}
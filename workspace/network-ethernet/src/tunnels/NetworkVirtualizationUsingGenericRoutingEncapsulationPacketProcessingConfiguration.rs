@@ -0,0 +1,37 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Configuration of Network Virtualization using Generic Routing Encapsulation (NVGRE) tunnel termination.
+#[derive(Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessingConfiguration<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Virtual subnets, keyed by Virtual Subnet Identifier (VSID).
+	pub virtual_subnets: HashMap<VirtualNetworkIdentifier, VirtualNetworkPacketProcessingConfiguration<ARP, IPV4, IPV6>>,
+}
+
+impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration> NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessingConfiguration<ARP, IPV4, IPV6>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Configure.
+	///
+	/// Fails if the Virtual LAN packet processing of a tunnel can not be configured (see `VirtualLanPacketProcessingConfiguration::configure()`).
+	#[inline(always)]
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(mut self, dropped_packet_reporting: &Rc<EINPDO>) -> Result<NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
+	{
+		Ok
+		(
+			NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing
+			{
+				our_tunnel_end_point_addresses: self.our_tunnel_end_point_addresses,
+				
+				virtual_subnets: self.virtual_subnets.drain().map(|(virtual_subnet_identifier, virtual_subnet)| virtual_subnet.configure(dropped_packet_reporting).map(|virtual_subnet| (virtual_subnet_identifier, virtual_subnet))).collect::<Result<_, _>>()?,
+			}
+		)
+	}
+}
@@ -0,0 +1,45 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Internet check sum (RFC 1071) calculation, used to validate outer tunnel headers when hardware has not done so.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct OnesComplementSum(u32);
+
+impl OnesComplementSum
+{
+	/// `bytes` are treated as a sequence of big-endian 16-bit words; an odd final byte is padded with zero.
+	///
+	/// Only the final call may pass an odd number of bytes.
+	#[inline(always)]
+	pub(crate) fn add_bytes(&mut self, bytes: &[u8])
+	{
+		let mut chunks = bytes.chunks(2);
+		while let Some(chunk) = chunks.next()
+		{
+			let word = if chunk.len() == 2
+			{
+				((chunk[0] as u32) << 8) | (chunk[1] as u32)
+			}
+			else
+			{
+				(chunk[0] as u32) << 8
+			};
+			self.add_word(word as u16);
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn add_word(&mut self, word: u16)
+	{
+		self.0 += word as u32;
+		self.0 = (self.0 & 0xFFFF) + (self.0 >> 16);
+	}
+	
+	/// Is the sum, including the transmitted check sum, valid (all ones)?
+	#[inline(always)]
+	pub(crate) fn is_valid(&self) -> bool
+	{
+		self.0 == 0xFFFF
+	}
+}
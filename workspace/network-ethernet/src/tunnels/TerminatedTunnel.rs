@@ -0,0 +1,64 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A tunnel packet whose headers have been validated.
+#[derive(Debug)]
+pub(crate) struct TerminatedTunnel<'a, EINPDO: 'a + EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: 'a + Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: 'a + Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: 'a + Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	/// Packet processing of the payload.
+	pub(crate) packet_processing_by_virtual_lan: &'a VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>,
+	
+	/// Offset of the payload from the start of the packet data, ie the length of the outer and tunnel headers.
+	pub(crate) payload_offset: u16,
+	
+	/// Either `EtherType::TransparentEthernetBridging` (the payload is an ethernet frame), `EtherType::InternetProtocolVersion4` or `EtherType::InternetProtocolVersion6`.
+	pub(crate) payload_ether_type: EtherType,
+}
+
+impl<'a, EINPDO: 'a + EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: 'a + Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: 'a + Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: 'a + Layer3PacketProcessing<CheckSumsValidated=bool>> TerminatedTunnel<'a, EINPDO, ARP, IPV4, IPV6>
+{
+	/// Is `payload_ether_type` one that can be a tunnel payload?
+	#[inline(always)]
+	pub(crate) fn is_supported_payload_ether_type(payload_ether_type: EtherType) -> bool
+	{
+		payload_ether_type == EtherType::TransparentEthernetBridging || payload_ether_type == EtherType::InternetProtocolVersion4 || payload_ether_type == EtherType::InternetProtocolVersion6
+	}
+	
+	/// Removes the outer and tunnel headers.
+	///
+	/// An internet protocol (IP) payload is given an ethernet header, with our valid unicast ethernet address for the tunnel as its destination and the outer source ethernet address as its source, so that it can be processed in the same way as an ethernet payload.
+	#[inline(always)]
+	pub(crate) fn decapsulate<EINP: EthernetIncomingNetworkPacket>(&self, packet: EINP) -> TunnelledEthernetIncomingNetworkPacket<EINP>
+	{
+		if self.payload_ether_type == EtherType::TransparentEthernetBridging
+		{
+			return TunnelledEthernetIncomingNetworkPacket::decapsulate(packet, self.payload_offset)
+		}
+		
+		// The outer ethernet and internet protocol (IP) headers are at least 34 bytes long, so the synthesized ethernet header never overlaps the outer ethernet header.
+		let ethernet_packet_header_offset = self.payload_offset - EthernetPacketHeader::SizeU16;
+		
+		let source = packet.ethernet_packet().ethernet_addresses().source;
+		let destination = *self.packet_processing_by_virtual_lan.none.our_valid_unicast_ethernet_address();
+		
+		let ethernet_packet_header = packet.offset_into_data::<EthernetPacketHeader>(ethernet_packet_header_offset as usize).as_ptr();
+		unsafe
+		{
+			*ethernet_packet_header = EthernetPacketHeader
+			{
+				ethernet_addresses: EthernetAddresses
+				{
+					destination,
+					source,
+				},
+				ether_type_or_legacy_ethernet_frame_size: EtherTypeOrLegacyEthernetFrameSize
+				{
+					ether_type: self.payload_ether_type,
+				},
+			}
+		}
+		
+		TunnelledEthernetIncomingNetworkPacket::decapsulate(packet, ethernet_packet_header_offset)
+	}
+}
@@ -0,0 +1,37 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a tunnel header, rather than the outer headers, was invalid, by kind of tunnel.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum TunnelDropReason
+{
+	/// Virtual eXtensible Local Area Network (VXLAN).
+	VirtualExtensibleLocalAreaNetwork
+	{
+		/// Why.
+		reason: VirtualExtensibleLocalAreaNetworkDropReason,
+	},
+	
+	/// Generic Routing Encapsulation (GRE).
+	GenericRoutingEncapsulation
+	{
+		/// Why.
+		reason: GenericRoutingEncapsulationDropReason,
+	},
+	
+	/// Network Virtualization using Generic Routing Encapsulation (NVGRE).
+	NetworkVirtualizationUsingGenericRoutingEncapsulation
+	{
+		/// Why.
+		reason: NetworkVirtualizationUsingGenericRoutingEncapsulationDropReason,
+	},
+	
+	/// Generic Network Virtualization Encapsulation (Geneve).
+	GenericNetworkVirtualizationEncapsulation
+	{
+		/// Why.
+		reason: GenericNetworkVirtualizationEncapsulationDropReason,
+	},
+}
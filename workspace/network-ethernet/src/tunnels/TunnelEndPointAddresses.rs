@@ -0,0 +1,34 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Our internet protocol (IP) addresses at which tunnels terminate.
+///
+/// Tunnel packets whose outer destination address is not one of these are dropped.
+#[derive(Debug, Default, Clone)]
+#[derive(Serialize, Deserialize)]
+pub struct TunnelEndPointAddresses
+{
+	/// Internet protocol (IP) version 4 addresses.
+	#[serde(default)]
+	pub internet_protocol_version_4: HashSet<Ipv4Addr>,
+	
+	/// Internet protocol (IP) version 6 addresses.
+	#[serde(default)]
+	pub internet_protocol_version_6: HashSet<Ipv6Addr>,
+}
+
+impl TunnelEndPointAddresses
+{
+	#[inline(always)]
+	pub(crate) fn is_internet_protocol_version_4_address_one_of_ours(&self, octets: &[u8; 4]) -> bool
+	{
+		self.internet_protocol_version_4.contains(&Ipv4Addr::from(*octets))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn is_internet_protocol_version_6_address_one_of_ours(&self, octets: &[u8; 16]) -> bool
+	{
+		self.internet_protocol_version_6.contains(&Ipv6Addr::from(*octets))
+	}
+}
@@ -0,0 +1,354 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Validated outer internet protocol (IP) header of a tunnel packet, and the position of the layer 4 (eg User Datagram Protocol (UDP)) header that follows it.
+///
+/// For tunnel packets, hardware offload check sum statuses are taken to refer to the inner packet (as is the case for DPDK), so outer check sums are always validated in software.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct TunnelOuterHeaders
+{
+	/// Offset of the layer 4 header from the start of the packet data.
+	pub(crate) layer_4_offset: usize,
+	
+	/// Length of the layer 4 header and its payload, as given by the outer internet protocol (IP) header; excludes any ethernet padding.
+	pub(crate) layer_4_length: u16,
+	
+	/// Internet protocol (IP) version 4 protocol number or internet protocol (IP) version 6 next header.
+	pub(crate) layer_4_protocol: u8,
+	
+	/// Sum of the source and destination addresses and the protocol, ie the pseudo-header less the layer 4 length.
+	pseudo_header_sum: OnesComplementSum,
+	
+	is_internet_protocol_version_6: bool,
+}
+
+impl TunnelOuterHeaders
+{
+	/// User Datagram Protocol (UDP) protocol number.
+	pub(crate) const UserDatagramProtocol: u8 = 17;
+	
+	/// Generic Routing Encapsulation (GRE) protocol number.
+	pub(crate) const GenericRoutingEncapsulation: u8 = 47;
+	
+	const UserDatagramProtocolHeaderSizeU16: u16 = 8;
+	
+	const InternetProtocolVersion4MinimumHeaderSizeU16: u16 = 20;
+	
+	const InternetProtocolVersion6HeaderSizeU16: u16 = 40;
+	
+	/// Validates the outer ethernet and internet protocol (IP) headers.
+	///
+	/// The outer ethernet frame may have IEEE 802.1Q or IEEE 802.1ad QinQ Virtual LAN tags, whether stripped by hardware or not; they are not otherwise used.
+	#[inline(always)]
+	pub(crate) fn parse(packet: impl EthernetIncomingNetworkPacket, our_tunnel_end_point_addresses: &TunnelEndPointAddresses) -> Result<Self, InvalidTunnelPacketReason>
+	{
+		let (layer_3_offset, ether_type) = Self::layer_3_offset_and_ether_type(packet)?;
+		
+		let layer_3_length = packet.packet_length_if_contiguous() - (layer_3_offset as u16);
+		
+		match ether_type
+		{
+			EtherType::InternetProtocolVersion4 => Self::parse_internet_protocol_version_4(packet, layer_3_offset, layer_3_length, our_tunnel_end_point_addresses),
+			
+			EtherType::InternetProtocolVersion6 => Self::parse_internet_protocol_version_6(packet, layer_3_offset, layer_3_length, our_tunnel_end_point_addresses),
+			
+			_ => Err(InvalidTunnelPacketReason::OuterLayer3ProtocolIsNotInternetProtocol),
+		}
+	}
+	
+	/// The offset, length and protocol of the layer 4 header, found without validating the outer internet protocol (IP) header.
+	///
+	/// Used to classify tunnel packets which hardware has not.
+	#[inline(always)]
+	pub(crate) fn peek_layer_4(packet: impl EthernetIncomingNetworkPacket) -> Option<(usize, u16, u8)>
+	{
+		let (layer_3_offset, ether_type) = Self::layer_3_offset_and_ether_type(packet).ok()?;
+		
+		let layer_3_length = packet.packet_length_if_contiguous().checked_sub(layer_3_offset as u16)?;
+		
+		match ether_type
+		{
+			EtherType::InternetProtocolVersion4 =>
+			{
+				if unlikely!(layer_3_length < Self::InternetProtocolVersion4MinimumHeaderSizeU16)
+				{
+					return None
+				}
+				
+				let header: &[u8; 20] = packet.offset_into_data_reference(layer_3_offset);
+				let header_length = ((header[0] & 0x0F) as u16) * 4;
+				if unlikely!(header_length < Self::InternetProtocolVersion4MinimumHeaderSizeU16 || header_length > layer_3_length)
+				{
+					return None
+				}
+				
+				Some((layer_3_offset + (header_length as usize), layer_3_length - header_length, header[9]))
+			}
+			
+			EtherType::InternetProtocolVersion6 =>
+			{
+				if unlikely!(layer_3_length < Self::InternetProtocolVersion6HeaderSizeU16)
+				{
+					return None
+				}
+				
+				let header: &[u8; 40] = packet.offset_into_data_reference(layer_3_offset);
+				
+				Some((layer_3_offset + (Self::InternetProtocolVersion6HeaderSizeU16 as usize), layer_3_length - Self::InternetProtocolVersion6HeaderSizeU16, header[6]))
+			}
+			
+			_ => None,
+		}
+	}
+	
+	/// The destination port of the User Datagram Protocol (UDP) header at `layer_4_offset`, if `layer_4_length` is long enough; the header is not otherwise validated.
+	#[inline(always)]
+	pub(crate) fn peek_user_datagram_protocol_destination_port(packet: impl EthernetIncomingNetworkPacket, layer_4_offset: usize, layer_4_length: u16) -> Option<u16>
+	{
+		if unlikely!(layer_4_length < Self::UserDatagramProtocolHeaderSizeU16)
+		{
+			return None
+		}
+		
+		let header: &[u8; 8] = packet.offset_into_data_reference(layer_4_offset);
+		Some(Self::network_endian_u16(header[2], header[3]))
+	}
+	
+	/// Nests `reason` inside the drop reason for the outer internet protocol (IP) version.
+	#[inline(always)]
+	pub(crate) fn tunnel_drop_reason(&self, reason: TunnelDropReason) -> InvalidTunnelPacketReason
+	{
+		if self.is_internet_protocol_version_6
+		{
+			InvalidTunnelPacketReason::InsideInternetProtocolVersion6 { reason }
+		}
+		else
+		{
+			InvalidTunnelPacketReason::InsideInternetProtocolVersion4 { reason }
+		}
+	}
+	
+	/// Validates that the layer 4 protocol is `layer_4_protocol`, eg Generic Routing Encapsulation (GRE), returning the offset and length of the layer 4 header.
+	#[inline(always)]
+	pub(crate) fn validate_layer_4_protocol(&self, layer_4_protocol: u8) -> Result<(usize, u16), InvalidTunnelPacketReason>
+	{
+		if unlikely!(self.layer_4_protocol != layer_4_protocol)
+		{
+			Err(InvalidTunnelPacketReason::UnexpectedOuterLayer4Protocol { protocol: self.layer_4_protocol })
+		}
+		else
+		{
+			Ok((self.layer_4_offset, self.layer_4_length))
+		}
+	}
+	
+	/// Validates the outer User Datagram Protocol (UDP) header, returning the offset and length of its payload.
+	///
+	/// A zero check sum is accepted, as permitted for tunnels over internet protocol (IP) version 4 (RFC 7348) and version 6 (RFC 6935).
+	#[inline(always)]
+	pub(crate) fn validate_user_datagram_protocol(&self, packet: impl EthernetIncomingNetworkPacket, tunnel_destination_port: u16) -> Result<(usize, u16), InvalidTunnelPacketReason>
+	{
+		use self::InvalidTunnelPacketReason::*;
+		
+		if unlikely!(self.layer_4_protocol != Self::UserDatagramProtocol)
+		{
+			return Err(UnexpectedOuterLayer4Protocol { protocol: self.layer_4_protocol })
+		}
+		
+		if unlikely!(self.layer_4_length < Self::UserDatagramProtocolHeaderSizeU16)
+		{
+			return Err(OuterUserDatagramProtocolHeaderIsInvalid)
+		}
+		
+		let header: &[u8; 8] = packet.offset_into_data_reference(self.layer_4_offset);
+		
+		let destination_port = Self::network_endian_u16(header[2], header[3]);
+		if unlikely!(destination_port != tunnel_destination_port)
+		{
+			return Err(OuterUserDatagramProtocolDestinationPortIsNotForTunnel { destination_port })
+		}
+		
+		let length = Self::network_endian_u16(header[4], header[5]);
+		if unlikely!(length < Self::UserDatagramProtocolHeaderSizeU16 || length > self.layer_4_length)
+		{
+			return Err(OuterUserDatagramProtocolHeaderIsInvalid)
+		}
+		
+		let check_sum = Self::network_endian_u16(header[6], header[7]);
+		if check_sum != 0
+		{
+			let mut sum = self.pseudo_header_sum;
+			sum.add_word(length);
+			sum.add_bytes(Self::bytes(packet, self.layer_4_offset, length));
+			if unlikely!(!sum.is_valid())
+			{
+				return Err(OuterUserDatagramProtocolCheckSumIsBad)
+			}
+		}
+		
+		Ok((self.layer_4_offset + (Self::UserDatagramProtocolHeaderSizeU16 as usize), length - Self::UserDatagramProtocolHeaderSizeU16))
+	}
+	
+	#[inline(always)]
+	fn layer_3_offset_and_ether_type(packet: impl EthernetIncomingNetworkPacket) -> Result<(usize, EtherType), InvalidTunnelPacketReason>
+	{
+		const UntaggedLayer3Offset: usize = EthernetPacketHeader::SizeU32 as usize;
+		const VirtualLanLayer3Offset: usize = UntaggedLayer3Offset + VirtualLanPacketHeader::IEEE_802_1Q_Size;
+		const QinQVirtualLanLayer3Offset: usize = UntaggedLayer3Offset + VirtualLanPacketHeader::IEEE_802_1ad_Size + VirtualLanPacketHeader::IEEE_802_1Q_Size;
+		
+		let ethernet_packet = packet.ethernet_packet();
+		
+		if packet.was_vlan_tag_control_information_stripped() || packet.was_vlan_qinq_tag_control_information_stripped()
+		{
+			return Ok((UntaggedLayer3Offset, ethernet_packet.potentially_invalid_ether_type()))
+		}
+		
+		match ethernet_packet.potentially_invalid_ether_type()
+		{
+			EtherType::VlanTagging =>
+			{
+				if unlikely!(packet.is_too_short_to_be_a_vlan_ethernet_packet())
+				{
+					return Err(InvalidTunnelPacketReason::OuterLayer3ProtocolIsNotInternetProtocol)
+				}
+				Ok((VirtualLanLayer3Offset, ethernet_packet.virtual_lan_packet().potentially_invalid_ether_type()))
+			}
+			
+			EtherType::QinQVlanTagging =>
+			{
+				if unlikely!(packet.is_too_short_to_be_a_qinq_vlan_ethernet_packet())
+				{
+					return Err(InvalidTunnelPacketReason::OuterLayer3ProtocolIsNotInternetProtocol)
+				}
+				Ok((QinQVirtualLanLayer3Offset, ethernet_packet.qinq_virtual_lan_packet().virtual_lan_packet().potentially_invalid_ether_type()))
+			}
+			
+			ether_type @ _ => Ok((UntaggedLayer3Offset, ether_type)),
+		}
+	}
+	
+	#[inline(always)]
+	fn parse_internet_protocol_version_4(packet: impl EthernetIncomingNetworkPacket, layer_3_offset: usize, layer_3_length: u16, our_tunnel_end_point_addresses: &TunnelEndPointAddresses) -> Result<Self, InvalidTunnelPacketReason>
+	{
+		use self::InvalidTunnelPacketReason::*;
+		
+		const Version: u8 = 4;
+		const FragmentOffsetAndMoreFragmentsMask: u16 = 0x3FFF;
+		
+		if unlikely!(layer_3_length < Self::InternetProtocolVersion4MinimumHeaderSizeU16)
+		{
+			return Err(OuterInternetProtocolVersion4HeaderIsInvalid)
+		}
+		
+		let header: &[u8; 20] = packet.offset_into_data_reference(layer_3_offset);
+		
+		if unlikely!(header[0] >> 4 != Version)
+		{
+			return Err(OuterInternetProtocolVersion4HeaderIsInvalid)
+		}
+		
+		let header_length = ((header[0] & 0x0F) as u16) * 4;
+		let total_length = Self::network_endian_u16(header[2], header[3]);
+		if unlikely!(header_length < Self::InternetProtocolVersion4MinimumHeaderSizeU16 || header_length > total_length || total_length > layer_3_length)
+		{
+			return Err(OuterInternetProtocolVersion4HeaderIsInvalid)
+		}
+		
+		if unlikely!(Self::network_endian_u16(header[6], header[7]) & FragmentOffsetAndMoreFragmentsMask != 0)
+		{
+			return Err(OuterInternetProtocolVersion4PacketIsAFragment)
+		}
+		
+		let mut header_sum = OnesComplementSum::default();
+		header_sum.add_bytes(Self::bytes(packet, layer_3_offset, header_length));
+		if unlikely!(!header_sum.is_valid())
+		{
+			return Err(OuterInternetProtocolVersion4CheckSumIsBad)
+		}
+		
+		if unlikely!(!our_tunnel_end_point_addresses.is_internet_protocol_version_4_address_one_of_ours(array_ref!(header, 16, 4)))
+		{
+			return Err(OuterDestinationInternetProtocolAddressIsNotOneOfOurs)
+		}
+		
+		let layer_4_protocol = header[9];
+		
+		let mut pseudo_header_sum = OnesComplementSum::default();
+		pseudo_header_sum.add_bytes(array_ref!(header, 12, 8));
+		pseudo_header_sum.add_word(layer_4_protocol as u16);
+		
+		Ok
+		(
+			Self
+			{
+				layer_4_offset: layer_3_offset + (header_length as usize),
+				layer_4_length: total_length - header_length,
+				layer_4_protocol,
+				pseudo_header_sum,
+				is_internet_protocol_version_6: false,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn parse_internet_protocol_version_6(packet: impl EthernetIncomingNetworkPacket, layer_3_offset: usize, layer_3_length: u16, our_tunnel_end_point_addresses: &TunnelEndPointAddresses) -> Result<Self, InvalidTunnelPacketReason>
+	{
+		use self::InvalidTunnelPacketReason::*;
+		
+		const Version: u8 = 6;
+		
+		if unlikely!(layer_3_length < Self::InternetProtocolVersion6HeaderSizeU16)
+		{
+			return Err(OuterInternetProtocolVersion6HeaderIsInvalid)
+		}
+		
+		let header: &[u8; 40] = packet.offset_into_data_reference(layer_3_offset);
+		
+		if unlikely!(header[0] >> 4 != Version)
+		{
+			return Err(OuterInternetProtocolVersion6HeaderIsInvalid)
+		}
+		
+		let payload_length = Self::network_endian_u16(header[4], header[5]);
+		if unlikely!(payload_length > layer_3_length - Self::InternetProtocolVersion6HeaderSizeU16)
+		{
+			return Err(OuterInternetProtocolVersion6HeaderIsInvalid)
+		}
+		
+		if unlikely!(!our_tunnel_end_point_addresses.is_internet_protocol_version_6_address_one_of_ours(array_ref!(header, 24, 16)))
+		{
+			return Err(OuterDestinationInternetProtocolAddressIsNotOneOfOurs)
+		}
+		
+		let layer_4_protocol = header[6];
+		
+		let mut pseudo_header_sum = OnesComplementSum::default();
+		pseudo_header_sum.add_bytes(array_ref!(header, 8, 32));
+		pseudo_header_sum.add_word(layer_4_protocol as u16);
+		
+		Ok
+		(
+			Self
+			{
+				layer_4_offset: layer_3_offset + (Self::InternetProtocolVersion6HeaderSizeU16 as usize),
+				layer_4_length: payload_length,
+				layer_4_protocol,
+				pseudo_header_sum,
+				is_internet_protocol_version_6: true,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn bytes<'a>(packet: impl EthernetIncomingNetworkPacket, offset: usize, length: u16) -> &'a [u8]
+	{
+		unsafe { from_raw_parts(packet.offset_into_data::<u8>(offset).as_ptr() as *const u8, length as usize) }
+	}
+	
+	#[inline(always)]
+	pub(crate) fn network_endian_u16(upper: u8, lower: u8) -> u16
+	{
+		((upper as u16) << 8) | (lower as u16)
+	}
+}
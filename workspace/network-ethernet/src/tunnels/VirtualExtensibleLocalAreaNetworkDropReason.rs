@@ -0,0 +1,22 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a Virtual eXtensible Local Area Network (VXLAN) packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum VirtualExtensibleLocalAreaNetworkDropReason
+{
+	/// The header is too short.
+	HeaderIsTooShort,
+	
+	/// The `I` flag is not set.
+	ValidVirtualNetworkIdentifierFlagIsNotSet,
+	
+	/// There is no configuration for this Virtual Network Identifier (VNI).
+	NoConfigurationForVirtualNetworkIdentifier
+	{
+		/// Virtual Network Identifier (VNI).
+		virtual_network_identifier: VirtualNetworkIdentifier,
+	},
+}
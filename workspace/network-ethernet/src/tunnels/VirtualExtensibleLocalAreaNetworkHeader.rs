@@ -0,0 +1,54 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Virtual eXtensible Local Area Network (VXLAN) header (RFC 7348, Section 5).
+///
+/// Follows the outer User Datagram Protocol (UDP) header and precedes the inner ethernet frame.
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct VirtualExtensibleLocalAreaNetworkHeader
+{
+	/// Flags; only the `I` flag is defined.
+	pub flags: u8,
+	
+	/// Reserved; ignored on receipt.
+	pub first_reserved: [u8; 3],
+	
+	/// Virtual Network Identifier (VNI).
+	pub virtual_network_identifier: [u8; 3],
+	
+	/// Reserved; ignored on receipt.
+	pub second_reserved: u8,
+}
+
+impl VirtualExtensibleLocalAreaNetworkHeader
+{
+	/// Size of header.
+	pub const Size: usize = 8;
+	
+	/// Size of header.
+	pub const SizeU16: u16 = Self::Size as u16;
+	
+	/// The `I` flag, which must be set for the Virtual Network Identifier (VNI) to be valid.
+	pub const ValidVirtualNetworkIdentifierFlag: u8 = 0b0000_1000;
+	
+	/// Well-known User Datagram Protocol (UDP) destination port assigned by IANA.
+	pub const UserDatagramProtocolPort: u16 = 4789;
+	
+	/// Virtual Network Identifier (VNI), if the `I` flag is set.
+	///
+	/// Other flags and reserved fields are ignored, as required by RFC 7348.
+	#[inline(always)]
+	pub fn virtual_network_identifier(&self) -> Option<VirtualNetworkIdentifier>
+	{
+		if self.flags & Self::ValidVirtualNetworkIdentifierFlag == 0
+		{
+			None
+		}
+		else
+		{
+			Some(VirtualNetworkIdentifier::from_octets(&self.virtual_network_identifier))
+		}
+	}
+}
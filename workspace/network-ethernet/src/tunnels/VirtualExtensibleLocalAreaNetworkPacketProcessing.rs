@@ -0,0 +1,64 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Terminates Virtual eXtensible Local Area Network (VXLAN) tunnels (RFC 7348).
+///
+/// Each Virtual Network Identifier (VNI) has its own Virtual LAN packet processing, used to process the inner ethernet frame.
+#[derive(Debug)]
+pub struct VirtualExtensibleLocalAreaNetworkPacketProcessing<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>>
+{
+	/// User Datagram Protocol (UDP) destination port.
+	pub user_datagram_protocol_destination_port: u16,
+	
+	/// Our tunnel end point addresses.
+	pub our_tunnel_end_point_addresses: TunnelEndPointAddresses,
+	
+	/// Virtual networks.
+	pub virtual_networks: HashMap<VirtualNetworkIdentifier, VirtualLanPacketProcessing<EINPDO, ARP, IPV4, IPV6>>,
+}
+
+impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=ARP::DropReason, IPV4INPDR=IPV4::DropReason, IPV6INPDR=IPV6::DropReason>, ARP: Layer3PacketProcessing<CheckSumsValidated=()>, IPV4: Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6: Layer3PacketProcessing<CheckSumsValidated=bool>> VirtualExtensibleLocalAreaNetworkPacketProcessing<EINPDO, ARP, IPV4, IPV6>
+{
+	/// Validates the outer and tunnel headers and finds the packet processing for the Virtual Network Identifier (VNI).
+	#[inline(always)]
+	pub(crate) fn terminate(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<TerminatedTunnel<EINPDO, ARP, IPV4, IPV6>, InvalidTunnelPacketReason>
+	{
+		use self::VirtualExtensibleLocalAreaNetworkDropReason::*;
+		
+		let tunnel_outer_headers = TunnelOuterHeaders::parse(packet, &self.our_tunnel_end_point_addresses)?;
+		
+		let (payload_offset, payload_length) = tunnel_outer_headers.validate_user_datagram_protocol(packet, self.user_datagram_protocol_destination_port)?;
+		
+		let tunnel_drop = |reason| Err(tunnel_outer_headers.tunnel_drop_reason(TunnelDropReason::VirtualExtensibleLocalAreaNetwork { reason }));
+		
+		if unlikely!(payload_length < VirtualExtensibleLocalAreaNetworkHeader::SizeU16)
+		{
+			return tunnel_drop(HeaderIsTooShort)
+		}
+		
+		let header: &VirtualExtensibleLocalAreaNetworkHeader = packet.offset_into_data_reference(payload_offset);
+		
+		let virtual_network_identifier = match header.virtual_network_identifier()
+		{
+			None => return tunnel_drop(ValidVirtualNetworkIdentifierFlagIsNotSet),
+			
+			Some(virtual_network_identifier) => virtual_network_identifier,
+		};
+		
+		match self.virtual_networks.get(&virtual_network_identifier)
+		{
+			None => tunnel_drop(NoConfigurationForVirtualNetworkIdentifier { virtual_network_identifier }),
+			
+			Some(packet_processing_by_virtual_lan) => Ok
+			(
+				TerminatedTunnel
+				{
+					packet_processing_by_virtual_lan,
+					payload_offset: (payload_offset + VirtualExtensibleLocalAreaNetworkHeader::Size) as u16,
+					payload_ether_type: EtherType::TransparentEthernetBridging,
+				}
+			),
+		}
+	}
+}
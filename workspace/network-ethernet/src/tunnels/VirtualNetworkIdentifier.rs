@@ -0,0 +1,65 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A Virtual eXtensible Local Area Network (VXLAN) Network Identifier (VNI), a 24-bit value.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct VirtualNetworkIdentifier(u32);
+
+impl Display for VirtualNetworkIdentifier
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "0x{:06X}", self.0)
+	}
+}
+
+impl Into<u32> for VirtualNetworkIdentifier
+{
+	#[inline(always)]
+	fn into(self) -> u32
+	{
+		self.0
+	}
+}
+
+impl TryFrom<u32> for VirtualNetworkIdentifier
+{
+	type Error = ();
+	
+	#[inline(always)]
+	fn try_from(value: u32) -> Result<Self, Self::Error>
+	{
+		Self::new(value)
+	}
+}
+
+impl VirtualNetworkIdentifier
+{
+	/// Maximum.
+	pub const Maximum: u32 = 0x00FF_FFFF;
+	
+	/// Parse.
+	///
+	/// Returns an error if > 0xFFFFFF.
+	#[inline(always)]
+	pub fn new(value: u32) -> Result<Self, ()>
+	{
+		if value <= Self::Maximum
+		{
+			Ok(VirtualNetworkIdentifier(value))
+		}
+		else
+		{
+			Err(())
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn from_octets(octets: &[u8; 3]) -> Self
+	{
+		VirtualNetworkIdentifier(((octets[0] as u32) << 16) | ((octets[1] as u32) << 8) | (octets[2] as u32))
+	}
+}
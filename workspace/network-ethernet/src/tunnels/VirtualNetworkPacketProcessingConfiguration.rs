@@ -0,0 +1,29 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Configuration of packet processing for the inner ethernet frames of a virtual network.
+#[derive(Debug)]
+#[derive(Serialize, Deserialize)]
+pub struct VirtualNetworkPacketProcessingConfiguration<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Our valid unicast ethernet address on this virtual network; this is usually not the same as that of the network interface.
+	pub our_valid_unicast_ethernet_address: MediaAccessControlAddress,
+	
+	/// Packet processing of inner ethernet frames, which may themselves have Virtual LAN tags.
+	pub packet_processing: VirtualLanPacketProcessingConfiguration<ARP, IPV4, IPV6>,
+}
+
+impl<ARP: Layer3PacketProcessingConfiguration, IPV4: Layer3PacketProcessingConfiguration, IPV6: Layer3PacketProcessingConfiguration> VirtualNetworkPacketProcessingConfiguration<ARP, IPV4, IPV6>
+	where ARP::L3PP : Layer3PacketProcessing<CheckSumsValidated=()>, IPV4::L3PP : Layer3PacketProcessing<CheckSumsValidated=(bool, bool)>, IPV6::L3PP : Layer3PacketProcessing<CheckSumsValidated=bool>
+{
+	/// Configure.
+	///
+	/// Fails if the Virtual LAN packet processing can not be configured (see `VirtualLanPacketProcessingConfiguration::configure()`).
+	#[inline(always)]
+	pub fn configure<EINPDO: EthernetIncomingNetworkPacketDropObserver<ARPINPDR=<ARP::L3PP as Layer3PacketProcessing>::DropReason, IPV4INPDR=<IPV4::L3PP as Layer3PacketProcessing>::DropReason, IPV6INPDR=<IPV6::L3PP as Layer3PacketProcessing>::DropReason>>(self, dropped_packet_reporting: &Rc<EINPDO>) -> Result<VirtualLanPacketProcessing<EINPDO, ARP::L3PP, IPV4::L3PP, IPV6::L3PP>, VirtualLanPacketProcessingConfigurationError>
+	{
+		self.packet_processing.configure(dropped_packet_reporting, self.our_valid_unicast_ethernet_address, None)
+	}
+}
@@ -0,0 +1,33 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+use super::*;
+
+
+include!("ConfiguredTunnel.rs");
+include!("GenericNetworkVirtualizationEncapsulationDropReason.rs");
+include!("GenericNetworkVirtualizationEncapsulationHeader.rs");
+include!("GenericNetworkVirtualizationEncapsulationPacketProcessing.rs");
+include!("GenericNetworkVirtualizationEncapsulationPacketProcessingConfiguration.rs");
+include!("GenericRoutingEncapsulationDropReason.rs");
+include!("GenericRoutingEncapsulationHeader.rs");
+include!("GenericRoutingEncapsulationPacketProcessing.rs");
+include!("GenericRoutingEncapsulationPacketProcessingConfiguration.rs");
+include!("GenericRoutingEncapsulationTunnel.rs");
+include!("InvalidTunnelPacketReason.rs");
+include!("NetworkVirtualizationUsingGenericRoutingEncapsulationDropReason.rs");
+include!("NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessing.rs");
+include!("NetworkVirtualizationUsingGenericRoutingEncapsulationPacketProcessingConfiguration.rs");
+include!("OnesComplementSum.rs");
+include!("TerminatedTunnel.rs");
+include!("TunnelDropReason.rs");
+include!("TunnelEndPointAddresses.rs");
+include!("TunnelOuterHeaders.rs");
+include!("TunnelledEthernetIncomingNetworkPacket.rs");
+include!("VirtualExtensibleLocalAreaNetworkDropReason.rs");
+include!("VirtualExtensibleLocalAreaNetworkHeader.rs");
+include!("VirtualExtensibleLocalAreaNetworkPacketProcessing.rs");
+include!("VirtualExtensibleLocalAreaNetworkPacketProcessingConfiguration.rs");
+include!("VirtualNetworkIdentifier.rs");
+include!("VirtualNetworkPacketProcessingConfiguration.rs");
@@ -73,6 +73,8 @@ impl Ord for ClassOfService
 
 impl ClassOfService
 {
+	pub(crate) const NumberOfClassesOfService: usize = 8;
+	
 	/// Equivalent `DifferentiatedServiceCodePoint`.
 	#[inline(always)]
 	pub fn equivalent_differentiated_service_code_point(&self) -> DifferentiatedServiceCodePoint
@@ -93,7 +95,22 @@ impl ClassOfService
 	}
 	
 	#[inline(always)]
-	fn order_value(&self) -> u8
+	pub(crate) fn from_order_value(order_value: u8) -> Self
+	{
+		use self::ClassOfService::*;
+		
+		match order_value
+		{
+			0 => Background,
+			
+			1 => BestEffort,
+			
+			other @ _ => unsafe { transmute(other) },
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn order_value(&self) -> u8
 	{
 		use self::ClassOfService::*;
 		
@@ -0,0 +1,109 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A colour-blind two rate three colour marker (RFC 2698) policer for one class of service.
+///
+/// Token buckets are kept in thousandths of a byte so that refilling them every millisecond does not lose fractions of a byte.
+#[derive(Debug)]
+pub struct ClassOfServicePolicer
+{
+	configuration: ClassOfServicePolicerConfiguration,
+	state: RefCell<ClassOfServicePolicerState>,
+}
+
+#[derive(Debug)]
+struct ClassOfServicePolicerState
+{
+	committed_tokens: u64,
+	peak_tokens: u64,
+	last_refilled_at: MonotonicMillisecondTimestamp,
+}
+
+impl ClassOfServicePolicer
+{
+	const MillibytesPerByte: u64 = 1000;
+	
+	#[inline(always)]
+	pub(crate) fn new(configuration: ClassOfServicePolicerConfiguration) -> Self
+	{
+		Self
+		{
+			state: RefCell::new
+			(
+				ClassOfServicePolicerState
+				{
+					committed_tokens: configuration.committed_burst_size * Self::MillibytesPerByte,
+					peak_tokens: configuration.peak_burst_size * Self::MillibytesPerByte,
+					last_refilled_at: MonotonicMillisecondTimestamp::Zero,
+				}
+			),
+			configuration,
+		}
+	}
+	
+	/// Marks a packet of `packet_length` bytes and returns the action to take, if any.
+	#[inline(always)]
+	pub(crate) fn police(&self, now: MonotonicMillisecondTimestamp, packet_length: u16) -> Option<(PolicingColour, PolicingAction)>
+	{
+		use self::PolicingColour::*;
+		
+		match self.mark(now, packet_length)
+		{
+			Green => None,
+			Yellow => Some((Yellow, self.configuration.yellow_action)),
+			Red => Some((Red, self.configuration.red_action)),
+		}
+	}
+	
+	/// Marks a packet of `packet_length` bytes, consuming tokens unless it is red.
+	#[inline(always)]
+	pub fn mark(&self, now: MonotonicMillisecondTimestamp, packet_length: u16) -> PolicingColour
+	{
+		let mut state = self.state.borrow_mut();
+		
+		self.refill(&mut state, now);
+		
+		let packet_tokens = (packet_length as u64) * Self::MillibytesPerByte;
+		
+		if state.peak_tokens < packet_tokens
+		{
+			PolicingColour::Red
+		}
+		else if state.committed_tokens < packet_tokens
+		{
+			state.peak_tokens -= packet_tokens;
+			PolicingColour::Yellow
+		}
+		else
+		{
+			state.peak_tokens -= packet_tokens;
+			state.committed_tokens -= packet_tokens;
+			PolicingColour::Green
+		}
+	}
+	
+	#[inline(always)]
+	fn refill(&self, state: &mut ClassOfServicePolicerState, now: MonotonicMillisecondTimestamp)
+	{
+		if now <= state.last_refilled_at
+		{
+			return
+		}
+		
+		let elapsed: u64 = (now - state.last_refilled_at).into();
+		state.last_refilled_at = now;
+		
+		// A rate in bytes per second is also a rate in thousandths of a byte per millisecond.
+		let configuration = &self.configuration;
+		state.committed_tokens = Self::add_tokens(state.committed_tokens, configuration.committed_information_rate, elapsed, configuration.committed_burst_size);
+		state.peak_tokens = Self::add_tokens(state.peak_tokens, configuration.peak_information_rate, elapsed, configuration.peak_burst_size);
+	}
+	
+	#[inline(always)]
+	fn add_tokens(tokens: u64, rate: u64, elapsed: u64, burst_size: u64) -> u64
+	{
+		let maximum_tokens = burst_size.saturating_mul(Self::MillibytesPerByte);
+		min(tokens.saturating_add(rate.saturating_mul(elapsed)), maximum_tokens)
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Configuration of a two rate three colour marker (RFC 2698) policer for one class of service.
+///
+/// Rates are in bytes per second and burst sizes in bytes; packet lengths include the ethernet header.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub struct ClassOfServicePolicerConfiguration
+{
+	/// Committed information rate (CIR), in bytes per second.
+	pub committed_information_rate: u64,
+	
+	/// Committed burst size (CBS), in bytes.
+	pub committed_burst_size: u64,
+	
+	/// Peak information rate (PIR), in bytes per second.
+	///
+	/// Should be equal to or greater than `committed_information_rate`.
+	pub peak_information_rate: u64,
+	
+	/// Peak burst size (PBS), in bytes.
+	pub peak_burst_size: u64,
+	
+	/// Action for yellow packets.
+	#[serde(default = "ClassOfServicePolicerConfiguration::yellow_action_default")]
+	pub yellow_action: PolicingAction,
+	
+	/// Action for red packets.
+	#[serde(default = "ClassOfServicePolicerConfiguration::red_action_default")]
+	pub red_action: PolicingAction,
+}
+
+impl ClassOfServicePolicerConfiguration
+{
+	/// Configure.
+	///
+	/// Both token buckets start full.
+	#[inline(always)]
+	pub fn configure(self) -> ClassOfServicePolicer
+	{
+		debug_assert!(self.peak_information_rate >= self.committed_information_rate, "peak_information_rate '{}' is less than committed_information_rate '{}'", self.peak_information_rate, self.committed_information_rate);
+		
+		ClassOfServicePolicer::new(self)
+	}
+	
+	#[inline(always)]
+	fn yellow_action_default() -> PolicingAction
+	{
+		PolicingAction::SetDropEligibleIndicator
+	}
+	
+	#[inline(always)]
+	fn red_action_default() -> PolicingAction
+	{
+		PolicingAction::Drop
+	}
+}
@@ -0,0 +1,32 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Two rate three colour marker (RFC 2698) policers by class of service.
+#[derive(Debug, Default)]
+pub struct ClassOfServicePolicers([Option<ClassOfServicePolicer>; 8]);
+
+impl ClassOfServicePolicers
+{
+	#[inline(always)]
+	pub(crate) fn new(configuration: HashMap<ClassOfService, ClassOfServicePolicerConfiguration>) -> Self
+	{
+		let mut this = Self::default();
+		for (class_of_service, policer_configuration) in configuration
+		{
+			unsafe { *this.0.get_unchecked_mut(class_of_service as u8 as usize) = Some(policer_configuration.configure()) }
+		}
+		this
+	}
+	
+	/// Polices a packet of `packet_length` bytes and `class_of_service`, returning the action to take, if any.
+	#[inline(always)]
+	pub(crate) fn police(&self, now: MonotonicMillisecondTimestamp, class_of_service: ClassOfService, packet_length: u16) -> Option<(PolicingColour, PolicingAction)>
+	{
+		match unsafe { self.0.get_unchecked(class_of_service as u8 as usize) }
+		{
+			&None => None,
+			&Some(ref policer) => policer.police(now, packet_length),
+		}
+	}
+}
@@ -47,6 +47,8 @@ impl Into<u8> for DifferentiatedServiceCodePoint
 
 impl DifferentiatedServiceCodePoint
 {
+	pub(crate) const NumberOfDifferentiatedServiceCodePoints: usize = 64;
+	
 	/// Also known as Best Effort.
 	pub const DefaultForwarding: DifferentiatedServiceCodePoint = DifferentiatedServiceCodePoint(0b000000);
 	
@@ -104,4 +106,17 @@ impl DifferentiatedServiceCodePoint
 	{
 		self.0 & 0b11 != 0
 	}
+	
+	/// Class selector (the top three bits), eg `5` for Expedited Forwarding.
+	#[inline(always)]
+	pub fn class_selector(self) -> u8
+	{
+		(self.0 & 0b111000) >> 3
+	}
+	
+	#[inline(always)]
+	pub(crate) fn index(self) -> usize
+	{
+		(self.0 & 0b111111) as usize
+	}
 }
@@ -0,0 +1,93 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// An internal priority, 0 (lowest) to 7 (highest) inclusive, used to queue and account for packets by class.
+///
+/// Assigned to received packets by a `QualityOfServiceMapping` from their IEEE 802.1p class of service or Differentiated Service Code Point (DSCP).
+///
+/// Defaults to `Lowest`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize)]
+pub struct InternalPriority(u8);
+
+impl<'deserialize> Deserialize<'deserialize> for InternalPriority
+{
+	#[inline(always)]
+	fn deserialize<D: Deserializer<'deserialize>>(deserializer: D) -> Result<Self, D::Error>
+	{
+		let value = u8::deserialize(deserializer)?;
+		Self::new(value).map_err(|()| <D::Error as de::Error>::custom("InternalPriority is greater than 7"))
+	}
+}
+
+impl Display for InternalPriority
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.0)
+	}
+}
+
+impl Default for InternalPriority
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::Lowest
+	}
+}
+
+impl Into<u8> for InternalPriority
+{
+	#[inline(always)]
+	fn into(self) -> u8
+	{
+		self.0
+	}
+}
+
+impl TryFrom<u8> for InternalPriority
+{
+	type Error = ();
+	
+	#[inline(always)]
+	fn try_from(value: u8) -> Result<Self, Self::Error>
+	{
+		Self::new(value)
+	}
+}
+
+impl InternalPriority
+{
+	/// Lowest.
+	pub const Lowest: Self = InternalPriority(0);
+	
+	/// Highest.
+	pub const Highest: Self = InternalPriority(7);
+	
+	pub(crate) const NumberOfInternalPriorities: usize = 8;
+	
+	/// Parse.
+	///
+	/// Returns an error if greater than 7.
+	#[inline(always)]
+	pub fn new(value: u8) -> Result<Self, ()>
+	{
+		if value <= Self::Highest.0
+		{
+			Ok(InternalPriority(value))
+		}
+		else
+		{
+			Err(())
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn index(self) -> usize
+	{
+		self.0 as usize
+	}
+}
@@ -0,0 +1,27 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Colour assigned to a packet by a two rate three colour marker (RFC 2698).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PolicingColour
+{
+	/// Does not exceed the committed information rate (CIR).
+	Green,
+	
+	/// Exceeds the committed information rate (CIR) but not the peak information rate (PIR).
+	Yellow,
+	
+	/// Exceeds the peak information rate (PIR).
+	Red,
+}
+
+impl Display for PolicingColour
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
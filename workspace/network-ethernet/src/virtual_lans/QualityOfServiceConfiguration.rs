@@ -0,0 +1,43 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Quality of service trust mode and mapping tables configuration.
+///
+/// Mappings not present use a default which is consistent with `ClassOfService::equivalent_differentiated_service_code_point()`:-
+///
+/// * a class of service maps to its internal priority in order, ie `Background` is `0`, `BestEffort` is `1` and `NetworkControl` is `7`;
+/// * a Differentiated Service Code Point (DSCP) maps to its class selector, ie its top three bits;
+/// * an internal priority maps to the class of service of the same order, and to that class of service's equivalent Differentiated Service Code Point (DSCP).
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct QualityOfServiceConfiguration
+{
+	/// Trust mode.
+	#[serde(default)] pub trust_mode: QualityOfServiceTrustMode,
+	
+	/// Internal priority for packets whose markings are not trusted or are absent.
+	#[serde(default)] pub default_internal_priority: InternalPriority,
+	
+	/// Ingress IEEE 802.1p class of service to internal priority.
+	#[serde(default)] pub class_of_service_to_internal_priority: HashMap<ClassOfService, InternalPriority>,
+	
+	/// Ingress Differentiated Service Code Point (DSCP) to internal priority.
+	#[serde(default)] pub differentiated_service_code_point_to_internal_priority: HashMap<DifferentiatedServiceCodePoint, InternalPriority>,
+	
+	/// Egress internal priority to IEEE 802.1p class of service.
+	#[serde(default)] pub internal_priority_to_class_of_service: HashMap<InternalPriority, ClassOfService>,
+	
+	/// Egress internal priority to Differentiated Service Code Point (DSCP).
+	#[serde(default)] pub internal_priority_to_differentiated_service_code_point: HashMap<InternalPriority, DifferentiatedServiceCodePoint>,
+}
+
+impl QualityOfServiceConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self) -> QualityOfServiceMapping
+	{
+		QualityOfServiceMapping::new(self)
+	}
+}
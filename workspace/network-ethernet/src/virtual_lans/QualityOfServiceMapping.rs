@@ -0,0 +1,135 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Quality of service trust mode and mapping tables.
+///
+/// Lookups are by indexing dense arrays.
+#[derive(Debug)]
+pub struct QualityOfServiceMapping
+{
+	trust_mode: QualityOfServiceTrustMode,
+	default_internal_priority: InternalPriority,
+	class_of_service_to_internal_priority: [InternalPriority; ClassOfService::NumberOfClassesOfService],
+	differentiated_service_code_point_to_internal_priority: Box<[InternalPriority]>,
+	internal_priority_to_class_of_service: [ClassOfService; InternalPriority::NumberOfInternalPriorities],
+	internal_priority_to_differentiated_service_code_point: [DifferentiatedServiceCodePoint; InternalPriority::NumberOfInternalPriorities],
+}
+
+impl Default for QualityOfServiceMapping
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		QualityOfServiceConfiguration::default().configure()
+	}
+}
+
+impl QualityOfServiceMapping
+{
+	#[inline(always)]
+	pub(crate) fn new(mut configuration: QualityOfServiceConfiguration) -> Self
+	{
+		let mut class_of_service_to_internal_priority = [InternalPriority::default(); ClassOfService::NumberOfClassesOfService];
+		for (index, internal_priority) in class_of_service_to_internal_priority.iter_mut().enumerate()
+		{
+			let class_of_service: ClassOfService = unsafe { transmute(index as u8) };
+			*internal_priority = match configuration.class_of_service_to_internal_priority.remove(&class_of_service)
+			{
+				Some(internal_priority) => internal_priority,
+				None => InternalPriority(class_of_service.order_value()),
+			}
+		}
+		
+		let differentiated_service_code_point_to_internal_priority = (0 .. DifferentiatedServiceCodePoint::NumberOfDifferentiatedServiceCodePoints).map(|index|
+		{
+			let differentiated_service_code_point = DifferentiatedServiceCodePoint::from(index as u8);
+			match configuration.differentiated_service_code_point_to_internal_priority.get(&differentiated_service_code_point)
+			{
+				Some(&internal_priority) => internal_priority,
+				None => InternalPriority(differentiated_service_code_point.class_selector()),
+			}
+		}).collect::<Vec<_>>().into_boxed_slice();
+		
+		let mut internal_priority_to_class_of_service = [ClassOfService::default(); InternalPriority::NumberOfInternalPriorities];
+		let mut internal_priority_to_differentiated_service_code_point = [DifferentiatedServiceCodePoint::default(); InternalPriority::NumberOfInternalPriorities];
+		for index in 0 .. InternalPriority::NumberOfInternalPriorities
+		{
+			let internal_priority = InternalPriority(index as u8);
+			let equivalent_class_of_service = ClassOfService::from_order_value(index as u8);
+			
+			internal_priority_to_class_of_service[index] = match configuration.internal_priority_to_class_of_service.get(&internal_priority)
+			{
+				Some(&class_of_service) => class_of_service,
+				None => equivalent_class_of_service,
+			};
+			
+			internal_priority_to_differentiated_service_code_point[index] = match configuration.internal_priority_to_differentiated_service_code_point.get(&internal_priority)
+			{
+				Some(&differentiated_service_code_point) => differentiated_service_code_point,
+				None => equivalent_class_of_service.equivalent_differentiated_service_code_point(),
+			};
+		}
+		
+		Self
+		{
+			trust_mode: configuration.trust_mode,
+			default_internal_priority: configuration.default_internal_priority,
+			class_of_service_to_internal_priority,
+			differentiated_service_code_point_to_internal_priority,
+			internal_priority_to_class_of_service,
+			internal_priority_to_differentiated_service_code_point,
+		}
+	}
+	
+	/// Trust mode.
+	#[inline(always)]
+	pub fn trust_mode(&self) -> QualityOfServiceTrustMode
+	{
+		self.trust_mode
+	}
+	
+	/// Assigns an internal priority to a received packet according to the trust mode.
+	///
+	/// `class_of_service` is `None` for untagged packets; `differentiated_service_code_point` is only called if the trust mode is `DifferentiatedServiceCodePoint`, and should return `None` for packets which are not Internet Protocol (IP).
+	#[inline(always)]
+	pub fn ingress_internal_priority(&self, class_of_service: Option<ClassOfService>, differentiated_service_code_point: impl FnOnce() -> Option<DifferentiatedServiceCodePoint>) -> InternalPriority
+	{
+		match self.trust_mode
+		{
+			QualityOfServiceTrustMode::Untrusted => self.default_internal_priority,
+			
+			QualityOfServiceTrustMode::ClassOfService => self.ingress_internal_priority_for_class_of_service(class_of_service),
+			
+			QualityOfServiceTrustMode::DifferentiatedServiceCodePoint => match differentiated_service_code_point()
+			{
+				None => self.ingress_internal_priority_for_class_of_service(class_of_service),
+				Some(differentiated_service_code_point) => unsafe { *self.differentiated_service_code_point_to_internal_priority.get_unchecked(differentiated_service_code_point.index()) },
+			},
+		}
+	}
+	
+	/// IEEE 802.1p class of service to use for an `internal_priority` on egress.
+	#[inline(always)]
+	pub fn egress_class_of_service(&self, internal_priority: InternalPriority) -> ClassOfService
+	{
+		unsafe { *self.internal_priority_to_class_of_service.get_unchecked(internal_priority.index()) }
+	}
+	
+	/// Differentiated Service Code Point (DSCP) to use for an `internal_priority` on egress.
+	#[inline(always)]
+	pub fn egress_differentiated_service_code_point(&self, internal_priority: InternalPriority) -> DifferentiatedServiceCodePoint
+	{
+		unsafe { *self.internal_priority_to_differentiated_service_code_point.get_unchecked(internal_priority.index()) }
+	}
+	
+	#[inline(always)]
+	fn ingress_internal_priority_for_class_of_service(&self, class_of_service: Option<ClassOfService>) -> InternalPriority
+	{
+		match class_of_service
+		{
+			None => self.default_internal_priority,
+			Some(class_of_service) => unsafe { *self.class_of_service_to_internal_priority.get_unchecked(class_of_service as u8 as usize) },
+		}
+	}
+}
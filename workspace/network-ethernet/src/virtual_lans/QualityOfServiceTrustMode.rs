@@ -0,0 +1,38 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Which markings of a received packet are trusted when assigning it an internal priority.
+///
+/// Defaults to `Untrusted`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum QualityOfServiceTrustMode
+{
+	/// All packets are assigned the default internal priority.
+	Untrusted,
+	
+	/// Packets are assigned an internal priority from their (inner) IEEE 802.1p class of service; untagged packets are assigned the default internal priority.
+	ClassOfService,
+	
+	/// Internet Protocol (IP) packets are assigned an internal priority from their Differentiated Service Code Point (DSCP); other packets are treated as if the trust mode was `ClassOfService`.
+	DifferentiatedServiceCodePoint,
+}
+
+impl Display for QualityOfServiceTrustMode
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl Default for QualityOfServiceTrustMode
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		QualityOfServiceTrustMode::Untrusted
+	}
+}
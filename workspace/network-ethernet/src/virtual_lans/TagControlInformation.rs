@@ -64,21 +64,103 @@ impl Debug for TagControlInformation
 
 impl TagControlInformation
 {
+	const ClassOfServiceMask: u16 = 0b1110_0000_0000_0000;
+	
+	const ClassOfServiceShift: u16 = 13;
+	
+	const DropEligibleIndicatorBit: u16 = 0b0001_0000_0000_0000;
+	
+	const VirtualLanIdentifierMask: u16 = 0x0FFF;
+	
+	/// Creates a new instance; a `virtual_lan_identifier` of `None` is a priority tag.
+	#[inline(always)]
+	pub fn new(class_of_service: ClassOfService, drop_eligible_indicator: DropEligibleIndicator, virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Self
+	{
+		let class_of_service = (class_of_service as u16) << Self::ClassOfServiceShift;
+		
+		let drop_eligible_indicator = if drop_eligible_indicator
+		{
+			Self::DropEligibleIndicatorBit
+		}
+		else
+		{
+			0
+		};
+		
+		let virtual_lan_identifier = match virtual_lan_identifier
+		{
+			None => 0,
+			Some(virtual_lan_identifier) => virtual_lan_identifier.0,
+		};
+		
+		TagControlInformation::from(class_of_service | drop_eligible_indicator | virtual_lan_identifier)
+	}
+	
+	/// Returns a copy with the virtual LAN identifier replaced; a `virtual_lan_identifier` of `None` is a priority tag.
+	#[inline(always)]
+	pub fn with_virtual_lan_identifier(self, virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Self
+	{
+		let virtual_lan_identifier = match virtual_lan_identifier
+		{
+			None => 0,
+			Some(virtual_lan_identifier) => virtual_lan_identifier.0,
+		};
+		
+		TagControlInformation::from((self.0.to_native_endian() & !Self::VirtualLanIdentifierMask) | virtual_lan_identifier)
+	}
+	
+	/// Returns a copy with the class of service replaced.
+	#[inline(always)]
+	pub fn with_class_of_service(self, class_of_service: ClassOfService) -> Self
+	{
+		TagControlInformation::from((self.0.to_native_endian() & !Self::ClassOfServiceMask) | ((class_of_service as u16) << Self::ClassOfServiceShift))
+	}
+	
+	/// Returns a copy with the drop eligible indicator replaced.
+	#[inline(always)]
+	pub fn with_drop_eligible_indicator(self, drop_eligible_indicator: DropEligibleIndicator) -> Self
+	{
+		let value = self.0.to_native_endian();
+		
+		TagControlInformation::from(if drop_eligible_indicator
+		{
+			value | Self::DropEligibleIndicatorBit
+		}
+		else
+		{
+			value & !Self::DropEligibleIndicatorBit
+		})
+	}
+	
+	/// Class of service.
+	#[inline(always)]
+	pub fn class_of_service(self) -> ClassOfService
+	{
+		unsafe { transmute(((self.0.to_native_endian() & Self::ClassOfServiceMask) >> Self::ClassOfServiceShift) as u8) }
+	}
+	
+	/// Drop eligible indicator.
+	#[inline(always)]
+	pub fn drop_eligible_indicator(self) -> DropEligibleIndicator
+	{
+		self.0.to_native_endian() & Self::DropEligibleIndicatorBit == Self::DropEligibleIndicatorBit
+	}
+	
 	/// Parse.
 	#[inline(always)]
 	pub fn parse(self) -> Result<(ClassOfService, DropEligibleIndicator, Option<VirtualLanIdentifier>), ()>
 	{
 		let value = self.0.to_native_endian();
 		
-		let virtual_lan_identifier = match value & 0x0FFF
+		let virtual_lan_identifier = match value & Self::VirtualLanIdentifierMask
 		{
 			0 => None,
 			0x0FFF => return Err(()),
 			valid @ _ => Some(VirtualLanIdentifier(valid))
 		};
 		
-		let class_of_service = unsafe { transmute((value & 0b1110_0000_0000_0000 >> 13) as u8) };
-		let drop_eligible_indicator = value & 0b0001_0000_0000_0000 == 0b0001_0000_0000_0000;
+		let class_of_service = unsafe { transmute(((value & Self::ClassOfServiceMask) >> Self::ClassOfServiceShift) as u8) };
+		let drop_eligible_indicator = value & Self::DropEligibleIndicatorBit == Self::DropEligibleIndicatorBit;
 		Ok((class_of_service, drop_eligible_indicator, virtual_lan_identifier))
 	}
 }
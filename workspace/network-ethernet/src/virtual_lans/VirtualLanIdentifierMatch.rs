@@ -0,0 +1,106 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Matches Virtual LAN identifiers in configuration, so that many Virtual LANs can share one instance of packet processing.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum VirtualLanIdentifierMatch
+{
+	/// Matches any Virtual LAN identifier, and also priority tags (which do not have a Virtual LAN identifier).
+	Any,
+	
+	/// Matches only priority tags (which do not have a Virtual LAN identifier).
+	PriorityTag,
+	
+	/// Matches exactly one Virtual LAN identifier.
+	Exactly(VirtualLanIdentifier),
+	
+	/// Matches an inclusive range of Virtual LAN identifiers.
+	Range
+	{
+		/// First Virtual LAN identifier, inclusive.
+		first: VirtualLanIdentifier,
+		
+		/// Last Virtual LAN identifier, inclusive.
+		last: VirtualLanIdentifier,
+	},
+}
+
+impl Display for VirtualLanIdentifierMatch
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl From<Option<VirtualLanIdentifier>> for VirtualLanIdentifierMatch
+{
+	#[inline(always)]
+	fn from(value: Option<VirtualLanIdentifier>) -> Self
+	{
+		use self::VirtualLanIdentifierMatch::*;
+		
+		match value
+		{
+			None => PriorityTag,
+			Some(virtual_lan_identifier) => Exactly(virtual_lan_identifier),
+		}
+	}
+}
+
+impl VirtualLanIdentifierMatch
+{
+	/// Does this match `virtual_lan_identifier`?
+	///
+	/// A `virtual_lan_identifier` of `None` is a priority tag.
+	#[inline(always)]
+	pub fn matches(&self, virtual_lan_identifier: Option<VirtualLanIdentifier>) -> bool
+	{
+		self.matches_index(VirtualLanIdentifierTable::<()>::index(virtual_lan_identifier))
+	}
+	
+	/// Is this a range whose first Virtual LAN identifier is after its last?
+	#[inline(always)]
+	pub fn is_inverted_range(&self) -> bool
+	{
+		match *self
+		{
+			VirtualLanIdentifierMatch::Range { first, last } => first.0 > last.0,
+			
+			_ => false,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn validate(&self) -> Result<(), VirtualLanPacketProcessingConfigurationError>
+	{
+		if unlikely!(self.is_inverted_range())
+		{
+			Err(VirtualLanPacketProcessingConfigurationError::VirtualLanIdentifierRangeIsInverted(*self))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn matches_index(&self, index: usize) -> bool
+	{
+		use self::VirtualLanIdentifierMatch::*;
+		
+		match *self
+		{
+			Any => index < VirtualLanIdentifierTable::<()>::NumberOfEntries,
+			
+			PriorityTag => index == 0,
+			
+			Exactly(virtual_lan_identifier) => index == virtual_lan_identifier.0 as usize,
+			
+			Range { first, last } => index >= first.0 as usize && index <= last.0 as usize,
+		}
+	}
+}
@@ -0,0 +1,131 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A dense table of values looked up by a pair of outer and inner Virtual LAN identifiers, as used by IEEE 802.1ad QinQ.
+///
+/// Lookup is by indexing two arrays of 4096 entries rather than by hashing; many pairs may share one value, and outer Virtual LAN identifiers with identical inner entries share one array.
+#[derive(Debug)]
+pub struct VirtualLanIdentifierPairTable<V>
+{
+	values: Vec<V>,
+	outer_indices: Box<[u16]>,
+	inner_indices: Vec<Box<[u16]>>,
+}
+
+impl<V> Default for VirtualLanIdentifierPairTable<V>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			values: Vec::new(),
+			outer_indices: VirtualLanIdentifierTable::<V>::absent_indices(),
+			inner_indices: Vec::new(),
+		}
+	}
+}
+
+impl<V> VirtualLanIdentifierPairTable<V>
+{
+	/// Gets the value for `(outer_virtual_lan_identifier, inner_virtual_lan_identifier)`, if any.
+	///
+	/// A virtual LAN identifier of `None` is a priority tag.
+	#[inline(always)]
+	pub fn get(&self, outer_virtual_lan_identifier: Option<VirtualLanIdentifier>, inner_virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Option<&V>
+	{
+		const Absent: u16 = VirtualLanIdentifierTable::<()>::Absent;
+		
+		let inner_indices = match unsafe { *self.outer_indices.get_unchecked(VirtualLanIdentifierTable::<V>::index(outer_virtual_lan_identifier)) }
+		{
+			Absent => return None,
+			inner_indices_index @ _ => unsafe { self.inner_indices.get_unchecked(inner_indices_index as usize) },
+		};
+		
+		match unsafe { *inner_indices.get_unchecked(VirtualLanIdentifierTable::<V>::index(inner_virtual_lan_identifier)) }
+		{
+			Absent => None,
+			value_index @ _ => Some(unsafe { self.values.get_unchecked(value_index as usize) }),
+		}
+	}
+	
+	/// Inserts `value` for all pairs matched by `outer_virtual_lan_identifier_match` and `inner_virtual_lan_identifier_match` which do not already have a value; all of them share the one value.
+	///
+	/// Insert more specific matches first.
+	#[inline(always)]
+	pub(crate) fn insert(&mut self, outer_virtual_lan_identifier_match: &VirtualLanIdentifierMatch, inner_virtual_lan_identifier_match: &VirtualLanIdentifierMatch, value: V) -> Result<(), VirtualLanPacketProcessingConfigurationError>
+	{
+		const Absent: u16 = VirtualLanIdentifierTable::<()>::Absent;
+		
+		outer_virtual_lan_identifier_match.validate()?;
+		inner_virtual_lan_identifier_match.validate()?;
+		
+		let value_index = VirtualLanIdentifierTable::push_value(&mut self.values, value);
+		
+		// Outer Virtual LAN identifiers which shared inner entries before this insertion continue to share them afterwards.
+		let mut replacement_inner_indices_indices: HashMap<u16, u16> = HashMap::new();
+		
+		for outer_index in 0 .. VirtualLanIdentifierTable::<V>::NumberOfEntries
+		{
+			if !outer_virtual_lan_identifier_match.matches_index(outer_index)
+			{
+				continue
+			}
+			
+			let existing_inner_indices_index = self.outer_indices[outer_index];
+			
+			let replacement_inner_indices_index = match replacement_inner_indices_indices.get(&existing_inner_indices_index)
+			{
+				Some(&replacement_inner_indices_index) => replacement_inner_indices_index,
+				
+				None =>
+				{
+					let mut inner_indices = match existing_inner_indices_index
+					{
+						Absent => VirtualLanIdentifierTable::<V>::absent_indices(),
+						_ => self.inner_indices[existing_inner_indices_index as usize].clone(),
+					};
+					VirtualLanIdentifierTable::<V>::fill_absent(&mut inner_indices, inner_virtual_lan_identifier_match, value_index);
+					
+					let replacement_inner_indices_index = VirtualLanIdentifierTable::push_value(&mut self.inner_indices, inner_indices);
+					replacement_inner_indices_indices.insert(existing_inner_indices_index, replacement_inner_indices_index);
+					replacement_inner_indices_index
+				}
+			};
+			
+			self.outer_indices[outer_index] = replacement_inner_indices_index;
+		}
+		
+		self.remove_unreferenced_inner_indices();
+		Ok(())
+	}
+	
+	/// Inner entries replaced by an insertion are no longer referenced by any outer Virtual LAN identifier; they are removed and the remaining inner entries renumbered.
+	#[inline(always)]
+	fn remove_unreferenced_inner_indices(&mut self)
+	{
+		const Absent: u16 = VirtualLanIdentifierTable::<()>::Absent;
+		
+		let mut renumbered_inner_indices_indices = vec![Absent; self.inner_indices.len()];
+		let mut referenced_inner_indices = Vec::with_capacity(self.inner_indices.len());
+		
+		for inner_indices_index in self.outer_indices.iter_mut()
+		{
+			if *inner_indices_index == Absent
+			{
+				continue
+			}
+			
+			let renumbered_inner_indices_index = &mut renumbered_inner_indices_indices[*inner_indices_index as usize];
+			if *renumbered_inner_indices_index == Absent
+			{
+				*renumbered_inner_indices_index = referenced_inner_indices.len() as u16;
+				referenced_inner_indices.push(replace(&mut self.inner_indices[*inner_indices_index as usize], Box::default()));
+			}
+			*inner_indices_index = *renumbered_inner_indices_index;
+		}
+		
+		self.inner_indices = referenced_inner_indices;
+	}
+}
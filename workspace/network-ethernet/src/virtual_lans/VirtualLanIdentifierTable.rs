@@ -0,0 +1,98 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A dense table of values looked up by Virtual LAN identifier.
+///
+/// Lookup is by indexing an array of 4096 entries rather than by hashing; many Virtual LAN identifiers may share one value.
+#[derive(Debug)]
+pub struct VirtualLanIdentifierTable<V>
+{
+	values: Vec<V>,
+	indices: Box<[u16]>,
+}
+
+impl<V> Default for VirtualLanIdentifierTable<V>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			values: Vec::new(),
+			indices: Self::absent_indices(),
+		}
+	}
+}
+
+impl<V> VirtualLanIdentifierTable<V>
+{
+	pub(crate) const NumberOfEntries: usize = 4096;
+	
+	pub(crate) const Absent: u16 = ::std::u16::MAX;
+	
+	/// Gets the value for `virtual_lan_identifier`, if any.
+	///
+	/// A `virtual_lan_identifier` of `None` is a priority tag.
+	#[inline(always)]
+	pub fn get(&self, virtual_lan_identifier: Option<VirtualLanIdentifier>) -> Option<&V>
+	{
+		const Absent: u16 = VirtualLanIdentifierTable::<()>::Absent;
+		
+		match unsafe { *self.indices.get_unchecked(Self::index(virtual_lan_identifier)) }
+		{
+			Absent => None,
+			value_index @ _ => Some(unsafe { self.values.get_unchecked(value_index as usize) }),
+		}
+	}
+	
+	/// Inserts `value` for all Virtual LAN identifiers matched by `virtual_lan_identifier_match` which do not already have a value; all of them share the one value.
+	///
+	/// Insert more specific matches first.
+	#[inline(always)]
+	pub(crate) fn insert(&mut self, virtual_lan_identifier_match: &VirtualLanIdentifierMatch, value: V) -> Result<(), VirtualLanPacketProcessingConfigurationError>
+	{
+		virtual_lan_identifier_match.validate()?;
+		
+		let value_index = Self::push_value(&mut self.values, value);
+		Self::fill_absent(&mut self.indices, virtual_lan_identifier_match, value_index);
+		Ok(())
+	}
+	
+	#[inline(always)]
+	pub(crate) fn index(virtual_lan_identifier: Option<VirtualLanIdentifier>) -> usize
+	{
+		match virtual_lan_identifier
+		{
+			None => 0,
+			Some(virtual_lan_identifier) => virtual_lan_identifier.0 as usize,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn absent_indices() -> Box<[u16]>
+	{
+		vec![Self::Absent; Self::NumberOfEntries].into_boxed_slice()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn push_value(values: &mut Vec<V>, value: V) -> u16
+	{
+		let value_index = values.len();
+		assert!(value_index < Self::Absent as usize, "Too many values");
+		values.push(value);
+		value_index as u16
+	}
+	
+	#[inline(always)]
+	pub(crate) fn fill_absent(indices: &mut [u16], virtual_lan_identifier_match: &VirtualLanIdentifierMatch, value_index: u16)
+	{
+		for (index, existing_value_index) in indices.iter_mut().enumerate()
+		{
+			if *existing_value_index == Self::Absent && virtual_lan_identifier_match.matches_index(index)
+			{
+				*existing_value_index = value_index
+			}
+		}
+	}
+}
@@ -0,0 +1,194 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// An operation which rewrites the Virtual LAN tags of an ethernet frame.
+///
+/// Operations act on the outermost tag of the frame as it is at the time the operation is applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub enum VirtualLanTagOperation
+{
+	/// Push a new outermost IEEE 802.1ad service tag (S-tag; ether type `0x88A8`).
+	///
+	/// If `class_of_service` or `drop_eligible_indicator` are `None` they are copied from the current outermost tag, or defaulted if the frame is untagged.
+	PushServiceTag
+	{
+		/// Virtual LAN identifier; `None` pushes a priority tag.
+		virtual_lan_identifier: Option<VirtualLanIdentifier>,
+		
+		/// Class of service.
+		class_of_service: Option<ClassOfService>,
+		
+		/// Drop eligible indicator.
+		drop_eligible_indicator: Option<DropEligibleIndicator>,
+	},
+	
+	/// Push a new outermost IEEE 802.1Q customer tag (C-tag; ether type `0x8100`).
+	///
+	/// If `class_of_service` or `drop_eligible_indicator` are `None` they are copied from the current outermost tag, or defaulted if the frame is untagged.
+	PushCustomerTag
+	{
+		/// Virtual LAN identifier; `None` pushes a priority tag.
+		virtual_lan_identifier: Option<VirtualLanIdentifier>,
+		
+		/// Class of service.
+		class_of_service: Option<ClassOfService>,
+		
+		/// Drop eligible indicator.
+		drop_eligible_indicator: Option<DropEligibleIndicator>,
+	},
+	
+	/// Pop the outermost tag.
+	///
+	/// Fails if the frame is untagged.
+	Pop,
+	
+	/// Swap the Virtual LAN identifier of the outermost tag.
+	///
+	/// Fails if the frame is untagged.
+	Swap
+	{
+		/// Virtual LAN identifier; `None` makes the outermost tag a priority tag.
+		virtual_lan_identifier: Option<VirtualLanIdentifier>,
+	},
+	
+	/// Remap the class of service and drop eligible indicator of the outermost tag.
+	///
+	/// Fails if the frame is untagged.
+	Remap
+	{
+		/// Classes of service not present are left unchanged.
+		#[serde(default)]
+		class_of_service: HashMap<ClassOfService, ClassOfService>,
+		
+		/// If `None`, the drop eligible indicator is left unchanged.
+		#[serde(default)]
+		drop_eligible_indicator: Option<DropEligibleIndicator>,
+	},
+}
+
+impl VirtualLanTagOperation
+{
+	const EthernetAddressesSize: usize = EthernetPacketHeader::SizeLessEtherTypeU32 as usize;
+	
+	const TagControlInformationOffset: usize = EthernetPacketHeader::SizeU32 as usize;
+	
+	#[inline(always)]
+	pub(crate) fn apply(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<(), ()>
+	{
+		use self::VirtualLanTagOperation::*;
+		
+		match *self
+		{
+			PushServiceTag { virtual_lan_identifier, class_of_service, drop_eligible_indicator } => Self::push(packet, EtherType::QinQVlanTagging, virtual_lan_identifier, class_of_service, drop_eligible_indicator),
+			
+			PushCustomerTag { virtual_lan_identifier, class_of_service, drop_eligible_indicator } => Self::push(packet, EtherType::VlanTagging, virtual_lan_identifier, class_of_service, drop_eligible_indicator),
+			
+			Pop => Self::pop(packet),
+			
+			Swap { virtual_lan_identifier } =>
+			{
+				let tag_control_information = Self::outermost_tag_control_information(packet).ok_or(())?;
+				Self::set_outermost_tag_control_information(packet, tag_control_information.with_virtual_lan_identifier(virtual_lan_identifier));
+				Ok(())
+			}
+			
+			Remap { ref class_of_service, drop_eligible_indicator } =>
+			{
+				let mut tag_control_information = Self::outermost_tag_control_information(packet).ok_or(())?;
+				
+				if let Some(&remapped_class_of_service) = class_of_service.get(&tag_control_information.class_of_service())
+				{
+					tag_control_information = tag_control_information.with_class_of_service(remapped_class_of_service)
+				}
+				
+				if let Some(drop_eligible_indicator) = drop_eligible_indicator
+				{
+					tag_control_information = tag_control_information.with_drop_eligible_indicator(drop_eligible_indicator)
+				}
+				
+				Self::set_outermost_tag_control_information(packet, tag_control_information);
+				Ok(())
+			}
+		}
+	}
+	
+	/// Inserts a tag immediately after the ethernet addresses.
+	#[inline(always)]
+	pub(crate) fn insert_tag(packet: impl EthernetIncomingNetworkPacket, tag_protocol_identifier: EtherType, tag_control_information: TagControlInformation) -> Result<(), ()>
+	{
+		const TagSize: u16 = VirtualLanPacketHeader::IEEE_802_1Q_SizeU16;
+		
+		if unlikely!(!packet.prepend(TagSize))
+		{
+			return Err(())
+		}
+		
+		unsafe
+		{
+			let data = packet.offset_into_data::<u8>(0).as_ptr();
+			copy(data.offset(TagSize as isize), data, Self::EthernetAddressesSize);
+			*packet.offset_into_data::<EtherType>(Self::EthernetAddressesSize).as_ptr() = tag_protocol_identifier;
+			*packet.offset_into_data::<TagControlInformation>(Self::TagControlInformationOffset).as_ptr() = tag_control_information;
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn push(packet: impl EthernetIncomingNetworkPacket, tag_protocol_identifier: EtherType, virtual_lan_identifier: Option<VirtualLanIdentifier>, class_of_service: Option<ClassOfService>, drop_eligible_indicator: Option<DropEligibleIndicator>) -> Result<(), ()>
+	{
+		let (default_class_of_service, default_drop_eligible_indicator) = match Self::outermost_tag_control_information(packet)
+		{
+			None => (ClassOfService::default(), false),
+			Some(tag_control_information) => (tag_control_information.class_of_service(), tag_control_information.drop_eligible_indicator()),
+		};
+		
+		let tag_control_information = TagControlInformation::new(class_of_service.unwrap_or(default_class_of_service), drop_eligible_indicator.unwrap_or(default_drop_eligible_indicator), virtual_lan_identifier);
+		
+		Self::insert_tag(packet, tag_protocol_identifier, tag_control_information)
+	}
+	
+	#[inline(always)]
+	fn pop(packet: impl EthernetIncomingNetworkPacket) -> Result<(), ()>
+	{
+		const TagSize: u16 = VirtualLanPacketHeader::IEEE_802_1Q_SizeU16;
+		
+		if Self::outermost_tag_control_information(packet).is_none()
+		{
+			return Err(())
+		}
+		
+		unsafe
+		{
+			let data = packet.offset_into_data::<u8>(0).as_ptr();
+			copy(data, data.offset(TagSize as isize), Self::EthernetAddressesSize);
+		}
+		packet.remove_from_start(TagSize);
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn outermost_tag_control_information(packet: impl EthernetIncomingNetworkPacket) -> Option<TagControlInformation>
+	{
+		if unlikely!(packet.is_too_short_to_be_a_vlan_ethernet_packet())
+		{
+			return None
+		}
+		
+		match packet.ethernet_packet().potentially_invalid_ether_type()
+		{
+			EtherType::VlanTagging | EtherType::QinQVlanTagging => Some(*packet.offset_into_data_reference::<TagControlInformation>(Self::TagControlInformationOffset)),
+			
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn set_outermost_tag_control_information(packet: impl EthernetIncomingNetworkPacket, tag_control_information: TagControlInformation)
+	{
+		unsafe { *packet.offset_into_data::<TagControlInformation>(Self::TagControlInformationOffset).as_ptr() = tag_control_information }
+	}
+}
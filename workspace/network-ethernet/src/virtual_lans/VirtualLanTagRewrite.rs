@@ -0,0 +1,56 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Rewrites the Virtual LAN tags of an ethernet frame and then transmits it out of an egress port.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct VirtualLanTagRewrite
+{
+	/// Operations, applied in order.
+	///
+	/// Any tags stripped by hardware are re-inserted into the frame before the first operation is applied.
+	pub operations: Vec<VirtualLanTagOperation>,
+	
+	/// Port to transmit the rewritten frame out of.
+	pub egress_port_number: u16,
+}
+
+impl VirtualLanTagRewrite
+{
+	/// Fails if there is insufficient headroom to push a tag, or if an operation requires a tag and the frame is untagged; the frame may have been partially rewritten.
+	#[inline(always)]
+	pub(crate) fn rewrite(&self, packet: impl EthernetIncomingNetworkPacket) -> Result<(), ()>
+	{
+		Self::reinsert_stripped_tags(packet)?;
+		
+		for operation in self.operations.iter()
+		{
+			operation.apply(packet)?;
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn reinsert_stripped_tags(packet: impl EthernetIncomingNetworkPacket) -> Result<(), ()>
+	{
+		if packet.was_vlan_qinq_tag_control_information_stripped()
+		{
+			let (outer_tag_control_information, inner_tag_control_information) = packet.stripped_vlan_qinq_tag_control_information();
+			packet.clear_stripped_vlan_tag_control_information();
+			VirtualLanTagOperation::insert_tag(packet, EtherType::VlanTagging, inner_tag_control_information)?;
+			VirtualLanTagOperation::insert_tag(packet, EtherType::QinQVlanTagging, outer_tag_control_information)
+		}
+		else if packet.was_vlan_tag_control_information_stripped()
+		{
+			let tag_control_information = packet.stripped_vlan_tag_control_information();
+			packet.clear_stripped_vlan_tag_control_information();
+			VirtualLanTagOperation::insert_tag(packet, EtherType::VlanTagging, tag_control_information)
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+}
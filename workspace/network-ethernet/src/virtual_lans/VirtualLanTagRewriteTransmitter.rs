@@ -0,0 +1,13 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Transmits frames whose Virtual LAN tags have been rewritten by a `VirtualLanTagRewrite`.
+pub trait VirtualLanTagRewriteTransmitter: Debug
+{
+	/// Implement this to transmit `packet` out of the port `egress_port_number`.
+	///
+	/// The implementation takes responsibility for the packet; it must eventually transmit or free it.
+	#[inline(always)]
+	fn transmit(&self, egress_port_number: u16, packet: impl EthernetIncomingNetworkPacket);
+}
@@ -6,11 +6,26 @@ use super::*;
 
 
 include!("ClassOfService.rs");
+include!("ClassOfServicePolicer.rs");
+include!("ClassOfServicePolicerConfiguration.rs");
+include!("ClassOfServicePolicers.rs");
 include!("DifferentiatedServiceCodePoint.rs");
 include!("DropEligibleIndicator.rs");
+include!("InternalPriority.rs");
 include!("QinQVirtualLanPacket.rs");
 include!("PermittedClassesOfService.rs");
+include!("PolicingAction.rs");
+include!("PolicingColour.rs");
+include!("QualityOfServiceConfiguration.rs");
+include!("QualityOfServiceMapping.rs");
+include!("QualityOfServiceTrustMode.rs");
 include!("TagControlInformation.rs");
 include!("VirtualLanIdentifier.rs");
+include!("VirtualLanIdentifierMatch.rs");
+include!("VirtualLanIdentifierPairTable.rs");
+include!("VirtualLanIdentifierTable.rs");
 include!("VirtualLanPacket.rs");
 include!("VirtualLanPacketHeader.rs");
+include!("VirtualLanTagOperation.rs");
+include!("VirtualLanTagRewrite.rs");
+include!("VirtualLanTagRewriteTransmitter.rs");
@@ -0,0 +1,210 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#![allow(non_upper_case_globals)]
+
+
+extern crate network_ethernet;
+extern crate network_time;
+
+
+use ::network_ethernet::MediaAccessControlAddress;
+use ::network_ethernet::link_aggregation::*;
+use ::network_time::MillisecondDuration;
+use ::network_time::MonotonicMillisecondTimestamp;
+use ::std::cell::RefCell;
+
+
+/// Captures transmitted Link Aggregation Control Protocol Data Units (LACPDUs) so they can be delivered to the other end of the link.
+#[derive(Debug, Default)]
+struct CapturingTransmitter
+{
+	transmitted: RefCell<Vec<(u16, LinkAggregationControlProtocolDataUnit)>>,
+}
+
+impl LinkAggregationControlProtocolDataUnitTransmitter for CapturingTransmitter
+{
+	#[inline(always)]
+	fn transmit(&self, port_number: u16, link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit)
+	{
+		self.transmitted.borrow_mut().push((port_number, *link_aggregation_control_protocol_data_unit))
+	}
+}
+
+impl CapturingTransmitter
+{
+	/// Port `n` of one group is cabled to port `n` of the other.
+	#[inline(always)]
+	fn deliver_to(&self, now: MonotonicMillisecondTimestamp, other: &LinkAggregationGroup) -> usize
+	{
+		let transmitted = self.transmitted.replace(Vec::new());
+		for &(port_number, ref link_aggregation_control_protocol_data_unit) in transmitted.iter()
+		{
+			assert!(link_aggregation_control_protocol_data_unit.is_valid(), "transmitted an invalid LACPDU on port {}", port_number);
+			other.process_link_aggregation_control_protocol_data_unit(now, port_number, link_aggregation_control_protocol_data_unit)
+		}
+		transmitted.len()
+	}
+}
+
+/// Calls back into the group that is transmitting.
+#[derive(Debug)]
+struct ReentrantTransmitter<'a>
+{
+	group: &'a LinkAggregationGroup,
+	transmitted: RefCell<usize>,
+}
+
+impl<'a> LinkAggregationControlProtocolDataUnitTransmitter for ReentrantTransmitter<'a>
+{
+	#[inline(always)]
+	fn transmit(&self, port_number: u16, _link_aggregation_control_protocol_data_unit: &LinkAggregationControlProtocolDataUnit)
+	{
+		self.group.is_collecting(port_number);
+		*self.transmitted.borrow_mut() += 1
+	}
+}
+
+const PortNumbers: [u16; 2] = [1, 2];
+
+fn group(system: [u8; 6], active: bool) -> LinkAggregationGroup
+{
+	let configuration = LinkAggregationGroupConfiguration
+	{
+		system_priority: LinkAggregationGroupConfiguration::DefaultSystemPriority,
+		key: 1,
+		active,
+		short_timeout: true,
+		collector_maximum_delay: 0,
+		ports: PortNumbers.iter().map(|&port_number| LinkAggregationPortConfiguration { port_number, port_priority: LinkAggregationPortConfiguration::DefaultPortPriority }).collect(),
+	};
+	configuration.configure(MediaAccessControlAddress::from_octets(system))
+}
+
+/// Polls both groups every 100 milliseconds for `duration`, exchanging LACPDUs; returns the time reached and the number of LACPDUs exchanged.
+fn exchange(mut now: MonotonicMillisecondTimestamp, duration: MillisecondDuration, a: &LinkAggregationGroup, b: &LinkAggregationGroup) -> (MonotonicMillisecondTimestamp, usize)
+{
+	let step = MillisecondDuration::from_milliseconds(100);
+	let until = now + duration;
+
+	let a_transmitter = CapturingTransmitter::default();
+	let b_transmitter = CapturingTransmitter::default();
+
+	let mut exchanged = 0;
+	while now < until
+	{
+		a.poll(now, &a_transmitter);
+		b.poll(now, &b_transmitter);
+		exchanged += a_transmitter.deliver_to(now, b);
+		exchanged += b_transmitter.deliver_to(now, a);
+		now = now + step;
+	}
+	(now, exchanged)
+}
+
+fn assert_aggregated(group: &LinkAggregationGroup)
+{
+	assert_eq!(group.number_of_distributing_ports(), PortNumbers.len());
+	for &port_number in PortNumbers.iter()
+	{
+		assert_eq!(group.port_states(port_number), Some((LinkAggregationReceiveMachineState::Current, LinkAggregationMuxMachineState::Distributing)));
+		assert!(group.is_collecting(port_number));
+		assert!(group.is_distributing(port_number));
+	}
+	assert!(group.distributing_port_for_flow(0).is_some());
+}
+
+fn link_up(now: MonotonicMillisecondTimestamp, a: &LinkAggregationGroup, b: &LinkAggregationGroup)
+{
+	for &port_number in PortNumbers.iter()
+	{
+		a.link_up(now, port_number);
+		b.link_up(now, port_number);
+	}
+}
+
+#[test]
+fn two_active_groups_aggregate_all_ports()
+{
+	let a = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x01], true);
+	let b = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x02], true);
+
+	let now = MonotonicMillisecondTimestamp::Zero;
+	link_up(now, &a, &b);
+	assert_eq!(a.number_of_distributing_ports(), 0);
+
+	let (_, exchanged) = exchange(now, MillisecondDuration::from_seconds(10), &a, &b);
+
+	assert_ne!(exchanged, 0);
+	assert_aggregated(&a);
+	assert_aggregated(&b);
+}
+
+#[test]
+fn active_and_passive_groups_aggregate_all_ports()
+{
+	let a = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x01], true);
+	let b = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x02], false);
+
+	let now = MonotonicMillisecondTimestamp::Zero;
+	link_up(now, &a, &b);
+
+	exchange(now, MillisecondDuration::from_seconds(10), &a, &b);
+
+	assert_aggregated(&a);
+	assert_aggregated(&b);
+}
+
+#[test]
+fn two_passive_groups_never_aggregate()
+{
+	let a = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x01], false);
+	let b = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x02], false);
+
+	let now = MonotonicMillisecondTimestamp::Zero;
+	link_up(now, &a, &b);
+
+	let (_, exchanged) = exchange(now, MillisecondDuration::from_seconds(10), &a, &b);
+
+	assert_eq!(exchanged, 0);
+	assert_eq!(a.number_of_distributing_ports(), 0);
+	assert_eq!(b.number_of_distributing_ports(), 0);
+}
+
+#[test]
+fn link_down_removes_port_from_distribution()
+{
+	let a = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x01], true);
+	let b = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x02], true);
+
+	let now = MonotonicMillisecondTimestamp::Zero;
+	link_up(now, &a, &b);
+	let (now, _) = exchange(now, MillisecondDuration::from_seconds(10), &a, &b);
+	assert_aggregated(&a);
+
+	a.link_down(now, 2);
+	b.link_down(now, 2);
+	exchange(now, MillisecondDuration::from_seconds(2), &a, &b);
+
+	assert_eq!(a.number_of_distributing_ports(), 1);
+	assert!(a.is_distributing(1));
+	assert!(!a.is_distributing(2));
+	assert_eq!(b.number_of_distributing_ports(), 1);
+	assert_eq!(a.distributing_port_for_flow(12345), Some(1));
+}
+
+#[test]
+fn transmitter_can_call_back_into_group()
+{
+	let a = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x01], true);
+	let b = group([0x02, 0x00, 0x00, 0x00, 0x00, 0x02], true);
+
+	let now = MonotonicMillisecondTimestamp::Zero;
+	link_up(now, &a, &b);
+
+	let transmitter = ReentrantTransmitter { group: &a, transmitted: RefCell::new(0) };
+	a.poll(now, &transmitter);
+
+	assert_eq!(*transmitter.transmitted.borrow(), PortNumbers.len());
+}
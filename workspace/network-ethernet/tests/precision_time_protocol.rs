@@ -0,0 +1,233 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#![allow(non_upper_case_globals)]
+
+
+extern crate network_ethernet;
+
+
+use ::network_ethernet::precision_time_protocol::*;
+
+
+const DomainNumber: u8 = 0;
+
+const Master: PrecisionTimeProtocolPortIdentity = PrecisionTimeProtocolPortIdentity
+{
+	clock_identity: [0x00, 0x1B, 0x21, 0xFF, 0xFE, 0x00, 0x00, 0x01],
+	port_number: 1,
+};
+
+const Slave: PrecisionTimeProtocolPortIdentity = PrecisionTimeProtocolPortIdentity
+{
+	clock_identity: [0x00, 0x1B, 0x21, 0xFF, 0xFE, 0x00, 0x00, 0x02],
+	port_number: 1,
+};
+
+const NanosecondsPerSecond: u64 = 1_000_000_000;
+
+fn push_network_endian(bytes: &mut Vec<u8>, value: u64, size: usize)
+{
+	for index in (0 .. size).rev()
+	{
+		bytes.push((value >> (index * 8)) as u8)
+	}
+}
+
+/// A canned message: a common header (IEEE 1588-2008, Section 13.3) followed by a timestamp and, for a Delay_Resp, the requesting port identity.
+fn message(message_type: u8, flags: u16, correction_nanoseconds: i64, source_port_identity: PrecisionTimeProtocolPortIdentity, sequence_id: u16, seconds: u64, nanoseconds: u32, requesting_port_identity: Option<PrecisionTimeProtocolPortIdentity>) -> Vec<u8>
+{
+	let message_length: u16 = if requesting_port_identity.is_some() { 54 } else { 44 };
+	
+	let mut bytes = Vec::with_capacity(message_length as usize);
+	bytes.push(message_type);
+	bytes.push(PrecisionTimeProtocolMessageHeader::Version);
+	push_network_endian(&mut bytes, message_length as u64, 2);
+	bytes.push(DomainNumber);
+	bytes.push(0);
+	push_network_endian(&mut bytes, flags as u64, 2);
+	push_network_endian(&mut bytes, (correction_nanoseconds << 16) as u64, 8);
+	bytes.extend_from_slice(&[0; 4]);
+	bytes.extend_from_slice(&source_port_identity.clock_identity);
+	push_network_endian(&mut bytes, source_port_identity.port_number as u64, 2);
+	push_network_endian(&mut bytes, sequence_id as u64, 2);
+	bytes.push(0);
+	bytes.push(0x7F);
+	push_network_endian(&mut bytes, seconds, 6);
+	push_network_endian(&mut bytes, nanoseconds as u64, 4);
+	if let Some(requesting_port_identity) = requesting_port_identity
+	{
+		bytes.extend_from_slice(&requesting_port_identity.clock_identity);
+		push_network_endian(&mut bytes, requesting_port_identity.port_number as u64, 2);
+	}
+	
+	assert_eq!(bytes.len(), message_length as usize);
+	bytes
+}
+
+fn sync(flags: u16, correction_nanoseconds: i64, sequence_id: u16, seconds: u64, nanoseconds: u32) -> Vec<u8>
+{
+	message(PrecisionTimeProtocolMessageHeader::SyncMessageType, flags, correction_nanoseconds, Master, sequence_id, seconds, nanoseconds, None)
+}
+
+fn follow_up(correction_nanoseconds: i64, sequence_id: u16, seconds: u64, nanoseconds: u32) -> Vec<u8>
+{
+	message(PrecisionTimeProtocolMessageHeader::FollowUpMessageType, 0, correction_nanoseconds, Master, sequence_id, seconds, nanoseconds, None)
+}
+
+fn delay_request(sequence_id: u16, seconds: u64, nanoseconds: u32) -> Vec<u8>
+{
+	message(PrecisionTimeProtocolMessageHeader::DelayRequestMessageType, 0, 0, Slave, sequence_id, seconds, nanoseconds, None)
+}
+
+fn delay_response(correction_nanoseconds: i64, sequence_id: u16, seconds: u64, nanoseconds: u32, requesting_port_identity: PrecisionTimeProtocolPortIdentity) -> Vec<u8>
+{
+	message(PrecisionTimeProtocolMessageHeader::DelayResponseMessageType, 0, correction_nanoseconds, Master, sequence_id, seconds, nanoseconds, Some(requesting_port_identity))
+}
+
+fn nanoseconds(seconds: u64, nanoseconds: u64) -> u64
+{
+	seconds * NanosecondsPerSecond + nanoseconds
+}
+
+fn packet_processing() -> PrecisionTimeProtocolPacketProcessing
+{
+	PrecisionTimeProtocolPacketProcessing::new(PrecisionTimeProtocolOrdinaryClock::new(Slave, DomainNumber))
+}
+
+#[test]
+fn canned_messages_parse()
+{
+	match PrecisionTimeProtocolMessage::parse(&sync(PrecisionTimeProtocolMessageHeader::TwoStepFlag, 0, 1, 100, 5)).unwrap()
+	{
+		PrecisionTimeProtocolMessage::Sync { header, origin_timestamp } =>
+		{
+			assert!(header.is_two_step());
+			assert_eq!(header.sequence_id, 1);
+			assert_eq!(header.source_port_identity, Master);
+			assert_eq!(origin_timestamp, PrecisionTimeProtocolTimestamp { seconds: 100, nanoseconds: 5 });
+		}
+		other => panic!("not a Sync: {:?}", other),
+	}
+	
+	match PrecisionTimeProtocolMessage::parse(&follow_up(3, 1, 100, 7)).unwrap()
+	{
+		PrecisionTimeProtocolMessage::FollowUp { header, precise_origin_timestamp } =>
+		{
+			assert_eq!(header.correction_nanoseconds(), 3);
+			assert_eq!(precise_origin_timestamp, PrecisionTimeProtocolTimestamp { seconds: 100, nanoseconds: 7 });
+		}
+		other => panic!("not a Follow_Up: {:?}", other),
+	}
+	
+	match PrecisionTimeProtocolMessage::parse(&delay_request(9, 101, 0)).unwrap()
+	{
+		PrecisionTimeProtocolMessage::DelayRequest { header, origin_timestamp } =>
+		{
+			assert_eq!(header.source_port_identity, Slave);
+			assert_eq!(origin_timestamp, PrecisionTimeProtocolTimestamp { seconds: 101, nanoseconds: 0 });
+		}
+		other => panic!("not a Delay_Req: {:?}", other),
+	}
+	
+	match PrecisionTimeProtocolMessage::parse(&delay_response(0, 9, 101, 11, Slave)).unwrap()
+	{
+		PrecisionTimeProtocolMessage::DelayResponse { receive_timestamp, requesting_port_identity, .. } =>
+		{
+			assert_eq!(receive_timestamp, PrecisionTimeProtocolTimestamp { seconds: 101, nanoseconds: 11 });
+			assert_eq!(requesting_port_identity, Slave);
+		}
+		other => panic!("not a Delay_Resp: {:?}", other),
+	}
+}
+
+/// Our clock is 1,000ns ahead of the master and the path delay is 500ns in each direction.
+#[test]
+fn two_step_exchange_computes_offset_and_mean_path_delay()
+{
+	let packet_processing = packet_processing();
+	
+	let t1 = nanoseconds(100, 0);
+	let t2 = t1 + 500 + 1_000;
+	let t3 = nanoseconds(101, 0);
+	let t4 = t3 + 500 - 1_000;
+	
+	assert_eq!(packet_processing.process_message(&sync(PrecisionTimeProtocolMessageHeader::TwoStepFlag, 0, 1, 100, 0), Some(t2)), Ok(None));
+	assert_eq!(packet_processing.process_message(&follow_up(0, 1, t1 / NanosecondsPerSecond, (t1 % NanosecondsPerSecond) as u32), None), Ok(None));
+	
+	packet_processing.delay_request_transmitted(7, t3);
+	assert_eq!(packet_processing.process_message(&delay_request(7, 101, 0), Some(t3)), Ok(None), "Delay_Req messages from other slaves are ignored");
+	
+	let measurement = packet_processing.process_message(&delay_response(0, 7, t4 / NanosecondsPerSecond, (t4 % NanosecondsPerSecond) as u32, Slave), None).unwrap().unwrap();
+	assert_eq!(measurement, PrecisionTimeProtocolMeasurement { master_to_slave_delay: 1_500, slave_to_master_delay: -500, mean_path_delay: 500, offset_from_master: 1_000 });
+	assert_eq!(packet_processing.ordinary_clock().measurement(), Some(measurement));
+}
+
+/// Residence times in the correction fields of the Sync, Follow_Up and Delay_Resp are removed from the delays.
+#[test]
+fn one_step_and_two_step_exchanges_honour_correction_fields()
+{
+	let packet_processing = packet_processing();
+	
+	let t1 = nanoseconds(200, 0);
+	let t2 = t1 + 500 + 100;
+	assert_eq!(packet_processing.process_message(&sync(0, 100, 2, 200, 0), Some(t2)), Ok(None));
+	
+	let t3 = nanoseconds(201, 0);
+	let t4 = t3 + 500 + 40;
+	packet_processing.delay_request_transmitted(8, t3);
+	let measurement = packet_processing.process_message(&delay_response(40, 8, t4 / NanosecondsPerSecond, (t4 % NanosecondsPerSecond) as u32, Slave), None).unwrap().unwrap();
+	assert_eq!(measurement, PrecisionTimeProtocolMeasurement { master_to_slave_delay: 500, slave_to_master_delay: 500, mean_path_delay: 500, offset_from_master: 0 });
+	
+	let t1 = nanoseconds(300, 0);
+	let t2 = t1 + 500 + 30 + 20;
+	assert_eq!(packet_processing.process_message(&sync(PrecisionTimeProtocolMessageHeader::TwoStepFlag, 30, 3, 300, 0), Some(t2)), Ok(None));
+	assert_eq!(packet_processing.process_message(&follow_up(20, 3, 300, 0), None), Ok(None));
+	
+	let t3 = nanoseconds(301, 0);
+	let t4 = t3 + 500;
+	packet_processing.delay_request_transmitted(9, t3);
+	let measurement = packet_processing.process_message(&delay_response(0, 9, t4 / NanosecondsPerSecond, (t4 % NanosecondsPerSecond) as u32, Slave), None).unwrap().unwrap();
+	assert_eq!(measurement.mean_path_delay, 500);
+	assert_eq!(measurement.offset_from_master, 0);
+}
+
+#[test]
+fn mismatched_follow_up_and_delay_response_are_dropped()
+{
+	let packet_processing = packet_processing();
+	
+	assert_eq!(packet_processing.process_message(&sync(PrecisionTimeProtocolMessageHeader::TwoStepFlag, 0, 4, 400, 0), Some(nanoseconds(400, 1_000))), Ok(None));
+	assert_eq!(packet_processing.process_message(&follow_up(0, 5, 400, 0), None), Err(PrecisionTimeProtocolDropReason::FollowUpDoesNotMatchSync { sequence_id: 5 }));
+	
+	packet_processing.delay_request_transmitted(10, nanoseconds(401, 0));
+	assert_eq!(packet_processing.process_message(&delay_response(0, 11, 401, 0, Slave), None), Err(PrecisionTimeProtocolDropReason::DelayResponseIsNotForUs { sequence_id: 11 }));
+	assert_eq!(packet_processing.process_message(&delay_response(0, 10, 401, 0, Master), None), Err(PrecisionTimeProtocolDropReason::DelayResponseIsNotForUs { sequence_id: 10 }));
+}
+
+#[test]
+fn sync_without_hardware_receive_timestamp_is_dropped()
+{
+	assert_eq!(packet_processing().process_message(&sync(0, 0, 1, 100, 0), None), Err(PrecisionTimeProtocolDropReason::HardwareReceiveTimestampIsAbsent));
+}
+
+#[test]
+fn timestamp_with_nanoseconds_of_one_second_or_more_is_dropped()
+{
+	assert_eq!(PrecisionTimeProtocolMessage::parse(&sync(0, 0, 1, 100, 1_000_000_000)), Err(PrecisionTimeProtocolDropReason::TimestampIsInvalid { message_type: PrecisionTimeProtocolMessageHeader::SyncMessageType }));
+}
+
+#[test]
+fn timestamps_with_48_bit_seconds_do_not_overflow()
+{
+	let latest = PrecisionTimeProtocolTimestamp { seconds: (1 << 48) - 1, nanoseconds: 999_999_999 };
+	let earliest = PrecisionTimeProtocolTimestamp::default();
+	
+	assert_eq!(latest.to_nanoseconds(), None);
+	assert_eq!(latest.nanoseconds_since(earliest), i64::max_value());
+	assert_eq!(earliest.nanoseconds_since(latest), i64::min_value());
+	assert_eq!(latest.nanoseconds_since(PrecisionTimeProtocolTimestamp { seconds: (1 << 48) - 2, nanoseconds: 999_999_998 }), 1_000_000_001);
+	
+	assert_eq!(PrecisionTimeProtocolTimestamp::from_nanoseconds(nanoseconds(100, 5)).to_nanoseconds(), Some(nanoseconds(100, 5)));
+}
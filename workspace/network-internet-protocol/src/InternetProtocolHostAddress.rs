@@ -67,6 +67,12 @@ pub trait InternetProtocolHostAddress: Sized + Debug + Display + PartialOrd + Or
 	#[inline(always)]
 	fn to_media_access_control_address(&self) -> Result<MediaAccessControlAddress, ()>;
 	
+	/// Is this a link-local (unicast) address?
+	///
+	/// Such addresses are only valid on the local link and must never be sent via a router.
+	#[inline(always)]
+	fn is_link_local_scope(&self) -> bool;
+	
 	/// Nibbles length.
 	const NibblesLength: usize = Self::Size * 2;
 	
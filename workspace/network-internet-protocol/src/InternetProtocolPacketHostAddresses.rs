@@ -0,0 +1,91 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// The source and destination host addresses of the Internet Protocol (IP) packet containing a layer 4 packet.
+///
+/// Used to calculate layer 4 check sums over a pseudo-header.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InternetProtocolPacketHostAddresses
+{
+	/// Internet Protocol (IP) version 4.
+	Version4
+	{
+		/// Source address.
+		source: InternetProtocolVersion4HostAddress,
+		
+		/// Destination address.
+		destination: InternetProtocolVersion4HostAddress,
+	},
+	
+	/// Internet Protocol (IP) version 6.
+	Version6
+	{
+		/// Source address.
+		source: InternetProtocolVersion6HostAddress,
+		
+		/// Destination address.
+		destination: InternetProtocolVersion6HostAddress,
+	},
+}
+
+impl InternetProtocolPacketHostAddresses
+{
+	/// Is this Internet Protocol (IP) version 6?
+	#[inline(always)]
+	pub fn is_version_6(&self) -> bool
+	{
+		match *self
+		{
+			InternetProtocolPacketHostAddresses::Version4 { .. } => false,
+			InternetProtocolPacketHostAddresses::Version6 { .. } => true,
+		}
+	}
+	
+	/// Whether the destination address is unicast, multicast or broadcast.
+	#[inline(always)]
+	pub fn destination_needs_to_support(&self) -> Layer4ProtocolNeedsToSupport
+	{
+		match *self
+		{
+			InternetProtocolPacketHostAddresses::Version4 { destination, .. } => if destination.is_broadcast()
+			{
+				Layer4ProtocolNeedsToSupport::Broadcast
+			}
+			else if destination.is_multicast()
+			{
+				Layer4ProtocolNeedsToSupport::Multicast
+			}
+			else
+			{
+				Layer4ProtocolNeedsToSupport::Unicast
+			},
+			
+			InternetProtocolPacketHostAddresses::Version6 { ref destination, .. } => if destination.is_multicast().is_some()
+			{
+				Layer4ProtocolNeedsToSupport::Multicast
+			}
+			else
+			{
+				Layer4ProtocolNeedsToSupport::Unicast
+			},
+		}
+	}
+	
+	/// Calculates the check sum of a layer 4 packet, including its pseudo-header.
+	///
+	/// If the layer 4 packet's check sum field is correct, the result `validates()`.
+	#[inline(always)]
+	pub fn layer_4_check_sum(&self, layer_4_packet: &Layer4Packet, layer_4_length: u16, layer_4_protocol_number: Layer4ProtocolNumber) -> Rfc1141CompliantCheckSum
+	{
+		let internet_packet_payload_pointer = unsafe { NonNull::new_unchecked(layer_4_packet as *const Layer4Packet as *mut u8) };
+		let layer_4_packet_size = layer_4_length as usize;
+		
+		match *self
+		{
+			InternetProtocolPacketHostAddresses::Version4 { ref source, ref destination } => InternetProtocolVersion4PseudoHeader::internet_protocol_version_4_layer_4_check_sum(source, destination, internet_packet_payload_pointer, layer_4_packet_size, layer_4_protocol_number),
+			
+			InternetProtocolPacketHostAddresses::Version6 { ref source, ref destination } => InternetProtocolVersion6PseudoHeader::internet_protocol_version_6_layer_4_check_sum(source, destination, internet_packet_payload_pointer, layer_4_packet_size, layer_4_protocol_number),
+		}
+	}
+}
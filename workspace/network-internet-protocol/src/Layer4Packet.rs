@@ -36,4 +36,15 @@ impl Layer4Packet
 	{
 		unsafe { transmute(&self.other) }
 	}
+	
+	/// The bytes from the start of `header` up to, but excluding, this layer 4 packet.
+	///
+	/// `header` must be the Internet Protocol (IP) header this layer 4 packet was found in; for IP version 6, the bytes include any extension headers.
+	#[inline(always)]
+	pub(crate) fn preceding_headers<'lifetime, Header>(&'lifetime self, header: &'lifetime Header) -> &'lifetime [u8]
+	{
+		let start = header as *const Header as *const u8;
+		let end = &self.other as *const PhantomData<u8> as *const u8;
+		unsafe { from_raw_parts(start, (end as usize) - (start as usize)) }
+	}
 }
@@ -11,6 +11,14 @@ pub trait Layer4PacketProcessing: Debug
 	/// `layer_4_length` is NOT the same as the Internet Protocol (IP) version 6 payload size; in this case, it is the IPv6 payload size ***less*** the extensions headers size.
 	///
 	/// RFC 2675 IPv6 jumbograms are not supported.
+	///
+	/// The internal priority assigned by quality of service mapping, to queue and account for packets by class, is available from `packet.internal_priority()`.
+	///
+	/// `internet_protocol_headers` are the bytes of the Internet Protocol (IP) header, including any IP version 6 extension headers, that immediately precede `layer_4_packet`, eg to quote in an Internet Control Message Protocol (ICMP) error message.
+	///
+	/// `internet_protocol_packet_host_addresses` are those of the containing Internet Protocol (IP) packet, eg for check sum validation.
+	///
+	/// If a drop reason is returned then `packet` has not been consumed; the caller reports and drops it, wrapping the drop reason with its own.
 	#[inline(always)]
-	fn process<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool);
+	fn process<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, internet_protocol_headers: &'lifetime [u8], internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool) -> Result<(), Self::DropReason>;
 }
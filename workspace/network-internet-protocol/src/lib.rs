@@ -39,6 +39,10 @@ extern crate serde;
 #[macro_use] extern crate serde_derive;
 
 
+/// Neighbour resolution and address announcement, using the Address Resolution Protocol (ARP) or Neighbour Discovery (ND).
+pub mod neighbour_resolution;
+
+
 /// Incoming network packet processing.
 #[macro_use] pub mod packet_processing;
 
@@ -58,6 +62,7 @@ pub mod version_4;
 pub mod version_6;
 
 
+use self::neighbour_resolution::*;
 use self::packet_processing::*;
 use self::version_4::*;
 use self::version_6::*;
@@ -75,6 +80,7 @@ use ::network_ethernet::packet_processing::EthernetIncomingNetworkPacketDropObse
 use ::network_ethernet::packet_processing::EthernetIncomingNetworkPacketDropReason;
 use ::network_ethernet::packet_processing::Layer3PacketProcessing;
 use ::network_packet::IncomingNetworkPacketProcessingDropReason;
+use ::network_time::MillisecondDuration;
 use ::network_time::MonotonicMillisecondTimestamp;
 use ::serde::Deserialize;
 use ::serde::Deserializer;
@@ -83,7 +89,10 @@ use ::serde::Serializer;
 use ::serde::de;
 use ::serde::de::DeserializeOwned;
 use ::serde::de::Visitor;
+use ::std::cell::Cell;
+use ::std::cell::RefCell;
 use ::std::cmp::min;
+use ::std::collections::VecDeque;
 use ::std::cmp::Ordering;
 use ::std::convert::TryFrom;
 use ::std::fmt;
@@ -104,6 +113,7 @@ use ::std::ops::Index;
 use ::std::ptr::copy_nonoverlapping;
 use ::std::ptr::NonNull;
 use ::std::rc::Rc;
+use ::std::slice::from_raw_parts;
 use ::treebitmap::address::Address as TreeBitmapAddress;
 use ::treebitmap::tree_bitmap::TreeBitmap;
 
@@ -113,6 +123,7 @@ include!("InternetHeaderLength.rs");
 include!("InternetProtocolHostAddress.rs");
 include!("InternetProtocolMaskBits.rs");
 include!("InternetProtocolNetworkAddress.rs");
+include!("InternetProtocolPacketHostAddresses.rs");
 include!("InternetProtocolVersion4OrVersion6OrBoth.rs");
 include!("Layer4Packet.rs");
 include!("Layer4PacketProcessing.rs");
@@ -0,0 +1,54 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Address announcement configuration.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct AddressAnnouncementConfiguration
+{
+	/// Number of gratuitous Address Resolution Protocol (ARP) requests or unsolicited Neighbour Advertisements sent for each announcement.
+	///
+	/// Defaults to 3 (RFC 4861 Section 10, `MAX_NEIGHBOR_ADVERTISEMENT`).
+	#[serde(default = "AddressAnnouncementConfiguration::count_default")] pub count: u8,
+	
+	/// Interval between each of them.
+	///
+	/// Defaults to 1 second (RFC 4861 Section 10, `RETRANS_TIMER`).
+	#[serde(default = "AddressAnnouncementConfiguration::interval_default")] pub interval: MillisecondDuration,
+}
+
+impl Default for AddressAnnouncementConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			count: Self::count_default(),
+			interval: Self::interval_default(),
+		}
+	}
+}
+
+impl AddressAnnouncementConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure(self, transmitter: Rc<dyn AddressAnnouncementTransmitter>) -> AddressAnnouncements
+	{
+		AddressAnnouncements::new(self, transmitter)
+	}
+	
+	#[inline(always)]
+	fn count_default() -> u8
+	{
+		3
+	}
+	
+	#[inline(always)]
+	fn interval_default() -> MillisecondDuration
+	{
+		MillisecondDuration::OneSecond
+	}
+}
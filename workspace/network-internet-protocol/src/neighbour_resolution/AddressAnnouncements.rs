@@ -0,0 +1,190 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Announces our addresses so that peers replace stale cache entries, eg when a service address moves between hosts.
+///
+/// Internet protocol (IP) version 4 host addresses are announced with a burst of gratuitous Address Resolution Protocol (ARP) requests (ARP Announcements, RFC 5227 Section 2.3); internet protocol (IP) version 6 host addresses are announced with a burst of unsolicited Neighbour Advertisements with the override flag set (RFC 4861 Section 7.2.6).
+///
+/// The first frame of a burst is transmitted immediately; `progress()` should be called periodically to transmit the remainder.
+#[derive(Debug)]
+pub struct AddressAnnouncements
+{
+	configuration: AddressAnnouncementConfiguration,
+	
+	transmitter: Rc<dyn AddressAnnouncementTransmitter>,
+	
+	internet_protocol_version_4: RefCell<HashMap<InternetProtocolVersion4HostAddress, AddressAnnouncement>>,
+	
+	internet_protocol_version_6: RefCell<HashMap<InternetProtocolVersion6HostAddress, AddressAnnouncement>>,
+}
+
+impl AddressAnnouncements
+{
+	/// Size of a gratuitous Address Resolution Protocol (ARP) request frame, excluding the frame check sequence.
+	pub const GratuitousAddressResolutionProtocolFrameSize: usize = 42;
+	
+	/// Size of an unsolicited Neighbour Advertisement frame, excluding the frame check sequence.
+	pub const UnsolicitedNeighbourAdvertisementFrameSize: usize = 86;
+	
+	const NeighbourAdvertisementSize: usize = 32;
+	
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(configuration: AddressAnnouncementConfiguration, transmitter: Rc<dyn AddressAnnouncementTransmitter>) -> Self
+	{
+		Self
+		{
+			configuration,
+			transmitter,
+			internet_protocol_version_4: RefCell::new(HashMap::default()),
+			internet_protocol_version_6: RefCell::new(HashMap::default()),
+		}
+	}
+	
+	/// Announces an internet protocol (IP) version 4 host address, replacing any announcement still in progress for it.
+	#[inline(always)]
+	pub fn announce_internet_protocol_version_4_host_address(&self, now: MonotonicMillisecondTimestamp, internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress, media_access_control_address: MediaAccessControlAddress)
+	{
+		let frame = Self::gratuitous_address_resolution_protocol_frame(internet_protocol_version_4_host_address, media_access_control_address);
+		self.announce(now, &self.internet_protocol_version_4, internet_protocol_version_4_host_address, frame.to_vec())
+	}
+	
+	/// Announces an internet protocol (IP) version 6 host address, replacing any announcement still in progress for it.
+	#[inline(always)]
+	pub fn announce_internet_protocol_version_6_host_address(&self, now: MonotonicMillisecondTimestamp, internet_protocol_version_6_host_address: InternetProtocolVersion6HostAddress, media_access_control_address: MediaAccessControlAddress)
+	{
+		let frame = Self::unsolicited_neighbour_advertisement_frame(&internet_protocol_version_6_host_address, media_access_control_address);
+		self.announce(now, &self.internet_protocol_version_6, internet_protocol_version_6_host_address, frame.to_vec())
+	}
+	
+	/// Cancels any announcement still in progress for an internet protocol (IP) version 4 host address, eg when it is no longer ours.
+	#[inline(always)]
+	pub fn cancel_internet_protocol_version_4_host_address(&self, internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress)
+	{
+		self.internet_protocol_version_4.borrow_mut().remove(&internet_protocol_version_4_host_address);
+	}
+	
+	/// Cancels any announcement still in progress for an internet protocol (IP) version 6 host address, eg when it is no longer ours.
+	#[inline(always)]
+	pub fn cancel_internet_protocol_version_6_host_address(&self, internet_protocol_version_6_host_address: &InternetProtocolVersion6HostAddress)
+	{
+		self.internet_protocol_version_6.borrow_mut().remove(internet_protocol_version_6_host_address);
+	}
+	
+	/// Transmits the remaining frames of announcements that are due.
+	#[inline]
+	pub fn progress(&self, now: MonotonicMillisecondTimestamp)
+	{
+		let mut due = Vec::new();
+		Self::progress_announcements(now, self.configuration.interval, &self.internet_protocol_version_4, &mut due);
+		Self::progress_announcements(now, self.configuration.interval, &self.internet_protocol_version_6, &mut due);
+		
+		for frame in due
+		{
+			self.transmitter.transmit(&frame)
+		}
+	}
+	
+	/// A gratuitous Address Resolution Protocol (ARP) request (an ARP Announcement), broadcast, with both the sender and target protocol addresses set to `internet_protocol_version_4_host_address` and a zero target hardware address.
+	#[inline(always)]
+	pub fn gratuitous_address_resolution_protocol_frame(internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress, media_access_control_address: MediaAccessControlAddress) -> [u8; AddressAnnouncements::GratuitousAddressResolutionProtocolFrameSize]
+	{
+		let media_access_control_address = media_access_control_address.to_octets();
+		let internet_protocol_version_4_host_address = internet_protocol_version_4_host_address.0;
+		
+		let mut frame = [0u8; Self::GratuitousAddressResolutionProtocolFrameSize];
+		
+		// Ethernet header.
+		frame[0 .. 6].copy_from_slice(&[0xFF; 6]);
+		frame[6 .. 12].copy_from_slice(&media_access_control_address);
+		frame[12 .. 14].copy_from_slice(&[0x08, 0x06]);
+		
+		// Hardware type (ethernet), protocol type (internet protocol version 4), hardware address length, protocol address length and operation (request).
+		frame[14 .. 22].copy_from_slice(&[0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x01]);
+		frame[22 .. 28].copy_from_slice(&media_access_control_address);
+		frame[28 .. 32].copy_from_slice(&internet_protocol_version_4_host_address);
+		frame[38 .. 42].copy_from_slice(&internet_protocol_version_4_host_address);
+		
+		frame
+	}
+	
+	/// An unsolicited Neighbour Advertisement to the all-nodes multicast address, with the override flag set and a target link-layer address option.
+	#[inline(always)]
+	pub fn unsolicited_neighbour_advertisement_frame(internet_protocol_version_6_host_address: &InternetProtocolVersion6HostAddress, media_access_control_address: MediaAccessControlAddress) -> [u8; AddressAnnouncements::UnsolicitedNeighbourAdvertisementFrameSize]
+	{
+		const OverrideFlag: u8 = 0x20;
+		const TargetLinkLayerAddressOption: u8 = 2;
+		
+		let media_access_control_address = media_access_control_address.to_octets();
+		let destination = &InternetProtocolVersion6HostAddress::MulticastAllNodesLinkLocal;
+		
+		let mut frame = [0u8; Self::UnsolicitedNeighbourAdvertisementFrameSize];
+		
+		// Ethernet header; the destination is the all-nodes multicast address mapped to ethernet (RFC 2464 Section 7).
+		frame[0 .. 6].copy_from_slice(&[0x33, 0x33, destination.0[12], destination.0[13], destination.0[14], destination.0[15]]);
+		frame[6 .. 12].copy_from_slice(&media_access_control_address);
+		frame[12 .. 14].copy_from_slice(&[0x86, 0xDD]);
+		
+		// Internet protocol (IP) version 6 header: version, payload length, next header and a hop limit of 255.
+		frame[14] = 0x60;
+		frame[18 .. 20].copy_from_slice(&[0x00, Self::NeighbourAdvertisementSize as u8]);
+		frame[20] = Layer4ProtocolNumber::InternetControlMessageProtocolIpV6 as u8;
+		frame[21] = 255;
+		frame[22 .. 38].copy_from_slice(&internet_protocol_version_6_host_address.0);
+		frame[38 .. 54].copy_from_slice(&destination.0);
+		
+		// Neighbour Advertisement; the check sum is calculated last.
+		frame[54] = 136;
+		frame[58] = OverrideFlag;
+		frame[62 .. 78].copy_from_slice(&internet_protocol_version_6_host_address.0);
+		frame[78] = TargetLinkLayerAddressOption;
+		frame[79] = 1;
+		frame[80 .. 86].copy_from_slice(&media_access_control_address);
+		
+		let check_sum = InternetProtocolVersion6PseudoHeader::internet_protocol_version_6_layer_4_check_sum(internet_protocol_version_6_host_address, destination, unsafe { NonNull::new_unchecked(frame.as_mut_ptr().add(54)) }, Self::NeighbourAdvertisementSize, Layer4ProtocolNumber::InternetControlMessageProtocolIpV6);
+		frame[56 .. 58].copy_from_slice(check_sum.to_network_endian().bytes());
+		
+		frame
+	}
+	
+	#[inline(always)]
+	fn announce<HostAddress: InternetProtocolHostAddress>(&self, now: MonotonicMillisecondTimestamp, announcements: &RefCell<HashMap<HostAddress, AddressAnnouncement>>, host_address: HostAddress, frame: Vec<u8>)
+	{
+		if self.configuration.count == 0
+		{
+			return
+		}
+		
+		self.transmitter.transmit(&frame);
+		
+		let remaining = self.configuration.count - 1;
+		let mut announcements = announcements.borrow_mut();
+		if remaining == 0
+		{
+			announcements.remove(&host_address);
+		}
+		else
+		{
+			announcements.insert(host_address, AddressAnnouncement { frame, remaining, next_announcement_at: now + self.configuration.interval });
+		}
+	}
+	
+	#[inline(always)]
+	fn progress_announcements<HostAddress: InternetProtocolHostAddress>(now: MonotonicMillisecondTimestamp, interval: MillisecondDuration, announcements: &RefCell<HashMap<HostAddress, AddressAnnouncement>>, due: &mut Vec<Vec<u8>>)
+	{
+		let mut announcements = announcements.borrow_mut();
+		
+		for announcement in announcements.values_mut()
+		{
+			if now >= announcement.next_announcement_at
+			{
+				due.push(announcement.frame.clone());
+				announcement.remaining -= 1;
+				announcement.next_announcement_at = now + interval;
+			}
+		}
+		
+		announcements.retain(|_, announcement| announcement.remaining != 0);
+	}
+}
@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct PendingResolution<Packet: PendingResolutionPacket>
+{
+	pub(crate) packets: VecDeque<Packet>,
+	
+	pub(crate) solicitations_sent: u8,
+	
+	pub(crate) retransmission_interval: MillisecondDuration,
+	
+	pub(crate) next_solicitation_at: MonotonicMillisecondTimestamp,
+}
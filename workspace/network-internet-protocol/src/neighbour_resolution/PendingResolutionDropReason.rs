@@ -0,0 +1,20 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Why a parked packet was dropped.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Serialize, Deserialize)]
+pub enum PendingResolutionDropReason
+{
+	/// The queue for the next hop was full, so the oldest parked packet was dropped.
+	QueueForNextHopIsFull,
+	
+	/// Parking the packet would have exceeded the cap on total parked memory.
+	TotalParkedMemoryCapExceeded,
+	
+	/// The next hop did not answer the Address Resolution Protocol (ARP) requests or Neighbour Solicitations.
+	///
+	/// An Internet Control Message Protocol (ICMP) host-unreachable (destination unreachable, code 1 for version 4 and code 3, address unreachable, for version 6) should be signalled to the sender of the packet.
+	HostUnreachable,
+}
@@ -0,0 +1,23 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Sends solicitations and transmits or drops parked packets on behalf of a `PendingResolutionQueue`.
+///
+/// Methods are never called whilst the queue is borrowed, so they may park packets.
+pub trait PendingResolutionObserver<HostAddress: InternetProtocolHostAddress>: Debug
+{
+	/// Parked packet type.
+	type Packet: PendingResolutionPacket;
+	
+	/// Send an Address Resolution Protocol (ARP) request or Neighbour Solicitation for `next_hop`.
+	///
+	/// `solicitation` is one-based.
+	fn solicit(&self, now: MonotonicMillisecondTimestamp, next_hop: &HostAddress, solicitation: u8);
+	
+	/// Transmit a parked packet now `next_hop` has been resolved.
+	fn transmit(&self, next_hop: &HostAddress, next_hop_media_access_control_address: &MediaAccessControlAddress, packet: Self::Packet);
+	
+	/// Drop (free) a parked packet.
+	fn dropped(&self, next_hop: &HostAddress, packet: Self::Packet, reason: PendingResolutionDropReason);
+}
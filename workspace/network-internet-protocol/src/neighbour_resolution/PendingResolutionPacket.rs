@@ -0,0 +1,11 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A packet that can be parked whilst its next hop is resolved.
+pub trait PendingResolutionPacket: Debug
+{
+	/// Length in bytes, used to cap total parked memory.
+	#[inline(always)]
+	fn length(&self) -> usize;
+}
@@ -0,0 +1,193 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Parks packets for next hops whose media access control address is not yet known, whilst Address Resolution Protocol (ARP) requests or Neighbour Solicitations are in flight.
+///
+/// Solicitations are retransmitted with exponential back-off (RFC 7048) until either the next hop is resolved, when its parked packets are transmitted in order, or the retransmission limit is reached, when its parked packets are dropped as host-unreachable.
+///
+/// Each next hop has a bounded queue, from which the oldest packet is dropped when full, and the total length of all parked packets is capped.
+///
+/// `progress()` should be called periodically, eg once every few milliseconds.
+#[derive(Debug)]
+pub struct PendingResolutionQueue<HostAddress: InternetProtocolHostAddress, PRO: PendingResolutionObserver<HostAddress>>
+{
+	configuration: PendingResolutionQueueConfiguration,
+	
+	observer: PRO,
+	
+	pending: RefCell<HashMap<HostAddress, PendingResolution<PRO::Packet>>>,
+	
+	total_parked_bytes: Cell<usize>,
+}
+
+impl<HostAddress: InternetProtocolHostAddress, PRO: PendingResolutionObserver<HostAddress>> PendingResolutionQueue<HostAddress, PRO>
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(configuration: PendingResolutionQueueConfiguration, observer: PRO) -> Self
+	{
+		Self
+		{
+			configuration,
+			observer,
+			pending: RefCell::new(HashMap::default()),
+			total_parked_bytes: Cell::new(0),
+		}
+	}
+	
+	/// Observer.
+	#[inline(always)]
+	pub fn observer(&self) -> &PRO
+	{
+		&self.observer
+	}
+	
+	/// Is resolution of `next_hop` in flight?
+	#[inline(always)]
+	pub fn is_pending(&self, next_hop: &HostAddress) -> bool
+	{
+		self.pending.borrow().contains_key(next_hop)
+	}
+	
+	/// Total length of all parked packets.
+	#[inline(always)]
+	pub fn total_parked_bytes(&self) -> usize
+	{
+		self.total_parked_bytes.get()
+	}
+	
+	/// Parks a packet until `next_hop` is resolved.
+	///
+	/// If resolution of `next_hop` is not already in flight, the first solicitation is sent immediately.
+	#[inline]
+	pub fn park(&self, now: MonotonicMillisecondTimestamp, next_hop: HostAddress, packet: PRO::Packet)
+	{
+		use self::PendingResolutionDropReason::*;
+		
+		let length = packet.length();
+		if unlikely!(self.total_parked_bytes() + length > self.configuration.maximum_total_parked_bytes)
+		{
+			return self.observer.dropped(&next_hop, packet, TotalParkedMemoryCapExceeded)
+		}
+		
+		let (is_new, oldest_packet) =
+		{
+			let mut pending = self.pending.borrow_mut();
+			
+			let is_new = !pending.contains_key(&next_hop);
+			let pending_resolution = pending.entry(next_hop.clone()).or_insert_with(|| PendingResolution
+			{
+				packets: VecDeque::with_capacity(self.configuration.maximum_packets_per_next_hop),
+				solicitations_sent: 1,
+				retransmission_interval: self.configuration.initial_retransmission_interval,
+				next_solicitation_at: now + self.configuration.initial_retransmission_interval,
+			});
+			
+			let oldest_packet = if unlikely!(pending_resolution.packets.len() >= self.configuration.maximum_packets_per_next_hop)
+			{
+				pending_resolution.packets.pop_front()
+			}
+			else
+			{
+				None
+			};
+			
+			pending_resolution.packets.push_back(packet);
+			
+			(is_new, oldest_packet)
+		};
+		
+		self.total_parked_bytes.set(self.total_parked_bytes() + length);
+		
+		if let Some(oldest_packet) = oldest_packet
+		{
+			self.dropped(&next_hop, oldest_packet, QueueForNextHopIsFull)
+		}
+		
+		if is_new
+		{
+			self.observer.solicit(now, &next_hop, 1)
+		}
+	}
+	
+	/// Transmits all packets parked for `next_hop`, in the order they were parked, eg when an Address Resolution Protocol (ARP) reply or Neighbour Advertisement is received.
+	#[inline]
+	pub fn resolved(&self, next_hop: &HostAddress, next_hop_media_access_control_address: &MediaAccessControlAddress)
+	{
+		let pending_resolution = match self.pending.borrow_mut().remove(next_hop)
+		{
+			None => return,
+			
+			Some(pending_resolution) => pending_resolution,
+		};
+		
+		for packet in pending_resolution.packets
+		{
+			self.unpark(&packet);
+			self.observer.transmit(next_hop, next_hop_media_access_control_address, packet)
+		}
+	}
+	
+	/// Retransmits solicitations that are due, doubling the retransmission interval each time, and drops the parked packets of next hops that have not answered within the retransmission limit.
+	#[inline]
+	pub fn progress(&self, now: MonotonicMillisecondTimestamp)
+	{
+		let mut solicitations = Vec::new();
+		let mut unreachable_next_hops = Vec::new();
+		
+		{
+			let mut pending = self.pending.borrow_mut();
+			
+			for (next_hop, pending_resolution) in pending.iter_mut()
+			{
+				if now < pending_resolution.next_solicitation_at
+				{
+					continue
+				}
+				
+				if pending_resolution.solicitations_sent >= self.configuration.maximum_solicitations
+				{
+					unreachable_next_hops.push(next_hop.clone());
+					continue
+				}
+				
+				pending_resolution.solicitations_sent += 1;
+				pending_resolution.retransmission_interval = min(pending_resolution.retransmission_interval * 2, self.configuration.maximum_retransmission_interval);
+				pending_resolution.next_solicitation_at = now + pending_resolution.retransmission_interval;
+				solicitations.push((next_hop.clone(), pending_resolution.solicitations_sent));
+			}
+		}
+		
+		for (next_hop, solicitation) in solicitations
+		{
+			self.observer.solicit(now, &next_hop, solicitation)
+		}
+		
+		for next_hop in unreachable_next_hops
+		{
+			// The observer may have resolved (or otherwise removed) the next hop when soliciting; the borrow is not held whilst packets are dropped, as the observer may also re-enter.
+			let pending_resolution = self.pending.borrow_mut().remove(&next_hop);
+			if let Some(pending_resolution) = pending_resolution
+			{
+				for packet in pending_resolution.packets
+				{
+					self.dropped(&next_hop, packet, PendingResolutionDropReason::HostUnreachable)
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn dropped(&self, next_hop: &HostAddress, packet: PRO::Packet, reason: PendingResolutionDropReason)
+	{
+		self.unpark(&packet);
+		self.observer.dropped(next_hop, packet, reason)
+	}
+	
+	#[inline(always)]
+	fn unpark(&self, packet: &PRO::Packet)
+	{
+		self.total_parked_bytes.set(self.total_parked_bytes() - packet.length())
+	}
+}
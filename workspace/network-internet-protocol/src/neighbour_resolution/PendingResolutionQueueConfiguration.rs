@@ -0,0 +1,90 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Pending resolution queue configuration.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct PendingResolutionQueueConfiguration
+{
+	/// Maximum number of packets parked per next hop.
+	///
+	/// Defaults to 3.
+	#[serde(default = "PendingResolutionQueueConfiguration::maximum_packets_per_next_hop_default")] pub maximum_packets_per_next_hop: usize,
+	
+	/// Cap on the total length of all parked packets.
+	///
+	/// Defaults to 64Kb.
+	#[serde(default = "PendingResolutionQueueConfiguration::maximum_total_parked_bytes_default")] pub maximum_total_parked_bytes: usize,
+	
+	/// Maximum number of Address Resolution Protocol (ARP) requests or Neighbour Solicitations sent before the next hop is considered unreachable.
+	///
+	/// Defaults to 3 (RFC 4861 Section 10, `MAX_MULTICAST_SOLICIT`).
+	#[serde(default = "PendingResolutionQueueConfiguration::maximum_solicitations_default")] pub maximum_solicitations: u8,
+	
+	/// Interval before the first retransmission; it doubles for each subsequent retransmission.
+	///
+	/// Defaults to 1 second (RFC 4861 Section 10, `RETRANS_TIMER`).
+	#[serde(default = "PendingResolutionQueueConfiguration::initial_retransmission_interval_default")] pub initial_retransmission_interval: MillisecondDuration,
+	
+	/// Maximum interval between retransmissions.
+	///
+	/// Defaults to 60 seconds (RFC 7048 Section 4, `MAX_RETRANS_TIMER`).
+	#[serde(default = "PendingResolutionQueueConfiguration::maximum_retransmission_interval_default")] pub maximum_retransmission_interval: MillisecondDuration,
+}
+
+impl Default for PendingResolutionQueueConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			maximum_packets_per_next_hop: Self::maximum_packets_per_next_hop_default(),
+			maximum_total_parked_bytes: Self::maximum_total_parked_bytes_default(),
+			maximum_solicitations: Self::maximum_solicitations_default(),
+			initial_retransmission_interval: Self::initial_retransmission_interval_default(),
+			maximum_retransmission_interval: Self::maximum_retransmission_interval_default(),
+		}
+	}
+}
+
+impl PendingResolutionQueueConfiguration
+{
+	/// Configure.
+	#[inline(always)]
+	pub fn configure<HostAddress: InternetProtocolHostAddress, PRO: PendingResolutionObserver<HostAddress>>(self, observer: PRO) -> PendingResolutionQueue<HostAddress, PRO>
+	{
+		PendingResolutionQueue::new(self, observer)
+	}
+	
+	#[inline(always)]
+	fn maximum_packets_per_next_hop_default() -> usize
+	{
+		3
+	}
+	
+	#[inline(always)]
+	fn maximum_total_parked_bytes_default() -> usize
+	{
+		64 * 1024
+	}
+	
+	#[inline(always)]
+	fn maximum_solicitations_default() -> u8
+	{
+		3
+	}
+	
+	#[inline(always)]
+	fn initial_retransmission_interval_default() -> MillisecondDuration
+	{
+		MillisecondDuration::OneSecond
+	}
+	
+	#[inline(always)]
+	fn maximum_retransmission_interval_default() -> MillisecondDuration
+	{
+		MillisecondDuration::OneMinute
+	}
+}
@@ -53,6 +53,7 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<IPV4INPDR=InternetProtoco
 		{
 			now,
 			ethernet_addresses,
+			internal_priority: packet.internal_priority(),
 			reason,
 		};
 		
@@ -92,20 +93,32 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<IPV4INPDR=InternetProtoco
 	}
 	
 	#[inline(always)]
-	pub(crate) fn process_internet_control_message_protocol_version_4<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
+	pub(crate) fn process_internet_control_message_protocol_version_4<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, header: &'lifetime InternetProtocolVersion4PacketHeader, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
 	{
-		self.internet_control_message_protocol_version_4_processing.process(now, packet, layer_4_packet, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		let internet_protocol_packet_host_addresses = InternetProtocolPacketHostAddresses::Version4 { source: header.source_address, destination: header.destination_address };
+		if let Err(reason) = self.internet_control_message_protocol_version_4_processing.process(now, packet, layer_4_packet, layer_4_length, layer_4_packet.preceding_headers(header), internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		{
+			drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::ProblematicInternetControlMessageProtocolVersion4Packet { header: header.non_null(), reason }, ethernet_addresses, self, packet)
+		}
 	}
 	
 	#[inline(always)]
-	pub(crate) fn process_transmission_control_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
+	pub(crate) fn process_transmission_control_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, header: &'lifetime InternetProtocolVersion4PacketHeader, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
 	{
-		self.transmission_control_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		let internet_protocol_packet_host_addresses = InternetProtocolPacketHostAddresses::Version4 { source: header.source_address, destination: header.destination_address };
+		if let Err(reason) = self.transmission_control_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, layer_4_packet.preceding_headers(header), internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		{
+			drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::ProblematicTransmissionControlProtocolPacket { header: header.non_null(), reason }, ethernet_addresses, self, packet)
+		}
 	}
 	
 	#[inline(always)]
-	pub(crate) fn process_user_datagram_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
+	pub(crate) fn process_user_datagram_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, header: &'lifetime InternetProtocolVersion4PacketHeader, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
 	{
-		self.user_datagram_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		let internet_protocol_packet_host_addresses = InternetProtocolPacketHostAddresses::Version4 { source: header.source_address, destination: header.destination_address };
+		if let Err(reason) = self.user_datagram_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, layer_4_packet.preceding_headers(header), internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		{
+			drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::ProblematicUserDatagramProtocolPacket { header: header.non_null(), reason }, ethernet_addresses, self, packet)
+		}
 	}
 }
@@ -9,7 +9,7 @@ pub struct InternetProtocolVersion6PacketProcessing<EINPDO: EthernetIncomingNetw
 	dropped_packet_reporting: Rc<EINPDO>,
 	
 	/// No sender packet should be received from this address; if it was, it implies loopback on this interface, which is daft.
-	our_valid_internet_protocol_version_6_host_addresses: HashSet<InternetProtocolVersion6HostAddress>,
+	our_valid_internet_protocol_version_6_host_addresses: Rc<OurValidInternetProtocolVersion6HostAddresses>,
 	
 	/// No sender packet should be received from this address; if it was, it implies loopback on this interface, which is daft.
 	our_valid_internet_protocol_version_6_multicast_addresses: HashSet<InternetProtocolVersion6HostAddress>,
@@ -52,6 +52,7 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<IPV6INPDR=InternetProtoco
 		{
 			now,
 			ethernet_addresses,
+			internal_priority: packet.internal_priority(),
 			reason,
 		};
 		
@@ -75,7 +76,7 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<IPV6INPDR=InternetProtoco
 	{
 		debug_assert!(internet_protocol_version_6_host_address.is_valid_unicast(), "internet_protocol_version_6_host_address '{:?}' is not valid unicast", internet_protocol_version_6_host_address);
 		
-		self.our_valid_internet_protocol_version_6_host_addresses.contains(&internet_protocol_version_6_host_address)
+		self.our_valid_internet_protocol_version_6_host_addresses.is_internet_protocol_version_6_host_address_one_of_ours(internet_protocol_version_6_host_address)
 	}
 	
 	#[inline(always)]
@@ -96,20 +97,32 @@ impl<EINPDO: EthernetIncomingNetworkPacketDropObserver<IPV6INPDR=InternetProtoco
 	}
 	
 	#[inline(always)]
-	pub(crate) fn process_internet_control_message_protocol_version_6<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
+	pub(crate) fn process_internet_control_message_protocol_version_6<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, header: &'lifetime InternetProtocolVersion6PacketHeader, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
 	{
-		self.internet_control_message_protocol_version_6_processing.process(now, packet, layer_4_packet, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		let internet_protocol_packet_host_addresses = InternetProtocolPacketHostAddresses::Version6 { source: header.source_address, destination: header.destination_address };
+		if let Err(reason) = self.internet_control_message_protocol_version_6_processing.process(now, packet, layer_4_packet, layer_4_length, layer_4_packet.preceding_headers(header), internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		{
+			drop!(now, InternetProtocolVersion6IncomingNetworkPacketDropReason::ProblematicInternetControlMessageProtocolVersion6Packet { header: header.non_null(), reason }, ethernet_addresses, self, packet)
+		}
 	}
 	
 	#[inline(always)]
-	pub(crate) fn process_transmission_control_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
+	pub(crate) fn process_transmission_control_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, header: &'lifetime InternetProtocolVersion6PacketHeader, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
 	{
-		self.transmission_control_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		let internet_protocol_packet_host_addresses = InternetProtocolPacketHostAddresses::Version6 { source: header.source_address, destination: header.destination_address };
+		if let Err(reason) = self.transmission_control_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, layer_4_packet.preceding_headers(header), internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		{
+			drop!(now, InternetProtocolVersion6IncomingNetworkPacketDropReason::ProblematicTransmissionControlProtocolPacket { header: header.non_null(), reason }, ethernet_addresses, self, packet)
+		}
 	}
 	
 	#[inline(always)]
-	pub(crate) fn process_user_datagram_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
+	pub(crate) fn process_user_datagram_protocol<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, header: &'lifetime InternetProtocolVersion6PacketHeader, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool)
 	{
-		self.user_datagram_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		let internet_protocol_packet_host_addresses = InternetProtocolPacketHostAddresses::Version6 { source: header.source_address, destination: header.destination_address };
+		if let Err(reason) = self.user_datagram_protocol_processing.process(now, packet, layer_4_packet, layer_4_length, layer_4_packet.preceding_headers(header), internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+		{
+			drop!(now, InternetProtocolVersion6IncomingNetworkPacketDropReason::ProblematicUserDatagramProtocolPacket { header: header.non_null(), reason }, ethernet_addresses, self, packet)
+		}
 	}
 }
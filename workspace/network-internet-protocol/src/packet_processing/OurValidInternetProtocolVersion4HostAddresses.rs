@@ -7,17 +7,70 @@
 /// Used for both Internet Protocol (IP) version 4 packet processing and Address Resolution Protocol (ARP) packet processing `network-address-resolution-protocol` crate.
 ///
 /// No sender packet should be received from this address; if it was, it implies loopback on this interface, which is daft.
+///
+/// Each address is associated with the media access control address it is used with; if there are address announcements, gaining an address or changing its media access control address announces it.
 #[derive(Debug)]
-pub struct OurValidInternetProtocolVersion4HostAddresses(HashSet<InternetProtocolVersion4HostAddress>);
+pub struct OurValidInternetProtocolVersion4HostAddresses
+{
+	addresses: RefCell<HashMap<InternetProtocolVersion4HostAddress, MediaAccessControlAddress>>,
+	
+	announcements: Option<Rc<AddressAnnouncements>>,
+}
 
 impl OurValidInternetProtocolVersion4HostAddresses
 {
+	/// Creates a new instance without any addresses.
+	#[inline(always)]
+	pub fn new(announcements: Option<Rc<AddressAnnouncements>>) -> Self
+	{
+		Self
+		{
+			addresses: RefCell::new(HashMap::default()),
+			announcements,
+		}
+	}
+	
+	/// Adds an address, or changes the media access control address it is used with.
+	///
+	/// Announces the address if it is new or its media access control address has changed.
+	#[inline(always)]
+	pub fn insert(&self, now: MonotonicMillisecondTimestamp, internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress, media_access_control_address: MediaAccessControlAddress)
+	{
+		debug_assert!(internet_protocol_version_4_host_address.is_valid_unicast(), "internet_protocol_version_4_host_address '{:?}' is not valid unicast", internet_protocol_version_4_host_address);
+		debug_assert!(media_access_control_address.is_valid_unicast(), "media_access_control_address '{:?}' is not valid unicast", media_access_control_address);
+		
+		let previous_media_access_control_address = self.addresses.borrow_mut().insert(internet_protocol_version_4_host_address, media_access_control_address);
+		if previous_media_access_control_address == Some(media_access_control_address)
+		{
+			return
+		}
+		
+		if let Some(ref announcements) = self.announcements
+		{
+			announcements.announce_internet_protocol_version_4_host_address(now, internet_protocol_version_4_host_address, media_access_control_address)
+		}
+	}
+	
+	/// Removes an address, returning the media access control address it was used with, if any.
+	///
+	/// Cancels any announcement of the address still in progress.
+	#[inline(always)]
+	pub fn remove(&self, internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress) -> Option<MediaAccessControlAddress>
+	{
+		if let Some(ref announcements) = self.announcements
+		{
+			announcements.cancel_internet_protocol_version_4_host_address(internet_protocol_version_4_host_address)
+		}
+		
+		self.addresses.borrow_mut().remove(&internet_protocol_version_4_host_address)
+	}
+	
 	/// Used for both Internet Protocol (IP) version 4 packet processing and Address Resolution Protocol (ARP) packet processing  `network-address-resolution-protocol` crate.
 	#[inline(always)]
 	pub fn is_internet_protocol_version_4_host_address_one_of_ours(&self, internet_protocol_version_4_host_address: InternetProtocolVersion4HostAddress) -> bool
 	{
 		debug_assert!(internet_protocol_version_4_host_address.is_valid_unicast(), "internet_protocol_version_4_host_address '{:?}' is not valid unicast", internet_protocol_version_4_host_address);
 		
-		self.0.contains(&internet_protocol_version_4_host_address)
+		self.addresses.borrow().contains_key(&internet_protocol_version_4_host_address)
 	}
 }
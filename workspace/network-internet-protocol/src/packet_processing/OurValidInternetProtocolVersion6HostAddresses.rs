@@ -0,0 +1,76 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Our unicast internet protocol (IP) version 6 host addresses valid for this network interface.
+///
+/// Used for Internet Protocol (IP) version 6 packet processing.
+///
+/// No sender packet should be received from this address; if it was, it implies loopback on this interface, which is daft.
+///
+/// Each address is associated with the media access control address it is used with; if there are address announcements, gaining an address or changing its media access control address announces it.
+#[derive(Debug)]
+pub struct OurValidInternetProtocolVersion6HostAddresses
+{
+	addresses: RefCell<HashMap<InternetProtocolVersion6HostAddress, MediaAccessControlAddress>>,
+	
+	announcements: Option<Rc<AddressAnnouncements>>,
+}
+
+impl OurValidInternetProtocolVersion6HostAddresses
+{
+	/// Creates a new instance without any addresses.
+	#[inline(always)]
+	pub fn new(announcements: Option<Rc<AddressAnnouncements>>) -> Self
+	{
+		Self
+		{
+			addresses: RefCell::new(HashMap::default()),
+			announcements,
+		}
+	}
+	
+	/// Adds an address, or changes the media access control address it is used with.
+	///
+	/// Announces the address if it is new or its media access control address has changed.
+	#[inline(always)]
+	pub fn insert(&self, now: MonotonicMillisecondTimestamp, internet_protocol_version_6_host_address: InternetProtocolVersion6HostAddress, media_access_control_address: MediaAccessControlAddress)
+	{
+		debug_assert!(internet_protocol_version_6_host_address.is_valid_unicast(), "internet_protocol_version_6_host_address '{:?}' is not valid unicast", internet_protocol_version_6_host_address);
+		debug_assert!(media_access_control_address.is_valid_unicast(), "media_access_control_address '{:?}' is not valid unicast", media_access_control_address);
+		
+		let previous_media_access_control_address = self.addresses.borrow_mut().insert(internet_protocol_version_6_host_address, media_access_control_address);
+		if previous_media_access_control_address == Some(media_access_control_address)
+		{
+			return
+		}
+		
+		if let Some(ref announcements) = self.announcements
+		{
+			announcements.announce_internet_protocol_version_6_host_address(now, internet_protocol_version_6_host_address, media_access_control_address)
+		}
+	}
+	
+	/// Removes an address, returning the media access control address it was used with, if any.
+	///
+	/// Cancels any announcement of the address still in progress.
+	#[inline(always)]
+	pub fn remove(&self, internet_protocol_version_6_host_address: &InternetProtocolVersion6HostAddress) -> Option<MediaAccessControlAddress>
+	{
+		if let Some(ref announcements) = self.announcements
+		{
+			announcements.cancel_internet_protocol_version_6_host_address(internet_protocol_version_6_host_address)
+		}
+		
+		self.addresses.borrow_mut().remove(internet_protocol_version_6_host_address)
+	}
+	
+	/// Is this address one of ours?
+	#[inline(always)]
+	pub fn is_internet_protocol_version_6_host_address_one_of_ours(&self, internet_protocol_version_6_host_address: &InternetProtocolVersion6HostAddress) -> bool
+	{
+		debug_assert!(internet_protocol_version_6_host_address.is_valid_unicast(), "internet_protocol_version_6_host_address '{:?}' is not valid unicast", internet_protocol_version_6_host_address);
+		
+		self.addresses.borrow().contains_key(internet_protocol_version_6_host_address)
+	}
+}
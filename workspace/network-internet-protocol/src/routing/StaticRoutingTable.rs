@@ -12,29 +12,49 @@ pub struct StaticRoutingTable<NetworkAddress: InternetProtocolNetworkAddress>
 impl<NetworkAddress: InternetProtocolNetworkAddress> StaticRoutingTable<NetworkAddress>
 {
 	/// Finds the information necessary to send to this address.
+	///
+	/// Link-local destinations (eg `169.254.0.0/16` for version 4) are never sent to a router, even if a static route or the default route would otherwise match.
+	/// If the media access control address of such a destination can not be derived from it then `None` is returned; the caller should resolve it on the local link (eg using the Address Resolution Protocol, ARP).
 	#[inline(always)]
-	pub fn route_to_next_hop(&self, destination_internet_protocol_host_address: &NetworkAddress::HostAddress) -> EthernetDestination
+	pub fn route_to_next_hop(&self, destination_internet_protocol_host_address: &NetworkAddress::HostAddress) -> Option<EthernetDestination>
 	{
+		if unlikely!(destination_internet_protocol_host_address.is_link_local_scope())
+		{
+			return match destination_internet_protocol_host_address.to_media_access_control_address()
+			{
+				Err(()) => None,
+				
+				Ok(media_access_control_address) => Some
+				(
+					EthernetDestination
+					{
+						media_access_control_address,
+						ethernet_frame_length: self.default_route_to_next_hop.ethernet_frame_length,
+					}
+				),
+			}
+		}
+		
 		let nibbles = destination_internet_protocol_host_address.nibbles_non_destructively();
 		match self.longest_prefix_match.longest_match_value(nibbles.as_ref())
 		{
-			None => self.default_route_to_next_hop,
+			None => Some(self.default_route_to_next_hop),
 			
 			Some(route) =>
 			{
 				let next_hop_internet_protocol_host_address = route.next_hop_internet_protocol_host_address(destination_internet_protocol_host_address);
 				match route.next_hop_media_access_control_address(next_hop_internet_protocol_host_address)
 				{
-					Err(()) => self.default_route_to_next_hop,
+					Err(()) => Some(self.default_route_to_next_hop),
 					
-					Ok(media_access_control_address) =>
-					{
+					Ok(media_access_control_address) => Some
+					(
 						EthernetDestination
 						{
 							media_access_control_address,
 							ethernet_frame_length: route.next_hop_ethernet_frame_length(),
 						}
-					}
+					),
 				}
 			}
 		}
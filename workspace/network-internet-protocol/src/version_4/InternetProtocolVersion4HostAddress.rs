@@ -199,6 +199,12 @@ impl InternetProtocolHostAddress for InternetProtocolVersion4HostAddress
 		MediaAccessControlAddress::from_private_internet_protocol_version_4_host_address(self)
 	}
 	
+	#[inline(always)]
+	fn is_link_local_scope(&self) -> bool
+	{
+		self.is_link_local()
+	}
+	
 	#[inline(always)]
 	fn nibbles_non_destructively(&self) -> <Self as TreeBitmapAddress>::Nibbles
 	{
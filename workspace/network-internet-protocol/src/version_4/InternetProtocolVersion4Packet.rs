@@ -300,7 +300,7 @@ impl InternetProtocolVersion4Packet
 					drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::EthernetAddressWasNotUnicast { header: header.non_null() }, ethernet_addresses, packet_processing, packet)
 				}
 				
-				packet_processing.process_internet_control_message_protocol_version_4(now, packet, &self.payload, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+				packet_processing.process_internet_control_message_protocol_version_4(now, packet, header, &self.payload, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
 			}
 			
 			KnownOrUnknownLayer4ProtocolNumber::TransmissionControlProtocol =>
@@ -331,7 +331,7 @@ impl InternetProtocolVersion4Packet
 					drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::UnicastDestinationIsNotUs { header: header.non_null() }, ethernet_addresses, packet_processing, packet)
 				}
 				
-				packet_processing.process_transmission_control_protocol(now, packet, &self.payload, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+				packet_processing.process_transmission_control_protocol(now, packet, header, &self.payload, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
 			},
 			
 			KnownOrUnknownLayer4ProtocolNumber::UserDatagramProtocol =>
@@ -340,7 +340,7 @@ impl InternetProtocolVersion4Packet
 				let destination_address = header.destination_address;
 				let (_, destination_ethernet_address) = ethernet_addresses.addresses();
 				
-				if unlikely!(source_address.is_not_valid_unicast() || !source_address.is_unspecified())
+				if unlikely!(source_address.is_not_valid_unicast() && !source_address.is_unspecified())
 				{
 					drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::SourceAddressNotValidUnicastOrUnspecified { header: header.non_null() }, ethernet_addresses, packet_processing, packet)
 				}
@@ -397,7 +397,7 @@ impl InternetProtocolVersion4Packet
 					drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::DestinationWasLoopbackOrDocumentationAddress { header: header.non_null() }, ethernet_addresses, packet_processing, packet)
 				}
 				
-				packet_processing.process_user_datagram_protocol(now, packet, &self.payload, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+				packet_processing.process_user_datagram_protocol(now, packet, header, &self.payload, layer_4_length, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
 			},
 			
 			unsupported_layer_4_protocol @ _ => drop!(now, InternetProtocolVersion4IncomingNetworkPacketDropReason::UnsupportedLayer4Protocol { header: header.non_null(), unsupported_layer_4_protocol }, ethernet_addresses, packet_processing, packet)
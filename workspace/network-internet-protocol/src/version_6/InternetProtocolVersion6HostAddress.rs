@@ -200,6 +200,12 @@ impl InternetProtocolHostAddress for InternetProtocolVersion6HostAddress
 		MediaAccessControlAddress::from_internet_protocol_version_6_host_address(*self)
 	}
 	
+	#[inline(always)]
+	fn is_link_local_scope(&self) -> bool
+	{
+		self.is_link_local_unicast()
+	}
+	
 	#[inline(always)]
 	fn nibbles_non_destructively(&self) -> <Self as TreeBitmapAddress>::Nibbles
 	{
@@ -188,6 +188,12 @@ impl InternetProtocolVersion6PacketHeader
 		unsafe { *self.version_and_traffic_class_and_flow_label.get_unchecked_mut(1) = second_byte }
 	}
 	
+	#[inline(always)]
+	pub(crate) fn non_null(&self) -> NonNull<Self>
+	{
+		unsafe { NonNull::new_unchecked(self as *const Self as *mut Self) }
+	}
+	
 	#[inline(always)]
 	pub(crate) fn source_address_is_same_as_destination_address(&self) -> bool
 	{
@@ -101,4 +101,17 @@ pub trait IncomingNetworkPacket: Sized + Copy
 	/// Hardware offloading categorisation indicates an unwanted packet.
 	#[inline(always)]
 	fn hardware_offload_categorisation_indicates_an_unwanted_packet(self) -> bool;
+	
+	/// A network driver may timestamp incoming packets in hardware, usually only IEEE 1588 Precision Time Protocol (PTP) event messages.
+	///
+	/// The timestamp is in nanoseconds since the epoch of the network card's clock.
+	///
+	/// A DPDK implementation would use `rte_eth_timesync_read_rx_timestamp()` if the packet has `PKT_RX_IEEE1588_TMST` set.
+	///
+	/// The default implementation returns `None`.
+	#[inline(always)]
+	fn hardware_receive_timestamp(self) -> Option<u64>
+	{
+		None
+	}
 }
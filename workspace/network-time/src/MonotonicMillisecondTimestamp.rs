@@ -76,6 +76,18 @@ impl MonotonicMillisecondTimestamp
 		Self::cycles_since_boot() / Self::cycles_per_microsecond()
 	}
 	
+	/// Nanoseconds since boot.
+	///
+	/// Derived from the same cycle counter as `now()` and `microseconds_since_boot()`, so its precision is limited by the whole number of cycles per microsecond.
+	#[inline(always)]
+	pub fn nanoseconds_since_boot() -> u64
+	{
+		let cycles_since_boot = Self::cycles_since_boot();
+		let cycles_per_microsecond = Self::cycles_per_microsecond();
+		
+		(cycles_since_boot / cycles_per_microsecond) * 1_000 + (cycles_since_boot % cycles_per_microsecond) * 1_000 / cycles_per_microsecond
+	}
+	
 	#[inline(always)]
 	fn cycles_per_millisecond() -> u64
 	{
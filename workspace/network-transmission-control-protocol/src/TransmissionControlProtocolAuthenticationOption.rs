@@ -0,0 +1,20 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// RFC 5925 Section 2.2: the authentication option (TCP-AO).
+///
+/// The message authentication code (MAC) itself is not retained; it can only be verified with the keys of a master key tuple (MKT).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct TransmissionControlProtocolAuthenticationOption
+{
+	/// Also known as 'KeyID'; identifies the master key tuple (MKT) used to generate the message authentication code (MAC).
+	pub key_id: u8,
+	
+	/// Also known as 'RNextKeyID'; the master key tuple (MKT) the sender is ready to receive.
+	pub receive_next_key_id: u8,
+	
+	/// Length of the message authentication code (MAC), in bytes.
+	pub message_authentication_code_length: u8,
+}
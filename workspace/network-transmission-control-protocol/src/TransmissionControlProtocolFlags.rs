@@ -0,0 +1,45 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+bitflags!
+{
+	/// RFC 793 and RFC 3168 flags.
+	#[derive(Deserialize, Serialize)]
+	#[repr(C, packed)]
+	pub struct TransmissionControlProtocolFlags: u8
+	{
+		/// Also known as 'FIN'; no more data from sender.
+		const Finish = 0b0000_0001;
+		
+		/// Also known as 'SYN'; synchronize sequence numbers.
+		const Synchronize = 0b0000_0010;
+		
+		/// Also known as 'RST'; reset the connection.
+		const Reset = 0b0000_0100;
+		
+		/// Also known as 'PSH'; push function.
+		const Push = 0b0000_1000;
+		
+		/// Also known as 'ACK'; the acknowledgment number is significant.
+		const Acknowledgment = 0b0001_0000;
+		
+		/// Also known as 'URG'; the urgent pointer is significant.
+		const Urgent = 0b0010_0000;
+		
+		/// Also known as 'ECE'; RFC 3168 Explicit Congestion Notification (ECN) echo.
+		const ExplicitCongestionNotificationEcho = 0b0100_0000;
+		
+		/// Also known as 'CWR'; RFC 3168 congestion window reduced.
+		const CongestionWindowReduced = 0b1000_0000;
+	}
+}
+
+impl Default for TransmissionControlProtocolFlags
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::empty()
+	}
+}
@@ -0,0 +1,293 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Captures the reason and salient data for dropping a packet earlier than might be expected.
+///
+/// This reason is reported immediately before the underlying packet is dropped, at which point all referenced data will no longer exist.
+///
+/// Salient data is by its nature unlikely to always be completely valid, and should be used only as a source of raw bytes.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub enum TransmissionControlProtocolIncomingNetworkPacketDropReason
+{
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The Internet Protocol (IP) payload is too short to contain a header.
+	PacketIsTooShort,
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The data offset is less than the size of the header.
+	DataOffsetIsTooSmall
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The data offset is greater than the Internet Protocol (IP) payload length.
+	DataOffsetExceedsPacket
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Internet Protocol (IP) payload length.
+		layer_4_length: u16,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// No flags are set; this is a 'null' scan.
+	NoFlagsAreSet
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The synchronize (SYN) and finish (FIN) flags can never legitimately be set together.
+	SynchronizeAndFinishAreBothSet
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The synchronize (SYN) and reset (RST) flags can never legitimately be set together.
+	SynchronizeAndResetAreBothSet
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The finish (FIN), push (PSH) and urgent (URG) flags are all set; this is a 'Christmas tree' (Xmas) scan.
+	ChristmasTreeFlagsAreSet
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// The check sum, which includes the pseudo-header, is invalid.
+	CheckSumWhenCalculatedInSoftwareWasInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// Port zero is reserved.
+	SourcePortIsZero
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// Port zero is reserved.
+	DestinationPortIsZero
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// An option other than end of option list or no-operation is the last byte of the options space.
+	OptionLengthIsMissing
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option kind.
+		kind: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// An option's length is less than two, and so does not include its kind and length bytes.
+	OptionLengthIsLessThanTwo
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option kind.
+		kind: u8,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// An option's length extends beyond the end of the options space (as defined by the data offset).
+	OptionOverflowsOptionsSpace
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option kind.
+		kind: u8,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// An option occurs more than once.
+	OptionIsDuplicated
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option kind.
+		kind: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 793 Section 3.1: the maximum segment size (MSS) option length must be 4.
+	MaximumSegmentSizeOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 7323 Section 2.2: the window scale option length must be 3.
+	WindowScaleOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 2018 Section 2: the selective acknowledgment (SACK) permitted option length must be 2.
+	SelectiveAcknowledgmentPermittedOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 2018 Section 3: the selective acknowledgment (SACK) option length must be 2 plus 8 for each of between 1 and 4 blocks.
+	SelectiveAcknowledgmentOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 2018 Section 3: a selective acknowledgment (SACK) block's right edge must be after its left edge.
+	SelectiveAcknowledgmentBlockIsEmptyOrInverted
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 7323 Section 3.2: the timestamps option length must be 10.
+	TimestampsOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 2385 Section 3.0: the MD5 signature option length must be 18.
+	Md5SignatureOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 5925 Section 2.2: the authentication option (TCP-AO) length must be at least 4.
+	AuthenticationOptionLengthIsInvalid
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+		
+		/// Option length.
+		length: u8,
+	},
+	
+	/// Occurs during Transmission Control Protocol (TCP) packet processing.
+	///
+	/// RFC 5925 Section 2.2: the authentication option (TCP-AO) and the MD5 signature option must not both be present.
+	Md5SignatureAndAuthenticationOptionAreBothPresent
+	{
+		/// Transmission Control Protocol (TCP) packet header.
+		#[serde(serialize_with = "TransmissionControlProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<TransmissionControlProtocolPacketHeader>,
+	},
+}
+
+impl IncomingNetworkPacketProcessingDropReason for TransmissionControlProtocolIncomingNetworkPacketDropReason
+{
+}
+
+impl TransmissionControlProtocolIncomingNetworkPacketDropReason
+{
+	#[inline(always)]
+	fn serialize_non_null<S: Serializer, T: Serialize>(to_serialize: &NonNull<T>, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		unsafe { to_serialize.as_ref().serialize(serializer) }
+	}
+}
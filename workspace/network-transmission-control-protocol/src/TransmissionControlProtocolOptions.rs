@@ -0,0 +1,285 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A typed view of the options of a Transmission Control Protocol (TCP) segment.
+///
+/// Options of unknown kind are ignored (RFC 1122 Section 4.2.2.5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize)]
+pub struct TransmissionControlProtocolOptions
+{
+	/// RFC 793 Section 3.1: maximum segment size (MSS); only significant if the synchronize (SYN) flag is set.
+	pub maximum_segment_size: Option<u16>,
+	
+	/// RFC 7323 Section 2: window scale shift count; only significant if the synchronize (SYN) flag is set.
+	///
+	/// Shift counts greater than 14 are treated as 14 (RFC 7323 Section 2.3).
+	pub window_scale: Option<u8>,
+	
+	/// RFC 2018 Section 2: selective acknowledgment (SACK) permitted; only significant if the synchronize (SYN) flag is set.
+	pub selective_acknowledgment_permitted: bool,
+	
+	/// RFC 2018 Section 3: selective acknowledgment (SACK) blocks.
+	pub selective_acknowledgment_blocks: Option<ArrayVec<[TransmissionControlProtocolSelectiveAcknowledgmentBlock; 4]>>,
+	
+	/// RFC 7323 Section 3: timestamps.
+	pub timestamps: Option<TransmissionControlProtocolTimestamps>,
+	
+	/// RFC 2385: an MD5 signature is present.
+	///
+	/// The signature itself can only be verified with the connection's key.
+	pub md5_signature_is_present: bool,
+	
+	/// RFC 5925: authentication option (TCP-AO).
+	pub authentication_option: Option<TransmissionControlProtocolAuthenticationOption>,
+}
+
+impl Default for TransmissionControlProtocolOptions
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			maximum_segment_size: None,
+			window_scale: None,
+			selective_acknowledgment_permitted: false,
+			selective_acknowledgment_blocks: None,
+			timestamps: None,
+			md5_signature_is_present: false,
+			authentication_option: None,
+		}
+	}
+}
+
+impl TransmissionControlProtocolOptions
+{
+	/// The maximum number of selective acknowledgment (SACK) blocks that fit in the options space.
+	pub const MaximumSelectiveAcknowledgmentBlocks: usize = 4;
+	
+	/// RFC 7323 Section 2.3: the maximum window scale shift count.
+	pub const MaximumWindowScale: u8 = 14;
+	
+	const EndOfOptionListKind: u8 = 0;
+	
+	const NoOperationKind: u8 = 1;
+	
+	const MaximumSegmentSizeKind: u8 = 2;
+	
+	const WindowScaleKind: u8 = 3;
+	
+	const SelectiveAcknowledgmentPermittedKind: u8 = 4;
+	
+	const SelectiveAcknowledgmentKind: u8 = 5;
+	
+	const TimestampsKind: u8 = 8;
+	
+	const Md5SignatureKind: u8 = 19;
+	
+	const AuthenticationOptionKind: u8 = 29;
+	
+	const SelectiveAcknowledgmentBlockSize: u8 = 8;
+	
+	#[inline]
+	pub(crate) fn parse(header: &TransmissionControlProtocolPacketHeader, options: &[u8]) -> Result<Self, TransmissionControlProtocolIncomingNetworkPacketDropReason>
+	{
+		let mut parsed = Self::default();
+		
+		let options_length = options.len();
+		let mut index = 0;
+		while index < options_length
+		{
+			let kind = unsafe { *options.get_unchecked(index) };
+			
+			match kind
+			{
+				TransmissionControlProtocolOptions::EndOfOptionListKind => break,
+				
+				TransmissionControlProtocolOptions::NoOperationKind =>
+				{
+					index += 1;
+					continue
+				}
+				
+				_ => (),
+			}
+			
+			if unlikely!(index + 1 == options_length)
+			{
+				return Err(OptionLengthIsMissing { header: header.non_null(), kind })
+			}
+			
+			let length = unsafe { *options.get_unchecked(index + 1) };
+			
+			if unlikely!(length < 2)
+			{
+				return Err(OptionLengthIsLessThanTwo { header: header.non_null(), kind, length })
+			}
+			
+			let end = index + (length as usize);
+			if unlikely!(end > options_length)
+			{
+				return Err(OptionOverflowsOptionsSpace { header: header.non_null(), kind, length })
+			}
+			
+			let data = unsafe { options.get_unchecked(index + 2 .. end) };
+			
+			parsed.parse_option(header, kind, length, data)?;
+			
+			index = end;
+		}
+		
+		if unlikely!(parsed.md5_signature_is_present && parsed.authentication_option.is_some())
+		{
+			return Err(Md5SignatureAndAuthenticationOptionAreBothPresent { header: header.non_null() })
+		}
+		
+		Ok(parsed)
+	}
+	
+	#[inline(always)]
+	fn parse_option(&mut self, header: &TransmissionControlProtocolPacketHeader, kind: u8, length: u8, data: &[u8]) -> Result<(), TransmissionControlProtocolIncomingNetworkPacketDropReason>
+	{
+		macro_rules! guard_duplicate
+		{
+			($is_present: expr) =>
+			{
+				if unlikely!($is_present)
+				{
+					return Err(OptionIsDuplicated { header: header.non_null(), kind })
+				}
+			}
+		}
+		
+		macro_rules! guard_length
+		{
+			($is_valid: expr, $drop_reason: ident) =>
+			{
+				if unlikely!(!($is_valid))
+				{
+					return Err($drop_reason { header: header.non_null(), length })
+				}
+			}
+		}
+		
+		match kind
+		{
+			TransmissionControlProtocolOptions::MaximumSegmentSizeKind =>
+			{
+				guard_duplicate!(self.maximum_segment_size.is_some());
+				guard_length!(length == 4, MaximumSegmentSizeOptionLengthIsInvalid);
+				
+				self.maximum_segment_size = Some(Self::read_u16(data, 0));
+			}
+			
+			TransmissionControlProtocolOptions::WindowScaleKind =>
+			{
+				guard_duplicate!(self.window_scale.is_some());
+				guard_length!(length == 3, WindowScaleOptionLengthIsInvalid);
+				
+				let shift_count = unsafe { *data.get_unchecked(0) };
+				self.window_scale = Some(if unlikely!(shift_count > Self::MaximumWindowScale)
+				{
+					Self::MaximumWindowScale
+				}
+				else
+				{
+					shift_count
+				});
+			}
+			
+			TransmissionControlProtocolOptions::SelectiveAcknowledgmentPermittedKind =>
+			{
+				guard_duplicate!(self.selective_acknowledgment_permitted);
+				guard_length!(length == 2, SelectiveAcknowledgmentPermittedOptionLengthIsInvalid);
+				
+				self.selective_acknowledgment_permitted = true;
+			}
+			
+			TransmissionControlProtocolOptions::SelectiveAcknowledgmentKind =>
+			{
+				guard_duplicate!(self.selective_acknowledgment_blocks.is_some());
+				
+				let blocks_length = length - 2;
+				let number_of_blocks = blocks_length / Self::SelectiveAcknowledgmentBlockSize;
+				guard_length!(blocks_length % Self::SelectiveAcknowledgmentBlockSize == 0 && number_of_blocks >= 1 && number_of_blocks as usize <= Self::MaximumSelectiveAcknowledgmentBlocks, SelectiveAcknowledgmentOptionLengthIsInvalid);
+				
+				let mut selective_acknowledgment_blocks = ArrayVec::new();
+				let mut offset = 0;
+				while offset < blocks_length as usize
+				{
+					let left_edge = Self::read_u32(data, offset);
+					let right_edge = Self::read_u32(data, offset + 4);
+					
+					// Sequence number comparison modulo 2^32 (RFC 793 Section 3.3).
+					if unlikely!((right_edge.wrapping_sub(left_edge) as i32) <= 0)
+					{
+						return Err(SelectiveAcknowledgmentBlockIsEmptyOrInverted { header: header.non_null() })
+					}
+					
+					selective_acknowledgment_blocks.push(TransmissionControlProtocolSelectiveAcknowledgmentBlock { left_edge, right_edge });
+					offset += Self::SelectiveAcknowledgmentBlockSize as usize;
+				}
+				self.selective_acknowledgment_blocks = Some(selective_acknowledgment_blocks);
+			}
+			
+			TransmissionControlProtocolOptions::TimestampsKind =>
+			{
+				guard_duplicate!(self.timestamps.is_some());
+				guard_length!(length == 10, TimestampsOptionLengthIsInvalid);
+				
+				self.timestamps = Some
+				(
+					TransmissionControlProtocolTimestamps
+					{
+						value: Self::read_u32(data, 0),
+						echo_reply: Self::read_u32(data, 4),
+					}
+				);
+			}
+			
+			TransmissionControlProtocolOptions::Md5SignatureKind =>
+			{
+				guard_duplicate!(self.md5_signature_is_present);
+				guard_length!(length == 18, Md5SignatureOptionLengthIsInvalid);
+				
+				self.md5_signature_is_present = true;
+			}
+			
+			TransmissionControlProtocolOptions::AuthenticationOptionKind =>
+			{
+				guard_duplicate!(self.authentication_option.is_some());
+				guard_length!(length >= 4, AuthenticationOptionLengthIsInvalid);
+				
+				self.authentication_option = Some
+				(
+					TransmissionControlProtocolAuthenticationOption
+					{
+						key_id: unsafe { *data.get_unchecked(0) },
+						receive_next_key_id: unsafe { *data.get_unchecked(1) },
+						message_authentication_code_length: length - 4,
+					}
+				);
+			}
+			
+			_ => (),
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn read_u16(data: &[u8], offset: usize) -> u16
+	{
+		let bytes = unsafe { data.get_unchecked(offset .. offset + 2) };
+		((bytes[0] as u16) << 8) | (bytes[1] as u16)
+	}
+	
+	#[inline(always)]
+	fn read_u32(data: &[u8], offset: usize) -> u32
+	{
+		let bytes = unsafe { data.get_unchecked(offset .. offset + 4) };
+		((bytes[0] as u32) << 24) | ((bytes[1] as u32) << 16) | ((bytes[2] as u32) << 8) | (bytes[3] as u32)
+	}
+}
@@ -0,0 +1,125 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// This is a specialized structure designed to represent a buffer of packet data.
+#[repr(C, packed)]
+#[derive(Debug)]
+pub struct TransmissionControlProtocolPacket
+{
+	/// Header.
+	pub header: TransmissionControlProtocolPacketHeader,
+	
+	/// Options, if any, followed by the payload.
+	pub options_and_payload: PhantomData<u8>,
+}
+
+impl Display for TransmissionControlProtocolPacket
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl TransmissionControlProtocolPacket
+{
+	/// Use this to eliminate invalid traffic.
+	#[inline(always)]
+	pub fn is_packet_length_too_short(layer_4_length: u16) -> bool
+	{
+		layer_4_length < TransmissionControlProtocolPacketHeader::HeaderSizeU16
+	}
+	
+	#[inline(always)]
+	pub(crate) fn process<'lifetime>(&'lifetime self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &TransmissionControlProtocolPacketProcessing, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses, layer_4_check_sum_validated_in_hardware: bool) -> Result<(), TransmissionControlProtocolIncomingNetworkPacketDropReason>
+	{
+		let header = &self.header;
+		
+		let data_offset = header.data_offset();
+		
+		if unlikely!(data_offset < TransmissionControlProtocolPacketHeader::HeaderSizeU16)
+		{
+			return Err(DataOffsetIsTooSmall { header: header.non_null() })
+		}
+		
+		if unlikely!(data_offset > layer_4_length)
+		{
+			return Err(DataOffsetExceedsPacket { header: header.non_null(), layer_4_length })
+		}
+		
+		Self::validate_flags(header)?;
+		
+		if unlikely!(!layer_4_check_sum_validated_in_hardware)
+		{
+			let check_sum = internet_protocol_packet_host_addresses.layer_4_check_sum(layer_4_packet, layer_4_length, Layer4ProtocolNumber::TransmissionControlProtocol);
+			if unlikely!(!check_sum.validates())
+			{
+				return Err(CheckSumWhenCalculatedInSoftwareWasInvalid { header: header.non_null() })
+			}
+		}
+		
+		if unlikely!(header.source_port() == 0)
+		{
+			return Err(SourcePortIsZero { header: header.non_null() })
+		}
+		
+		if unlikely!(header.destination_port() == 0)
+		{
+			return Err(DestinationPortIsZero { header: header.non_null() })
+		}
+		
+		let options = TransmissionControlProtocolOptions::parse(header, self.options(data_offset))?;
+		
+		packet_processing.segment(now, packet, self, &options, data_offset, layer_4_length, internet_protocol_packet_host_addresses);
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn validate_flags(header: &TransmissionControlProtocolPacketHeader) -> Result<(), TransmissionControlProtocolIncomingNetworkPacketDropReason>
+	{
+		let flags = header.flags();
+		
+		if unlikely!(flags.is_empty())
+		{
+			return Err(NoFlagsAreSet { header: header.non_null() })
+		}
+		
+		if unlikely!(flags.contains(TransmissionControlProtocolFlags::Synchronize | TransmissionControlProtocolFlags::Finish))
+		{
+			return Err(SynchronizeAndFinishAreBothSet { header: header.non_null() })
+		}
+		
+		if unlikely!(flags.contains(TransmissionControlProtocolFlags::Synchronize | TransmissionControlProtocolFlags::Reset))
+		{
+			return Err(SynchronizeAndResetAreBothSet { header: header.non_null() })
+		}
+		
+		if unlikely!(flags.contains(TransmissionControlProtocolFlags::Finish | TransmissionControlProtocolFlags::Push | TransmissionControlProtocolFlags::Urgent))
+		{
+			return Err(ChristmasTreeFlagsAreSet { header: header.non_null() })
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn options(&self, data_offset: u16) -> &[u8]
+	{
+		unsafe { from_raw_parts(self.options_and_payload_pointer(), (data_offset - TransmissionControlProtocolPacketHeader::HeaderSizeU16) as usize) }
+	}
+	
+	#[inline(always)]
+	pub(crate) fn payload(&self, data_offset: u16, layer_4_length: u16) -> &[u8]
+	{
+		let options_length = (data_offset - TransmissionControlProtocolPacketHeader::HeaderSizeU16) as usize;
+		unsafe { from_raw_parts(self.options_and_payload_pointer().add(options_length), (layer_4_length - data_offset) as usize) }
+	}
+	
+	#[inline(always)]
+	fn options_and_payload_pointer(&self) -> *const u8
+	{
+		&self.options_and_payload as *const PhantomData<u8> as *const u8
+	}
+}
@@ -0,0 +1,229 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Transmission Control Protocol (TCP) header (RFC 793), excluding options.
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct TransmissionControlProtocolPacketHeader
+{
+	/// Source port.
+	pub source_port: NetworkEndianU16,
+	
+	/// Destination port.
+	pub destination_port: NetworkEndianU16,
+	
+	/// Sequence number.
+	pub sequence_number: NetworkEndianU32,
+	
+	/// Acknowledgment number.
+	pub acknowledgment_number: NetworkEndianU32,
+	
+	/// The top 4 bits are the data offset, in 32-bit words; the bottom 4 bits are reserved.
+	///
+	/// The bottom-most reserved bit was formerly the experimental nonce sum (NS) flag of RFC 3540, which is now historic (RFC 8311); it is now the Accurate Explicit Congestion Notification (AccECN) 'AE' flag.
+	///
+	/// Reserved bits are not validated, as receivers must ignore them (RFC 9293 Section 3.1).
+	pub data_offset_and_reserved: u8,
+	
+	/// Flags.
+	pub flags: TransmissionControlProtocolFlags,
+	
+	/// Window size.
+	pub window_size: NetworkEndianU16,
+	
+	/// The check sum includes a pseudo-header, the options and the payload.
+	pub check_sum: InternetCheckSum,
+	
+	/// Urgent pointer.
+	pub urgent_pointer: NetworkEndianU16,
+}
+
+impl Display for TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl Into<tcp_hdr> for TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> tcp_hdr
+	{
+		unsafe { transmute(self) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<&'a tcp_hdr> for &'a TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> &'a tcp_hdr
+	{
+		unsafe { transmute(self) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<NonNull<tcp_hdr>> for &'a mut TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> NonNull<tcp_hdr>
+	{
+		unsafe { NonNull::new_unchecked(self as *mut TransmissionControlProtocolPacketHeader as *mut tcp_hdr) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<*const tcp_hdr> for &'a TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> *const tcp_hdr
+	{
+		self as *const TransmissionControlProtocolPacketHeader as *const _
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<*mut tcp_hdr> for &'a mut TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> *mut tcp_hdr
+	{
+		self as *mut TransmissionControlProtocolPacketHeader as *mut _
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<&'a mut tcp_hdr> for &'a mut TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> &'a mut tcp_hdr
+	{
+		unsafe { transmute(self) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl From<tcp_hdr> for TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn from(value: tcp_hdr) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> From<&'a tcp_hdr> for &'a TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn from(value: &'a tcp_hdr) -> &'a TransmissionControlProtocolPacketHeader
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> From<&'a mut tcp_hdr> for &'a mut TransmissionControlProtocolPacketHeader
+{
+	#[inline(always)]
+	fn from(value: &'a mut tcp_hdr) -> &'a mut TransmissionControlProtocolPacketHeader
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+impl TransmissionControlProtocolPacketHeader
+{
+	/// Header size, excluding options.
+	pub const HeaderSize: usize = size_of::<Self>();
+	
+	/// Header size, excluding options.
+	pub const HeaderSizeU16: u16 = Self::HeaderSize as u16;
+	
+	/// Source port.
+	#[inline(always)]
+	pub fn source_port(&self) -> u16
+	{
+		self.source_port.to_native_endian()
+	}
+	
+	/// Destination port.
+	#[inline(always)]
+	pub fn destination_port(&self) -> u16
+	{
+		self.destination_port.to_native_endian()
+	}
+	
+	/// Sequence number.
+	#[inline(always)]
+	pub fn sequence_number(&self) -> u32
+	{
+		self.sequence_number.to_native_endian()
+	}
+	
+	/// Acknowledgment number.
+	#[inline(always)]
+	pub fn acknowledgment_number(&self) -> u32
+	{
+		self.acknowledgment_number.to_native_endian()
+	}
+	
+	/// Data offset, in bytes; the size of the header including options.
+	#[inline(always)]
+	pub fn data_offset(&self) -> u16
+	{
+		((self.data_offset_and_reserved >> 4) as u16) * 4
+	}
+	
+	/// Reserved bits, including the AccECN 'AE' flag.
+	///
+	/// Senders should set these to zero, but receivers must ignore them (RFC 9293 Section 3.1); segments are not dropped if any are set.
+	#[inline(always)]
+	pub fn reserved_bits(&self) -> u8
+	{
+		self.data_offset_and_reserved & 0b0000_1111
+	}
+	
+	/// Is the Accurate Explicit Congestion Notification (AccECN) 'AE' flag set?
+	///
+	/// This is the bottom-most reserved bit, formerly the nonce sum (NS) flag.
+	#[inline(always)]
+	pub fn accurate_explicit_congestion_notification_flag(&self) -> bool
+	{
+		self.data_offset_and_reserved & 0b0000_0001 != 0
+	}
+	
+	/// Flags.
+	#[inline(always)]
+	pub fn flags(&self) -> TransmissionControlProtocolFlags
+	{
+		self.flags
+	}
+	
+	/// Window size, before any scaling.
+	#[inline(always)]
+	pub fn window_size(&self) -> u16
+	{
+		self.window_size.to_native_endian()
+	}
+	
+	/// Urgent pointer.
+	#[inline(always)]
+	pub fn urgent_pointer(&self) -> u16
+	{
+		self.urgent_pointer.to_native_endian()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn non_null(&self) -> NonNull<Self>
+	{
+		unsafe { NonNull::new_unchecked(self as *const Self as *mut Self) }
+	}
+}
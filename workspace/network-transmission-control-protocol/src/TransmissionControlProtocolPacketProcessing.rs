@@ -0,0 +1,53 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Implementation of Transmission Control Protocol (TCP) packet processing.
+///
+/// Validates the header, check sum and options of each segment before passing it to a segment callback.
+#[derive(Debug)]
+pub struct TransmissionControlProtocolPacketProcessing
+{
+	segment_callback: Rc<dyn TransmissionControlProtocolSegmentCallback>,
+}
+
+impl Layer4PacketProcessing for TransmissionControlProtocolPacketProcessing
+{
+	type DropReason = TransmissionControlProtocolIncomingNetworkPacketDropReason;
+	
+	#[inline(always)]
+	fn process<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, _internet_protocol_headers: &'lifetime [u8], internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses, _ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool) -> Result<(), Self::DropReason>
+	{
+		if unlikely!(TransmissionControlProtocolPacket::is_packet_length_too_short(layer_4_length))
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let transmission_control_protocol_packet: &'lifetime TransmissionControlProtocolPacket = layer_4_packet.as_type();
+		
+		transmission_control_protocol_packet.process(now, packet, self, layer_4_packet, layer_4_length, internet_protocol_packet_host_addresses, layer_4_check_sum_validated_in_hardware)
+	}
+}
+
+impl TransmissionControlProtocolPacketProcessing
+{
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(segment_callback: Rc<dyn TransmissionControlProtocolSegmentCallback>) -> Self
+	{
+		Self
+		{
+			segment_callback,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn segment<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, transmission_control_protocol_packet: &'lifetime TransmissionControlProtocolPacket, options: &TransmissionControlProtocolOptions, data_offset: u16, layer_4_length: u16, internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses)
+	{
+		let payload = transmission_control_protocol_packet.payload(data_offset, layer_4_length);
+		
+		self.segment_callback.received(now, &internet_protocol_packet_host_addresses, &transmission_control_protocol_packet.header, options, payload);
+		
+		packet.free_direct_contiguous_packet();
+	}
+}
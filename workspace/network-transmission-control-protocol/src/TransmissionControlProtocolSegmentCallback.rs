@@ -0,0 +1,13 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Called for each segment that has passed header validation and option parsing.
+///
+/// This is the point at which a connection state machine would receive segments.
+pub trait TransmissionControlProtocolSegmentCallback: Debug
+{
+	/// `header` and `payload` refer to the received packet's memory, and are only valid for the duration of this call.
+	#[inline(always)]
+	fn received(&self, now: MonotonicMillisecondTimestamp, internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses, header: &TransmissionControlProtocolPacketHeader, options: &TransmissionControlProtocolOptions, payload: &[u8]);
+}
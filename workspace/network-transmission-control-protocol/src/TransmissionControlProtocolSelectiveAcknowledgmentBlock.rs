@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// RFC 2018 Section 3: a selective acknowledgment (SACK) block, a contiguous block of data received and queued.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct TransmissionControlProtocolSelectiveAcknowledgmentBlock
+{
+	/// Sequence number of the first byte of the block.
+	pub left_edge: u32,
+	
+	/// Sequence number immediately following the last byte of the block.
+	pub right_edge: u32,
+}
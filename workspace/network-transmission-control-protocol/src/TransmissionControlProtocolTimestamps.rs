@@ -0,0 +1,15 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// RFC 7323 Section 3: timestamps.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct TransmissionControlProtocolTimestamps
+{
+	/// Also known as 'TSval'; the current value of the sender's timestamp clock.
+	pub value: u32,
+	
+	/// Also known as 'TSecr'; only significant if the acknowledgment (ACK) flag is set.
+	pub echo_reply: u32,
+}
@@ -0,0 +1,70 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#![allow(non_upper_case_globals)]
+#![deny(missing_docs)]
+#![feature(const_fn)]
+
+
+//! # network-transmission-control-protocol
+//!
+//! A domain model of Transmission Control Protocol (TCP) packets and associated types.
+//!
+//! Incoming segments have their header, flags, check sum and options validated; options are parsed into a typed view.
+//!
+//! This crate has the optional feature `dpdk-sys`, which adds support for converting Into and From DPDK representations.
+
+
+#[macro_use] extern crate bitflags;
+#[cfg(feature = "dpdk-sys")] extern crate dpdk_sys;
+#[macro_use] extern crate likely;
+extern crate network_check_sum;
+extern crate network_collections;
+extern crate network_endian;
+extern crate network_ethernet;
+extern crate network_internet_protocol;
+extern crate network_packet;
+extern crate network_time;
+extern crate serde;
+#[macro_use] extern crate serde_derive;
+
+
+use self::TransmissionControlProtocolIncomingNetworkPacketDropReason::*;
+#[cfg(feature = "dpdk-sys")] use dpdk_sys::*;
+use ::network_check_sum::InternetCheckSum;
+use ::network_collections::ArrayVec;
+use ::network_endian::*;
+use ::network_ethernet::EthernetAddresses;
+use ::network_ethernet::packet_processing::EthernetIncomingNetworkPacket;
+use ::network_internet_protocol::InternetProtocolPacketHostAddresses;
+use ::network_internet_protocol::Layer4Packet;
+use ::network_internet_protocol::Layer4PacketProcessing;
+use ::network_internet_protocol::Layer4ProtocolNumber;
+use ::network_packet::IncomingNetworkPacket;
+use ::network_packet::IncomingNetworkPacketProcessingDropReason;
+use ::network_time::MonotonicMillisecondTimestamp;
+use ::serde::Serialize;
+use ::serde::Serializer;
+use ::std::fmt;
+use ::std::fmt::Debug;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::marker::PhantomData;
+use ::std::mem::size_of;
+#[cfg(feature = "dpdk-sys")] use ::std::mem::transmute;
+use ::std::ptr::NonNull;
+use ::std::rc::Rc;
+use ::std::slice::from_raw_parts;
+
+
+include!("TransmissionControlProtocolAuthenticationOption.rs");
+include!("TransmissionControlProtocolFlags.rs");
+include!("TransmissionControlProtocolIncomingNetworkPacketDropReason.rs");
+include!("TransmissionControlProtocolOptions.rs");
+include!("TransmissionControlProtocolPacket.rs");
+include!("TransmissionControlProtocolPacketHeader.rs");
+include!("TransmissionControlProtocolPacketProcessing.rs");
+include!("TransmissionControlProtocolSegmentCallback.rs");
+include!("TransmissionControlProtocolSelectiveAcknowledgmentBlock.rs");
+include!("TransmissionControlProtocolTimestamps.rs");
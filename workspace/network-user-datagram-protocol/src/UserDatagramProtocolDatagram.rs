@@ -0,0 +1,23 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Salient data of a received User Datagram Protocol (UDP) datagram, other than its payload.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UserDatagramProtocolDatagram
+{
+	/// Source and destination Internet Protocol (IP) addresses.
+	pub internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses,
+	
+	/// Whether the datagram was received as unicast, multicast or broadcast.
+	pub delivery: Layer4ProtocolNeedsToSupport,
+	
+	/// Source port; zero if not used.
+	pub source_port: u16,
+	
+	/// Destination port.
+	pub destination_port: u16,
+	
+	/// Length of payload.
+	pub payload_length: u16,
+}
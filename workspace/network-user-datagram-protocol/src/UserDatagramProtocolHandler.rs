@@ -0,0 +1,21 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Handles datagrams received on a bound port.
+#[derive(Debug, Clone)]
+pub enum UserDatagramProtocolHandler
+{
+	/// Calls back for each datagram, without copying its payload.
+	Callback(Rc<dyn UserDatagramProtocolDatagramCallback>),
+	
+	/// Passes each datagram, with the hardware receive timestamp of its packet, to Precision Time Protocol (PTP) packet processing.
+	///
+	/// Use `UserDatagramProtocolPortBindings::bind_precision_time_protocol()` to bind the event and general message ports, 319 and 320.
+	PrecisionTimeProtocol(Rc<PrecisionTimeProtocolPacketProcessing>),
+	
+	/// Queues each datagram to be received later.
+	///
+	/// Each payload is copied exactly once, into the queue's magic ring buffer, so that its packet can be freed immediately; it is not zero-copy. Use `Callback` to avoid the copy.
+	#[cfg(any(target_os = "android", target_os = "linux"))] ReceiveQueue(Rc<UserDatagramProtocolReceiveQueue>),
+}
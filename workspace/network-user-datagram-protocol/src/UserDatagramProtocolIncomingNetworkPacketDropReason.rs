@@ -0,0 +1,115 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Captures the reason and salient data for dropping a packet earlier than might be expected.
+///
+/// This reason is reported immediately before the underlying packet is dropped, at which point all referenced data will no longer exist.
+///
+/// Salient data is by its nature unlikely to always be completely valid, and should be used only as a source of raw bytes.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub enum UserDatagramProtocolIncomingNetworkPacketDropReason
+{
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// The Internet Protocol (IP) payload is too short to contain a header.
+	PacketIsTooShort,
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// The length is less than the size of the header.
+	LengthIsLessThanHeader
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// The length is not the same as the Internet Protocol (IP) payload length.
+	LengthIsNotInternetProtocolPayloadLength
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+		
+		/// Internet Protocol (IP) payload length.
+		layer_4_length: u16,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// RFC 8200 Section 8.1: a zero check sum is not permitted over Internet Protocol (IP) version 6.
+	CheckSumIsNotUsedForInternetProtocolVersion6
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	CheckSumWhenCalculatedInSoftwareWasInvalid
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// Port zero is reserved.
+	DestinationPortIsZero
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// The destination port is not bound, or is bound but not for this kind (unicast, multicast or broadcast) of datagram.
+	DestinationPortIsNotBound
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// The destination port is bound to Precision Time Protocol (PTP) packet processing, which dropped the message.
+	PrecisionTimeProtocolMessageWasDropped
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+		
+		/// Why the message was dropped.
+		reason: PrecisionTimeProtocolDropReason,
+	},
+	
+	/// Occurs during User Datagram Protocol (UDP) packet processing.
+	///
+	/// The receive queue the destination port is bound to is full.
+	ReceiveQueueIsFull
+	{
+		/// User Datagram Protocol (UDP) packet header.
+		#[serde(serialize_with = "UserDatagramProtocolIncomingNetworkPacketDropReason::serialize_non_null")]
+		header: NonNull<UserDatagramProtocolPacketHeader>,
+	},
+}
+
+impl IncomingNetworkPacketProcessingDropReason for UserDatagramProtocolIncomingNetworkPacketDropReason
+{
+}
+
+impl UserDatagramProtocolIncomingNetworkPacketDropReason
+{
+	#[inline(always)]
+	fn serialize_non_null<S: Serializer, T: Serialize>(to_serialize: &NonNull<T>, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		unsafe { to_serialize.as_ref().serialize(serializer) }
+	}
+}
@@ -0,0 +1,32 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// The local address a port is bound to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub enum UserDatagramProtocolLocalAddress
+{
+	/// Any local address, Internet Protocol (IP) version 4 or version 6; the wildcard address.
+	Any,
+	
+	/// An Internet Protocol (IP) version 4 unicast, multicast or broadcast address.
+	InternetProtocolVersion4(InternetProtocolVersion4HostAddress),
+	
+	/// An Internet Protocol (IP) version 6 unicast or multicast address.
+	InternetProtocolVersion6(InternetProtocolVersion6HostAddress),
+}
+
+impl UserDatagramProtocolLocalAddress
+{
+	#[inline(always)]
+	pub(crate) fn destination(internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses) -> Self
+	{
+		match *internet_protocol_packet_host_addresses
+		{
+			InternetProtocolPacketHostAddresses::Version4 { destination, .. } => UserDatagramProtocolLocalAddress::InternetProtocolVersion4(destination),
+			
+			InternetProtocolPacketHostAddresses::Version6 { destination, .. } => UserDatagramProtocolLocalAddress::InternetProtocolVersion6(destination),
+		}
+	}
+}
@@ -0,0 +1,81 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// This is a specialized structure designed to represent a buffer of packet data.
+#[repr(C, packed)]
+#[derive(Debug)]
+pub struct UserDatagramProtocolPacket
+{
+	/// Header.
+	pub header: UserDatagramProtocolPacketHeader,
+	
+	/// Payload.
+	pub payload: PhantomData<u8>,
+}
+
+impl Display for UserDatagramProtocolPacket
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl UserDatagramProtocolPacket
+{
+	/// Use this to eliminate invalid traffic.
+	#[inline(always)]
+	pub fn is_packet_length_too_short(layer_4_length: u16) -> bool
+	{
+		layer_4_length < UserDatagramProtocolPacketHeader::HeaderSizeU16
+	}
+	
+	#[inline(always)]
+	pub(crate) fn process<'lifetime>(&'lifetime self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, packet_processing: &UserDatagramProtocolPacketProcessing, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, internet_protocol_headers: &'lifetime [u8], internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool) -> Result<(), UserDatagramProtocolIncomingNetworkPacketDropReason>
+	{
+		let header = &self.header;
+		
+		let length = header.length();
+		
+		if unlikely!(length < UserDatagramProtocolPacketHeader::HeaderSizeU16)
+		{
+			return Err(LengthIsLessThanHeader { header: header.non_null() })
+		}
+		
+		if unlikely!(length != layer_4_length)
+		{
+			return Err(LengthIsNotInternetProtocolPayloadLength { header: header.non_null(), layer_4_length })
+		}
+		
+		if unlikely!(header.check_sum_is_not_used())
+		{
+			if unlikely!(internet_protocol_packet_host_addresses.is_version_6())
+			{
+				return Err(CheckSumIsNotUsedForInternetProtocolVersion6 { header: header.non_null() })
+			}
+		}
+		else if unlikely!(!layer_4_check_sum_validated_in_hardware)
+		{
+			let check_sum = internet_protocol_packet_host_addresses.layer_4_check_sum(layer_4_packet, layer_4_length, Layer4ProtocolNumber::UserDatagramProtocol);
+			if unlikely!(!check_sum.validates())
+			{
+				return Err(CheckSumWhenCalculatedInSoftwareWasInvalid { header: header.non_null() })
+			}
+		}
+		
+		if unlikely!(header.destination_port() == 0)
+		{
+			return Err(DestinationPortIsZero { header: header.non_null() })
+		}
+		
+		packet_processing.deliver(now, packet, self, layer_4_length, internet_protocol_headers, internet_protocol_packet_host_addresses, ethernet_addresses)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn payload(&self, payload_length: u16) -> &[u8]
+	{
+		unsafe { from_raw_parts(&self.payload as *const PhantomData<u8> as *const u8, payload_length as usize) }
+	}
+}
@@ -0,0 +1,169 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// User Datagram Protocol (UDP) header (RFC 768).
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct UserDatagramProtocolPacketHeader
+{
+	/// Source port.
+	///
+	/// Optional; zero if not used.
+	pub source_port: NetworkEndianU16,
+	
+	/// Destination port.
+	pub destination_port: NetworkEndianU16,
+	
+	/// Length of the header and payload.
+	pub length: NetworkEndianU16,
+	
+	/// The check sum includes a pseudo-header and the payload.
+	///
+	/// Optional for Internet Protocol (IP) version 4, when it is zero if not used; mandatory for Internet Protocol (IP) version 6.
+	pub check_sum: InternetCheckSum,
+}
+
+impl Display for UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl Into<udp_hdr> for UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> udp_hdr
+	{
+		unsafe { transmute(self) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<&'a udp_hdr> for &'a UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> &'a udp_hdr
+	{
+		unsafe { transmute(self) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<NonNull<udp_hdr>> for &'a mut UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> NonNull<udp_hdr>
+	{
+		unsafe { NonNull::new_unchecked(self as *mut UserDatagramProtocolPacketHeader as *mut udp_hdr) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<*const udp_hdr> for &'a UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> *const udp_hdr
+	{
+		self as *const UserDatagramProtocolPacketHeader as *const _
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<*mut udp_hdr> for &'a mut UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> *mut udp_hdr
+	{
+		self as *mut UserDatagramProtocolPacketHeader as *mut _
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> Into<&'a mut udp_hdr> for &'a mut UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn into(self) -> &'a mut udp_hdr
+	{
+		unsafe { transmute(self) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl From<udp_hdr> for UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn from(value: udp_hdr) -> Self
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> From<&'a udp_hdr> for &'a UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn from(value: &'a udp_hdr) -> &'a UserDatagramProtocolPacketHeader
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+#[cfg(feature = "dpdk-sys")]
+impl<'a> From<&'a mut udp_hdr> for &'a mut UserDatagramProtocolPacketHeader
+{
+	#[inline(always)]
+	fn from(value: &'a mut udp_hdr) -> &'a mut UserDatagramProtocolPacketHeader
+	{
+		unsafe { transmute(value) }
+	}
+}
+
+impl UserDatagramProtocolPacketHeader
+{
+	/// Header size.
+	pub const HeaderSize: usize = size_of::<Self>();
+	
+	/// Header size.
+	pub const HeaderSizeU16: u16 = Self::HeaderSize as u16;
+	
+	/// Source port.
+	#[inline(always)]
+	pub fn source_port(&self) -> u16
+	{
+		self.source_port.to_native_endian()
+	}
+	
+	/// Destination port.
+	#[inline(always)]
+	pub fn destination_port(&self) -> u16
+	{
+		self.destination_port.to_native_endian()
+	}
+	
+	/// Length of the header and payload.
+	#[inline(always)]
+	pub fn length(&self) -> u16
+	{
+		self.length.to_native_endian()
+	}
+	
+	/// Was a check sum not computed by the sender?
+	#[inline(always)]
+	pub fn check_sum_is_not_used(&self) -> bool
+	{
+		let check_sum: u16 = self.check_sum.into();
+		check_sum == 0
+	}
+	
+	#[inline(always)]
+	pub(crate) fn non_null(&self) -> NonNull<Self>
+	{
+		unsafe { NonNull::new_unchecked(self as *const Self as *mut Self) }
+	}
+}
@@ -0,0 +1,108 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Implementation of User Datagram Protocol (UDP) packet processing.
+#[derive(Debug)]
+pub struct UserDatagramProtocolPacketProcessing
+{
+	port_bindings: UserDatagramProtocolPortBindings,
+	
+	port_unreachable: Option<UserDatagramProtocolPortUnreachable>,
+}
+
+impl Layer4PacketProcessing for UserDatagramProtocolPacketProcessing
+{
+	type DropReason = UserDatagramProtocolIncomingNetworkPacketDropReason;
+	
+	#[inline(always)]
+	fn process<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, layer_4_packet: &'lifetime Layer4Packet, layer_4_length: u16, internet_protocol_headers: &'lifetime [u8], internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses, ethernet_addresses: &'lifetime EthernetAddresses, layer_4_check_sum_validated_in_hardware: bool) -> Result<(), Self::DropReason>
+	{
+		if unlikely!(UserDatagramProtocolPacket::is_packet_length_too_short(layer_4_length))
+		{
+			return Err(PacketIsTooShort)
+		}
+		
+		let user_datagram_protocol_packet: &'lifetime UserDatagramProtocolPacket = layer_4_packet.as_type();
+		
+		user_datagram_protocol_packet.process(now, packet, self, layer_4_packet, layer_4_length, internet_protocol_headers, internet_protocol_packet_host_addresses, ethernet_addresses, layer_4_check_sum_validated_in_hardware)
+	}
+}
+
+impl UserDatagramProtocolPacketProcessing
+{
+	/// Creates a new instance.
+	///
+	/// If `port_unreachable` is `None` then no response is sent for datagrams received for unbound ports.
+	#[inline(always)]
+	pub fn new(port_unreachable: Option<UserDatagramProtocolPortUnreachable>) -> Self
+	{
+		Self
+		{
+			port_bindings: UserDatagramProtocolPortBindings::default(),
+			port_unreachable,
+		}
+	}
+	
+	/// Port bindings, eg to bind a port.
+	#[inline(always)]
+	pub fn port_bindings(&self) -> &UserDatagramProtocolPortBindings
+	{
+		&self.port_bindings
+	}
+	
+	#[inline(always)]
+	pub(crate) fn deliver<'lifetime>(&self, now: MonotonicMillisecondTimestamp, packet: impl EthernetIncomingNetworkPacket, user_datagram_protocol_packet: &'lifetime UserDatagramProtocolPacket, layer_4_length: u16, internet_protocol_headers: &'lifetime [u8], internet_protocol_packet_host_addresses: InternetProtocolPacketHostAddresses, ethernet_addresses: &'lifetime EthernetAddresses) -> Result<(), UserDatagramProtocolIncomingNetworkPacketDropReason>
+	{
+		let header = &user_datagram_protocol_packet.header;
+		
+		let datagram = UserDatagramProtocolDatagram
+		{
+			internet_protocol_packet_host_addresses,
+			delivery: internet_protocol_packet_host_addresses.destination_needs_to_support(),
+			source_port: header.source_port(),
+			destination_port: header.destination_port(),
+			payload_length: layer_4_length - UserDatagramProtocolPacketHeader::HeaderSizeU16,
+		};
+		
+		let handler = match self.port_bindings.handler(&datagram)
+		{
+			None =>
+			{
+				if datagram.delivery == Layer4ProtocolNeedsToSupport::Unicast
+				{
+					if let Some(ref port_unreachable) = self.port_unreachable
+					{
+						let invoking_packet = unsafe { from_raw_parts(internet_protocol_headers.as_ptr(), internet_protocol_headers.len() + (layer_4_length as usize)) };
+						port_unreachable.respond(now, ethernet_addresses, &internet_protocol_packet_host_addresses, invoking_packet)
+					}
+				}
+				
+				return Err(DestinationPortIsNotBound { header: header.non_null() })
+			}
+			
+			Some(handler) => handler,
+		};
+		
+		let payload = user_datagram_protocol_packet.payload(datagram.payload_length);
+		
+		match handler
+		{
+			UserDatagramProtocolHandler::Callback(callback) => callback.received(now, &datagram, payload),
+			
+			UserDatagramProtocolHandler::PrecisionTimeProtocol(precision_time_protocol_packet_processing) => if let Err(reason) = precision_time_protocol_packet_processing.process_message(payload, packet.hardware_receive_timestamp())
+			{
+				return Err(PrecisionTimeProtocolMessageWasDropped { header: header.non_null(), reason })
+			},
+			
+			#[cfg(any(target_os = "android", target_os = "linux"))]
+			UserDatagramProtocolHandler::ReceiveQueue(receive_queue) => if unlikely!(receive_queue.enqueue(&datagram, payload).is_err())
+			{
+				return Err(ReceiveQueueIsFull { header: header.non_null() })
+			},
+		}
+		
+		packet.free_direct_contiguous_packet();
+		Ok(())
+	}
+}
@@ -0,0 +1,77 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// User Datagram Protocol (UDP) packet processing configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize)]
+pub struct UserDatagramProtocolPacketProcessingConfiguration
+{
+	/// Respond to unicast datagrams received for unbound ports with an Internet Control Message Protocol (ICMP) Port Unreachable message.
+	///
+	/// Defaults to true (RFC 1122 Section 4.1.3.1); responses are never sent for multicast or broadcast datagrams.
+	#[serde(default = "UserDatagramProtocolPacketProcessingConfiguration::respond_with_port_unreachable_default")] pub respond_with_port_unreachable: bool,
+	
+	/// Interval over which Port Unreachable messages are rate limited (RFC 4443 Section 2.4 (f)).
+	///
+	/// Defaults to 1 second.
+	#[serde(default = "UserDatagramProtocolPacketProcessingConfiguration::rate_limit_interval_default")] pub rate_limit_interval: MillisecondDuration,
+	
+	/// Maximum number of Port Unreachable messages sent in an interval.
+	///
+	/// Defaults to 10.
+	#[serde(default = "UserDatagramProtocolPacketProcessingConfiguration::maximum_port_unreachable_messages_per_rate_limit_interval_default")] pub maximum_port_unreachable_messages_per_rate_limit_interval: u16,
+}
+
+impl Default for UserDatagramProtocolPacketProcessingConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			respond_with_port_unreachable: Self::respond_with_port_unreachable_default(),
+			rate_limit_interval: Self::rate_limit_interval_default(),
+			maximum_port_unreachable_messages_per_rate_limit_interval: Self::maximum_port_unreachable_messages_per_rate_limit_interval_default(),
+		}
+	}
+}
+
+impl UserDatagramProtocolPacketProcessingConfiguration
+{
+	/// Configure.
+	///
+	/// `port_unreachable_transmitter` is only used if `respond_with_port_unreachable` is true.
+	#[inline(always)]
+	pub fn configure(self, port_unreachable_transmitter: Option<Rc<dyn UserDatagramProtocolPortUnreachableTransmitter>>) -> UserDatagramProtocolPacketProcessing
+	{
+		let port_unreachable = if self.respond_with_port_unreachable
+		{
+			port_unreachable_transmitter.map(|transmitter| UserDatagramProtocolPortUnreachable::new(transmitter, self.rate_limit_interval, self.maximum_port_unreachable_messages_per_rate_limit_interval))
+		}
+		else
+		{
+			None
+		};
+		
+		UserDatagramProtocolPacketProcessing::new(port_unreachable)
+	}
+	
+	#[inline(always)]
+	fn respond_with_port_unreachable_default() -> bool
+	{
+		true
+	}
+	
+	#[inline(always)]
+	fn rate_limit_interval_default() -> MillisecondDuration
+	{
+		MillisecondDuration::OneSecond
+	}
+	
+	#[inline(always)]
+	fn maximum_port_unreachable_messages_per_rate_limit_interval_default() -> u16
+	{
+		10
+	}
+}
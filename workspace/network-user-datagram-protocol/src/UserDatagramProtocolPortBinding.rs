@@ -0,0 +1,36 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct UserDatagramProtocolPortBinding
+{
+	handler: UserDatagramProtocolHandler,
+	supports: HashSet<Layer4ProtocolNeedsToSupport>,
+}
+
+impl UserDatagramProtocolPortBinding
+{
+	#[inline(always)]
+	pub(crate) fn new(handler: UserDatagramProtocolHandler, supports: HashSet<Layer4ProtocolNeedsToSupport>) -> Self
+	{
+		Self
+		{
+			handler,
+			supports,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn handler_if_supports(&self, delivery: Layer4ProtocolNeedsToSupport) -> Option<UserDatagramProtocolHandler>
+	{
+		if self.supports.contains(&delivery)
+		{
+			Some(self.handler.clone())
+		}
+		else
+		{
+			None
+		}
+	}
+}
@@ -0,0 +1,92 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Maps a local address (or the wildcard address) and port to a handler.
+///
+/// A binding to a specific local address takes precedence over one to the wildcard address.
+#[derive(Debug, Default)]
+pub struct UserDatagramProtocolPortBindings
+{
+	bindings: RefCell<HashMap<(UserDatagramProtocolLocalAddress, u16), UserDatagramProtocolPortBinding>>,
+}
+
+impl UserDatagramProtocolPortBindings
+{
+	/// Binds a port.
+	///
+	/// `supports` restricts delivery to unicast, multicast and / or broadcast datagrams; datagrams of other kinds are treated as if the port is not bound.
+	///
+	/// Returns an error if the local address and port are already bound.
+	#[inline(always)]
+	pub fn bind(&self, local_address: UserDatagramProtocolLocalAddress, port: u16, supports: HashSet<Layer4ProtocolNeedsToSupport>, handler: UserDatagramProtocolHandler) -> Result<(), ()>
+	{
+		let mut bindings = self.bindings.borrow_mut();
+		
+		let key = (local_address, port);
+		if bindings.contains_key(&key)
+		{
+			return Err(())
+		}
+		
+		bindings.insert(key, UserDatagramProtocolPortBinding::new(handler, supports));
+		Ok(())
+	}
+	
+	/// Binds the Precision Time Protocol (PTP) event and general message ports, 319 and 320 (IEEE 1588-2008, Annexes D and E), for unicast and multicast datagrams.
+	///
+	/// Returns an error, binding neither, if either the local address and port 319 or the local address and port 320 are already bound.
+	#[inline(always)]
+	pub fn bind_precision_time_protocol(&self, local_address: UserDatagramProtocolLocalAddress, precision_time_protocol_packet_processing: &Rc<PrecisionTimeProtocolPacketProcessing>) -> Result<(), ()>
+	{
+		let supports = || [Layer4ProtocolNeedsToSupport::Unicast, Layer4ProtocolNeedsToSupport::Multicast].iter().cloned().collect::<HashSet<_>>();
+		let handler = UserDatagramProtocolHandler::PrecisionTimeProtocol(precision_time_protocol_packet_processing.clone());
+		
+		self.bind(local_address, PrecisionTimeProtocolPacketProcessing::EventUserDatagramProtocolPort, supports(), handler.clone())?;
+		
+		if self.bind(local_address, PrecisionTimeProtocolPacketProcessing::GeneralUserDatagramProtocolPort, supports(), handler).is_err()
+		{
+			self.unbind(local_address, PrecisionTimeProtocolPacketProcessing::EventUserDatagramProtocolPort);
+			return Err(())
+		}
+		
+		Ok(())
+	}
+	
+	/// Unbinds a port.
+	///
+	/// Returns `true` if it was bound.
+	#[inline(always)]
+	pub fn unbind(&self, local_address: UserDatagramProtocolLocalAddress, port: u16) -> bool
+	{
+		self.bindings.borrow_mut().remove(&(local_address, port)).is_some()
+	}
+	
+	/// Is the local address and port bound?
+	#[inline(always)]
+	pub fn is_bound(&self, local_address: UserDatagramProtocolLocalAddress, port: u16) -> bool
+	{
+		self.bindings.borrow().contains_key(&(local_address, port))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn handler(&self, datagram: &UserDatagramProtocolDatagram) -> Option<UserDatagramProtocolHandler>
+	{
+		let bindings = self.bindings.borrow();
+		
+		let destination_port = datagram.destination_port;
+		let destination = UserDatagramProtocolLocalAddress::destination(&datagram.internet_protocol_packet_host_addresses);
+		
+		match bindings.get(&(destination, destination_port))
+		{
+			Some(binding) => binding.handler_if_supports(datagram.delivery),
+			
+			None => match bindings.get(&(UserDatagramProtocolLocalAddress::Any, destination_port))
+			{
+				Some(binding) => binding.handler_if_supports(datagram.delivery),
+				
+				None => None,
+			}
+		}
+	}
+}
@@ -0,0 +1,164 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Sends Internet Control Message Protocol (ICMP) Destination Unreachable (Port Unreachable) messages for unicast datagrams received for unbound ports (RFC 1122 Section 4.1.3.1).
+///
+/// Messages quote the datagram as received, from the start of its Internet Protocol (IP) header, truncated so that the message fits in 576 bytes for IP version 4 (RFC 1812 Section 4.3.2.3) or the minimum MTU of 1280 bytes for IP version 6 (RFC 4443 Section 2.4 (c)).
+///
+/// Messages are not sent for datagrams received as ethernet multicast or broadcast, or from a source address that does not identify a single host (RFC 1122 Section 3.2.2), and are rate limited (RFC 4443 Section 2.4 (f)).
+#[derive(Debug)]
+pub struct UserDatagramProtocolPortUnreachable
+{
+	transmitter: Rc<dyn UserDatagramProtocolPortUnreachableTransmitter>,
+	
+	rate_limit_interval: MillisecondDuration,
+	
+	maximum_messages_per_rate_limit_interval: u16,
+	
+	rate_limit_interval_started_at: Cell<MonotonicMillisecondTimestamp>,
+	
+	messages: Cell<u16>,
+}
+
+impl UserDatagramProtocolPortUnreachable
+{
+	const HeaderSize: usize = 8;
+	
+	const InternetControlMessageProtocolVersion4MaximumQuoteSize: usize = 576 - 20 - Self::HeaderSize;
+	
+	const InternetControlMessageProtocolVersion6MaximumQuoteSize: usize = 1280 - 40 - Self::HeaderSize;
+	
+	const DestinationUnreachableVersion4Type: u8 = 3;
+	
+	const PortUnreachableVersion4Code: u8 = 3;
+	
+	const DestinationUnreachableVersion6Type: u8 = 1;
+	
+	const PortUnreachableVersion6Code: u8 = 4;
+	
+	/// Creates a new instance.
+	///
+	/// At most `maximum_messages_per_rate_limit_interval` messages are sent in each `rate_limit_interval`.
+	#[inline(always)]
+	pub fn new(transmitter: Rc<dyn UserDatagramProtocolPortUnreachableTransmitter>, rate_limit_interval: MillisecondDuration, maximum_messages_per_rate_limit_interval: u16) -> Self
+	{
+		Self
+		{
+			transmitter,
+			rate_limit_interval,
+			maximum_messages_per_rate_limit_interval,
+			rate_limit_interval_started_at: Cell::new(MonotonicMillisecondTimestamp::Zero),
+			messages: Cell::new(0),
+		}
+	}
+	
+	/// Builds an ICMP version 4 or ICMP version 6 Port Unreachable message, including its check sum.
+	///
+	/// `internet_protocol_packet_host_addresses` are those of the datagram received.
+	///
+	/// `invoking_packet` is the datagram received, from the start of its IP header (including any IP version 6 extension headers) to the end of its User Datagram Protocol (UDP) payload.
+	#[inline]
+	pub fn message(internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses, invoking_packet: &[u8]) -> Vec<u8>
+	{
+		match *internet_protocol_packet_host_addresses
+		{
+			InternetProtocolPacketHostAddresses::Version4 { .. } =>
+			{
+				let quote = &invoking_packet[.. min(invoking_packet.len(), Self::InternetControlMessageProtocolVersion4MaximumQuoteSize)];
+				
+				let mut message = Self::header(Self::DestinationUnreachableVersion4Type, Self::PortUnreachableVersion4Code, quote);
+				let message_length = message.len();
+				let check_sum = Rfc1141CompliantCheckSum::finalize(Rfc1141CompliantCheckSum::from_data_check_sum_partial(unsafe { NonNull::new_unchecked(message.as_mut_ptr()) }, message_length, 0));
+				Self::set_check_sum(&mut message, check_sum);
+				message
+			}
+			
+			InternetProtocolPacketHostAddresses::Version6 { source, destination } =>
+			{
+				let quote = &invoking_packet[.. min(invoking_packet.len(), Self::InternetControlMessageProtocolVersion6MaximumQuoteSize)];
+				
+				let mut message = Self::header(Self::DestinationUnreachableVersion6Type, Self::PortUnreachableVersion6Code, quote);
+				let message_length = message.len();
+				
+				// The message is sent from the datagram's destination to its source.
+				let check_sum = InternetProtocolVersion6PseudoHeader::internet_protocol_version_6_layer_4_check_sum(&destination, &source, unsafe { NonNull::new_unchecked(message.as_mut_ptr()) }, message_length, Layer4ProtocolNumber::InternetControlMessageProtocolIpV6);
+				Self::set_check_sum(&mut message, check_sum);
+				message
+			}
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn respond(&self, now: MonotonicMillisecondTimestamp, ethernet_addresses: &EthernetAddresses, internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses, invoking_packet: &[u8])
+	{
+		if unlikely!(ethernet_addresses.destination.is_multicast_or_broadcast())
+		{
+			return
+		}
+		
+		if unlikely!(Self::source_is_not_a_single_host(internet_protocol_packet_host_addresses))
+		{
+			return
+		}
+		
+		if unlikely!(self.is_rate_limit_exceeded(now))
+		{
+			return
+		}
+		
+		let message = Self::message(internet_protocol_packet_host_addresses, invoking_packet);
+		self.transmitter.transmit(&Self::reverse(internet_protocol_packet_host_addresses), &message)
+	}
+	
+	#[inline(always)]
+	fn source_is_not_a_single_host(internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses) -> bool
+	{
+		match *internet_protocol_packet_host_addresses
+		{
+			InternetProtocolPacketHostAddresses::Version4 { source, .. } => source.is_unspecified() || source.is_loopback() || source.is_multicast() || source.is_broadcast(),
+			
+			InternetProtocolPacketHostAddresses::Version6 { ref source, .. } => source.is_unspecified() || source.is_loopback() || source.is_multicast().is_some(),
+		}
+	}
+	
+	#[inline(always)]
+	fn is_rate_limit_exceeded(&self, now: MonotonicMillisecondTimestamp) -> bool
+	{
+		if now - self.rate_limit_interval_started_at.get() >= self.rate_limit_interval
+		{
+			self.rate_limit_interval_started_at.set(now);
+			self.messages.set(0)
+		}
+		
+		let messages = self.messages.get().saturating_add(1);
+		self.messages.set(messages);
+		messages > self.maximum_messages_per_rate_limit_interval
+	}
+	
+	#[inline(always)]
+	fn reverse(internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses) -> InternetProtocolPacketHostAddresses
+	{
+		match *internet_protocol_packet_host_addresses
+		{
+			InternetProtocolPacketHostAddresses::Version4 { source, destination } => InternetProtocolPacketHostAddresses::Version4 { source: destination, destination: source },
+			
+			InternetProtocolPacketHostAddresses::Version6 { source, destination } => InternetProtocolPacketHostAddresses::Version6 { source: destination, destination: source },
+		}
+	}
+	
+	#[inline(always)]
+	fn header(type_: u8, code: u8, quote: &[u8]) -> Vec<u8>
+	{
+		let mut message = Vec::with_capacity(Self::HeaderSize + quote.len());
+		message.extend_from_slice(&[type_, code, 0, 0, 0, 0, 0, 0]);
+		message.extend_from_slice(quote);
+		message
+	}
+	
+	#[inline(always)]
+	fn set_check_sum(message: &mut [u8], check_sum: Rfc1141CompliantCheckSum)
+	{
+		message[2 .. 4].copy_from_slice(check_sum.to_network_endian().bytes());
+	}
+}
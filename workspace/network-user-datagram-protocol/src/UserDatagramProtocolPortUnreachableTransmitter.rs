@@ -0,0 +1,14 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// Transmits an Internet Control Message Protocol (ICMP) Destination Unreachable (Port Unreachable) message.
+pub trait UserDatagramProtocolPortUnreachableTransmitter: Debug
+{
+	/// `internet_protocol_packet_host_addresses` are those of the message, ie the reverse of those of the datagram received for an unbound port.
+	///
+	/// `internet_control_message_protocol_message` is a complete ICMP version 4 or ICMP version 6 message, including its check sum; it should be sent in an Internet Protocol (IP) packet with a protocol (next header) of 1 or 58 respectively.
+	///
+	/// `internet_control_message_protocol_message` is only valid for the duration of this call.
+	fn transmit(&self, internet_protocol_packet_host_addresses: &InternetProtocolPacketHostAddresses, internet_control_message_protocol_message: &[u8]);
+}
@@ -0,0 +1,91 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+/// A bounded receive queue of datagrams, built on a magic ring buffer.
+///
+/// Each datagram is copied once, into the magic ring buffer; thereafter it is read in place, as records in a magic ring buffer never wrap.
+///
+/// Datagrams received when the queue is full are dropped.
+#[derive(Debug)]
+pub struct UserDatagramProtocolReceiveQueue
+{
+	magic_ring_buffer: RefCell<MagicRingBuffer>,
+}
+
+impl UserDatagramProtocolReceiveQueue
+{
+	const DatagramSize: usize = size_of::<UserDatagramProtocolDatagram>();
+	
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(magic_ring_buffer: MagicRingBuffer) -> Self
+	{
+		Self
+		{
+			magic_ring_buffer: RefCell::new(magic_ring_buffer),
+		}
+	}
+	
+	/// Is the queue empty?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		let used: usize = self.magic_ring_buffer.borrow().used().into();
+		used == 0
+	}
+	
+	/// Receives the oldest datagram, if any, removing it from the queue once `receiver` returns.
+	///
+	/// `receiver` must not use this queue.
+	#[inline]
+	pub fn receive<R, Receiver: FnOnce(&UserDatagramProtocolDatagram, &[u8]) -> R>(&self, receiver: Receiver) -> Option<R>
+	{
+		if self.is_empty()
+		{
+			return None
+		}
+		
+		let mut magic_ring_buffer = self.magic_ring_buffer.borrow_mut();
+		
+		let (result, record_size) =
+		{
+			let read_buffer = magic_ring_buffer.read_buffer();
+			
+			let datagram = unsafe { read_unaligned(read_buffer.as_ptr() as *const UserDatagramProtocolDatagram) };
+			let record_size = Self::DatagramSize + (datagram.payload_length as usize);
+			let payload = &read_buffer[Self::DatagramSize .. record_size];
+			
+			(receiver(&datagram, payload), record_size)
+		};
+		
+		magic_ring_buffer.read_commit(Bytes::from(record_size));
+		Some(result)
+	}
+	
+	/// Copies `datagram` and `payload` into the magic ring buffer, the only copy made of the payload; the packet it came from can then be freed.
+	///
+	/// Fails, copying nothing, if there is not enough space.
+	#[inline(always)]
+	pub(crate) fn enqueue(&self, datagram: &UserDatagramProtocolDatagram, payload: &[u8]) -> Result<(), ()>
+	{
+		let mut magic_ring_buffer = self.magic_ring_buffer.borrow_mut();
+		
+		let record_size = Self::DatagramSize + payload.len();
+		
+		{
+			let write_buffer = magic_ring_buffer.write_buffer();
+			
+			if unlikely!(write_buffer.len() < record_size)
+			{
+				return Err(())
+			}
+			
+			unsafe { write_unaligned(write_buffer.as_mut_ptr() as *mut UserDatagramProtocolDatagram, *datagram) };
+			write_buffer[Self::DatagramSize .. record_size].copy_from_slice(payload);
+		}
+		
+		magic_ring_buffer.write_commit(Bytes::from(record_size));
+		Ok(())
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of network. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT. No part of network, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2016-2018 The developers of network. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/network/master/COPYRIGHT.
+
+
+#![allow(non_upper_case_globals)]
+#![deny(missing_docs)]
+#![feature(const_fn)]
+
+
+//! # network-user-datagram-protocol
+//!
+//! A domain model of User Datagram Protocol (UDP) packets and associated types.
+//!
+//! This crate has the optional feature `dpdk-sys`, which adds support for converting Into and From DPDK representations.
+
+
+#[cfg(feature = "dpdk-sys")] extern crate dpdk_sys;
+#[macro_use] extern crate likely;
+extern crate hashbrown;
+extern crate network_check_sum;
+extern crate network_collections;
+extern crate network_endian;
+extern crate network_ethernet;
+extern crate network_internet_protocol;
+extern crate network_packet;
+extern crate network_time;
+extern crate serde;
+#[macro_use] extern crate serde_derive;
+
+
+use self::UserDatagramProtocolIncomingNetworkPacketDropReason::*;
+#[cfg(feature = "dpdk-sys")] use dpdk_sys::*;
+use ::hashbrown::HashMap;
+use ::hashbrown::HashSet;
+use ::network_check_sum::InternetCheckSum;
+use ::network_check_sum::Rfc1141CompliantCheckSum;
+#[cfg(any(target_os = "android", target_os = "linux"))] use ::network_collections::magic_ring_buffer::Bytes;
+#[cfg(any(target_os = "android", target_os = "linux"))] use ::network_collections::magic_ring_buffer::MagicRingBuffer;
+use ::network_endian::*;
+use ::network_ethernet::EthernetAddresses;
+use ::network_ethernet::packet_processing::EthernetIncomingNetworkPacket;
+use ::network_ethernet::precision_time_protocol::PrecisionTimeProtocolDropReason;
+use ::network_ethernet::precision_time_protocol::PrecisionTimeProtocolPacketProcessing;
+use ::network_internet_protocol::InternetProtocolPacketHostAddresses;
+use ::network_internet_protocol::Layer4Packet;
+use ::network_internet_protocol::Layer4PacketProcessing;
+use ::network_internet_protocol::Layer4ProtocolNeedsToSupport;
+use ::network_internet_protocol::Layer4ProtocolNumber;
+use ::network_internet_protocol::version_4::InternetProtocolVersion4HostAddress;
+use ::network_internet_protocol::version_6::InternetProtocolVersion6HostAddress;
+use ::network_internet_protocol::version_6::InternetProtocolVersion6PseudoHeader;
+use ::network_packet::IncomingNetworkPacket;
+use ::network_packet::IncomingNetworkPacketProcessingDropReason;
+use ::network_time::MillisecondDuration;
+use ::network_time::MonotonicMillisecondTimestamp;
+use ::serde::Serialize;
+use ::serde::Serializer;
+use ::std::cell::Cell;
+use ::std::cell::RefCell;
+use ::std::cmp::min;
+use ::std::fmt;
+use ::std::fmt::Debug;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::marker::PhantomData;
+use ::std::mem::size_of;
+#[cfg(feature = "dpdk-sys")] use ::std::mem::transmute;
+use ::std::ptr::NonNull;
+#[cfg(any(target_os = "android", target_os = "linux"))] use ::std::ptr::read_unaligned;
+#[cfg(any(target_os = "android", target_os = "linux"))] use ::std::ptr::write_unaligned;
+use ::std::rc::Rc;
+use ::std::slice::from_raw_parts;
+
+
+include!("UserDatagramProtocolDatagram.rs");
+include!("UserDatagramProtocolDatagramCallback.rs");
+include!("UserDatagramProtocolHandler.rs");
+include!("UserDatagramProtocolIncomingNetworkPacketDropReason.rs");
+include!("UserDatagramProtocolLocalAddress.rs");
+include!("UserDatagramProtocolPacket.rs");
+include!("UserDatagramProtocolPacketHeader.rs");
+include!("UserDatagramProtocolPacketProcessing.rs");
+include!("UserDatagramProtocolPacketProcessingConfiguration.rs");
+include!("UserDatagramProtocolPortBinding.rs");
+include!("UserDatagramProtocolPortBindings.rs");
+include!("UserDatagramProtocolPortUnreachable.rs");
+include!("UserDatagramProtocolPortUnreachableTransmitter.rs");
+#[cfg(any(target_os = "android", target_os = "linux"))] include!("UserDatagramProtocolReceiveQueue.rs");